@@ -84,6 +84,13 @@ fn localnet_genesis(
         }
     }
 
+    // Subnets to create at genesis, read from a JSON file of pallet_subtensor::GenesisSubnet.
+    let mut subnets: Vec<pallet_subtensor::GenesisSubnet<AccountId>> = Vec::new();
+    if let Ok(subnets_path) = env::var("BT_GENESIS_SUBNETS") {
+        let file = File::open(&subnets_path).expect("Could not open BT_GENESIS_SUBNETS file");
+        subnets = json::from_reader(file).expect("Invalid format for BT_GENESIS_SUBNETS.");
+    }
+
     let trimvirate_members: Vec<AccountId> = bounded_vec![
         get_account_id_from_seed::<sr25519::Public>("Alice"),
         get_account_id_from_seed::<sr25519::Public>("Bob"),
@@ -116,5 +123,8 @@ fn localnet_genesis(
         "senateMembers": {
             "members": senate_members,
         },
+        "subtensorModule": {
+            "subnets": subnets,
+        },
     })
 }
@@ -66,7 +66,7 @@ where
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
-    use subtensor_custom_rpc::{SubtensorCustom, SubtensorCustomApiServer};
+    use subtensor_custom_rpc::{SubtensorCustom, SubtensorCustomApiServer, SubtensorJsonApiServer};
 
     let mut module = RpcModule::new(());
     let FullDeps {
@@ -78,7 +78,13 @@ where
     } = deps;
 
     // Custom RPC methods for Paratensor
-    module.merge(SubtensorCustom::new(client.clone()).into_rpc())?;
+    module.merge(SubtensorCustomApiServer::into_rpc(SubtensorCustom::new(
+        client.clone(),
+    )))?;
+    // The same runtime APIs decoded to JSON, under the subtensor_* namespace.
+    module.merge(SubtensorJsonApiServer::into_rpc(SubtensorCustom::new(
+        client.clone(),
+    )))?;
 
     module.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
     module.merge(TransactionPayment::new(client).into_rpc())?;
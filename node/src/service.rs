@@ -38,10 +38,14 @@ impl HostFunctions for ExecutorDispatch {
 impl sc_executor::NativeExecutionDispatch for ExecutorDispatch {
     // Only enable the benchmarking host functions when we actually want to benchmark.
     #[cfg(feature = "runtime-benchmarks")]
-    type ExtendHostFunctions = frame_benchmarking::benchmarking::HostFunctions;
-    // Otherwise we only use the default Substrate host functions.
+    type ExtendHostFunctions = (
+        frame_benchmarking::benchmarking::HostFunctions,
+        pallet_subtensor::epoch::host::subtensor_math::HostFunctions,
+    );
+    // Otherwise we only add the epoch math host functions to the default Substrate ones. They
+    // are registered whether or not the runtime uses them.
     #[cfg(not(feature = "runtime-benchmarks"))]
-    type ExtendHostFunctions = ();
+    type ExtendHostFunctions = pallet_subtensor::epoch::host::subtensor_math::HostFunctions;
 
     fn dispatch(method: &str, data: &[u8]) -> Option<Vec<u8>> {
         node_subtensor_runtime::api::dispatch(method, data)
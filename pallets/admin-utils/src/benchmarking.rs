@@ -34,9 +34,9 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn sudo_set_default_take() {
+    fn sudo_set_max_delegate_take() {
         #[extrinsic_call]
-		_(RawOrigin::Root, 100u16/*default_take*/)/*sudo_set_default_take*/;
+		_(RawOrigin::Root, 100u16/*max_take*/)/*sudo_set_max_delegate_take*/;
     }
 
     #[benchmark]
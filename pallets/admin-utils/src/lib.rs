@@ -17,7 +17,10 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_support::traits::tokens::Balance;
     use frame_system::pallet_prelude::*;
+    use sp_core::H256;
     use sp_runtime::BoundedVec;
+    use sp_std::collections::btree_set::BTreeSet;
+    use sp_std::vec::Vec;
 
     /// The main data structure of the module.
     #[pallet::pallet]
@@ -62,6 +65,24 @@ pub mod pallet {
         MaxValidatorsLargerThanMaxUIds,
         /// The maximum number of subnet validators must be more than the current number of UIDs already in the subnet.
         MaxAllowedUIdsLessThanCurrentUIds,
+        /// The maximum number of allowed UIDs must fit within the weights and bonds storage row bound.
+        MaxAllowedUidsExceedsWeightsRowLength,
+        /// The tempo must be greater than zero.
+        InvalidTempo,
+        /// The minimum stake to register exceeds MaxMinStakeToRegister.
+        MinStakeToRegisterTooHigh,
+        /// More hotkeys are reserved than MaxReservedUids allows.
+        TooManyReservedHotkeys,
+        /// The number of reservable hotkeys exceeds the MaxReservedHotkeys bound.
+        MaxReservedUidsTooHigh,
+        /// The default take is outside the min ..= max delegate take range.
+        DefaultTakeOutOfRange,
+        /// The min allowed weights of a subnet would exceed its max allowed weights.
+        MinAllowedWeightsAboveMax,
+        /// The emission tithe exceeds MAX_EMISSION_TITHE.
+        EmissionTitheTooHigh,
+        /// The bootstrap grant needs more validators than a new subnet may have.
+        BootstrapGrantMinValidatorsTooHigh,
     }
 
     /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -86,15 +107,16 @@ pub mod pallet {
             Ok(())
         }
 
-        /// The extrinsic sets the default take for the network.
+        /// The extrinsic sets the maximum delegate take for the network.
         /// It is only callable by the root account.
-        /// The extrinsic will call the Subtensor pallet to set the default take.
+        /// The extrinsic will call the Subtensor pallet to set the maximum delegate take.
+        /// The default take of new delegates is set by sudo_set_default_delegate_take.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::sudo_set_default_take())]
-        pub fn sudo_set_default_take(origin: OriginFor<T>, default_take: u16) -> DispatchResult {
+        #[pallet::weight(T::WeightInfo::sudo_set_max_delegate_take())]
+        pub fn sudo_set_max_delegate_take(origin: OriginFor<T>, max_take: u16) -> DispatchResult {
             ensure_root(origin)?;
-            pallet_subtensor::Pallet::<T>::set_max_delegate_take(default_take);
-            log::debug!("DefaultTakeSet( default_take: {:?} ) ", default_take);
+            pallet_subtensor::Pallet::<T>::set_max_delegate_take(max_take);
+            log::debug!("MaxDelegateTakeSet( max_take: {:?} ) ", max_take);
             Ok(())
         }
 
@@ -288,9 +310,9 @@ pub mod pallet {
             Ok(())
         }
 
-        /// The extrinsic sets the adjustment beta for a subnet.
+        /// The extrinsic sets the max weight limit for a subnet.
         /// It is only callable by the root account or subnet owner.
-        /// The extrinsic will call the Subtensor pallet to set the adjustment beta.
+        /// The new value is staged and takes effect at the subnet's next tempo boundary.
         #[pallet::call_index(12)]
         #[pallet::weight(T::WeightInfo::sudo_set_max_weight_limit())]
         pub fn sudo_set_max_weight_limit(
@@ -304,9 +326,9 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_max_weight_limit(netuid, max_weight_limit);
+            pallet_subtensor::Pallet::<T>::schedule_max_weight_limit(netuid, max_weight_limit);
             log::debug!(
-                "MaxWeightLimitSet( netuid: {:?} max_weight_limit: {:?} ) ",
+                "MaxWeightLimitScheduled( netuid: {:?} max_weight_limit: {:?} ) ",
                 netuid,
                 max_weight_limit
             );
@@ -354,6 +376,11 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
+            ensure!(
+                min_allowed_weights
+                    <= pallet_subtensor::Pallet::<T>::get_max_allowed_weights(netuid),
+                Error::<T>::MinAllowedWeightsAboveMax
+            );
             pallet_subtensor::Pallet::<T>::set_min_allowed_weights(netuid, min_allowed_weights);
             log::debug!(
                 "MinAllowedWeightSet( netuid: {:?} min_allowed_weights: {:?} ) ",
@@ -382,6 +409,11 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::get_subnetwork_n(netuid) < max_allowed_uids,
                 Error::<T>::MaxAllowedUIdsLessThanCurrentUIds
             );
+            ensure!(
+                max_allowed_uids as u32
+                    <= <T as pallet_subtensor::Config>::MaxWeightsRowLength::get(),
+                Error::<T>::MaxAllowedUidsExceedsWeightsRowLength
+            );
             pallet_subtensor::Pallet::<T>::set_max_allowed_uids(netuid, max_allowed_uids);
             log::debug!(
                 "MaxAllowedUidsSet( netuid: {:?} max_allowed_uids: {:?} ) ",
@@ -393,7 +425,7 @@ pub mod pallet {
 
         /// The extrinsic sets the kappa for a subnet.
         /// It is only callable by the root account or subnet owner.
-        /// The extrinsic will call the Subtensor pallet to set the kappa.
+        /// The new value is staged and takes effect at the subnet's next tempo boundary.
         #[pallet::call_index(16)]
         #[pallet::weight(T::WeightInfo::sudo_set_kappa())]
         pub fn sudo_set_kappa(origin: OriginFor<T>, netuid: u16, kappa: u16) -> DispatchResult {
@@ -403,8 +435,8 @@ pub mod pallet {
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_kappa(netuid, kappa);
-            log::debug!("KappaSet( netuid: {:?} kappa: {:?} ) ", netuid, kappa);
+            pallet_subtensor::Pallet::<T>::schedule_kappa(netuid, kappa);
+            log::debug!("KappaScheduled( netuid: {:?} kappa: {:?} ) ", netuid, kappa);
             Ok(())
         }
 
@@ -739,17 +771,24 @@ pub mod pallet {
         }
 
         /// The extrinsic sets the tempo for a subnet.
-        /// It is only callable by the root account.
-        /// The extrinsic will call the Subtensor pallet to set the tempo.
+        /// It is only callable by the root account or subnet owner. The subnet owner cannot go
+        /// below MinOwnerTempo and may change the tempo once per OwnerTempoChangeRateLimit.
+        /// The extrinsic will call the Subtensor pallet to change the tempo, anchoring the next
+        /// epoch one new tempo after the last so that no epoch is skipped or repeated.
         #[pallet::call_index(30)]
         #[pallet::weight(T::WeightInfo::sudo_set_tempo())]
         pub fn sudo_set_tempo(origin: OriginFor<T>, netuid: u16, tempo: u16) -> DispatchResult {
-            ensure_root(origin)?;
+            let is_root: bool = ensure_root(origin.clone()).is_ok();
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
-            pallet_subtensor::Pallet::<T>::set_tempo(netuid, tempo);
+            ensure!(tempo > 0, Error::<T>::InvalidTempo);
+            if !is_root {
+                pallet_subtensor::Pallet::<T>::ensure_owner_tempo_change(netuid, tempo)?;
+            }
+            pallet_subtensor::Pallet::<T>::change_tempo(netuid, tempo);
             log::debug!("TempoSet( netuid: {:?} tempo: {:?} ) ", netuid, tempo);
             Ok(())
         }
@@ -1035,6 +1074,7 @@ pub mod pallet {
         }
 
         /// Sets values for liquid alpha
+        /// The new values are staged and take effect at the subnet's next tempo boundary.
         #[pallet::call_index(51)]
         #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
         pub fn sudo_set_alpha_values(
@@ -1196,6 +1236,677 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Sets whether the pending emission share of neurons pruned mid-tempo is burned
+        /// instead of being paid out to the pruned hotkey's owner.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `burn` - True to burn the pruned neuron's share, false to pay it out.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(56)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_prune_emission_burn(
+            origin: OriginFor<T>,
+            netuid: u16,
+            burn: bool,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_prune_emission_burn(netuid, burn);
+            log::debug!(
+                "PruneEmissionBurnSet( netuid: {:?}, burn: {:?} ) ",
+                netuid,
+                burn
+            );
+            Ok(())
+        }
+
+        /// Sets the number of tempos over which a newly registered subnet's emission ramps up
+        /// to its full value. Zero disables the ramp.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `tempos` - The length of the ramp in tempos.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(57)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_emission_ramp_tempos(
+            origin: OriginFor<T>,
+            tempos: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_subnet_emission_ramp_tempos(tempos);
+            log::debug!("SubnetEmissionRampTemposSet( tempos: {:?} ) ", tempos);
+            Ok(())
+        }
+
+        /// Sets the fraction of block emission, normalized to u16::MAX, that is minted into
+        /// the treasury instead of the subnets.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `tithe` - The treasury share of block emission.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `EmissionTitheTooHigh` - If the tithe exceeds MAX_EMISSION_TITHE.
+        #[pallet::call_index(58)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_emission_tithe(origin: OriginFor<T>, tithe: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                tithe <= pallet_subtensor::coinbase::treasury::MAX_EMISSION_TITHE,
+                Error::<T>::EmissionTitheTooHigh
+            );
+            pallet_subtensor::Pallet::<T>::set_emission_tithe(tithe);
+            log::debug!("EmissionTitheSet( tithe: {:?} ) ", tithe);
+            Ok(())
+        }
+
+        /// Sets whether saturating arithmetic caught by the `safe-math-audit` feature emits a
+        /// diagnostic event.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `enabled` - True to emit an event on every audited saturation.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(59)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_safe_math_audit_events(
+            origin: OriginFor<T>,
+            enabled: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_safe_math_audit_events(enabled);
+            log::debug!("SafeMathAuditEventsSet( enabled: {:?} ) ", enabled);
+            Ok(())
+        }
+
+        /// Sets the minimum axon version of a subnet. Axons below it are hidden from metagraph
+        /// queries but stay registered.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `version` - The minimum axon version.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(60)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_axon_version(
+            origin: OriginFor<T>,
+            netuid: u16,
+            version: u32,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_min_axon_version(netuid, version);
+            log::debug!(
+                "MinAxonVersionSet( netuid: {:?}, version: {:?} ) ",
+                netuid,
+                version
+            );
+            Ok(())
+        }
+
+        /// Sets how burn registrations are priced on a subnet: a fixed burn adjusted every
+        /// interval, or a sealed-bid auction settled at the end of every interval. Leaving the
+        /// auction refunds the bids of the interval in progress.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `mechanism` - The registration mechanism.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(61)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_registration_mechanism(
+            origin: OriginFor<T>,
+            netuid: u16,
+            mechanism: pallet_subtensor::RegistrationMechanism,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_registration_mechanism(netuid, mechanism);
+            log::debug!(
+                "RegistrationMechanismSet( netuid: {:?}, mechanism: {:?} ) ",
+                netuid,
+                mechanism
+            );
+            Ok(())
+        }
+
+        /// Sets the number of blocks after registration before a neuron may set weights on a
+        /// subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `period` - The weights immunity period in blocks.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(62)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_weights_immunity_period(
+            origin: OriginFor<T>,
+            netuid: u16,
+            period: u64,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_weights_immunity_period(netuid, period);
+            log::debug!(
+                "WeightsImmunityPeriodSet( netuid: {:?}, period: {:?} ) ",
+                netuid,
+                period
+            );
+            Ok(())
+        }
+
+        /// Sets whether accounts acting as their own hotkey and coldkey are rejected when
+        /// registering or staking. Hotkeys owned by another coldkey are always rejected as
+        /// coldkeys.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `strict` - True to also reject self-owned combined keys.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(63)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_strict_key_usage_separation(
+            origin: OriginFor<T>,
+            strict: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_strict_key_usage_separation(strict);
+            log::debug!("StrictKeyUsageSeparationSet( strict: {:?} ) ", strict);
+            Ok(())
+        }
+
+        /// Disables or re-enables a subnet's emission. While disabled the subnet keeps running
+        /// but its emission is burned, which sanctions an exploited subnet without dissolving it.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `disabled` - True to burn the subnet's emission.
+        /// * `reenable_block` - The block emission resumes at, or None to disable indefinitely.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(64)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_subnet_emission_disabled(
+            origin: OriginFor<T>,
+            netuid: u16,
+            disabled: bool,
+            reenable_block: Option<u64>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_subnet_emission_disabled(
+                netuid,
+                disabled,
+                reenable_block,
+            );
+            log::debug!(
+                "EmissionDisabledSet( netuid: {:?}, disabled: {:?}, reenable_block: {:?} ) ",
+                netuid,
+                disabled,
+                reenable_block
+            );
+            Ok(())
+        }
+
+        /// Sets the stake a coldkey must hold to register a neuron on a subnet. The stake is
+        /// checked, not spent.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `min_stake` - The minimum stake to register, in rao.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `MinStakeToRegisterTooHigh` - If the stake exceeds MaxMinStakeToRegister.
+        #[pallet::call_index(65)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_min_stake_to_register(
+            origin: OriginFor<T>,
+            netuid: u16,
+            min_stake: u64,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                min_stake <= pallet_subtensor::Pallet::<T>::get_max_min_stake_to_register(),
+                Error::<T>::MinStakeToRegisterTooHigh
+            );
+
+            pallet_subtensor::Pallet::<T>::set_min_stake_to_register(netuid, min_stake);
+            log::debug!(
+                "MinStakeToRegisterSet( netuid: {:?}, min_stake: {:?} ) ",
+                netuid,
+                min_stake
+            );
+            Ok(())
+        }
+
+        /// Sets the upper bound on the minimum stake to register a subnet owner may set.
+        /// Subnets already requiring more keep their value until it is next set.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `max_min_stake` - The upper bound, in rao.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        #[pallet::call_index(66)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_min_stake_to_register(
+            origin: OriginFor<T>,
+            max_min_stake: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            pallet_subtensor::Pallet::<T>::set_max_min_stake_to_register(max_min_stake);
+            log::debug!(
+                "MaxMinStakeToRegisterSet( max_min_stake: {:?} ) ",
+                max_min_stake
+            );
+            Ok(())
+        }
+
+        /// Sets the hash registrations on a subnet must echo, such as the hash of an off-chain
+        /// task spec, or clears it. Registration bids carry no challenge and are rejected while
+        /// a commit is set.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `commit` - The challenge commit, or None to accept any registration.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(67)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_registration_challenge_commit(
+            origin: OriginFor<T>,
+            netuid: u16,
+            commit: Option<H256>,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_registration_challenge_commit(netuid, commit);
+            log::debug!(
+                "RegistrationChallengeCommitSet( netuid: {:?}, commit: {:?} ) ",
+                netuid,
+                commit
+            );
+            Ok(())
+        }
+
+        /// Sets the hotkeys a subnet holds uids for, such as the owner's own validators. Until
+        /// a reserved hotkey registers, its slot is not given to other hotkeys, and once it does
+        /// its uid is never pruned. Repeated hotkeys are kept once, and the set replaces any
+        /// previous one.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `hotkeys` - The reserved hotkeys, at most MaxReservedHotkeys of them.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `TooManyReservedHotkeys` - If more hotkeys are given than MaxReservedUids.
+        #[pallet::call_index(68)]
+        #[pallet::weight(Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(200_000, 0).saturating_mul(hotkeys.len() as u64))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1)))]
+        pub fn sudo_set_reserved_hotkeys(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkeys: pallet_subtensor::ReservedHotkeysOf<T>,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            let mut seen: BTreeSet<T::AccountId> = BTreeSet::new();
+            let mut reserved = hotkeys;
+            reserved.retain(|hotkey| seen.insert(hotkey.clone()));
+            ensure!(
+                reserved.len() <= pallet_subtensor::Pallet::<T>::get_max_reserved_uids() as usize,
+                Error::<T>::TooManyReservedHotkeys
+            );
+
+            log::debug!(
+                "ReservedHotkeysSet( netuid: {:?}, hotkeys: {:?} ) ",
+                netuid,
+                reserved
+            );
+            pallet_subtensor::Pallet::<T>::set_reserved_hotkeys(netuid, reserved);
+            Ok(())
+        }
+
+        /// Sets the number of hotkeys a subnet owner may reserve against pruning. Subnets
+        /// already reserving more keep their set until it is next changed.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `max_reserved` - The number of hotkeys.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `MaxReservedUidsTooHigh` - If the number exceeds MaxReservedHotkeys.
+        #[pallet::call_index(69)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_reserved_uids(
+            origin: OriginFor<T>,
+            max_reserved: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                max_reserved as u32 <= <T as pallet_subtensor::Config>::MaxReservedHotkeys::get(),
+                Error::<T>::MaxReservedUidsTooHigh
+            );
+
+            pallet_subtensor::Pallet::<T>::set_max_reserved_uids(max_reserved);
+            log::debug!("MaxReservedUidsSet( max_reserved: {:?} ) ", max_reserved);
+            Ok(())
+        }
+
+        /// Sets whether validator permits on a subnet only go to neurons that updated their
+        /// weights within the activity cutoff. Permits of stale validators then pass to the next
+        /// highest-stake active candidates at each epoch.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `active_permits_only` - Whether only active neurons receive permits.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        #[pallet::call_index(70)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_active_permits_only(
+            origin: OriginFor<T>,
+            netuid: u16,
+            active_permits_only: bool,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_active_permits_only(netuid, active_permits_only);
+            log::debug!(
+                "ActivePermitsOnlySet( netuid: {:?}, active_permits_only: {:?} ) ",
+                netuid,
+                active_permits_only
+            );
+            Ok(())
+        }
+
+        /// Sets the maximum number of weights a neuron may set on a subnet. Weights are still
+        /// capped at the number of neurons on the subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `max_allowed_weights` - The new maximum number of weights.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `MinAllowedWeightsAboveMax` - If the maximum is below the min allowed weights.
+        #[pallet::call_index(72)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_allowed_weights(
+            origin: OriginFor<T>,
+            netuid: u16,
+            max_allowed_weights: u16,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                max_allowed_weights
+                    >= pallet_subtensor::Pallet::<T>::get_min_allowed_weights(netuid),
+                Error::<T>::MinAllowedWeightsAboveMax
+            );
+
+            pallet_subtensor::Pallet::<T>::set_max_allowed_weights(netuid, max_allowed_weights);
+            log::debug!(
+                "MaxAllowedWeightsSet( netuid: {:?}, max_allowed_weights: {:?} ) ",
+                netuid,
+                max_allowed_weights
+            );
+            Ok(())
+        }
+
+        /// Stages several hyperparameter changes that are applied together at the subnet's next
+        /// tempo boundary, so the subnet never runs an epoch with some of them applied and
+        /// others not. A bundle replaces any bundle staged earlier in the same tempo.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `bundle` - The hyperparameters to change; fields left None keep their value.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        /// * `MinAllowedWeightsAboveMax` - If the resulting min allowed weights exceed the max.
+        #[pallet::call_index(73)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn set_hyperparam_bundle(
+            origin: OriginFor<T>,
+            netuid: u16,
+            bundle: pallet_subtensor::HyperparamBundle,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            let min_allowed_weights: u16 = bundle
+                .min_allowed_weights
+                .unwrap_or_else(|| pallet_subtensor::Pallet::<T>::get_min_allowed_weights(netuid));
+            let max_allowed_weights: u16 = bundle
+                .max_allowed_weights
+                .unwrap_or_else(|| pallet_subtensor::Pallet::<T>::get_max_allowed_weights(netuid));
+            ensure!(
+                min_allowed_weights <= max_allowed_weights,
+                Error::<T>::MinAllowedWeightsAboveMax
+            );
+
+            log::debug!(
+                "HyperparamBundleScheduled( netuid: {:?}, bundle: {:?} ) ",
+                netuid,
+                bundle
+            );
+            pallet_subtensor::Pallet::<T>::schedule_hyperparam_bundle(netuid, bundle);
+            Ok(())
+        }
+
+        /// The extrinsic sets the bootstrap grant parameters for new subnets.
+        /// It is only callable by the root account.
+        /// The minimum validators may not exceed the max allowed validators of a new subnet.
+        /// The extrinsic will call the Subtensor pallet to set the bootstrap grant parameters.
+        #[pallet::call_index(74)]
+        #[pallet::weight((
+			Weight::from_parts(14_000_000, 0)
+				.saturating_add(T::DbWeight::get().writes(4)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+        pub fn sudo_set_bootstrap_grant(
+            origin: OriginFor<T>,
+            share: u16,
+            tempos: u16,
+            min_validators: u16,
+            min_miners: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                min_validators
+                    <= <T as pallet_subtensor::Config>::InitialMaxAllowedValidators::get(),
+                Error::<T>::BootstrapGrantMinValidatorsTooHigh
+            );
+
+            pallet_subtensor::Pallet::<T>::set_bootstrap_grant_params(
+                share,
+                tempos,
+                min_validators,
+                min_miners,
+            );
+
+            log::debug!(
+                "BootstrapGrant( share: {:?}, tempos: {:?}, min_validators: {:?}, min_miners: {:?} ) ",
+                share,
+                tempos,
+                min_validators,
+                min_miners
+            );
+
+            Ok(())
+        }
+
+        /// Configures the flagging of UIDs whose weights are degenerate for consecutive tempos.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account or subnet owner.
+        /// * `netuid` - The unique identifier for the subnet.
+        /// * `tempos` - Consecutive tempos of degenerate weights that flag a UID, 0 to disable.
+        /// * `duplicate_keys` - UIDs sharing the same weights for them to be flagged, at least 2.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither root nor the subnet owner.
+        /// * `SubnetDoesNotExist` - If the subnet does not exist.
+        ///
+        /// Disabling the flagging clears a streak and a flag per UID, at most
+        /// `MaxWeightsRowLength` of each, so the call is charged for that many.
+        #[pallet::call_index(75)]
+        #[pallet::weight(Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(
+                (<T as pallet_subtensor::Config>::MaxWeightsRowLength::get() as u64)
+                    .saturating_mul(2)
+                    .saturating_add(2)
+            )))]
+        pub fn sudo_set_weight_flagging(
+            origin: OriginFor<T>,
+            netuid: u16,
+            tempos: u16,
+            duplicate_keys: u16,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_weight_flagging(netuid, tempos, duplicate_keys);
+            log::debug!(
+                "WeightFlaggingSet( netuid: {:?}, tempos: {:?}, duplicate_keys: {:?} ) ",
+                netuid,
+                tempos,
+                duplicate_keys
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the default delegate take, given to new delegates and to hotkeys
+        /// that never set a take. It must lie within the min ..= max delegate take.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the default delegate take.
+        #[pallet::call_index(76)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_default_delegate_take(
+            origin: OriginFor<T>,
+            default_take: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                default_take >= pallet_subtensor::Pallet::<T>::get_min_delegate_take()
+                    && default_take <= pallet_subtensor::Pallet::<T>::get_max_delegate_take(),
+                Error::<T>::DefaultTakeOutOfRange
+            );
+            pallet_subtensor::Pallet::<T>::set_default_delegate_take(default_take);
+            log::debug!(
+                "DefaultDelegateTakeSet( default_take: {:?} ) ",
+                default_take
+            );
+            Ok(())
+        }
     }
 }
 
@@ -35,7 +35,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn swap_authorities(a: u32, ) -> Weight;
     fn sudo_set_min_delegate_take() -> Weight;
-    fn sudo_set_default_take() -> Weight;
+    fn sudo_set_max_delegate_take() -> Weight;
 	fn sudo_set_serving_rate_limit() -> Weight;
 	fn sudo_set_max_difficulty() -> Weight;
 	fn sudo_set_min_difficulty() -> Weight;
@@ -83,9 +83,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: SubtensorModule DefaultTake (r:0 w:1)
-	/// Proof Skipped: SubtensorModule DefaultTake (max_values: Some(1), max_size: None, mode: Measured)
-	fn sudo_set_default_take() -> Weight {
+	/// Storage: SubtensorModule MaxDelegateTake (r:0 w:1)
+	/// Proof Skipped: SubtensorModule MaxDelegateTake (max_values: Some(1), max_size: None, mode: Measured)
+	fn sudo_set_max_delegate_take() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `655`
 		//  Estimated: `655`
@@ -451,9 +451,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	/// Storage: SubtensorModule DefaultTake (r:0 w:1)
-	/// Proof Skipped: SubtensorModule DefaultTake (max_values: Some(1), max_size: None, mode: Measured)
-	fn sudo_set_default_take() -> Weight {
+	/// Storage: SubtensorModule MaxDelegateTake (r:0 w:1)
+	/// Proof Skipped: SubtensorModule MaxDelegateTake (max_values: Some(1), max_size: None, mode: Measured)
+	fn sudo_set_max_delegate_take() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `655`
 		//  Estimated: `655`
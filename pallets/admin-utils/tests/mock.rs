@@ -1,17 +1,19 @@
 #![allow(clippy::arithmetic_side_effects, clippy::unwrap_used)]
 
+use codec::{Decode, Encode};
 use frame_support::{
     assert_ok, derive_impl, parameter_types,
-    traits::{Everything, Hooks, PrivilegeCmp},
+    traits::{Everything, Hooks, PrivilegeCmp, Randomness},
     weights,
 };
 use frame_system as system;
 use frame_system::{limits, EnsureNever, EnsureRoot};
+use scale_info::TypeInfo;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::U256;
 use sp_core::{ConstU64, H256};
 use sp_runtime::{
-    traits::{BlakeTwo256, ConstU32, IdentityLookup},
+    traits::{BlakeTwo256, ConstU32, Hash, IdentifyAccount, IdentityLookup, Lazy, Verify},
     BuildStorage, Perbill,
 };
 use sp_std::cmp::Ordering;
@@ -26,7 +28,7 @@ frame_support::construct_runtime!(
         System: frame_system,
         Balances: pallet_balances,
         AdminUtils: pallet_admin_utils,
-        SubtensorModule: pallet_subtensor::{Pallet, Call, Storage, Event<T>, Error<T>},
+        SubtensorModule: pallet_subtensor::{Pallet, Call, Storage, Event<T>, Error<T>, ValidateUnsigned},
         Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
     }
 );
@@ -63,6 +65,38 @@ pub type Balance = u64;
 #[allow(dead_code)]
 pub type BlockNumber = u64;
 
+/// Public key of a test weights signature: the hotkey account itself.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct TestWeightsSigner(pub U256);
+
+impl IdentifyAccount for TestWeightsSigner {
+    type AccountId = U256;
+    fn into_account(self) -> U256 {
+        self.0
+    }
+}
+
+/// Test weights signature: the signing hotkey and the hash of the signed payload.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct TestWeightsSignature(pub U256, pub H256);
+
+impl Verify for TestWeightsSignature {
+    type Signer = TestWeightsSigner;
+    fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &U256) -> bool {
+        self.0 == *signer && self.1 == BlakeTwo256::hash(msg.get())
+    }
+}
+
+/// Test randomness: the hash of the subject and the current block.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        let block: u64 = System::block_number();
+        (BlakeTwo256::hash_of(&(subject, block)), block)
+    }
+}
+
 parameter_types! {
     pub const InitialMinAllowedWeights: u16 = 0;
     pub const InitialEmissionValue: u16 = 0;
@@ -125,6 +159,31 @@ parameter_types! {
     pub const InitialNetworkMaxStake: u64 = u64::MAX; // Maximum possible value for u64, this make the make stake infinity
     pub const InitialColdkeySwapScheduleDuration: u64 = 5 * 24 * 60 * 60 / 12; // 5 days
     pub const InitialDissolveNetworkScheduleDuration: u64 = 5 * 24 * 60 * 60 / 12; // 5 days
+    pub const MaxWeightsRowLength: u32 = 4096;
+    pub const TakeChangeProposalExpiry: u64 = 10;
+    pub const MetricsRetentionBlocks: u64 = 10;
+    pub const InitialSubnetEmissionRampTempos: u16 = 0; // tempos before a new subnet receives its full emission
+    pub const ConvictionLockPeriod: u64 = 10;
+    pub const StakeCheckpointInterval: u64 = 10;
+    pub const MaxStakeCheckpoints: u32 = 5;
+    pub const BlocksPerYear: u64 = 7200 * 365;
+    pub const DelegateInactivityGracePeriod: u64 = 10;
+    pub const MaxAuxScoreKinds: u16 = 4;
+    pub const BlockStepWeightLimit: Weight = Weight::MAX;
+    pub const DelegateStatsInterval: u64 = 10;
+    pub const InitialMaxMinStakeToRegister: u64 = 1_000_000_000; // 1000 TAO
+    pub const InitialMaxReservedUids: u16 = 4;
+    pub const MaxPortfolioHotkeys: u32 = 4;
+    pub const TakeIncreaseScheduleDuration: BlockNumber = 100;
+    pub const TreasuryProposalDeposit: u64 = 100;
+    pub const MaxRegistrationBids: u32 = 8;
+    pub const RandomnessBeaconEnabled: bool = true;
+    pub const RegistrationBidDeposit: u64 = 10;
+    pub const KeepStakingAccountsAlive: bool = true;
+    pub const PublicGoodReleaseDelay: u64 = 100;
+    pub const MinOwnerTempo: u16 = 2;
+    pub const OwnerTempoChangeRateLimit: u64 = 10;
+    pub const MaxReservedHotkeys: u32 = 16;
 }
 
 impl pallet_subtensor::Config for Test {
@@ -134,6 +193,7 @@ impl pallet_subtensor::Config for Test {
     type InitialIssuance = InitialIssuance;
     type SudoRuntimeCall = TestRuntimeCall;
     type CouncilOrigin = EnsureNever<AccountId>;
+    type SenateOrigin = EnsureNever<AccountId>;
     type SenateMembers = ();
     type TriumvirateInterface = ();
     type Scheduler = Scheduler;
@@ -188,8 +248,38 @@ impl pallet_subtensor::Config for Test {
     type InitialHotkeyEmissionTempo = InitialHotkeyEmissionTempo;
     type InitialNetworkMaxStake = InitialNetworkMaxStake;
     type Preimages = ();
+    type WeightsSignature = TestWeightsSignature;
+    type WeightsSigner = TestWeightsSigner;
+    type Randomness = TestRandomness;
+    type RandomnessBeaconEnabled = RandomnessBeaconEnabled;
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    type MaxWeightsRowLength = MaxWeightsRowLength;
+    type TakeChangeProposalExpiry = TakeChangeProposalExpiry;
+    type MetricsRetentionBlocks = MetricsRetentionBlocks;
+    type InitialSubnetEmissionRampTempos = InitialSubnetEmissionRampTempos;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type StakeCheckpointInterval = StakeCheckpointInterval;
+    type MaxStakeCheckpoints = MaxStakeCheckpoints;
+    type BlocksPerYear = BlocksPerYear;
+    type DelegateInactivityGracePeriod = DelegateInactivityGracePeriod;
+    type MaxAuxScoreKinds = MaxAuxScoreKinds;
+    type BlockStepWeightLimit = BlockStepWeightLimit;
+    type DelegateStatsInterval = DelegateStatsInterval;
+    type InitialMaxMinStakeToRegister = InitialMaxMinStakeToRegister;
+    type InitialMaxReservedUids = InitialMaxReservedUids;
+    type OnEmission = ();
+    type OnTakeChanged = ();
+    type MaxPortfolioHotkeys = MaxPortfolioHotkeys;
+    type TakeIncreaseScheduleDuration = TakeIncreaseScheduleDuration;
+    type TreasuryProposalDeposit = TreasuryProposalDeposit;
+    type MaxRegistrationBids = MaxRegistrationBids;
+    type RegistrationBidDeposit = RegistrationBidDeposit;
+    type KeepStakingAccountsAlive = KeepStakingAccountsAlive;
+    type PublicGoodReleaseDelay = PublicGoodReleaseDelay;
+    type MinOwnerTempo = MinOwnerTempo;
+    type OwnerTempoChangeRateLimit = OwnerTempoChangeRateLimit;
+    type MaxReservedHotkeys = MaxReservedHotkeys;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -2,34 +2,73 @@ use frame_support::sp_runtime::DispatchError;
 use frame_support::{
     assert_err, assert_noop, assert_ok,
     dispatch::{DispatchClass, GetDispatchInfo, Pays},
+    traits::Get,
+    BoundedVec,
 };
 use frame_system::Config;
 use pallet_admin_utils::Error;
 use pallet_subtensor::Error as SubtensorError;
-use pallet_subtensor::{migrations, Event};
-use sp_core::U256;
+use pallet_subtensor::{migrations, Event, HyperparamBundle, RegistrationMechanism};
+use sp_core::{H256, U256};
 
 mod mock;
 use mock::*;
 
 #[test]
-fn test_sudo_set_default_take() {
+fn test_sudo_set_max_delegate_take() {
     new_test_ext().execute_with(|| {
         let to_be_set: u16 = 10;
+        let init_value: u16 = SubtensorModule::get_max_delegate_take();
+        assert_eq!(
+            AdminUtils::sudo_set_max_delegate_take(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_max_delegate_take(), init_value);
+        assert_ok!(AdminUtils::sudo_set_max_delegate_take(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_max_delegate_take(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_default_delegate_take() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = SubtensorModule::get_min_delegate_take();
         let init_value: u16 = SubtensorModule::get_default_delegate_take();
+        let max_take: u16 = SubtensorModule::get_max_delegate_take();
         assert_eq!(
-            AdminUtils::sudo_set_default_take(
+            AdminUtils::sudo_set_default_delegate_take(
                 <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
                 to_be_set
             ),
             Err(DispatchError::BadOrigin)
         );
+        assert_eq!(
+            AdminUtils::sudo_set_default_delegate_take(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                to_be_set - 1
+            ),
+            Err(Error::<Test>::DefaultTakeOutOfRange.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_default_delegate_take(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                SubtensorModule::get_max_delegate_take() + 1
+            ),
+            Err(Error::<Test>::DefaultTakeOutOfRange.into())
+        );
         assert_eq!(SubtensorModule::get_default_delegate_take(), init_value);
-        assert_ok!(AdminUtils::sudo_set_default_take(
+        assert_ok!(AdminUtils::sudo_set_default_delegate_take(
             <<Test as Config>::RuntimeOrigin>::root(),
             to_be_set
         ));
         assert_eq!(SubtensorModule::get_default_delegate_take(), to_be_set);
+        assert_eq!(SubtensorModule::get_max_delegate_take(), max_take);
     });
 }
 
@@ -311,6 +350,12 @@ fn test_sudo_set_max_weight_limit() {
             netuid,
             to_be_set
         ));
+        assert_eq!(SubtensorModule::get_max_weight_limit(netuid), init_value);
+        assert_eq!(
+            SubtensorModule::get_pending_max_weight_limit(netuid),
+            Some(to_be_set)
+        );
+        SubtensorModule::apply_pending_hyperparams(netuid);
         assert_eq!(SubtensorModule::get_max_weight_limit(netuid), to_be_set);
     });
 }
@@ -470,6 +515,30 @@ fn test_sudo_set_and_decrease_max_allowed_uids() {
     });
 }
 
+#[test]
+fn test_sudo_set_max_allowed_uids_above_weights_row_length() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        let init_value: u16 = SubtensorModule::get_max_allowed_uids(netuid);
+        let row_length: u32 = <Test as pallet_subtensor::Config>::MaxWeightsRowLength::get();
+        assert_eq!(
+            AdminUtils::sudo_set_max_allowed_uids(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                (row_length + 1) as u16
+            ),
+            Err(Error::<Test>::MaxAllowedUidsExceedsWeightsRowLength.into())
+        );
+        assert_eq!(SubtensorModule::get_max_allowed_uids(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_max_allowed_uids(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            row_length as u16
+        ));
+    });
+}
+
 #[test]
 fn test_sudo_set_kappa() {
     new_test_ext().execute_with(|| {
@@ -499,6 +568,9 @@ fn test_sudo_set_kappa() {
             netuid,
             to_be_set
         ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_value);
+        assert_eq!(SubtensorModule::get_pending_kappa(netuid), Some(to_be_set));
+        SubtensorModule::apply_pending_hyperparams(netuid);
         assert_eq!(SubtensorModule::get_kappa(netuid), to_be_set);
     });
 }
@@ -1251,6 +1323,7 @@ fn test_sudo_get_set_alpha() {
             alpha_low,
             alpha_high
         ));
+        SubtensorModule::apply_pending_hyperparams(netuid);
         let (grabbed_alpha_low, grabbed_alpha_high): (u16, u16) =
             SubtensorModule::get_alpha_values(netuid);
 
@@ -1435,3 +1508,651 @@ fn test_sudo_set_dissolve_network_schedule_duration() {
         System::assert_last_event(Event::DissolveNetworkScheduleDurationSet(new_duration).into());
     });
 }
+
+#[test]
+fn test_sudo_set_prune_emission_burn() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        assert!(!SubtensorModule::get_prune_emission_burn(netuid));
+        assert_eq!(
+            AdminUtils::sudo_set_prune_emission_burn(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_prune_emission_burn(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_prune_emission_burn(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_emission_ramp_tempos() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = 7;
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_emission_ramp_tempos(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_emission_ramp_tempos(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            pallet_subtensor::SubnetEmissionRampTempos::<Test>::get(),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_emission_tithe() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = u16::MAX / 20;
+        assert_eq!(
+            AdminUtils::sudo_set_emission_tithe(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_emission_tithe(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                pallet_subtensor::coinbase::treasury::MAX_EMISSION_TITHE + 1
+            ),
+            Err(Error::<Test>::EmissionTitheTooHigh.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_emission_tithe(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(pallet_subtensor::EmissionTithe::<Test>::get(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_safe_math_audit_events() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_safe_math_audit_events(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert!(!pallet_subtensor::SafeMathAuditEvents::<Test>::get());
+        assert_ok!(AdminUtils::sudo_set_safe_math_audit_events(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            true
+        ));
+        assert!(pallet_subtensor::SafeMathAuditEvents::<Test>::get());
+    });
+}
+
+#[test]
+fn test_sudo_set_min_axon_version() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u32 = 700;
+        add_network(netuid, 10);
+
+        assert_eq!(SubtensorModule::get_min_axon_version(netuid), 0);
+        assert_eq!(
+            AdminUtils::sudo_set_min_axon_version(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_min_axon_version(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_min_axon_version(netuid), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_registration_mechanism() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        assert_eq!(
+            pallet_subtensor::SubnetRegistrationMechanism::<Test>::get(netuid),
+            RegistrationMechanism::FixedBurn
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_registration_mechanism(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                RegistrationMechanism::Auction
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_registration_mechanism(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            RegistrationMechanism::Auction
+        ));
+        assert_eq!(
+            pallet_subtensor::SubnetRegistrationMechanism::<Test>::get(netuid),
+            RegistrationMechanism::Auction
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_weights_immunity_period() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u64 = 360;
+        add_network(netuid, 10);
+
+        assert_eq!(SubtensorModule::get_weights_immunity_period(netuid), 0);
+        assert_eq!(
+            AdminUtils::sudo_set_weights_immunity_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_weights_immunity_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_weights_immunity_period(netuid),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_strict_key_usage_separation() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_strict_key_usage_separation(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert!(!pallet_subtensor::StrictKeyUsageSeparation::<Test>::get());
+        assert_ok!(AdminUtils::sudo_set_strict_key_usage_separation(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            true
+        ));
+        assert!(pallet_subtensor::StrictKeyUsageSeparation::<Test>::get());
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_emission_disabled() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_emission_disabled(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true,
+                None
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_emission_disabled(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true,
+                None
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_emission_disabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true,
+            Some(100)
+        ));
+        assert_eq!(
+            pallet_subtensor::EmissionDisabled::<Test>::get(netuid),
+            Some(100)
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_emission_disabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            false,
+            None
+        ));
+        assert!(!SubtensorModule::is_subnet_emission_disabled(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_tempo() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(7);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                5
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::root(), netuid + 1, 5),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, 0),
+            Err(Error::<Test>::InvalidTempo.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            5
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 5);
+        assert!(pallet_subtensor::NextEpochBlock::<Test>::get(netuid).is_some());
+
+        // The owner is held to a tempo floor and a rate limit; root is not.
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, 1),
+            Err(SubtensorError::<Test>::TempoTooLow.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, 6),
+            Err(SubtensorError::<Test>::TempoChangeRateLimitExceeded.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            1
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 1);
+
+        run_to_block(System::block_number() + OwnerTempoChangeRateLimit::get());
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            6
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 6);
+    });
+}
+
+#[test]
+fn test_sudo_set_min_stake_to_register() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(7);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        let max_min_stake: u64 = SubtensorModule::get_max_min_stake_to_register();
+
+        assert_eq!(
+            AdminUtils::sudo_set_min_stake_to_register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                max_min_stake
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_min_stake_to_register(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                max_min_stake + 1
+            ),
+            Err(Error::<Test>::MinStakeToRegisterTooHigh.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_min_stake_to_register(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            max_min_stake
+        ));
+        assert_eq!(
+            SubtensorModule::get_min_stake_to_register(netuid),
+            max_min_stake
+        );
+
+        // Only root may raise the bound.
+        assert_eq!(
+            AdminUtils::sudo_set_max_min_stake_to_register(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                max_min_stake + 1
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_max_min_stake_to_register(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            max_min_stake + 1
+        ));
+        assert_ok!(AdminUtils::sudo_set_min_stake_to_register(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            max_min_stake + 1
+        ));
+    });
+}
+
+#[test]
+fn test_sudo_set_registration_challenge_commit() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(7);
+        let commit = H256::repeat_byte(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_registration_challenge_commit(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                Some(commit)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_registration_challenge_commit(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            Some(commit)
+        ));
+        assert_eq!(
+            SubtensorModule::get_registration_challenge_commit(netuid),
+            Some(commit)
+        );
+        assert_ok!(AdminUtils::sudo_set_registration_challenge_commit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            None
+        ));
+        assert_eq!(
+            SubtensorModule::get_registration_challenge_commit(netuid),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_reserved_hotkeys() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(7);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        let max_reserved: u16 = SubtensorModule::get_max_reserved_uids();
+        let bounded = |keys: Vec<U256>| -> pallet_subtensor::ReservedHotkeysOf<Test> {
+            BoundedVec::truncate_from(keys)
+        };
+        let hotkeys: Vec<U256> = (0..max_reserved).map(U256::from).collect();
+
+        assert_eq!(
+            AdminUtils::sudo_set_reserved_hotkeys(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                bounded(hotkeys.clone())
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_reserved_hotkeys(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                bounded((0..=max_reserved).map(U256::from).collect())
+            ),
+            Err(Error::<Test>::TooManyReservedHotkeys.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_reserved_hotkeys(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            bounded(hotkeys.clone())
+        ));
+        assert_eq!(SubtensorModule::get_reserved_hotkeys(netuid), hotkeys);
+
+        // Repeated hotkeys are kept once and do not count against the limit.
+        let mut repeated: Vec<U256> = hotkeys.clone();
+        repeated.extend(hotkeys.iter().take(1));
+        assert_ok!(AdminUtils::sudo_set_reserved_hotkeys(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            bounded(repeated)
+        ));
+        assert_eq!(SubtensorModule::get_reserved_hotkeys(netuid), hotkeys);
+
+        let bound: u32 = <Test as pallet_subtensor::Config>::MaxReservedHotkeys::get();
+        assert_eq!(
+            AdminUtils::sudo_set_max_reserved_uids(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                bound as u16 + 1
+            ),
+            Err(Error::<Test>::MaxReservedUidsTooHigh.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_reserved_uids(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                max_reserved + 1
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_max_reserved_uids(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            max_reserved + 1
+        ));
+        assert_eq!(SubtensorModule::get_max_reserved_uids(), max_reserved + 1);
+    });
+}
+
+#[test]
+fn test_sudo_set_active_permits_only() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert!(!SubtensorModule::get_active_permits_only(netuid));
+        assert_eq!(
+            AdminUtils::sudo_set_active_permits_only(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_active_permits_only(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_active_permits_only(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_active_permits_only(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_bootstrap_grant() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_bootstrap_grant(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                u16::MAX / 2,
+                10,
+                1,
+                2
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        // A new subnet could never hold more validators than InitialMaxAllowedValidators.
+        assert_eq!(
+            AdminUtils::sudo_set_bootstrap_grant(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                u16::MAX / 2,
+                10,
+                101,
+                2
+            ),
+            Err(Error::<Test>::BootstrapGrantMinValidatorsTooHigh.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_bootstrap_grant(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            u16::MAX / 2,
+            10,
+            1,
+            2
+        ));
+        assert_eq!(
+            pallet_subtensor::BootstrapGrantShare::<Test>::get(),
+            u16::MAX / 2
+        );
+        assert_eq!(pallet_subtensor::BootstrapGrantTempos::<Test>::get(), 10);
+        assert_eq!(
+            pallet_subtensor::BootstrapGrantMinValidators::<Test>::get(),
+            1
+        );
+        assert_eq!(pallet_subtensor::BootstrapGrantMinMiners::<Test>::get(), 2);
+    });
+}
+
+#[test]
+fn test_sudo_set_max_allowed_weights() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u16 = 10;
+        add_network(netuid, 10);
+        let init_value: u16 = SubtensorModule::get_max_allowed_weights(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_max_allowed_weights(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_max_allowed_weights(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        SubtensorModule::set_min_allowed_weights(netuid, to_be_set + 1);
+        assert_eq!(
+            AdminUtils::sudo_set_max_allowed_weights(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                to_be_set
+            ),
+            Err(Error::<Test>::MinAllowedWeightsAboveMax.into())
+        );
+        assert_eq!(SubtensorModule::get_max_allowed_weights(netuid), init_value);
+        SubtensorModule::set_min_allowed_weights(netuid, to_be_set);
+        assert_ok!(AdminUtils::sudo_set_max_allowed_weights(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_max_allowed_weights(netuid), to_be_set);
+
+        // The floor cannot be raised above the new cap.
+        assert_eq!(
+            AdminUtils::sudo_set_min_allowed_weights(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                to_be_set + 1
+            ),
+            Err(Error::<Test>::MinAllowedWeightsAboveMax.into())
+        );
+    });
+}
+
+#[test]
+fn test_set_hyperparam_bundle() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        let init_kappa: u16 = SubtensorModule::get_kappa(netuid);
+        let init_rho: u16 = SubtensorModule::get_rho(netuid);
+        let bundle = HyperparamBundle {
+            kappa: Some(init_kappa + 1),
+            activity_cutoff: Some(500),
+            min_allowed_weights: Some(4),
+            max_allowed_weights: Some(8),
+            ..Default::default()
+        };
+        assert_eq!(
+            AdminUtils::set_hyperparam_bundle(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                bundle.clone()
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::set_hyperparam_bundle(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                bundle.clone()
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::set_hyperparam_bundle(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                HyperparamBundle {
+                    min_allowed_weights: Some(9),
+                    ..bundle.clone()
+                }
+            ),
+            Err(Error::<Test>::MinAllowedWeightsAboveMax.into())
+        );
+        assert_ok!(AdminUtils::set_hyperparam_bundle(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            bundle.clone()
+        ));
+
+        // Nothing changes until the tempo boundary, then everything does.
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_kappa);
+        assert_eq!(
+            SubtensorModule::get_pending_hyperparam_bundle(netuid),
+            Some(bundle)
+        );
+        SubtensorModule::apply_pending_hyperparams(netuid);
+        assert_eq!(SubtensorModule::get_kappa(netuid), init_kappa + 1);
+        assert_eq!(SubtensorModule::get_rho(netuid), init_rho);
+        assert_eq!(SubtensorModule::get_activity_cutoff(netuid), 500);
+        assert_eq!(SubtensorModule::get_min_allowed_weights(netuid), 4);
+        assert_eq!(SubtensorModule::get_max_allowed_weights(netuid), 8);
+        assert_eq!(SubtensorModule::get_pending_hyperparam_bundle(netuid), None);
+    });
+}
@@ -25,8 +25,12 @@ pub mod pallet {
     use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
     use frame_system::pallet_prelude::{BlockNumberFor, *};
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     // Configure the pallet by specifying the parameters and types on which it depends.
@@ -71,6 +75,13 @@ pub mod pallet {
             /// The account
             who: T::AccountId,
         },
+        /// The commitment of an account that may no longer commit was removed
+        CommitmentRemoved {
+            /// The netuid of the commitment
+            netuid: u16,
+            /// The account
+            who: T::AccountId,
+        },
     }
 
     #[pallet::error]
@@ -81,6 +92,12 @@ pub mod pallet {
         AccountNotAllowedCommit,
         /// Account is trying to commit data too fast, rate limit exceeded
         CommitmentSetRateLimitExceeded,
+        /// The subnet holds as many commitments as it allows
+        TooManyCommitments,
+        /// The account has no commitment on the subnet
+        CommitmentNotFound,
+        /// The account may still commit to the subnet
+        CommitmentNotStale,
     }
 
     /// Identity data by account
@@ -108,6 +125,27 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Number of commitments held by each subnet
+    #[pallet::storage]
+    pub(super) type CommitmentCount<T: Config> = StorageMap<_, Identity, u16, u32, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T>>() >= 1 {
+                return T::DbWeight::get().reads(1);
+            }
+            // Count the commitments made before subnets were bounded.
+            let mut reads: u64 = 1;
+            for (netuid, _) in <CommitmentOf<T>>::iter_keys() {
+                CommitmentCount::<T>::mutate(netuid, |count| *count = count.saturating_add(1));
+                reads = reads.saturating_add(1);
+            }
+            STORAGE_VERSION.put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(reads, reads)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Set the commitment for a given netuid
@@ -143,7 +181,15 @@ pub mod pallet {
             }
 
             let fd = <BalanceOf<T>>::from(extra_fields).saturating_mul(T::FieldDeposit::get());
-            let mut id = match <CommitmentOf<T>>::get(netuid, &who) {
+            let existing = <CommitmentOf<T>>::get(netuid, &who);
+            let is_new: bool = existing.is_none();
+            if is_new {
+                ensure!(
+                    CommitmentCount::<T>::get(netuid) < T::CanCommit::max_commitments(netuid),
+                    Error::<T>::TooManyCommitments
+                );
+            }
+            let mut id = match existing {
                 Some(mut id) => {
                     id.info = *info;
                     id.block = cur_block;
@@ -169,16 +215,49 @@ pub mod pallet {
 
             <CommitmentOf<T>>::insert(netuid, &who, id);
             <LastCommitment<T>>::insert(netuid, &who, cur_block);
+            if is_new {
+                CommitmentCount::<T>::mutate(netuid, |count| *count = count.saturating_add(1));
+            }
             Self::deposit_event(Event::Commitment { netuid, who });
 
             Ok(())
         }
+
+        /// Remove the commitment of an account that may no longer commit to a netuid, freeing
+        /// its place on the subnet and returning its deposit
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(3, 4))]
+        pub fn remove_stale_commitment(
+            origin: OriginFor<T>,
+            netuid: u16,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(
+                !T::CanCommit::can_commit(netuid, &who),
+                Error::<T>::CommitmentNotStale
+            );
+
+            let id = <CommitmentOf<T>>::take(netuid, &who).ok_or(Error::<T>::CommitmentNotFound)?;
+            let err_amount = T::Currency::unreserve(&who, id.deposit);
+            debug_assert!(err_amount.is_zero());
+            <LastCommitment<T>>::remove(netuid, &who);
+            CommitmentCount::<T>::mutate(netuid, |count| *count = count.saturating_sub(1));
+            Self::deposit_event(Event::CommitmentRemoved { netuid, who });
+
+            Ok(())
+        }
     }
 }
 
 // Interfaces to interact with other pallets
 pub trait CanCommit<AccountId> {
     fn can_commit(netuid: u16, who: &AccountId) -> bool;
+
+    /// The number of commitments a subnet may hold.
+    fn max_commitments(_netuid: u16) -> u32 {
+        u32::MAX
+    }
 }
 
 impl<A> CanCommit<A> for () {
@@ -0,0 +1,260 @@
+//! JSON views of the delegate, neuron and subnet runtime APIs, under the `subtensor_*`
+//! namespace.
+//!
+//! The runtime APIs return SCALE encoded bytes. The types below decode them on the node and are
+//! returned as JSON objects, so clients do not need their own SCALE decoders. Each type mirrors
+//! the field order of its runtime struct, and must be changed along with it; the runtime tests
+//! decode each runtime struct into its mirror. Accounts are SS58 strings, in results and in
+//! parameters, and integers are JSON numbers.
+
+use codec::{Decode, Encode, HasCompact};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block as BlockT, AccountId32};
+
+use crate::{
+    DelegateInfoRuntimeApi, Error, NeuronInfoRuntimeApi, SubnetInfoRuntimeApi, SubtensorCustom,
+};
+
+/// A SCALE compact integer, serialized as a plain number.
+#[derive(Decode, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct CompactNum<T: HasCompact>(#[codec(compact)] pub T);
+
+/// Mirrors `pallet_subtensor::rpc_info::delegate_info::DelegateInfo`.
+#[derive(Decode, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct DelegateInfoJson {
+    pub delegate_ss58: AccountId32,
+    /// Take of the delegate, normalized to u16::MAX.
+    pub take: CompactNum<u16>,
+    /// (nominator, stake) of every nominator with stake.
+    pub nominators: Vec<(AccountId32, CompactNum<u64>)>,
+    pub owner_ss58: AccountId32,
+    /// Netuids the delegate is registered on.
+    pub registrations: Vec<CompactNum<u16>>,
+    /// Netuids the delegate holds a validator permit on.
+    pub validator_permits: Vec<CompactNum<u16>>,
+    /// Daily return per 1000 TAO staked, after the take.
+    pub return_per_1000: CompactNum<u64>,
+    pub total_daily_return: CompactNum<u64>,
+}
+
+/// Mirrors `pallet_subtensor::AxonInfo`.
+#[derive(Decode, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AxonInfoJson {
+    pub block: u64,
+    pub version: u32,
+    /// IPv4 or IPv6 address as an integer.
+    pub ip: u128,
+    pub port: u16,
+    /// 4 for IPv4, 6 for IPv6.
+    pub ip_type: u8,
+    pub protocol: u8,
+    pub placeholder1: u8,
+    pub placeholder2: u8,
+    pub capabilities: u64,
+}
+
+/// Mirrors `pallet_subtensor::PrometheusInfo`.
+#[derive(Decode, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct PrometheusInfoJson {
+    pub block: u64,
+    pub version: u32,
+    /// IPv4 or IPv6 address as an integer.
+    pub ip: u128,
+    pub port: u16,
+    /// 4 for IPv4, 6 for IPv6.
+    pub ip_type: u8,
+}
+
+/// Mirrors `pallet_subtensor::rpc_info::neuron_info::NeuronInfoLite`. Scores are normalized to
+/// u16::MAX.
+#[derive(Decode, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct NeuronInfoLiteJson {
+    pub hotkey: AccountId32,
+    pub coldkey: AccountId32,
+    pub uid: CompactNum<u16>,
+    pub netuid: CompactNum<u16>,
+    pub active: bool,
+    pub axon_info: AxonInfoJson,
+    pub prometheus_info: PrometheusInfoJson,
+    /// (coldkey, stake) of every coldkey staking to the neuron.
+    pub stake: Vec<(AccountId32, CompactNum<u64>)>,
+    pub rank: CompactNum<u16>,
+    pub emission: CompactNum<u64>,
+    pub incentive: CompactNum<u16>,
+    pub consensus: CompactNum<u16>,
+    pub trust: CompactNum<u16>,
+    pub validator_trust: CompactNum<u16>,
+    pub dividends: CompactNum<u16>,
+    pub last_update: CompactNum<u64>,
+    pub validator_permit: bool,
+    pub pruning_score: CompactNum<u16>,
+}
+
+/// Mirrors `pallet_subtensor::rpc_info::subnet_info::SubnetInfo`.
+#[derive(Decode, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct SubnetInfoJson {
+    pub netuid: CompactNum<u16>,
+    pub rho: CompactNum<u16>,
+    pub kappa: CompactNum<u16>,
+    pub difficulty: CompactNum<u64>,
+    pub immunity_period: CompactNum<u16>,
+    pub max_allowed_validators: CompactNum<u16>,
+    pub min_allowed_weights: CompactNum<u16>,
+    pub max_weights_limit: CompactNum<u16>,
+    pub scaling_law_power: CompactNum<u16>,
+    pub subnetwork_n: CompactNum<u16>,
+    pub max_allowed_uids: CompactNum<u16>,
+    pub blocks_since_last_step: CompactNum<u64>,
+    pub tempo: CompactNum<u16>,
+    pub network_modality: CompactNum<u16>,
+    pub network_connect: Vec<[u16; 2]>,
+    pub emission_values: CompactNum<u64>,
+    pub burn: CompactNum<u64>,
+    pub owner: AccountId32,
+}
+
+#[rpc(client, server, namespace = "subtensor")]
+pub trait SubtensorJsonApi<BlockHash> {
+    #[method(name = "getDelegates")]
+    fn get_delegates_json(&self, at: Option<BlockHash>) -> RpcResult<Vec<DelegateInfoJson>>;
+    #[method(name = "getDelegate")]
+    fn get_delegate_json(
+        &self,
+        delegate_ss58: AccountId32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<DelegateInfoJson>>;
+    #[method(name = "getNeuronsLite")]
+    fn get_neurons_lite_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NeuronInfoLiteJson>>;
+    #[method(name = "getNeuronLite")]
+    fn get_neuron_lite_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<NeuronInfoLiteJson>>;
+    #[method(name = "getSubnetInfo")]
+    fn get_subnet_info_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<SubnetInfoJson>>;
+    #[method(name = "getSubnetsInfo")]
+    fn get_subnets_info_json(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Option<SubnetInfoJson>>>;
+}
+
+/// Decodes the SCALE output of a runtime API.
+fn decode<T: Decode>(bytes: Vec<u8>, what: &str) -> RpcResult<T> {
+    T::decode(&mut bytes.as_slice())
+        .map_err(|e| Error::RuntimeError(format!("Unable to decode {}: {:?}", what, e)).into())
+}
+
+/// Decodes the output of a runtime API returning no bytes when there is no value.
+fn decode_optional<T: Decode>(bytes: Vec<u8>, what: &str) -> RpcResult<Option<T>> {
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+    decode(bytes, what).map(Some)
+}
+
+impl<C, Block> SubtensorJsonApiServer<<Block as BlockT>::Hash> for SubtensorCustom<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DelegateInfoRuntimeApi<Block>,
+    C::Api: NeuronInfoRuntimeApi<Block>,
+    C::Api: SubnetInfoRuntimeApi<Block>,
+{
+    fn get_delegates_json(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<DelegateInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_delegates(at)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)))?;
+        decode(bytes, "delegates info")
+    }
+
+    fn get_delegate_json(
+        &self,
+        delegate_ss58: AccountId32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<DelegateInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_delegate(at, delegate_ss58.encode())
+            .map_err(|e| Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)))?;
+        decode_optional(bytes, "delegate info")
+    }
+
+    fn get_neurons_lite_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NeuronInfoLiteJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api.get_neurons_lite(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e))
+        })?;
+        decode(bytes, "neurons lite info")
+    }
+
+    fn get_neuron_lite_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<NeuronInfoLiteJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_neuron_lite(at, netuid, uid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get neuron lite info: {:?}", e)))?;
+        decode_optional(bytes, "neuron lite info")
+    }
+
+    fn get_subnet_info_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<SubnetInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_subnet_info(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)))?;
+        decode_optional(bytes, "subnet info")
+    }
+
+    fn get_subnets_info_json(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Option<SubnetInfoJson>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_subnets_info(at)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get subnets info: {:?}", e)))?;
+        decode(bytes, "subnets info")
+    }
+}
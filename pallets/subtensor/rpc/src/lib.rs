@@ -11,6 +11,9 @@ use std::sync::Arc;
 
 use sp_api::ProvideRuntimeApi;
 
+pub mod json;
+pub use json::SubtensorJsonApiServer;
+
 pub use subtensor_custom_rpc_runtime_api::{
     DelegateInfoRuntimeApi, NeuronInfoRuntimeApi, SubnetInfoRuntimeApi,
     SubnetRegistrationRuntimeApi,
@@ -32,6 +35,34 @@ pub trait SubtensorCustomApi<BlockHash> {
         delegatee_account_vec: Vec<u8>,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "delegateInfo_getNominators")]
+    fn get_nominators(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        start: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "delegateInfo_getDelegateApr")]
+    fn get_delegate_apr(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        window: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<u64>;
+    #[method(name = "delegateInfo_getDelegateStats")]
+    fn get_delegate_stats(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "delegateInfo_getDelegateYield")]
+    fn get_delegate_yield(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 
     #[method(name = "neuronInfo_getNeuronsLite")]
     fn get_neurons_lite(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
@@ -41,6 +72,19 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_neurons(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "neuronInfo_getNeuron")]
     fn get_neuron(&self, netuid: u16, uid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getMetagraphFields")]
+    fn get_metagraph_fields(
+        &self,
+        netuid: u16,
+        fields: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNetuidsForHotkey")]
+    fn get_netuids_for_hotkey(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 
     #[method(name = "subnetInfo_getSubnetInfo")]
     fn get_subnet_info(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
@@ -52,6 +96,14 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_subnets_info_v2(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getSubnetHyperparams")]
     fn get_subnet_hyperparams(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getPendingEmission")]
+    fn get_pending_emission(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<u64>;
+    #[method(name = "subnetInfo_getSubnetState")]
+    fn get_subnet_state(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getSubnetRandomness")]
+    fn get_subnet_randomness(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getEpochChecksums")]
+    fn get_epoch_checksums(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 
     #[method(name = "subnetInfo_getLockCost")]
     fn get_network_lock_cost(&self, at: Option<BlockHash>) -> RpcResult<u64>;
@@ -139,6 +191,62 @@ where
         })
     }
 
+    fn get_nominators(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        start: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_nominators(at, delegate_account_vec, start, limit)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get nominators: {:?}", e)).into())
+    }
+
+    fn get_delegate_apr(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        window: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_delegate_apr(at, delegate_account_vec, window)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get delegate apr: {:?}", e)).into())
+    }
+
+    fn get_delegate_stats(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_delegate_stats(at, delegate_account_vec)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get delegate stats: {:?}", e)).into()
+            })
+    }
+
+    fn get_delegate_yield(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_delegate_yield(at, delegate_account_vec, netuid)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get delegate yield: {:?}", e)).into()
+            })
+    }
+
     fn get_neurons_lite(
         &self,
         netuid: u16,
@@ -187,6 +295,34 @@ where
             .map_err(|e| Error::RuntimeError(format!("Unable to get neuron info: {:?}", e)).into())
     }
 
+    fn get_metagraph_fields(
+        &self,
+        netuid: u16,
+        fields: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_metagraph_fields(at, netuid, fields).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get metagraph fields: {:?}", e)).into()
+        })
+    }
+
+    fn get_netuids_for_hotkey(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_netuids_for_hotkey(at, hotkey_account_vec)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get netuids for hotkey: {:?}", e)).into()
+            })
+    }
+
     fn get_subnet_info(
         &self,
         netuid: u16,
@@ -211,6 +347,57 @@ where
             .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)).into())
     }
 
+    fn get_pending_emission(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_pending_emission(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get pending emission: {:?}", e)).into()
+        })
+    }
+
+    fn get_subnet_state(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_subnet_state(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get subnet state: {:?}", e)).into())
+    }
+
+    fn get_subnet_randomness(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_subnet_randomness(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get subnet randomness: {:?}", e)).into()
+        })
+    }
+
+    fn get_epoch_checksums(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_epoch_checksums(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get epoch checksums: {:?}", e)).into()
+        })
+    }
+
     fn get_subnets_info(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
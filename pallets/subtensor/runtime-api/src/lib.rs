@@ -9,6 +9,10 @@ sp_api::decl_runtime_apis! {
         fn get_delegates() -> Vec<u8>;
         fn get_delegate( delegate_account_vec: Vec<u8> ) -> Vec<u8>;
         fn get_delegated( delegatee_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_nominators( delegate_account_vec: Vec<u8>, start: u32, limit: u32 ) -> Vec<u8>;
+        fn get_delegate_apr( delegate_account_vec: Vec<u8>, window: u32 ) -> u64;
+        fn get_delegate_stats( delegate_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_delegate_yield( delegate_account_vec: Vec<u8>, netuid: u16 ) -> Vec<u8>;
     }
 
     pub trait NeuronInfoRuntimeApi {
@@ -16,6 +20,8 @@ sp_api::decl_runtime_apis! {
         fn get_neuron(netuid: u16, uid: u16) -> Vec<u8>;
         fn get_neurons_lite(netuid: u16) -> Vec<u8>;
         fn get_neuron_lite(netuid: u16, uid: u16) -> Vec<u8>;
+        fn get_metagraph_fields(netuid: u16, fields: u32) -> Vec<u8>;
+        fn get_netuids_for_hotkey( hotkey_account_vec: Vec<u8> ) -> Vec<u8>;
     }
 
     pub trait SubnetInfoRuntimeApi {
@@ -24,14 +30,32 @@ sp_api::decl_runtime_apis! {
         fn get_subnet_info_v2(netuid: u16) -> Vec<u8>;
         fn get_subnets_info_v2() -> Vec<u8>;
         fn get_subnet_hyperparams(netuid: u16) -> Vec<u8>;
+        fn get_pending_emission(netuid: u16) -> u64;
+        fn get_subnet_state(netuid: u16) -> Vec<u8>;
+        fn get_subnet_randomness(netuid: u16) -> Vec<u8>;
+        fn get_epoch_checksums(netuid: u16) -> Vec<u8>;
     }
 
     pub trait StakeInfoRuntimeApi {
         fn get_stake_info_for_coldkey( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
         fn get_stake_info_for_coldkeys( coldkey_account_vecs: Vec<Vec<u8>> ) -> Vec<u8>;
+        fn get_emission_by_netuid( hotkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_hotkey_pending_emission( hotkey_account_vec: Vec<u8> ) -> u64;
+        fn get_stake_distribution( netuid: u16 ) -> Vec<u8>;
+        fn get_global_stake_distribution() -> Vec<u8>;
+        fn get_coldkey_report( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
+        fn get_scheduled_operations( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
     }
 
     pub trait SubnetRegistrationRuntimeApi {
         fn get_network_registration_cost() -> u64;
     }
+
+    pub trait PreflightRuntimeApi {
+        fn preflight_call( call: Vec<u8>, signer_account_vec: Vec<u8> ) -> Vec<u8>;
+    }
+
+    pub trait EpochSimulationRuntimeApi {
+        fn simulate_epoch( netuid: u16, rao_emission: u64, stake_overrides: Vec<u8> ) -> Vec<u8>;
+    }
 }
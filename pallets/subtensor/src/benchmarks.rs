@@ -65,7 +65,7 @@ benchmarks! {
         let amount_to_be_staked = 1000000u32.into();
       Subtensor::<T>::add_balance_to_coldkey_account(&coldkey.clone(), amount_to_be_staked);
 
-      Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone())?;
+      Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone(), None)?;
 
       let uid = Subtensor::<T>::get_uid_for_net_and_hotkey(netuid, &hotkey.clone()).unwrap();
       Subtensor::<T>::set_validator_permit_for_uid(netuid, uid, true);
@@ -76,6 +76,35 @@ benchmarks! {
   }: set_weights(RawOrigin::Signed( signer.clone() ), netuid, dests, weights, version_key)
 
 
+  benchmark_epoch {
+    // A full subnet where every validator weights every neuron. Run it against runtimes built
+    // with and without `native-epoch-math` to compare the host functions to the wasm math.
+    let netuid: u16 = 1;
+    let n: u16 = 4096;
+    let validators: u16 = 64;
+
+    Subtensor::<T>::init_new_network(netuid, 1);
+    Subtensor::<T>::set_max_allowed_uids(netuid, n);
+    Subtensor::<T>::set_max_allowed_validators(netuid, validators);
+
+    let row: Vec<(u16, u16)> = (0..n).map(|dest| (dest, u16::MAX)).collect();
+    for uid in 0..n {
+      let hotkey: T::AccountId = account("Alice", 0, uid as u32);
+      let coldkey: T::AccountId = account("Test", 0, uid as u32);
+      Subtensor::<T>::append_neuron(netuid, &hotkey, 0);
+      Subtensor::<T>::create_account_if_non_existent(&coldkey, &hotkey);
+      if uid < validators {
+        Subtensor::<T>::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000_000_000);
+        Subtensor::<T>::set_validator_permit_for_uid(netuid, uid, true);
+        Subtensor::<T>::set_last_update_for_uid(netuid, uid, 1);
+        Weights::<T>::insert(netuid, uid, WeightsRowOf::<T>::truncate_from(row.clone()));
+      }
+    }
+
+  }: {
+    Subtensor::<T>::epoch(netuid, 1_000_000_000);
+  }
+
   benchmark_become_delegate {
     // This is a whitelisted caller who can make transaction without weights.
     let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>();
@@ -99,7 +128,7 @@ benchmarks! {
     let amount_to_be_staked = 1000000000u32.into();
     Subtensor::<T>::add_balance_to_coldkey_account(&coldkey.clone(), amount_to_be_staked);
 
-    assert_ok!(Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone()));
+    assert_ok!(Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone(), None));
   }: become_delegate(RawOrigin::Signed( coldkey.clone() ), hotkey.clone())
 
   benchmark_add_stake {
@@ -128,7 +157,7 @@ benchmarks! {
     let amount_to_be_staked = 1000000000u64;
     Subtensor::<T>::add_balance_to_coldkey_account(&coldkey.clone(), amount_to_be_staked);
 
-    assert_ok!(Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone()));
+    assert_ok!(Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone(), None));
   }: add_stake(RawOrigin::Signed( coldkey.clone() ), hotkey, amount)
 
   benchmark_remove_stake{
@@ -158,7 +187,7 @@ benchmarks! {
     let wallet_bal = 1000000u32.into();
     Subtensor::<T>::add_balance_to_coldkey_account(&coldkey.clone(), wallet_bal);
 
-    assert_ok!(Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone()));
+    assert_ok!(Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone(), None));
     assert_ok!(Subtensor::<T>::do_become_delegate(RawOrigin::Signed(coldkey.clone()).into(), hotkey.clone(), Subtensor::<T>::get_default_delegate_take()));
 
       // Stake 10% of our current total staked TAO
@@ -193,7 +222,7 @@ benchmarks! {
     let amount_to_be_staked = 1000000u32.into();
     Subtensor::<T>::add_balance_to_coldkey_account(&caller.clone(), amount_to_be_staked);
 
-    assert_ok!(Subtensor::<T>::do_burned_registration(caller_origin.clone(), netuid, caller.clone()));
+    assert_ok!(Subtensor::<T>::do_burned_registration(caller_origin.clone(), netuid, caller.clone(), None));
 
     Subtensor::<T>::set_serving_rate_limit(netuid, 0);
 
@@ -219,7 +248,7 @@ benchmarks! {
     let amount_to_be_staked = 1000000u32.into();
     Subtensor::<T>::add_balance_to_coldkey_account(&caller.clone(), amount_to_be_staked);
 
-    assert_ok!(Subtensor::<T>::do_burned_registration(caller_origin.clone(), netuid, caller.clone()));
+    assert_ok!(Subtensor::<T>::do_burned_registration(caller_origin.clone(), netuid, caller.clone(), None));
     Subtensor::<T>::set_serving_rate_limit(netuid, 0);
 
   }: serve_prometheus(RawOrigin::Signed( caller.clone() ), netuid, version, ip, port, ip_type)
@@ -286,7 +315,7 @@ benchmarks! {
     let amount_to_be_staked =  100_000_000_000_000u64;
     Subtensor::<T>::add_balance_to_coldkey_account(&coldkey.clone(), amount_to_be_staked);
 
-    assert_ok!(Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone()));
+    assert_ok!(Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone(), None));
   }: root_register(RawOrigin::Signed(coldkey), hotkey)
 
   benchmark_register_network {
@@ -418,14 +447,16 @@ reveal_weights {
     Subtensor::<T>::set_validator_permit_for_uid(netuid, 0, true);
     Subtensor::<T>::set_commit_reveal_weights_interval(netuid, 0);
 
-    let commit_hash: H256 = BlakeTwo256::hash_of(&(
-      hotkey.clone(),
+    let block_number: u64 = Subtensor::<T>::get_current_block_as_u64();
+    let commit_hash: H256 = Subtensor::<T>::get_commit_hash(
+      &hotkey,
       netuid,
-      uids.clone(),
-      weight_values.clone(),
-      salt.clone(),
+      Subtensor::<T>::get_commit_tempo_index(netuid, block_number),
+      &uids,
+      &weight_values,
+      &salt,
       version_key,
-  ));
+    );
     let _ = Subtensor::<T>::commit_weights(<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(hotkey.clone())), netuid, commit_hash);
 
   }: reveal_weights(RawOrigin::Signed(hotkey.clone()), netuid, uids, weight_values, salt, version_key)
@@ -461,7 +492,7 @@ reveal_weights {
   Subtensor::<T>::set_burn(netuid, 1);
   let amount_to_be_staked = 1_000_000u32.into();
   Subtensor::<T>::add_balance_to_coldkey_account(&coldkey, amount_to_be_staked);
-  assert_ok!(Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone()));
+  assert_ok!(Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone(), None));
 }: set_childkey_take(RawOrigin::Signed(coldkey), hotkey, netuid, take)
 
   swap_coldkey {
@@ -1,23 +1,49 @@
 use super::*;
 use frame_support::storage::IterableStorageMap;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
 use substrate_fixed::types::I110F18;
 
 impl<T: Config> Pallet<T> {
     /// Executes the necessary operations for each block.
-    pub fn block_step() -> Result<(), &'static str> {
+    ///
+    /// The per-subnet phases tied to this exact block always run and are charged up front, and
+    /// the expiry phases always run and charge the weight they report. Registration adjustments,
+    /// subnet epochs, hotkey emission drains, stake checkpoints and delegate statistics are then
+    /// metered against the rest of BlockStepWeightLimit; once it runs out they are deferred to
+    /// the next block.
+    ///
+    /// Returns the weight consumed.
+    pub fn block_step() -> Weight {
         let block_number: u64 = Self::get_current_block_as_u64();
         log::debug!("block_step for block: {:?} ", block_number);
-        // --- 1. Adjust difficulties.
-        Self::adjust_registration_terms_for_networks();
-        // --- 2. Run emission through network.
-        Self::run_coinbase();
-        // Return ok.
-        Ok(())
+        let mut used_weight: Weight = Self::fixed_block_step_weight();
+        // --- 1. Lift hotkey sanctions, stake locks and delegate grace periods that end at this
+        // block. These cannot be deferred, so they are charged before any of the metered phases.
+        used_weight.saturating_accrue(Self::lift_expired_hotkey_sanctions(block_number));
+        used_weight.saturating_accrue(Self::unlock_expired_stake_locks(block_number));
+        used_weight.saturating_accrue(Self::report_inactive_delegates(block_number));
+        // --- 2. Adjust difficulties.
+        Self::adjust_registration_terms_for_networks(&mut used_weight);
+        // --- 3. Return control of subnets whose lease has ended.
+        Self::expire_subnet_leases();
+        // --- 4. Resume the emission of subnets whose sanction has ended.
+        Self::reenable_subnet_emissions(block_number);
+        // --- 4a. Run emission through network.
+        Self::run_coinbase(&mut used_weight);
+        // --- 5. Drop metrics older than the retention window.
+        Self::prune_block_metrics();
+        // --- 6. Checkpoint delegate stake and emission for return figures.
+        Self::record_stake_checkpoints(block_number, &mut used_weight);
+        // --- 7. Refresh the delegate statistics served to the runtime APIs.
+        Self::refresh_delegate_stats(block_number, &mut used_weight);
+        used_weight
     }
 
     /// Adjusts the network difficulties/burns of every active network. Resetting state parameters.
+    /// Adjustments that do not fit in the block step budget are left for the next block.
     ///
-    pub fn adjust_registration_terms_for_networks() {
+    pub fn adjust_registration_terms_for_networks(used_weight: &mut Weight) {
         log::debug!("adjust_registration_terms_for_networks");
 
         // --- 1. Iterate through each network.
@@ -35,7 +61,13 @@ impl<T: Config> Pallet<T> {
 
             // --- 3. Check if we are at the adjustment interval for this network.
             // If so, we need to adjust the registration difficulty based on target and actual registrations.
-            if current_block.saturating_sub(last_adjustment_block) >= adjustment_interval as u64 {
+            // An adjustment over budget stays due and runs in the next block.
+            if current_block.saturating_sub(last_adjustment_block) >= adjustment_interval as u64
+                && Self::try_charge_block_step(
+                    used_weight,
+                    Self::registration_adjustment_weight(netuid),
+                )
+            {
                 log::debug!("interval reached.");
 
                 // --- 4. Get the current counters for this network w.r.t burn and difficulty values.
@@ -158,16 +190,21 @@ impl<T: Config> Pallet<T> {
                     }
                 }
 
-                // --- 6. Drain all counters for this network for this interval.
+                // --- 6. Settle the registration auction, if the subnet runs one.
+                if SubnetRegistrationMechanism::<T>::get(netuid) == RegistrationMechanism::Auction {
+                    Self::settle_registration_auction(netuid);
+                }
+
+                // --- 7. Drain all counters for this network for this interval.
                 Self::set_last_adjustment_block(netuid, current_block);
                 Self::set_registrations_this_interval(netuid, 0);
                 Self::set_pow_registrations_this_interval(netuid, 0);
                 Self::set_burn_registrations_this_interval(netuid, 0);
             } else {
-                log::debug!("interval not reached.");
+                log::debug!("interval not reached or adjustment deferred.");
             }
 
-            // --- 7. Drain block registrations for each network. Needed for registration rate limits.
+            // --- 8. Drain block registrations for each network. Needed for registration rate limits.
             Self::set_registrations_this_block(netuid, 0);
         }
     }
@@ -239,4 +276,67 @@ impl<T: Config> Pallet<T> {
             return next_value.to_num::<u64>();
         }
     }
+
+    /// Charges `weight` to a deferrable block step phase if it fits in what is left of
+    /// BlockStepWeightLimit. Returns false, charging nothing, if the phase must be deferred.
+    pub fn try_charge_block_step(used_weight: &mut Weight, weight: Weight) -> bool {
+        let total: Weight = used_weight.saturating_add(weight);
+        if total.any_gt(T::BlockStepWeightLimit::get()) {
+            return false;
+        }
+        *used_weight = total;
+        true
+    }
+
+    /// Estimated weight of the per-subnet block step phases that cannot be deferred: lease
+    /// expiry, emission re-enabling, the root epoch, emission accumulation and metrics.
+    pub fn fixed_block_step_weight() -> Weight {
+        let subnets: u64 = Self::get_all_subnet_netuids().len() as u64;
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(subnets))
+            .saturating_add(Self::epoch_weight(Self::get_root_netuid()))
+            .saturating_add(T::DbWeight::get().reads_writes(
+                subnets.saturating_mul(12).saturating_add(20),
+                subnets.saturating_mul(4).saturating_add(10),
+            ))
+    }
+
+    /// Estimated weight of adjusting the registration terms of a subnet, including the
+    /// settlement of its registration auction.
+    pub fn registration_adjustment_weight(netuid: u16) -> Weight {
+        let bids: u64 = RegistrationBids::<T>::decode_len(netuid).unwrap_or(0) as u64;
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(bids))
+            .saturating_add(T::DbWeight::get().reads_writes(
+                bids.saturating_mul(10).saturating_add(12),
+                bids.saturating_mul(12).saturating_add(8),
+            ))
+    }
+
+    /// Estimated weight of running the epoch of a subnet, which grows with the square of its
+    /// neuron count through the weights and bonds matrices.
+    pub fn epoch_weight(netuid: u16) -> Weight {
+        let n: u64 = Self::get_subnetwork_n(netuid) as u64;
+        Weight::from_parts(100_000_000, 0)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(n))
+            .saturating_add(Weight::from_parts(50_000, 0).saturating_mul(n.saturating_mul(n)))
+            .saturating_add(T::DbWeight::get().reads_writes(
+                n.saturating_mul(6).saturating_add(40),
+                n.saturating_mul(3).saturating_add(20),
+            ))
+    }
+
+    /// Estimated weight of draining the pending emission of a hotkey to its nominators, read
+    /// from the stored nominator count of the hotkey.
+    pub fn hotkey_drain_weight(hotkey: &T::AccountId) -> Weight {
+        let nominators: u64 = HotkeyNominatorCount::<T>::get(hotkey) as u64;
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(nominators))
+            .saturating_add(T::DbWeight::get().reads_writes(
+                nominators.saturating_mul(3).saturating_add(9),
+                nominators.saturating_mul(3).saturating_add(6),
+            ))
+            // Every credited nominator and the owner's take emit a StakeAdded event.
+            .saturating_add(Self::stake_event_weight().saturating_mul(nominators.saturating_add(1)))
+    }
 }
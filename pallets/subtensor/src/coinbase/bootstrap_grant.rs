@@ -0,0 +1,98 @@
+use super::*;
+use substrate_fixed::types::I96F32;
+
+impl<T: Config> Pallet<T> {
+    /// Sets the bootstrap grant parameters for subnets registered from now on and emits the
+    /// `BootstrapGrantParamsSet` event. A zero share or zero tempos disables grants.
+    pub fn set_bootstrap_grant_params(
+        share: u16,
+        tempos: u16,
+        min_validators: u16,
+        min_miners: u16,
+    ) {
+        BootstrapGrantShare::<T>::set(share);
+        BootstrapGrantTempos::<T>::set(tempos);
+        BootstrapGrantMinValidators::<T>::set(min_validators);
+        BootstrapGrantMinMiners::<T>::set(min_miners);
+        Self::deposit_event(Event::BootstrapGrantParamsSet {
+            share,
+            tempos,
+            min_validators,
+            min_miners,
+        });
+    }
+
+    /// Opens the bootstrap grant of a newly registered subnet, if grants are enabled.
+    pub fn open_bootstrap_grant(netuid: u16) {
+        let tempos: u16 = BootstrapGrantTempos::<T>::get();
+        if tempos == 0 || BootstrapGrantShare::<T>::get() == 0 {
+            return;
+        }
+        BootstrapGrants::<T>::insert(netuid, (0, tempos));
+    }
+
+    /// Escrows the grant share of the owner cut of a bootstrapping subnet and returns the cut
+    /// left to pay out now. Called once per tempo, after the epoch has refreshed the activity
+    /// and validator permits of the subnet.
+    ///
+    /// The subnet must keep the minimum active validators and miners on every grant tempo. The
+    /// first tempo it falls short the grant is clawed back, this tempo's share included. If it
+    /// holds the minimum through the last grant tempo, the escrow is released to the subnet
+    /// controller. Escrowed emission is only minted when released, so a clawed back grant is
+    /// burned by dropping it.
+    pub fn escrow_bootstrap_grant(netuid: u16, owner_cut: u64) -> u64 {
+        let Some((escrowed, tempos_left)) = BootstrapGrants::<T>::get(netuid) else {
+            return owner_cut;
+        };
+        let share: u64 = I96F32::from_num(owner_cut)
+            .saturating_mul(
+                I96F32::from_num(BootstrapGrantShare::<T>::get())
+                    .saturating_div(I96F32::from_num(u16::MAX)),
+            )
+            .to_num::<u64>()
+            .min(owner_cut);
+        let escrowed: u64 = escrowed.saturating_add(share);
+        let tempos_left: u16 = tempos_left.saturating_sub(1);
+
+        if !Self::meets_bootstrap_grant_activity(netuid) {
+            BootstrapGrants::<T>::remove(netuid);
+            Self::deposit_event(Event::BootstrapGrantClawedBack {
+                netuid,
+                amount: escrowed,
+            });
+        } else if tempos_left > 0 {
+            BootstrapGrants::<T>::insert(netuid, (escrowed, tempos_left));
+        } else {
+            BootstrapGrants::<T>::remove(netuid);
+            let coldkey: T::AccountId = Self::get_subnet_controller(netuid);
+            Self::add_balance_to_coldkey_account(&coldkey, escrowed);
+            Self::coinbase(escrowed);
+            Self::deposit_event(Event::BootstrapGrantReleased {
+                netuid,
+                coldkey,
+                amount: escrowed,
+            });
+        }
+        owner_cut.saturating_sub(share)
+    }
+
+    /// Returns whether the subnet has the minimum active validators and miners a bootstrap
+    /// grant requires. Neurons with a validator permit count as validators, others as miners.
+    pub fn meets_bootstrap_grant_activity(netuid: u16) -> bool {
+        let active: Vec<bool> = Active::<T>::get(netuid);
+        let permits: Vec<bool> = ValidatorPermit::<T>::get(netuid);
+        let (validators, miners) = active
+            .iter()
+            .enumerate()
+            .filter(|(_, is_active)| **is_active)
+            .fold((0u16, 0u16), |(validators, miners), (uid, _)| {
+                if permits.get(uid).copied().unwrap_or(false) {
+                    (validators.saturating_add(1), miners)
+                } else {
+                    (validators, miners.saturating_add(1))
+                }
+            });
+        validators >= BootstrapGrantMinValidators::<T>::get()
+            && miners >= BootstrapGrantMinMiners::<T>::get()
+    }
+}
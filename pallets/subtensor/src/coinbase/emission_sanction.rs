@@ -0,0 +1,105 @@
+use super::*;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// Disables or re-enables a subnet's emission. While disabled the subnet keeps running its
+    /// epochs, but its block emission is never minted, burning it. Disabling also burns the
+    /// emission already pending on the subnet. With `reenable_block`, emission resumes
+    /// automatically at that block.
+    pub fn set_subnet_emission_disabled(netuid: u16, disabled: bool, reenable_block: Option<u64>) {
+        if !disabled {
+            EmissionDisabled::<T>::remove(netuid);
+            Self::deposit_event(Event::SubnetEmissionEnabled { netuid });
+            return;
+        }
+
+        let reenable_block: u64 = reenable_block.unwrap_or(u64::MAX);
+        EmissionDisabled::<T>::insert(netuid, reenable_block);
+        PendingEmission::<T>::insert(netuid, 0);
+        log::debug!(
+            "SubnetEmissionDisabled( netuid:{:?}, reenable_block:{:?} )",
+            netuid,
+            reenable_block
+        );
+        Self::deposit_event(Event::SubnetEmissionDisabled {
+            netuid,
+            reenable_block,
+        });
+    }
+
+    /// Returns true if the subnet's emission is currently burned.
+    pub fn is_subnet_emission_disabled(netuid: u16) -> bool {
+        EmissionDisabled::<T>::contains_key(netuid)
+    }
+
+    /// Re-enables the emission of every subnet whose re-enable block has been reached.
+    pub fn reenable_subnet_emissions(block: u64) {
+        let reenabled: Vec<u16> = EmissionDisabled::<T>::iter()
+            .filter(|(_, reenable_block)| *reenable_block <= block)
+            .map(|(netuid, _)| netuid)
+            .collect();
+        for netuid in reenabled {
+            Self::set_subnet_emission_disabled(netuid, false, None);
+        }
+    }
+
+    /// Burns the emission of a hotkey until `expiry_block`. Raises InvalidSanctionExpiry if the
+    /// expiry block is not in the future.
+    pub fn do_sanction_hotkey(hotkey: T::AccountId, expiry_block: u64) -> dispatch::DispatchResult {
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            expiry_block > Self::get_current_block_as_u64(),
+            Error::<T>::InvalidSanctionExpiry
+        );
+
+        SanctionedHotkeys::<T>::insert(&hotkey, expiry_block);
+        SanctionExpirations::<T>::append(expiry_block, hotkey.clone());
+        log::debug!(
+            "HotkeySanctioned( hotkey:{:?}, expiry_block:{:?} )",
+            hotkey,
+            expiry_block
+        );
+        Self::deposit_event(Event::HotkeySanctioned {
+            hotkey,
+            expiry_block,
+        });
+        Ok(())
+    }
+
+    /// Lifts the sanction of a hotkey. Raises HotkeyNotSanctioned if it has none.
+    pub fn do_lift_hotkey_sanction(hotkey: T::AccountId) -> dispatch::DispatchResult {
+        ensure!(
+            SanctionedHotkeys::<T>::take(&hotkey).is_some(),
+            Error::<T>::HotkeyNotSanctioned
+        );
+        log::debug!("HotkeySanctionLifted( hotkey:{:?} )", hotkey);
+        Self::deposit_event(Event::HotkeySanctionLifted { hotkey });
+        Ok(())
+    }
+
+    /// Returns true if the hotkey's emission is currently burned.
+    pub fn is_hotkey_sanctioned(hotkey: &T::AccountId) -> bool {
+        SanctionedHotkeys::<T>::contains_key(hotkey)
+    }
+
+    /// Lifts the sanction of every hotkey whose expiry block is `block`.
+    ///
+    /// Returns the weight consumed.
+    pub fn lift_expired_hotkey_sanctions(block: u64) -> Weight {
+        let expirations: Vec<T::AccountId> = SanctionExpirations::<T>::take(block);
+        let entries: u64 = expirations.len() as u64;
+        for hotkey in expirations {
+            // Sanctions that were moved, lifted or swapped away are no longer due at this block.
+            if SanctionedHotkeys::<T>::get(&hotkey) != Some(block) {
+                continue;
+            }
+            SanctionedHotkeys::<T>::remove(&hotkey);
+            Self::deposit_event(Event::HotkeySanctionLifted { hotkey });
+        }
+        T::DbWeight::get().reads_writes(entries.saturating_add(1), entries.saturating_add(1))
+    }
+}
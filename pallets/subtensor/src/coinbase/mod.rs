@@ -1,4 +1,7 @@
 use super::*;
 pub mod block_step;
+pub mod bootstrap_grant;
+pub mod emission_sanction;
 pub mod root;
 pub mod run_coinbase;
+pub mod treasury;
@@ -266,11 +266,7 @@ impl<T: Config> Pallet<T> {
         let subnet_list = Self::get_all_subnet_netuids();
 
         // --- 3. Iterate over stored weights and fill the matrix.
-        for (uid_i, weights_i) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(
-                Self::get_root_netuid(),
-            )
-        {
+        for (uid_i, weights_i) in Weights::<T>::iter_prefix(Self::get_root_netuid()) {
             // --- 4. Iterate over each weight entry in `weights_i` to update the corresponding value in the
             // initialized `weights` 2D vector. Here, `uid_j` represents a subnet, and `weight_ij` is the
             // weight of `uid_i` with respect to `uid_j`.
@@ -299,6 +295,13 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::NetworkRateLimitSet(limit));
     }
 
+    /// Sets the subnet emission ramp length and emit the `SubnetEmissionRampTemposSet` event
+    ///
+    pub fn set_subnet_emission_ramp_tempos(tempos: u16) {
+        SubnetEmissionRampTempos::<T>::set(tempos);
+        Self::deposit_event(Event::SubnetEmissionRampTemposSet(tempos));
+    }
+
     /// Checks if registrations are allowed for a given subnet.
     ///
     /// This function retrieves the subnet hyperparameters for the specified subnet and checks the `registration_allowed` flag.
@@ -542,7 +545,7 @@ impl<T: Config> Pallet<T> {
                     root_netuid,
                 )
             {
-                let stake_i: u64 = Self::get_total_stake_for_hotkey(&hotkey_i);
+                let stake_i: u64 = Self::get_governance_stake_for_hotkey(&hotkey_i);
                 if stake_i < lowest_stake {
                     lowest_stake = stake_i;
                     lowest_uid = uid_i;
@@ -554,7 +557,7 @@ impl<T: Config> Pallet<T> {
 
             // --- 13.1.2 The new account has a higher stake than the one being replaced.
             ensure!(
-                lowest_stake < Self::get_total_stake_for_hotkey(&hotkey),
+                lowest_stake < Self::get_governance_stake_for_hotkey(&hotkey),
                 Error::<T>::StakeTooLowForRoot
             );
 
@@ -576,7 +579,16 @@ impl<T: Config> Pallet<T> {
 
         // --- 14. Force all members on root to become a delegate.
         if !Self::hotkey_is_delegate(&hotkey) {
-            Self::delegate_hotkey(&hotkey, 11_796); // 18% cut defaulted.
+            let take: u16 = Self::get_default_delegate_take();
+            Self::ensure_delegate_take_in_range(take)?;
+            Self::delegate_hotkey(&hotkey, take);
+            Self::deposit_event(Event::DelegateAdded(coldkey.clone(), hotkey.clone(), take));
+            Self::deposit_event(Event::DelegateUpdated {
+                coldkey: coldkey.clone(),
+                hotkey: hotkey.clone(),
+                old_take: None,
+                new_take: take,
+            });
         }
 
         // --- 15. Update the registration counters for both the block and interval.
@@ -586,6 +598,7 @@ impl<T: Config> Pallet<T> {
         #[allow(clippy::arithmetic_side_effects)]
         // note this RA + clippy false positive is a known substrate issue
         RegistrationsThisBlock::<T>::mutate(root_netuid, |val| *val += 1);
+        Self::record_block_metric(|m| m.registrations = m.registrations.saturating_add(1));
 
         // --- 16. Log and announce the successful registration.
         log::debug!(
@@ -690,8 +703,8 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
 
-        // --- 3. Grab the hotkey's stake.
-        let current_stake = Self::get_total_stake_for_hotkey(hotkey);
+        // --- 3. Grab the hotkey's stake, weighted by the conviction of any locks.
+        let current_stake = Self::get_governance_stake_for_hotkey(hotkey);
 
         // Add the hotkey to the Senate.
         // If we're full, we'll swap out the lowest stake member.
@@ -700,14 +713,14 @@ impl<T: Config> Pallet<T> {
         if (members.len() as u32) == T::SenateMembers::max_members() {
             let mut sorted_members = members.clone();
             sorted_members.sort_by(|a, b| {
-                let a_stake = Self::get_total_stake_for_hotkey(a);
-                let b_stake = Self::get_total_stake_for_hotkey(b);
+                let a_stake = Self::get_governance_stake_for_hotkey(a);
+                let b_stake = Self::get_governance_stake_for_hotkey(b);
 
                 b_stake.cmp(&a_stake)
             });
 
             if let Some(last) = sorted_members.last() {
-                let last_stake = Self::get_total_stake_for_hotkey(last);
+                let last_stake = Self::get_governance_stake_for_hotkey(last);
 
                 if last_stake < current_stake {
                     // Swap the member with the lowest stake.
@@ -775,6 +788,12 @@ impl<T: Config> Pallet<T> {
             Error::<T>::UidVecContainInvalidOne
         );
 
+        // Check to see if the number of uids fits in a stored weights row.
+        ensure!(
+            Self::check_weights_row_length(&uids),
+            Error::<T>::WeightsRowTooLong
+        );
+
         // Check to see if the hotkey is registered to the passed network.
         ensure!(
             Self::is_hotkey_registered_on_network(netuid, &hotkey),
@@ -828,7 +847,11 @@ impl<T: Config> Pallet<T> {
         }
 
         // Set weights under netuid, uid double map entry.
-        Weights::<T>::insert(netuid, neuron_uid, zipped_weights);
+        let filtered_uids: Vec<(u16, WeightsFilter)> =
+            Self::filtered_weight_uids(netuid, neuron_uid, &zipped_weights);
+        let bounded_weights: WeightsRowOf<T> =
+            Self::bound_weights_row(netuid, Self::compress_weights_row(zipped_weights))?;
+        Weights::<T>::insert(netuid, neuron_uid, bounded_weights);
 
         // Set the activity for the weights on this network.
         Self::set_last_update_for_uid(netuid, neuron_uid, current_block);
@@ -840,6 +863,8 @@ impl<T: Config> Pallet<T> {
             neuron_uid
         );
         Self::deposit_event(Event::WeightsSet(netuid, neuron_uid));
+        Self::record_block_metric(|m| m.weight_sets = m.weight_sets.saturating_add(1));
+        Self::deposit_weights_filtered(netuid, neuron_uid, filtered_uids);
 
         // Return ok.
         Ok(())
@@ -989,6 +1014,7 @@ impl<T: Config> Pallet<T> {
         NetworkLastRegistered::<T>::set(current_block_number);
         NetworkRegisteredAt::<T>::insert(netuid_to_register, current_block_number);
         SubnetOwner::<T>::insert(netuid_to_register, coldkey);
+        Self::open_bootstrap_grant(netuid_to_register);
 
         // --- 9. Emit the NetworkAdded event.
         log::debug!(
@@ -1028,6 +1054,12 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotSubnetOwner
         );
 
+        // --- 3. Ensure the subnet is not leased out.
+        ensure!(
+            !Self::is_subnet_leased(netuid),
+            Error::<T>::SubnetLeaseActive
+        );
+
         // --- 4. Remove the subnet identity if it exists.
         if SubnetIdentities::<T>::take(netuid).is_some() {
             Self::deposit_event(Event::SubnetIdentityRemoved(netuid));
@@ -1156,21 +1188,27 @@ impl<T: Config> Pallet<T> {
         NetworkRegisteredAt::<T>::remove(netuid);
 
         // --- 7. Remove incentive mechanism memory.
+        let hotkeys: Vec<T::AccountId> = Keys::<T>::iter_prefix_values(netuid).collect();
         let _ = Uids::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = Keys::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = Bonds::<T>::clear_prefix(netuid, u32::MAX, None);
+        // Delegates that lose their last registration start their inactivity grace period.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        for hotkey in hotkeys {
+            IsNetworkMember::<T>::remove(&hotkey, netuid);
+            Self::note_hotkey_deregistered(&hotkey, current_block);
+        }
 
         // --- 8. Removes the weights for this subnet (do not remove).
         let _ = Weights::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = WeightCommits::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = FreeWeightsSetBlock::<T>::clear_prefix(netuid, u32::MAX, None);
+        Self::clear_aux_scores(netuid);
 
         // --- 9. Iterate over stored weights and fill the matrix.
-        for (uid_i, weights_i) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(
-                Self::get_root_netuid(),
-            )
-        {
+        for (uid_i, weights_i) in Weights::<T>::iter_prefix(Self::get_root_netuid()) {
             // Create a new vector to hold modified weights.
-            let mut modified_weights: Vec<(u16, u16)> = weights_i.clone();
+            let mut modified_weights: Vec<(u16, u16)> = weights_i.clone().into_inner();
             // Iterate over each weight entry to potentially update it.
             for (subnet_id, weight) in modified_weights.iter_mut() {
                 if subnet_id == &netuid {
@@ -1178,7 +1216,12 @@ impl<T: Config> Pallet<T> {
                     *weight = 0; // Set weight to 0 for the matching subnet_id.
                 }
             }
-            Weights::<T>::insert(Self::get_root_netuid(), uid_i, modified_weights);
+            // Zeroed entries are dropped from storage, so the row can only shrink.
+            Weights::<T>::insert(
+                Self::get_root_netuid(),
+                uid_i,
+                WeightsRowOf::<T>::truncate_from(Self::compress_weights_row(modified_weights)),
+            );
         }
 
         // --- 10. Remove various network-related parameters.
@@ -1192,18 +1235,48 @@ impl<T: Config> Pallet<T> {
         PruningScores::<T>::remove(netuid);
         LastUpdate::<T>::remove(netuid);
         ValidatorPermit::<T>::remove(netuid);
+        MetagraphRegisteredUids::<T>::remove(netuid);
+        MetagraphPrunedUids::<T>::remove(netuid);
         ValidatorTrust::<T>::remove(netuid);
 
         // --- 11. Erase network parameters.
         Tempo::<T>::remove(netuid);
+        NextEpochBlock::<T>::remove(netuid);
+        EpochDeferrals::<T>::remove(netuid);
+        LastTempoChangeBlock::<T>::remove(netuid);
         Kappa::<T>::remove(netuid);
+        PendingKappa::<T>::remove(netuid);
+        PendingMaxWeightsLimit::<T>::remove(netuid);
+        PendingAlphaValues::<T>::remove(netuid);
+        PendingHyperparamBundle::<T>::remove(netuid);
         Difficulty::<T>::remove(netuid);
         MaxAllowedUids::<T>::remove(netuid);
+        PruneEmissionBurn::<T>::remove(netuid);
+        MinAxonVersion::<T>::remove(netuid);
+        WeightsImmunityPeriod::<T>::remove(netuid);
+        MinStakeToRegister::<T>::remove(netuid);
+        ActivePermitsOnly::<T>::remove(netuid);
+        WeightFlagTempos::<T>::remove(netuid);
+        WeightFlagDuplicateKeys::<T>::remove(netuid);
+        let _ = DegenerateWeightStreaks::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = FlaggedUids::<T>::clear_prefix(netuid, u32::MAX, None);
+        RegistrationChallengeCommit::<T>::remove(netuid);
+        ReservedHotkeys::<T>::remove(netuid);
+        let _ = DelegateYields::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = HotkeyEmissionByNetuid::<T>::clear_prefix(netuid, u32::MAX, None);
+        Self::refund_registration_bids(netuid);
+        SubnetRegistrationMechanism::<T>::remove(netuid);
         ImmunityPeriod::<T>::remove(netuid);
         ActivityCutoff::<T>::remove(netuid);
         EmissionValues::<T>::remove(netuid);
+        EmissionDisabled::<T>::remove(netuid);
+        SubnetRandomness::<T>::remove(netuid);
+        EpochChecksums::<T>::remove(netuid);
+        // An open grant was never minted, so it is burned with the subnet.
+        BootstrapGrants::<T>::remove(netuid);
         MaxWeightsLimit::<T>::remove(netuid);
         MinAllowedWeights::<T>::remove(netuid);
+        MaxAllowedWeights::<T>::remove(netuid);
         RegistrationsThisInterval::<T>::remove(netuid);
         POWRegistrationsThisInterval::<T>::remove(netuid);
         BurnRegistrationsThisInterval::<T>::remove(netuid);
@@ -1212,6 +1285,7 @@ impl<T: Config> Pallet<T> {
         Self::add_balance_to_coldkey_account(&owner_coldkey, reserved_amount);
         Self::set_subnet_locked_balance(netuid, 0);
         SubnetOwner::<T>::remove(netuid);
+        SubnetLease::<T>::remove(netuid);
 
         // --- 13. Remove subnet identity if it exists.
         if SubnetIdentities::<T>::contains_key(netuid) {
@@ -1,7 +1,13 @@
 use super::*;
+use frame_support::weights::Weight;
 use substrate_fixed::types::I64F64;
 use substrate_fixed::types::I96F32;
 
+/// Consecutive blocks the due epoch of a subnet may be deferred for lack of block step budget.
+/// Once reached, the epoch runs whatever its weight, so an epoch that never fits the budget
+/// still runs.
+pub const MAX_EPOCH_DEFERRALS: u32 = 3;
+
 impl<T: Config> Pallet<T> {
     /// The `coinbase` function performs a four-part emission distribution process involving
     /// subnets, epochs, hotkeys, and nominators.
@@ -27,7 +33,11 @@ impl<T: Config> Pallet<T> {
     // Step 4: Further distribute emissions from hotkeys to nominators.
     // Finally, the emissions received by hotkeys are further distributed to their nominators,
     // who are stakeholders that support the hotkeys.
-    pub fn run_coinbase() {
+
+    // Epochs and hotkey drains are charged to `used_weight` and deferred to the next block
+    // once they no longer fit in the block step budget. An epoch deferred MAX_EPOCH_DEFERRALS
+    // times and the oldest deferred drain run regardless, so neither waits forever.
+    pub fn run_coinbase(used_weight: &mut Weight) {
         // --- 0. Get current block.
         let current_block: u64 = Self::get_current_block_as_u64();
         log::debug!("Current block: {:?}", current_block);
@@ -53,16 +63,60 @@ impl<T: Config> Pallet<T> {
             }
             // --- 3.1 Get the network's block-wise emission amount.
             // This value is newly minted TAO which has not reached staking accounts yet.
-            let subnet_blockwise_emission: u64 = EmissionValues::<T>::get(*netuid);
+            // During the start-up ramp only a fraction is accumulated; the remainder is never
+            // minted, which recycles it.
+            let full_blockwise_emission: u64 = EmissionValues::<T>::get(*netuid);
+            let subnet_blockwise_emission: u64 = Self::audited_saturating_mul(
+                I96F32::from_num(full_blockwise_emission),
+                Self::get_subnet_emission_ramp(*netuid, current_block),
+                SaturationSite::SubnetEmission,
+            )
+            .to_num::<u64>();
+            let ramp_remainder: u64 = Self::audited_saturating_sub(
+                full_blockwise_emission,
+                subnet_blockwise_emission,
+                SaturationSite::SubnetEmission,
+            );
+            if ramp_remainder > 0 {
+                Self::increase_rao_recycled(*netuid, ramp_remainder);
+            }
+
+            // --- 3.2 Subnets with emission disabled burn it by never minting it, tithe included.
+            if Self::is_subnet_emission_disabled(*netuid) {
+                log::debug!(
+                    "Burned subnet emission for netuid {:?}: {:?}",
+                    *netuid,
+                    subnet_blockwise_emission
+                );
+                continue;
+            }
+
+            // The treasury tithe is minted directly and removed from the subnet's share.
+            let subnet_blockwise_emission: u64 =
+                Self::pay_emission_tithe(subnet_blockwise_emission);
             log::debug!(
                 "Subnet block-wise emission for netuid {:?}: {:?}",
                 *netuid,
                 subnet_blockwise_emission
             );
 
-            // --- 3.2 Accumulate the subnet emission on the subnet.
+            // --- 3.3 Hand the emission to the runtime hook, which may redirect part of it.
+            let redirected: u64 =
+                T::OnEmission::on_subnet_emission(*netuid, subnet_blockwise_emission)
+                    .min(subnet_blockwise_emission);
+            let subnet_blockwise_emission: u64 = Self::audited_saturating_sub(
+                subnet_blockwise_emission,
+                redirected,
+                SaturationSite::SubnetEmission,
+            );
+
+            // --- 3.4 Accumulate the subnet emission on the subnet.
             PendingEmission::<T>::mutate(*netuid, |subnet_emission| {
-                *subnet_emission = subnet_emission.saturating_add(subnet_blockwise_emission);
+                *subnet_emission = Self::audited_saturating_add(
+                    *subnet_emission,
+                    subnet_blockwise_emission,
+                    SaturationSite::SubnetEmission,
+                );
                 log::debug!(
                     "Updated subnet emission for netuid {:?}: {:?}",
                     *netuid,
@@ -75,8 +129,20 @@ impl<T: Config> Pallet<T> {
         // Before accumulating on the hotkeys the function redistributes the emission towards hotkey parents.
         // subnet_emission --> epoch() --> hotkey_emission --> (hotkey + parent hotkeys)
         for netuid in subnets.clone().iter() {
-            // --- 4.1 Check to see if the subnet should run its epoch.
-            if Self::should_run_epoch(*netuid, current_block) {
+            // --- 4.1 Check to see if the subnet should run its epoch, deferring it to the next
+            // block if it does not fit in this one.
+            let mut run_epoch: bool = Self::should_run_epoch(*netuid, current_block);
+            if run_epoch {
+                let epoch_weight: Weight = Self::epoch_weight(*netuid);
+                if EpochDeferrals::<T>::get(*netuid) >= MAX_EPOCH_DEFERRALS {
+                    used_weight.saturating_accrue(epoch_weight);
+                } else if !Self::try_charge_block_step(used_weight, epoch_weight) {
+                    Self::defer_epoch(*netuid, current_block);
+                    run_epoch = false;
+                }
+            }
+            if run_epoch {
+                EpochDeferrals::<T>::remove(*netuid);
                 // --- 4.2 Drain the subnet emission.
                 let mut subnet_emission: u64 = PendingEmission::<T>::get(*netuid);
                 PendingEmission::<T>::insert(*netuid, 0);
@@ -89,33 +155,62 @@ impl<T: Config> Pallet<T> {
                 // --- 4.3 Set last step counter.
                 Self::set_blocks_since_last_step(*netuid, 0);
                 Self::set_last_mechanism_step_block(*netuid, current_block);
+                Self::advance_next_epoch_block(*netuid, current_block);
 
-                // --- 4.4 Distribute owner take.
-                if SubnetOwner::<T>::contains_key(netuid) {
+                // --- 4.4 Distribute owner take, paid out once the epoch has run.
+                let owner_cut: Option<u64> = if SubnetOwner::<T>::contains_key(netuid) {
                     // Does the subnet have an owner?
 
                     // --- 4.4.1 Compute the subnet owner cut.
-                    let owner_cut: I96F32 = I96F32::from_num(subnet_emission).saturating_mul(
+                    let owner_cut: I96F32 = Self::audited_saturating_mul(
+                        I96F32::from_num(subnet_emission),
                         I96F32::from_num(Self::get_subnet_owner_cut())
                             .saturating_div(I96F32::from_num(u16::MAX)),
+                        SaturationSite::OwnerCut,
                     );
 
                     // --- 4.4.2 Remove the cut from the subnet emission
-                    subnet_emission = subnet_emission.saturating_sub(owner_cut.to_num::<u64>());
-
-                    // --- 4.4.3 Add the cut to the balance of the owner
-                    Self::add_balance_to_coldkey_account(
-                        &Self::get_subnet_owner(*netuid),
+                    subnet_emission = Self::audited_saturating_sub(
+                        subnet_emission,
                         owner_cut.to_num::<u64>(),
+                        SaturationSite::OwnerCut,
                     );
-
-                    // --- 4.4.4 Increase total issuance on the chain.
-                    Self::coinbase(owner_cut.to_num::<u64>());
-                }
+                    Some(owner_cut.to_num::<u64>())
+                } else {
+                    None
+                };
 
                 // 4.3 Pass emission through epoch() --> hotkey emission.
+                let permits_before: Vec<bool> = ValidatorPermit::<T>::get(*netuid);
                 let hotkey_emission: Vec<(T::AccountId, u64, u64)> =
                     Self::epoch(*netuid, subnet_emission);
+                // Checksum the weights before anything staged for the next tempo lands.
+                Self::record_epoch_checksums(*netuid, &hotkey_emission);
+
+                if let Some(owner_cut) = owner_cut {
+                    // --- 4.4.3 Escrow the bootstrap grant share of a new subnet's cut. This
+                    // runs after the epoch, which writes the Active and ValidatorPermit vectors
+                    // the grant's activity check reads.
+                    let owner_cut_paid: u64 = Self::escrow_bootstrap_grant(*netuid, owner_cut);
+
+                    // --- 4.4.4 Add the cut to the balance of the owner (or lessee while leased)
+                    Self::add_balance_to_coldkey_account(
+                        &Self::get_subnet_controller(*netuid),
+                        owner_cut_paid,
+                    );
+
+                    // --- 4.4.5 Increase total issuance on the chain.
+                    Self::coinbase(owner_cut_paid);
+                }
+
+                used_weight.saturating_accrue(Self::update_weight_flags(*netuid));
+                Self::emit_metagraph_digest(*netuid, &permits_before);
+                // Hyperparameters staged during the tempo govern the next one.
+                Self::apply_pending_hyperparams(*netuid);
+                // Draw the randomness beacon for the tempo that starts now.
+                Self::update_subnet_randomness(*netuid);
+                // Auxiliary scores only live for the tempo they were published in.
+                Self::clear_aux_scores(*netuid);
                 log::debug!(
                     "Hotkey emission results for netuid {:?}: {:?}",
                     *netuid,
@@ -131,6 +226,17 @@ impl<T: Config> Pallet<T> {
                         validator_emission, // Amount received from validating
                         mining_emission,    // Amount recieved from mining.
                     );
+                    if !Self::is_hotkey_sanctioned(&hotkey) {
+                        Self::record_delegate_yield(
+                            *netuid,
+                            &hotkey,
+                            Self::audited_saturating_add(
+                                mining_emission,
+                                validator_emission,
+                                SaturationSite::HotkeyEmission,
+                            ),
+                        );
+                    }
                     log::debug!("Accumulated emissions on hotkey {:?} for netuid {:?}: mining {:?}, validator {:?}", hotkey, *netuid, mining_emission, validator_emission);
                 }
             } else {
@@ -148,6 +254,28 @@ impl<T: Config> Pallet<T> {
         // We keep track of the last stake increase event for accounting purposes.
         // hotkeys --> nominators.
         let emission_tempo: u64 = Self::get_hotkey_emission_tempo();
+        let mut deferred_drains: Vec<T::AccountId> = Vec::new();
+
+        // --- 5.0 Drains deferred from earlier blocks go first. The oldest one runs even if it
+        // does not fit, so a drain heavier than the whole budget is not deferred forever.
+        let mut drained_deferred: bool = false;
+        for hotkey in DeferredHotkeyDrains::<T>::take() {
+            let hotkey_emission: u64 = PendingdHotkeyEmission::<T>::get(&hotkey);
+            if hotkey_emission == 0 {
+                continue;
+            }
+            let drain_weight: Weight = Self::hotkey_drain_weight(&hotkey);
+            if !Self::try_charge_block_step(used_weight, drain_weight) {
+                if drained_deferred {
+                    deferred_drains.push(hotkey);
+                    continue;
+                }
+                used_weight.saturating_accrue(drain_weight);
+            }
+            drained_deferred = true;
+            Self::drain_and_mint_hotkey_emission(&hotkey, hotkey_emission, current_block);
+        }
+
         for (hotkey, hotkey_emission) in PendingdHotkeyEmission::<T>::iter() {
             // Check for zeros.
             // remove zero values.
@@ -155,23 +283,65 @@ impl<T: Config> Pallet<T> {
                 continue;
             }
 
-            // --- 5.1 Check if we should drain the hotkey emission on this block.
+            // --- 5.1 Check if we should drain the hotkey emission on this block, deferring it to
+            // the next block if it does not fit in this one.
             if Self::should_drain_hotkey(&hotkey, current_block, emission_tempo) {
-                // --- 5.2 Drain the hotkey emission and distribute it to nominators.
-                let total_new_tao: u64 =
-                    Self::drain_hotkey_emission(&hotkey, hotkey_emission, current_block);
-                log::debug!(
-                    "Drained hotkey emission for hotkey {:?} on block {:?}: {:?}",
-                    hotkey,
-                    current_block,
-                    hotkey_emission
-                );
-
-                // --- 5.3 Increase total issuance on the chain.
-                Self::coinbase(total_new_tao);
-                log::debug!("Increased total issuance by {:?}", total_new_tao);
+                if !Self::try_charge_block_step(used_weight, Self::hotkey_drain_weight(&hotkey)) {
+                    if !deferred_drains.contains(&hotkey) {
+                        deferred_drains.push(hotkey);
+                    }
+                    continue;
+                }
+                Self::drain_and_mint_hotkey_emission(&hotkey, hotkey_emission, current_block);
             }
         }
+        if !deferred_drains.is_empty() {
+            log::debug!("Deferred hotkey emission drains: {:?}", deferred_drains);
+            DeferredHotkeyDrains::<T>::put(deferred_drains);
+        }
+    }
+
+    /// Drains the pending emission of a hotkey to its nominators and mints it.
+    fn drain_and_mint_hotkey_emission(hotkey: &T::AccountId, emission: u64, block_number: u64) {
+        // --- 5.2 Drain the hotkey emission and distribute it to nominators.
+        let total_new_tao: u64 = Self::drain_hotkey_emission(hotkey, emission, block_number);
+        log::debug!(
+            "Drained hotkey emission for hotkey {:?} on block {:?}: {:?}",
+            hotkey,
+            block_number,
+            emission
+        );
+
+        // --- 5.3 Increase total issuance on the chain.
+        Self::coinbase(total_new_tao);
+        log::debug!("Increased total issuance by {:?}", total_new_tao);
+    }
+
+    /// Moves a due epoch of a subnet that does not fit in this block to the next block.
+    pub fn defer_epoch(netuid: u16, current_block: u64) {
+        NextEpochBlock::<T>::insert(netuid, current_block.saturating_add(1));
+        EpochDeferrals::<T>::mutate(netuid, |deferrals| *deferrals = deferrals.saturating_add(1));
+        log::debug!("EpochDeferred( netuid:{:?} )", netuid);
+        Self::deposit_event(Event::EpochDeferred { netuid });
+    }
+
+    /// Returns the fraction of its computed emission a subnet receives at `current_block`.
+    ///
+    /// A subnet in its n-th tempo (starting at 0) since registration receives
+    /// (n + 1) / (K + 1) of its emission, where K is `SubnetEmissionRampTempos`,
+    /// and the full emission from the K-th tempo onwards.
+    pub fn get_subnet_emission_ramp(netuid: u16, current_block: u64) -> I96F32 {
+        let ramp_tempos: u64 = SubnetEmissionRampTempos::<T>::get() as u64;
+        let blocks_per_tempo: u64 = (Self::get_tempo(netuid) as u64).saturating_add(1);
+        let tempos_elapsed: u64 = current_block
+            .saturating_sub(NetworkRegisteredAt::<T>::get(netuid))
+            .checked_div(blocks_per_tempo)
+            .unwrap_or(0);
+        if tempos_elapsed >= ramp_tempos {
+            return I96F32::from_num(1);
+        }
+        I96F32::from_num(tempos_elapsed.saturating_add(1))
+            .saturating_div(I96F32::from_num(ramp_tempos.saturating_add(1)))
     }
 
     /// Accumulates the mining and validator emissions on a hotkey and distributes the validator emission among its parents.
@@ -196,13 +366,20 @@ impl<T: Config> Pallet<T> {
         // --- 1. First, calculate the hotkey's share of the emission.
         let take_proportion: I64F64 = I64F64::from_num(Self::get_childkey_take(hotkey, netuid))
             .saturating_div(I64F64::from_num(u16::MAX));
-        let hotkey_take: u64 = take_proportion
-            .saturating_mul(I64F64::from_num(validating_emission))
-            .to_num::<u64>();
+        let hotkey_take: u64 = Self::audited_saturating_mul_i64f64(
+            take_proportion,
+            I64F64::from_num(validating_emission),
+            SaturationSite::HotkeyEmission,
+        )
+        .to_num::<u64>();
         // NOTE: Only the validation emission should be split amongst parents.
 
         // --- 2. Compute the remaining emission after the hotkey's share is deducted.
-        let emission_minus_take: u64 = validating_emission.saturating_sub(hotkey_take);
+        let emission_minus_take: u64 = Self::audited_saturating_sub(
+            validating_emission,
+            hotkey_take,
+            SaturationSite::HotkeyEmission,
+        );
 
         // --- 3. Track the remaining emission for accounting purposes.
         let mut remaining_emission: u64 = emission_minus_take;
@@ -220,31 +397,73 @@ impl<T: Config> Pallet<T> {
 
                 // --- 5.2 Calculate the portion of the hotkey's total stake contributed by this parent.
                 // Then, determine the parent's share of the remaining emission.
-                let stake_from_parent: I96F32 = I96F32::from_num(parent_stake).saturating_mul(
+                let stake_from_parent: I96F32 = Self::audited_saturating_mul(
+                    I96F32::from_num(parent_stake),
                     I96F32::from_num(proportion).saturating_div(I96F32::from_num(u64::MAX)),
+                    SaturationSite::HotkeyEmission,
                 );
                 let proportion_from_parent: I96F32 =
                     stake_from_parent.saturating_div(I96F32::from_num(total_hotkey_stake));
-                let parent_emission_take: u64 = proportion_from_parent
-                    .saturating_mul(I96F32::from_num(emission_minus_take))
-                    .to_num::<u64>();
+                let parent_emission_take: u64 = Self::audited_saturating_mul(
+                    proportion_from_parent,
+                    I96F32::from_num(emission_minus_take),
+                    SaturationSite::HotkeyEmission,
+                )
+                .to_num::<u64>();
 
                 // --- 5.5. Accumulate emissions for the parent hotkey.
-                PendingdHotkeyEmission::<T>::mutate(parent, |parent_accumulated| {
-                    *parent_accumulated = parent_accumulated.saturating_add(parent_emission_take)
-                });
+                Self::accrue_hotkey_emission(&parent, netuid, parent_emission_take);
 
                 // --- 5.6. Subtract the parent's share from the remaining emission for this hotkey.
-                remaining_emission = remaining_emission.saturating_sub(parent_emission_take);
+                remaining_emission = Self::audited_saturating_sub(
+                    remaining_emission,
+                    parent_emission_take,
+                    SaturationSite::HotkeyEmission,
+                );
             }
         }
 
         // --- 6. Add the remaining emission plus the hotkey's initial take to the pending emission for this hotkey.
+        let hotkey_emission: u64 = Self::audited_saturating_add(
+            Self::audited_saturating_add(
+                remaining_emission,
+                hotkey_take,
+                SaturationSite::HotkeyEmission,
+            ),
+            mining_emission,
+            SaturationSite::HotkeyEmission,
+        );
+        Self::accrue_hotkey_emission(hotkey, netuid, hotkey_emission);
+    }
+
+    /// Adds emission to the pending emission of a hotkey and records which subnet it came from.
+    /// The emission of a sanctioned hotkey is burned instead, so a sanction holds whether the
+    /// hotkey earns it directly or as a parent.
+    pub fn accrue_hotkey_emission(hotkey: &T::AccountId, netuid: u16, amount: u64) {
+        if Self::is_hotkey_sanctioned(hotkey) {
+            if amount > 0 {
+                Self::deposit_event(Event::SanctionedHotkeyEmissionBurned {
+                    hotkey: hotkey.clone(),
+                    netuid,
+                    amount,
+                });
+            }
+            return;
+        }
         PendingdHotkeyEmission::<T>::mutate(hotkey, |hotkey_pending| {
-            *hotkey_pending = hotkey_pending.saturating_add(
-                remaining_emission
-                    .saturating_add(hotkey_take)
-                    .saturating_add(mining_emission),
+            *hotkey_pending = Self::audited_saturating_add(
+                *hotkey_pending,
+                amount,
+                SaturationSite::HotkeyEmission,
+            )
+        });
+
+        // Record which subnet the emission came from.
+        HotkeyEmissionByNetuid::<T>::mutate(netuid, hotkey, |hotkey_by_netuid| {
+            *hotkey_by_netuid = Self::audited_saturating_add(
+                *hotkey_by_netuid,
+                amount,
+                SaturationSite::HotkeyEmission,
             )
         });
     }
@@ -291,22 +510,32 @@ impl<T: Config> Pallet<T> {
         // --- 4 Calculate the emission take for the hotkey.
         let take_proportion: I64F64 = I64F64::from_num(Delegates::<T>::get(hotkey))
             .saturating_div(I64F64::from_num(u16::MAX));
-        let hotkey_take: u64 =
-            (take_proportion.saturating_mul(I64F64::from_num(emission))).to_num::<u64>();
+        let hotkey_take: u64 = Self::audited_saturating_mul_i64f64(
+            take_proportion,
+            I64F64::from_num(emission),
+            SaturationSite::NominatorEmission,
+        )
+        .to_num::<u64>();
 
         // --- 5 Compute the remaining emission after deducting the hotkey's take.
-        let emission_minus_take: u64 = emission.saturating_sub(hotkey_take);
+        let emission_minus_take: u64 =
+            Self::audited_saturating_sub(emission, hotkey_take, SaturationSite::NominatorEmission);
 
         // --- 6 Calculate the remaining emission after the hotkey's take.
         let mut remainder: u64 = emission_minus_take;
 
         // --- 7 Iterate over each nominator and get all viable stake.
+        // Both walks cover the whole Stake prefix within this block, so that every share is
+        // taken of the same total. hotkey_drain_weight meters them by HotkeyNominatorCount.
         let mut total_viable_nominator_stake: u64 = total_hotkey_stake;
         for (nominator, _) in Stake::<T>::iter_prefix(hotkey) {
             let nonviable_nomintaor_stake = Self::get_nonviable_stake(hotkey, &nominator);
 
-            total_viable_nominator_stake =
-                total_viable_nominator_stake.saturating_sub(nonviable_nomintaor_stake);
+            total_viable_nominator_stake = Self::audited_saturating_sub(
+                total_viable_nominator_stake,
+                nonviable_nomintaor_stake,
+                SaturationSite::NominatorEmission,
+            );
         }
 
         // --- 8 Iterate over each nominator.
@@ -314,14 +543,20 @@ impl<T: Config> Pallet<T> {
             for (nominator, nominator_stake) in Stake::<T>::iter_prefix(hotkey) {
                 // --- 9 Skip emission for any stake the was added by the nominator since the last emission drain.
                 // This means the nominator will get emission on existing stake, but not on new stake, until the next emission drain.
-                let viable_nominator_stake =
-                    nominator_stake.saturating_sub(Self::get_nonviable_stake(hotkey, &nominator));
+                let viable_nominator_stake = Self::audited_saturating_sub(
+                    nominator_stake,
+                    Self::get_nonviable_stake(hotkey, &nominator),
+                    SaturationSite::NominatorEmission,
+                );
 
                 // --- 10 Calculate this nominator's share of the emission.
-                let nominator_emission: I64F64 = I64F64::from_num(viable_nominator_stake)
-                    .checked_div(I64F64::from_num(total_viable_nominator_stake))
-                    .unwrap_or(I64F64::from_num(0))
-                    .saturating_mul(I64F64::from_num(emission_minus_take));
+                let nominator_emission: I64F64 = Self::audited_saturating_mul_i64f64(
+                    I64F64::from_num(viable_nominator_stake)
+                        .checked_div(I64F64::from_num(total_viable_nominator_stake))
+                        .unwrap_or(I64F64::from_num(0)),
+                    I64F64::from_num(emission_minus_take),
+                    SaturationSite::NominatorEmission,
+                );
 
                 // --- 11 Increase the stake for the nominator.
                 Self::increase_stake_on_coldkey_hotkey_account(
@@ -331,21 +566,33 @@ impl<T: Config> Pallet<T> {
                 );
 
                 // --- 12* Record event and Subtract the nominator's emission from the remainder.
-                total_new_tao = total_new_tao.saturating_add(nominator_emission.to_num::<u64>());
-                remainder = remainder.saturating_sub(nominator_emission.to_num::<u64>());
+                total_new_tao = Self::audited_saturating_add(
+                    total_new_tao,
+                    nominator_emission.to_num::<u64>(),
+                    SaturationSite::NominatorEmission,
+                );
+                remainder = Self::audited_saturating_sub(
+                    remainder,
+                    nominator_emission.to_num::<u64>(),
+                    SaturationSite::NominatorEmission,
+                );
             }
         }
 
         // --- 13 Finally, add the stake to the hotkey itself, including its take and the remaining emission.
-        let hotkey_new_tao: u64 = hotkey_take.saturating_add(remainder);
+        let hotkey_new_tao: u64 =
+            Self::audited_saturating_add(hotkey_take, remainder, SaturationSite::NominatorEmission);
         Self::increase_stake_on_hotkey_account(hotkey, hotkey_new_tao);
 
         // --- 14 Reset the stake delta for the hotkey.
         let _ = StakeDeltaSinceLastEmissionDrain::<T>::clear_prefix(hotkey, u32::MAX, None);
 
         // --- 15 Record new tao creation event and return the amount created.
-        total_new_tao = total_new_tao.saturating_add(hotkey_new_tao);
-        total_new_tao
+        Self::audited_saturating_add(
+            total_new_tao,
+            hotkey_new_tao,
+            SaturationSite::NominatorEmission,
+        )
     }
 
     ///////////////
@@ -387,15 +634,63 @@ impl<T: Config> Pallet<T> {
     ///   100      0              99
     ///   100      1              98
     /// Special case: tempo = 0, the network never runs.
+    /// Once the tempo of a network has been changed, its next epoch block is anchored in
+    /// NextEpochBlock instead.
     ///
     pub fn blocks_until_next_epoch(netuid: u16, tempo: u16, block_number: u64) -> u64 {
         if tempo == 0 {
             return u64::MAX;
         }
+        if let Some(next_epoch_block) = NextEpochBlock::<T>::get(netuid) {
+            return next_epoch_block.saturating_sub(block_number);
+        }
         let netuid_plus_one = (netuid as u64).saturating_add(1);
         let block_plus_netuid = block_number.saturating_add(netuid_plus_one);
         let tempo_plus_one = (tempo as u64).saturating_add(1);
         let remainder = block_plus_netuid.rem_euclid(tempo_plus_one);
         (tempo as u64).saturating_sub(remainder)
     }
+
+    /// Changes the tempo of a network without skipping or repeating an epoch. The next epoch
+    /// is anchored one new tempo after the last one, or at the next block if that has already
+    /// passed, and every later epoch follows one tempo after the previous.
+    pub fn change_tempo(netuid: u16, tempo: u16) {
+        if tempo == 0 {
+            NextEpochBlock::<T>::remove(netuid);
+        } else {
+            let next_epoch_block: u64 = Self::get_last_mechanism_step_block(netuid)
+                .saturating_add(tempo as u64)
+                .saturating_add(1)
+                .max(Self::get_current_block_as_u64().saturating_add(1));
+            NextEpochBlock::<T>::insert(netuid, next_epoch_block);
+        }
+        Self::set_tempo(netuid, tempo);
+        LastTempoChangeBlock::<T>::insert(netuid, Self::get_current_block_as_u64());
+    }
+
+    /// Ensures a subnet owner may change the tempo of a network to `tempo`: it is at least
+    /// MinOwnerTempo and OwnerTempoChangeRateLimit blocks have passed since the last change.
+    pub fn ensure_owner_tempo_change(netuid: u16, tempo: u16) -> Result<(), Error<T>> {
+        ensure!(tempo >= T::MinOwnerTempo::get(), Error::<T>::TempoTooLow);
+        ensure!(
+            LastTempoChangeBlock::<T>::get(netuid).map_or(true, |last| {
+                Self::get_current_block_as_u64().saturating_sub(last)
+                    >= T::OwnerTempoChangeRateLimit::get()
+            }),
+            Error::<T>::TempoChangeRateLimitExceeded
+        );
+        Ok(())
+    }
+
+    /// Moves the anchored next epoch of a network one tempo past the epoch that just ran.
+    pub fn advance_next_epoch_block(netuid: u16, current_block: u64) {
+        if NextEpochBlock::<T>::contains_key(netuid) {
+            NextEpochBlock::<T>::insert(
+                netuid,
+                current_block
+                    .saturating_add(Self::get_tempo(netuid) as u64)
+                    .saturating_add(1),
+            );
+        }
+    }
 }
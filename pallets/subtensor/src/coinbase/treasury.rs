@@ -0,0 +1,226 @@
+use super::*;
+use frame_support::traits::{
+    tokens::{
+        fungible::{Inspect as _, Mutate as _},
+        Fortitude, Preservation,
+    },
+    Get,
+};
+use frame_support::PalletId;
+use frame_system::ensure_root;
+use sp_runtime::traits::AccountIdConversion;
+use substrate_fixed::types::I96F32;
+
+/// Identifier the keyless treasury account is derived from.
+pub const TREASURY_PALLET_ID: PalletId = PalletId(*b"st/trsry");
+
+/// Highest emission tithe governance may set, a fifth of block emission.
+pub const MAX_EMISSION_TITHE: u16 = u16::MAX / 5;
+
+impl<T: Config> Pallet<T> {
+    /// Returns the treasury account. No private key exists for it; funds only leave it
+    /// through approved spend proposals.
+    pub fn get_treasury_account() -> T::AccountId {
+        TREASURY_PALLET_ID.into_account_truncating()
+    }
+
+    /// Sets the emission tithe, at most MAX_EMISSION_TITHE, and emit the `EmissionTitheSet`
+    /// event
+    ///
+    pub fn set_emission_tithe(tithe: u16) {
+        let tithe: u16 = tithe.min(MAX_EMISSION_TITHE);
+        EmissionTithe::<T>::set(tithe);
+        Self::deposit_event(Event::EmissionTitheSet(tithe));
+    }
+
+    /// Returns the treasury balance that spends may use: everything above the existential
+    /// deposit, which keeps the account alive, and the proposal deposits it holds.
+    pub fn get_treasury_spendable_balance() -> u64 {
+        T::Currency::reducible_balance(
+            &Self::get_treasury_account(),
+            Preservation::Preserve,
+            Fortitude::Polite,
+        )
+        .saturating_sub(TreasuryDepositsHeld::<T>::get())
+    }
+
+    /// Mints the tithe of a subnet's block emission into the treasury and returns the
+    /// emission left for the subnet. While the tithe cannot be credited, e.g. it is below the
+    /// existential deposit of a treasury that does not exist yet, the subnet keeps it.
+    pub fn pay_emission_tithe(subnet_blockwise_emission: u64) -> u64 {
+        let tithe: u64 = Self::audited_saturating_mul(
+            I96F32::from_num(subnet_blockwise_emission),
+            I96F32::from_num(EmissionTithe::<T>::get()).saturating_div(I96F32::from_num(u16::MAX)),
+            SaturationSite::SubnetEmission,
+        )
+        .to_num::<u64>();
+        if tithe == 0
+            || !Self::can_add_balance_to_coldkey_account(&Self::get_treasury_account(), tithe)
+        {
+            return subnet_blockwise_emission;
+        }
+        Self::add_balance_to_coldkey_account(&Self::get_treasury_account(), tithe);
+        Self::coinbase(tithe);
+        Self::audited_saturating_sub(
+            subnet_blockwise_emission,
+            tithe,
+            SaturationSite::SubnetEmission,
+        )
+    }
+
+    /// ---- The implementation for the extrinsic propose_treasury_spend.
+    ///
+    /// Each coldkey may have one open proposal, which is replaced by a new one. Opening a
+    /// proposal moves TreasuryProposalDeposit into the treasury; it is refunded on approval
+    /// and kept by the treasury on rejection.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the proposing coldkey.
+    ///
+    /// * 'beneficiary' (T::AccountId):
+    ///     - The account to be paid.
+    ///
+    /// * 'amount' (u64):
+    ///     - The amount to be paid from the treasury.
+    ///
+    /// # Event:
+    /// * TreasurySpendProposed;
+    ///     - On successfully recording the proposal.
+    ///
+    /// # Raises:
+    /// * 'InsufficientTreasuryBalance':
+    ///     - The amount is zero or more than the treasury can spend.
+    ///
+    /// * 'NotEnoughBalanceForTreasuryDeposit':
+    ///     - The proposer cannot pay the proposal deposit.
+    ///
+    pub fn do_propose_treasury_spend(
+        origin: T::RuntimeOrigin,
+        beneficiary: T::AccountId,
+        amount: u64,
+    ) -> dispatch::DispatchResult {
+        let proposer = ensure_signed(origin)?;
+
+        ensure!(
+            amount > 0 && amount <= Self::get_treasury_spendable_balance(),
+            Error::<T>::InsufficientTreasuryBalance
+        );
+
+        // A replaced proposal keeps the deposit already paid for it.
+        let deposit: u64 = match TreasurySpendProposals::<T>::get(&proposer) {
+            Some((_, _, deposit)) => deposit,
+            None => {
+                let deposit: u64 = T::TreasuryProposalDeposit::get();
+                T::Currency::transfer(
+                    &proposer,
+                    &Self::get_treasury_account(),
+                    deposit,
+                    Preservation::Preserve,
+                )
+                .map_err(|_| Error::<T>::NotEnoughBalanceForTreasuryDeposit)?;
+                TreasuryDepositsHeld::<T>::mutate(|held| *held = held.saturating_add(deposit));
+                deposit
+            }
+        };
+        TreasurySpendProposals::<T>::insert(&proposer, (beneficiary.clone(), amount, deposit));
+
+        log::debug!(
+            "TreasurySpendProposed( proposer:{:?}, beneficiary:{:?}, amount:{:?} )",
+            proposer,
+            beneficiary,
+            amount
+        );
+        Self::deposit_event(Event::TreasurySpendProposed {
+            proposer,
+            beneficiary,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic approve_treasury_spend.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - Must be root.
+    ///
+    /// * 'proposer' (T::AccountId):
+    ///     - The coldkey whose proposal is approved.
+    ///
+    /// # Event:
+    /// * TreasurySpendApproved;
+    ///     - On successfully paying the beneficiary.
+    ///
+    /// # Raises:
+    /// * 'TreasuryProposalNotFound':
+    ///     - The proposer has no open proposal.
+    ///
+    /// * 'InsufficientTreasuryBalance':
+    ///     - The treasury cannot pay the amount and stay alive.
+    ///
+    pub fn do_approve_treasury_spend(
+        origin: T::RuntimeOrigin,
+        proposer: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        ensure_root(origin)?;
+
+        let (beneficiary, amount, deposit) = TreasurySpendProposals::<T>::get(&proposer)
+            .ok_or(Error::<T>::TreasuryProposalNotFound)?;
+        ensure!(
+            amount <= Self::get_treasury_spendable_balance(),
+            Error::<T>::InsufficientTreasuryBalance
+        );
+
+        // Both transfers leave the existential deposit in the treasury, so it is never reaped.
+        let treasury: T::AccountId = Self::get_treasury_account();
+        let paid: u64 =
+            T::Currency::transfer(&treasury, &beneficiary, amount, Preservation::Preserve)?;
+        T::Currency::transfer(&treasury, &proposer, deposit, Preservation::Preserve)?;
+        TreasuryDepositsHeld::<T>::mutate(|held| *held = held.saturating_sub(deposit));
+        TreasurySpendProposals::<T>::remove(&proposer);
+
+        log::debug!(
+            "TreasurySpendApproved( proposer:{:?}, beneficiary:{:?}, amount:{:?} )",
+            proposer,
+            beneficiary,
+            paid
+        );
+        Self::deposit_event(Event::TreasurySpendApproved {
+            proposer,
+            beneficiary,
+            amount: paid,
+        });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic reject_treasury_spend.
+    ///
+    /// The proposal deposit stays in the treasury.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - Must be root.
+    ///
+    /// * 'proposer' (T::AccountId):
+    ///     - The coldkey whose proposal is rejected.
+    ///
+    /// # Event:
+    /// * TreasurySpendRejected;
+    ///     - On successfully removing the proposal.
+    ///
+    pub fn do_reject_treasury_spend(
+        origin: T::RuntimeOrigin,
+        proposer: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        ensure_root(origin)?;
+
+        let (_, _, deposit) = TreasurySpendProposals::<T>::take(&proposer)
+            .ok_or(Error::<T>::TreasuryProposalNotFound)?;
+        TreasuryDepositsHeld::<T>::mutate(|held| *held = held.saturating_sub(deposit));
+
+        log::debug!("TreasurySpendRejected( proposer:{:?} )", proposer);
+        Self::deposit_event(Event::TreasurySpendRejected { proposer });
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+//! Host functions for the heaviest matrix operations of the epoch.
+//!
+//! With the `native-epoch-math` feature the sparse epoch computes its weighted medians and
+//! stake-weighted sums through `subtensor_math`, which runs natively on the node and spreads
+//! independent columns over threads. The host runs the same fixed point code as `math`, so its
+//! results are bit-identical to the wasm fallback used without the feature. Every node must
+//! register `subtensor_math::HostFunctions` before a runtime built with the feature is enacted.
+//! `benchmark_epoch` runs a full 4096-neuron epoch; benchmarking runtimes built with and without
+//! the feature measures what the host functions save.
+use crate::epoch::math::*;
+use sp_runtime_interface::runtime_interface;
+use sp_std::vec::Vec;
+use substrate_fixed::types::I32F32;
+
+/// Native implementations of epoch matrix operations over raw I32F32 bits.
+#[runtime_interface]
+pub trait SubtensorMath {
+    /// Column-wise sparse weighted median, as `math::weighted_median_col_sparse`.
+    fn weighted_median_col_sparse(
+        stake: Vec<i64>,
+        score: Vec<Vec<(u16, i64)>>,
+        columns: u16,
+        majority: i64,
+    ) -> Vec<i64> {
+        to_bits(&parallel_weighted_median_col_sparse(
+            &from_bits(&stake),
+            &sparse_from_bits(&score),
+            columns,
+            I32F32::from_bits(majority),
+        ))
+    }
+
+    /// Sparse matrix-vector product, as `math::matmul_sparse`.
+    fn matmul_sparse(matrix: Vec<Vec<(u16, i64)>>, vector: Vec<i64>, columns: u16) -> Vec<i64> {
+        to_bits(&crate::epoch::math::matmul_sparse(
+            &sparse_from_bits(&matrix),
+            &from_bits(&vector),
+            columns,
+        ))
+    }
+}
+
+/// Column-wise sparse weighted median, computed by the host with `native-epoch-math`.
+#[cfg(feature = "native-epoch-math")]
+pub fn epoch_weighted_median_col_sparse(
+    stake: &[I32F32],
+    score: &[Vec<(u16, I32F32)>],
+    columns: u16,
+    majority: I32F32,
+) -> Vec<I32F32> {
+    from_bits(&subtensor_math::weighted_median_col_sparse(
+        to_bits(stake),
+        sparse_to_bits(score),
+        columns,
+        majority.to_bits(),
+    ))
+}
+
+/// Column-wise sparse weighted median, computed in the runtime.
+#[cfg(not(feature = "native-epoch-math"))]
+pub fn epoch_weighted_median_col_sparse(
+    stake: &[I32F32],
+    score: &[Vec<(u16, I32F32)>],
+    columns: u16,
+    majority: I32F32,
+) -> Vec<I32F32> {
+    weighted_median_col_sparse(stake, score, columns, majority)
+}
+
+/// Sparse matrix-vector product, computed by the host with `native-epoch-math`.
+#[cfg(feature = "native-epoch-math")]
+pub fn epoch_matmul_sparse(
+    matrix: &[Vec<(u16, I32F32)>],
+    vector: &[I32F32],
+    columns: u16,
+) -> Vec<I32F32> {
+    from_bits(&subtensor_math::matmul_sparse(
+        sparse_to_bits(matrix),
+        to_bits(vector),
+        columns,
+    ))
+}
+
+/// Sparse matrix-vector product, computed in the runtime.
+#[cfg(not(feature = "native-epoch-math"))]
+pub fn epoch_matmul_sparse(
+    matrix: &[Vec<(u16, I32F32)>],
+    vector: &[I32F32],
+    columns: u16,
+) -> Vec<I32F32> {
+    matmul_sparse(matrix, vector, columns)
+}
+
+/// Splits the columns of a weighted median into one contiguous range per thread. Each column is
+/// computed exactly as `weighted_median_col_sparse` computes it, so the result does not depend
+/// on the number of threads.
+#[cfg(feature = "std")]
+fn parallel_weighted_median_col_sparse(
+    stake: &[I32F32],
+    score: &[Vec<(u16, I32F32)>],
+    columns: u16,
+    majority: I32F32,
+) -> Vec<I32F32> {
+    let threads: u16 = std::thread::available_parallelism()
+        .map(|n| u16::try_from(n.get()).unwrap_or(u16::MAX))
+        .unwrap_or(1);
+    let chunk: u16 = columns.div_ceil(threads).max(1);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..columns)
+            .step_by(chunk as usize)
+            .map(|first| {
+                let last: u16 = first.saturating_add(chunk).min(columns);
+                scope.spawn(move || {
+                    // Keep only this range's columns, renumbered from zero.
+                    let range_score: Vec<Vec<(u16, I32F32)>> = score
+                        .iter()
+                        .map(|row| {
+                            row.iter()
+                                .filter(|(c, _)| (first..last).contains(c))
+                                .map(|(c, v)| (c.saturating_sub(first), *v))
+                                .collect()
+                        })
+                        .collect();
+                    weighted_median_col_sparse(
+                        stake,
+                        &range_score,
+                        last.saturating_sub(first),
+                        majority,
+                    )
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    })
+}
+
+#[allow(dead_code)]
+fn to_bits(vector: &[I32F32]) -> Vec<i64> {
+    vector.iter().map(|v| v.to_bits()).collect()
+}
+
+#[allow(dead_code)]
+fn from_bits(vector: &[i64]) -> Vec<I32F32> {
+    vector.iter().map(|v| I32F32::from_bits(*v)).collect()
+}
+
+#[allow(dead_code)]
+fn sparse_to_bits(matrix: &[Vec<(u16, I32F32)>]) -> Vec<Vec<(u16, i64)>> {
+    matrix
+        .iter()
+        .map(|row| row.iter().map(|(c, v)| (*c, v.to_bits())).collect())
+        .collect()
+}
+
+#[allow(dead_code)]
+fn sparse_from_bits(matrix: &[Vec<(u16, i64)>]) -> Vec<Vec<(u16, I32F32)>> {
+    matrix
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|(c, v)| (*c, I32F32::from_bits(*v)))
+                .collect()
+        })
+        .collect()
+}
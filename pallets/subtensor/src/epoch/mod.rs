@@ -1,3 +1,4 @@
 use super::*;
+pub mod host;
 pub mod math;
 pub mod run_epoch;
@@ -1,4 +1,5 @@
 use super::*;
+use crate::epoch::host::{epoch_matmul_sparse, epoch_weighted_median_col_sparse};
 use crate::epoch::math::*;
 use frame_support::IterableStorageDoubleMap;
 use sp_std::vec;
@@ -72,9 +73,15 @@ impl<T: Config> Pallet<T> {
 
         // Calculate the final stake for the hotkey by adjusting the initial stake with the stakes
         // to/from children and parents.
-        let mut finalized_stake: u64 = initial_stake
-            .saturating_sub(stake_to_children)
-            .saturating_add(stake_from_parents);
+        let mut finalized_stake: u64 = Self::audited_saturating_add(
+            Self::audited_saturating_sub(
+                initial_stake,
+                stake_to_children,
+                SaturationSite::EpochStake,
+            ),
+            stake_from_parents,
+            SaturationSite::EpochStake,
+        );
 
         // get the max stake for the network
         let max_stake = Self::get_network_max_stake(netuid);
@@ -171,7 +178,8 @@ impl<T: Config> Pallet<T> {
         log::trace!("max_allowed_validators: {:?}", max_allowed_validators);
 
         // Get new validator permits.
-        let new_validator_permits: Vec<bool> = is_topk(&stake, max_allowed_validators as usize);
+        let new_validator_permits: Vec<bool> =
+            Self::get_new_validator_permits(netuid, &stake, &active, max_allowed_validators);
         log::trace!("new_validator_permits: {:?}", new_validator_permits);
 
         // ==================
@@ -202,6 +210,21 @@ impl<T: Config> Pallet<T> {
         inplace_mask_rows(&validator_forbids, &mut weights);
         log::trace!("W (permit): {:?}", &weights);
 
+        // Mask weights of validators that set fewer weights than the subnet requires.
+        let weighted_uids: Vec<Vec<u16>> = weights
+            .iter()
+            .map(|row| {
+                (0..n)
+                    .zip(row)
+                    .filter(|(_, weight)| **weight > I32F32::from_num(0))
+                    .map(|(uid_j, _)| uid_j)
+                    .collect()
+            })
+            .collect();
+        let below_floor: Vec<bool> = Self::get_weights_below_floor(netuid, &weighted_uids);
+        inplace_mask_rows(&below_floor, &mut weights);
+        log::trace!("W (permit+floor): {:?}", &weights);
+
         // Remove self-weight by masking diagonal.
         inplace_mask_diag(&mut weights);
         log::trace!("W (permit+diag):\n{:?}\n", &weights);
@@ -306,7 +329,13 @@ impl<T: Config> Pallet<T> {
 
         let server_emission: Vec<I96F32> = normalized_server_emission
             .iter()
-            .map(|se: &I32F32| I96F32::from_num(*se).saturating_mul(float_rao_emission))
+            .map(|se: &I32F32| {
+                Self::audited_saturating_mul(
+                    I96F32::from_num(*se),
+                    float_rao_emission,
+                    SaturationSite::EpochEmission,
+                )
+            })
             .collect();
         let server_emission: Vec<u64> = server_emission
             .iter()
@@ -315,7 +344,13 @@ impl<T: Config> Pallet<T> {
 
         let validator_emission: Vec<I96F32> = normalized_validator_emission
             .iter()
-            .map(|ve: &I32F32| I96F32::from_num(*ve).saturating_mul(float_rao_emission))
+            .map(|ve: &I32F32| {
+                Self::audited_saturating_mul(
+                    I96F32::from_num(*ve),
+                    float_rao_emission,
+                    SaturationSite::EpochEmission,
+                )
+            })
             .collect();
         let validator_emission: Vec<u64> = validator_emission
             .iter()
@@ -325,7 +360,13 @@ impl<T: Config> Pallet<T> {
         // Used only to track combined emission in the storage.
         let combined_emission: Vec<I96F32> = normalized_combined_emission
             .iter()
-            .map(|ce: &I32F32| I96F32::from_num(*ce).saturating_mul(float_rao_emission))
+            .map(|ce: &I32F32| {
+                Self::audited_saturating_mul(
+                    I96F32::from_num(*ce),
+                    float_rao_emission,
+                    SaturationSite::EpochEmission,
+                )
+            })
             .collect();
         let combined_emission: Vec<u64> = combined_emission
             .iter()
@@ -401,10 +442,15 @@ impl<T: Config> Pallet<T> {
                     let new_bonds_row: Vec<(u16, u16)> = (0..n)
                         .zip(vec_fixed_proportions_to_u16(ema_bond.clone()))
                         .collect();
-                    Bonds::<T>::insert(netuid, i as u16, new_bonds_row);
+                    // Rows hold at most n <= MaxAllowedUids <= MaxWeightsRowLength pairs.
+                    Bonds::<T>::insert(
+                        netuid,
+                        i as u16,
+                        WeightsRowOf::<T>::truncate_from(new_bonds_row),
+                    );
                 } else if validator_permit {
                     // Only overwrite the intersection.
-                    let new_empty_bonds_row: Vec<(u16, u16)> = vec![];
+                    let new_empty_bonds_row: WeightsRowOf<T> = WeightsRowOf::<T>::new();
                     Bonds::<T>::insert(netuid, i as u16, new_empty_bonds_row);
                 }
             });
@@ -507,7 +553,8 @@ impl<T: Config> Pallet<T> {
         log::trace!("max_allowed_validators: {:?}", max_allowed_validators);
 
         // Get new validator permits.
-        let new_validator_permits: Vec<bool> = is_topk(&stake, max_allowed_validators as usize);
+        let new_validator_permits: Vec<bool> =
+            Self::get_new_validator_permits(netuid, &stake, &active, max_allowed_validators);
         log::trace!("new_validator_permits: {:?}", new_validator_permits);
 
         // ==================
@@ -538,6 +585,20 @@ impl<T: Config> Pallet<T> {
         weights = mask_rows_sparse(&validator_forbids, &weights);
         log::trace!("Weights (permit): {:?}", &weights);
 
+        // Mask weights of validators that set fewer weights than the subnet requires.
+        let weighted_uids: Vec<Vec<u16>> = weights
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .filter(|(_, weight)| *weight > I32F32::from_num(0))
+                    .map(|(uid_j, _)| *uid_j)
+                    .collect()
+            })
+            .collect();
+        let below_floor: Vec<bool> = Self::get_weights_below_floor(netuid, &weighted_uids);
+        weights = mask_rows_sparse(&below_floor, &weights);
+        log::trace!("Weights (permit+floor): {:?}", &weights);
+
         // Remove self-weight by masking diagonal.
         weights = mask_diag_sparse(&weights);
         log::trace!("Weights (permit+diag): {:?}", &weights);
@@ -560,12 +621,13 @@ impl<T: Config> Pallet<T> {
         // ================================
 
         // Compute preranks: r_j = SUM(i) w_ij * s_i
-        let preranks: Vec<I32F32> = matmul_sparse(&weights, &active_stake, n);
+        let preranks: Vec<I32F32> = epoch_matmul_sparse(&weights, &active_stake, n);
         log::trace!("Ranks (before): {:?}", &preranks);
 
         // Clip weights at majority consensus
         let kappa: I32F32 = Self::get_float_kappa(netuid); // consensus majority ratio, e.g. 51%.
-        let consensus: Vec<I32F32> = weighted_median_col_sparse(&active_stake, &weights, n, kappa);
+        let consensus: Vec<I32F32> =
+            epoch_weighted_median_col_sparse(&active_stake, &weights, n, kappa);
         log::trace!("Consensus: {:?}", &consensus);
 
         weights = col_clip_sparse(&weights, &consensus);
@@ -579,7 +641,7 @@ impl<T: Config> Pallet<T> {
         // =============================
 
         // Compute ranks: r_j = SUM(i) w_ij * s_i.
-        let mut ranks: Vec<I32F32> = matmul_sparse(&weights, &active_stake, n);
+        let mut ranks: Vec<I32F32> = epoch_matmul_sparse(&weights, &active_stake, n);
         log::trace!("Ranks (after): {:?}", &ranks);
 
         // Compute server trust: ratio of rank after vs. rank before.
@@ -670,7 +732,13 @@ impl<T: Config> Pallet<T> {
 
         let server_emission: Vec<I96F32> = normalized_server_emission
             .iter()
-            .map(|se: &I32F32| I96F32::from_num(*se).saturating_mul(float_rao_emission))
+            .map(|se: &I32F32| {
+                Self::audited_saturating_mul(
+                    I96F32::from_num(*se),
+                    float_rao_emission,
+                    SaturationSite::EpochEmission,
+                )
+            })
             .collect();
         let server_emission: Vec<u64> = server_emission
             .iter()
@@ -679,7 +747,13 @@ impl<T: Config> Pallet<T> {
 
         let validator_emission: Vec<I96F32> = normalized_validator_emission
             .iter()
-            .map(|ve: &I32F32| I96F32::from_num(*ve).saturating_mul(float_rao_emission))
+            .map(|ve: &I32F32| {
+                Self::audited_saturating_mul(
+                    I96F32::from_num(*ve),
+                    float_rao_emission,
+                    SaturationSite::EpochEmission,
+                )
+            })
             .collect();
         let validator_emission: Vec<u64> = validator_emission
             .iter()
@@ -689,7 +763,13 @@ impl<T: Config> Pallet<T> {
         // Only used to track emission in storage.
         let combined_emission: Vec<I96F32> = normalized_combined_emission
             .iter()
-            .map(|ce: &I32F32| I96F32::from_num(*ce).saturating_mul(float_rao_emission))
+            .map(|ce: &I32F32| {
+                Self::audited_saturating_mul(
+                    I96F32::from_num(*ce),
+                    float_rao_emission,
+                    SaturationSite::EpochEmission,
+                )
+            })
             .collect();
         let combined_emission: Vec<u64> = combined_emission
             .iter()
@@ -775,10 +855,15 @@ impl<T: Config> Pallet<T> {
                         .iter()
                         .map(|(j, value)| (*j, fixed_proportion_to_u16(*value)))
                         .collect();
-                    Bonds::<T>::insert(netuid, i as u16, new_bonds_row);
+                    // Rows hold at most n <= MaxAllowedUids <= MaxWeightsRowLength pairs.
+                    Bonds::<T>::insert(
+                        netuid,
+                        i as u16,
+                        WeightsRowOf::<T>::truncate_from(new_bonds_row),
+                    );
                 } else if validator_permit {
                     // Only overwrite the intersection.
-                    let new_empty_bonds_row: Vec<(u16, u16)> = vec![];
+                    let new_empty_bonds_row: WeightsRowOf<T> = WeightsRowOf::<T>::new();
                     Bonds::<T>::insert(netuid, i as u16, new_empty_bonds_row);
                 }
             });
@@ -803,6 +888,31 @@ impl<T: Config> Pallet<T> {
         I32F32::from_num(Self::get_kappa(netuid)).saturating_div(I32F32::from_num(u16::MAX))
     }
 
+    /// Returns the top-k neurons by stake. When the subnet only grants permits to active
+    /// neurons, the stake of neurons past the activity cutoff is ignored, so the permits of
+    /// stale validators pass to the next highest-stake active candidates.
+    pub fn get_new_validator_permits(
+        netuid: u16,
+        stake: &[I32F32],
+        active: &[bool],
+        max_allowed_validators: u16,
+    ) -> Vec<bool> {
+        if !Self::get_active_permits_only(netuid) {
+            return is_topk(stake, max_allowed_validators as usize);
+        }
+
+        let active_stake: Vec<I32F32> = stake
+            .iter()
+            .zip(active)
+            .map(|(s, a)| if *a { *s } else { I32F32::from_num(0) })
+            .collect();
+        is_topk(&active_stake, max_allowed_validators as usize)
+            .iter()
+            .zip(active)
+            .map(|(permit, a)| *permit && *a)
+            .collect()
+    }
+
     pub fn get_normalized_stake(netuid: u16) -> Vec<I32F32> {
         let n = Self::get_subnetwork_n(netuid);
         let mut stake_64: Vec<I64F64> = (0..n)
@@ -829,12 +939,24 @@ impl<T: Config> Pallet<T> {
         block_at_registration
     }
 
+    /// Returns, for each uid, whether the uids it puts nonzero weight on fall short of the
+    /// subnet's min allowed weights. As in set_weights, the floor is capped at the subnet size
+    /// and a lone self-weight passes, so only rows that set_weights would now reject are flagged.
+    pub fn get_weights_below_floor(netuid: u16, weighted_uids: &[Vec<u16>]) -> Vec<bool> {
+        let floor: usize =
+            Self::get_min_allowed_weights(netuid).min(Self::get_subnetwork_n(netuid)) as usize;
+        (0..)
+            .zip(weighted_uids)
+            .map(|(uid_i, uids)| uids.len() < floor && uids.as_slice() != [uid_i])
+            .collect()
+    }
+
     /// Output unnormalized sparse weights, input weights are assumed to be row max-upscaled in u16.
     pub fn get_weights_sparse(netuid: u16) -> Vec<Vec<(u16, I32F32)>> {
         let n: usize = Self::get_subnetwork_n(netuid) as usize;
         let mut weights: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n];
         for (uid_i, weights_i) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(netuid)
+            <Weights<T> as IterableStorageDoubleMap<u16, u16, WeightsRowOf<T>>>::iter_prefix(netuid)
                 .filter(|(uid_i, _)| *uid_i < n as u16)
         {
             for (uid_j, weight_ij) in weights_i.iter().filter(|(uid_j, _)| *uid_j < n as u16) {
@@ -852,7 +974,7 @@ impl<T: Config> Pallet<T> {
         let n: usize = Self::get_subnetwork_n(netuid) as usize;
         let mut weights: Vec<Vec<I32F32>> = vec![vec![I32F32::from_num(0.0); n]; n];
         for (uid_i, weights_vec) in
-            <Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(netuid)
+            <Weights<T> as IterableStorageDoubleMap<u16, u16, WeightsRowOf<T>>>::iter_prefix(netuid)
                 .filter(|(uid_i, _)| *uid_i < n as u16)
         {
             for (uid_j, weight_ij) in weights_vec
@@ -875,7 +997,7 @@ impl<T: Config> Pallet<T> {
         let n: usize = Self::get_subnetwork_n(netuid) as usize;
         let mut bonds: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n];
         for (uid_i, bonds_vec) in
-            <Bonds<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(netuid)
+            <Bonds<T> as IterableStorageDoubleMap<u16, u16, WeightsRowOf<T>>>::iter_prefix(netuid)
                 .filter(|(uid_i, _)| *uid_i < n as u16)
         {
             for (uid_j, bonds_ij) in bonds_vec {
@@ -893,7 +1015,7 @@ impl<T: Config> Pallet<T> {
         let n: usize = Self::get_subnetwork_n(netuid) as usize;
         let mut bonds: Vec<Vec<I32F32>> = vec![vec![I32F32::from_num(0.0); n]; n];
         for (uid_i, bonds_vec) in
-            <Bonds<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)>>>::iter_prefix(netuid)
+            <Bonds<T> as IterableStorageDoubleMap<u16, u16, WeightsRowOf<T>>>::iter_prefix(netuid)
                 .filter(|(uid_i, _)| *uid_i < n as u16)
         {
             for (uid_j, bonds_ij) in bonds_vec.into_iter().filter(|(uid_j, _)| *uid_j < n as u16) {
@@ -1276,6 +1398,7 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Validates liquid alpha values and stages them for the subnet's next tempo boundary.
     pub fn do_set_alpha_values(
         origin: T::RuntimeOrigin,
         netuid: u16,
@@ -1306,7 +1429,7 @@ impl<T: Config> Pallet<T> {
             Error::<T>::AlphaLowOutOfRange
         );
 
-        AlphaValues::<T>::insert(netuid, (alpha_low, alpha_high));
+        Self::schedule_alpha_values(netuid, alpha_low, alpha_high);
 
         log::debug!(
             "AlphaValuesSet( netuid: {:?}, AlphaLow: {:?}, AlphaHigh: {:?} ) ",
@@ -65,12 +65,13 @@ pub mod pallet {
         dispatch::GetDispatchInfo,
         pallet_prelude::{DispatchResult, StorageMap, ValueQuery, *},
         traits::{
-            tokens::fungible, OriginTrait, QueryPreimage, StorePreimage, UnfilteredDispatchable,
+            tokens::fungible, OriginTrait, QueryPreimage, Randomness, StorePreimage,
+            UnfilteredDispatchable,
         },
     };
     use frame_system::pallet_prelude::*;
     use sp_core::H256;
-    use sp_runtime::traits::{Dispatchable, TrailingZeroInput};
+    use sp_runtime::traits::{Dispatchable, IdentifyAccount, TrailingZeroInput, Verify};
     use sp_std::vec;
     use sp_std::vec::Vec;
 
@@ -104,16 +105,38 @@ pub mod pallet {
     /// Struct for Axon.
     pub type AxonInfoOf = AxonInfo;
 
+    /// Bounded row of (uid, value) pairs stored for weights and bonds.
+    pub type WeightsRowOf<T> = BoundedVec<(u16, u16), <T as Config>::MaxWeightsRowLength>;
+
+    /// Bounded ring buffer of (block, total_stake, total_emission, take) checkpoints, oldest
+    /// first.
+    pub type StakeCheckpointsOf<T> =
+        BoundedVec<(u64, u64, u64, u16), <T as Config>::MaxStakeCheckpoints>;
+
+    /// Bounded list of the hotkeys a subnet holds uids for.
+    pub type ReservedHotkeysOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxReservedHotkeys>;
+
+    /// Bounded list of the sealed bids of a subnet registration auction.
+    pub type RegistrationBidsOf<T> =
+        BoundedVec<RegistrationBid<AccountIdOf<T>>, <T as Config>::MaxRegistrationBids>;
+
     /// local one
     pub type LocalCallOf<T> = <T as Config>::RuntimeCall;
 
+    /// Handle of a task in the scheduler queue, used to cancel it.
+    pub type SchedulerAddressOf<T> = <<T as Config>::Scheduler as ScheduleAnon<
+        BlockNumberFor<T>,
+        LocalCallOf<T>,
+        PalletsOriginOf<T>,
+    >>::Address;
+
     /// Data structure for Axon information.
-    #[crate::freeze_struct("3545cfb0cac4c1f5")]
+    #[crate::freeze_struct("f976b4b5bfa15f67")]
     #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
     pub struct AxonInfo {
         ///  Axon serving block.
         pub block: u64,
-        ///  Axon version
+        ///  Axon semantic version, packed so that newer versions compare greater.
         pub version: u32,
         ///  Axon u128 encoded ip address of type v6 or v4.
         pub ip: u128,
@@ -127,6 +150,8 @@ pub mod pallet {
         pub placeholder1: u8,
         ///  Axon proto placeholder 2.
         pub placeholder2: u8,
+        ///  Axon capability bitfield, with bits assigned by each subnet.
+        pub capabilities: u64,
     }
 
     ///  Struct for Prometheus.
@@ -182,6 +207,192 @@ pub mod pallet {
         /// The subnet's contact
         pub subnet_contact: Vec<u8>,
     }
+    /// Hyperparameter changes applied together at a subnet's next tempo boundary. Fields left
+    /// None keep their current value.
+    #[crate::freeze_struct("f7b4cc9ddffe5dc")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct HyperparamBundle {
+        /// The consensus majority threshold
+        pub kappa: Option<u16>,
+        /// The incentive steepness
+        pub rho: Option<u16>,
+        /// The max weight a neuron may put on a single uid
+        pub max_weight_limit: Option<u16>,
+        /// The min number of weights a neuron must set
+        pub min_allowed_weights: Option<u16>,
+        /// The max number of weights a neuron may set
+        pub max_allowed_weights: Option<u16>,
+        /// The blocks after which a neuron without weight updates is inactive
+        pub activity_cutoff: Option<u16>,
+        /// The blocks a newly registered neuron is protected from pruning
+        pub immunity_period: Option<u16>,
+        /// The min blocks between weight updates of a neuron
+        pub weights_set_rate_limit: Option<u64>,
+        /// The weights version key validators must set weights with
+        pub weights_version_key: Option<u64>,
+        /// The bonds moving average
+        pub bonds_moving_average: Option<u64>,
+    }
+    /// Data structure for per-block operation counters, recorded with the `metrics` feature.
+    #[crate::freeze_struct("8ecacbcd110aa57d")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct BlockMetricsInfo {
+        /// Neurons registered in the block, across all subnets
+        pub registrations: u32,
+        /// Successful weight sets in the block, across all subnets
+        pub weight_sets: u32,
+        /// Successful add and remove stake operations in the block
+        pub stake_ops: u32,
+    }
+    /// Data structure for the aggregate delegate statistics cached for the runtime APIs.
+    #[crate::freeze_struct("602b6c5c9a4fdcbe")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct DelegateStats {
+        /// Block the statistics were computed at
+        pub block: u64,
+        /// Number of nominators with stake on the delegate
+        pub nominators: u32,
+        /// Stake nominated by coldkeys other than the delegate's owner
+        pub nominated_stake: u64,
+        /// Emission the delegate currently earns per day
+        pub total_daily_return: u64,
+        /// Daily return per 1000 staked, net of the delegate take fee
+        pub return_per_1000: u64,
+    }
+    /// Data structure for the emission a delegate realized on a subnet in its last epoch.
+    #[crate::freeze_struct("9eecd8cf2affab50")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct DelegateYield {
+        /// Block of the epoch the yield was realized in
+        pub block: u64,
+        /// Emission the delegate's neuron earned in the epoch
+        pub emission: u64,
+        /// Stake of the delegate on the subnet during the epoch
+        pub stake: u64,
+        /// Emission per 1 TAO of stake, gross of the delegate take
+        pub emission_per_tao: u64,
+        /// Emission per 1 TAO of stake left to nominators after the delegate take
+        pub nominator_emission_per_tao: u64,
+    }
+    /// Emission and epoch code paths whose saturating arithmetic is counted with the
+    /// `safe-math-audit` feature. Every saturating add, sub and mul on emission amounts in the
+    /// coinbase is audited; in the epoch, the stake adjustment and the scaling of normalized
+    /// scores to emission are. The vector math of epoch/math.rs, divisions and block and tempo
+    /// scheduling arithmetic are not audited.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum SaturationSite {
+        /// Accumulating block emission on a subnet
+        SubnetEmission,
+        /// Removing the owner cut from a drained subnet emission
+        OwnerCut,
+        /// Splitting a hotkey's epoch emission between itself and its parents
+        HotkeyEmission,
+        /// Draining a hotkey's emission to its nominators
+        NominatorEmission,
+        /// Adjusting a hotkey's stake by its child and parent stake in the epoch
+        EpochStake,
+        /// Scaling normalized epoch scores to emission
+        EpochEmission,
+    }
+    /// How a subnet assigns UIDs to new burn registrations.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug, Default)]
+    pub enum RegistrationMechanism {
+        /// Each registration pays the current burn, adjusted every interval
+        #[default]
+        FixedBurn,
+        /// Sealed bids are committed and revealed over the interval and the highest ones win the
+        /// available UIDs
+        Auction,
+    }
+    /// A sealed bid in a subnet registration auction.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct RegistrationBid<AccountId> {
+        /// The coldkey paying the bid
+        pub coldkey: AccountId,
+        /// The hotkey to register
+        pub hotkey: AccountId,
+        /// The escrowed amount, an upper bound on the bid
+        pub escrow: u64,
+        /// The deposit, forfeited if the bid is never revealed
+        pub deposit: u64,
+        /// Hash of (coldkey, hotkey, netuid, amount, salt)
+        pub commitment: H256,
+        /// The bid amount, once revealed
+        pub revealed: Option<u64>,
+    }
+    /// A delayed privileged operation of a coldkey, dispatched by the scheduler.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum ScheduledOperation<AccountId> {
+        /// Swapping the coldkey to a new one
+        ColdkeySwap { new_coldkey: AccountId },
+        /// Dissolving a subnet owned by the coldkey
+        DissolveNetwork { netuid: u16 },
+        /// Increasing the take of a hotkey owned by the coldkey
+        TakeIncrease { hotkey: AccountId, take: u16 },
+    }
+    /// Why the weights of a UID were found degenerate at an epoch.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum DegenerateWeights {
+        /// Every weight set is zero
+        AllZero,
+        /// The only non-zero weight is on the UID itself
+        SelfOnly,
+        /// The same non-zero weights are set by at least the duplicate threshold of UIDs
+        Duplicate,
+    }
+    /// Why a weight in an applied weights set is not counted by the epoch.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum WeightsFilter {
+        /// The weight is zero and is not stored
+        ZeroWeight,
+        /// The weight is on the setting UID itself, which the epoch masks
+        SelfWeight,
+        /// The non-zero weights fall short of the subnet's min allowed weights, so the epoch
+        /// masks the whole row
+        BelowWeightFloor,
+    }
+    /// Data structure for a subnet created with its neurons and stake at genesis. Unset
+    /// hyperparameters keep the defaults of a newly registered network.
+    #[derive(Clone, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    pub struct GenesisSubnet<AccountId> {
+        /// The uid of the subnet, never the root network
+        pub netuid: u16,
+        /// The coldkey owning the subnet
+        pub owner: AccountId,
+        /// Blocks between epochs
+        pub tempo: u16,
+        /// Maximum number of neurons
+        #[serde(default)]
+        pub max_allowed_uids: Option<u16>,
+        /// Maximum number of validator permits
+        #[serde(default)]
+        pub max_allowed_validators: Option<u16>,
+        /// Blocks a new neuron is immune from pruning
+        #[serde(default)]
+        pub immunity_period: Option<u16>,
+        /// Minimum number of weights a validator must set
+        #[serde(default)]
+        pub min_allowed_weights: Option<u16>,
+        /// Maximum share of a single weight
+        #[serde(default)]
+        pub max_weights_limit: Option<u16>,
+        /// Consensus majority threshold
+        #[serde(default)]
+        pub kappa: Option<u16>,
+        /// Blocks between weight sets of a validator
+        #[serde(default)]
+        pub weights_set_rate_limit: Option<u64>,
+        /// Cost of a burn registration
+        #[serde(default)]
+        pub burn: Option<u64>,
+        /// Difficulty of a POW registration
+        #[serde(default)]
+        pub difficulty: Option<u64>,
+        /// Neurons registered in uid order, as (coldkey, hotkey, stake)
+        #[serde(default)]
+        pub neurons: Vec<(AccountId, AccountId, u64)>,
+    }
     /// ============================
     /// ==== Staking + Accounts ====
     /// ============================
@@ -196,6 +407,11 @@ pub mod pallet {
     pub fn DefaultDelegateTake<T: Config>() -> u16 {
         T::InitialDefaultDelegateTake::get()
     }
+    #[pallet::type_value]
+    /// Default take of a hotkey that never set one.
+    pub fn DefaultHotkeyTake<T: Config>() -> u16 {
+        Pallet::<T>::get_default_delegate_take()
+    }
 
     #[pallet::type_value]
     /// Default childkey take.
@@ -307,6 +523,16 @@ pub mod pallet {
         T::InitialMaxBurn::get()
     }
     #[pallet::type_value]
+    /// Default upper bound on a subnet's minimum stake to register.
+    pub fn DefaultMaxMinStakeToRegister<T: Config>() -> u64 {
+        T::InitialMaxMinStakeToRegister::get()
+    }
+    #[pallet::type_value]
+    /// Default number of hotkeys a subnet owner may reserve.
+    pub fn DefaultMaxReservedUids<T: Config>() -> u16 {
+        T::InitialMaxReservedUids::get()
+    }
+    #[pallet::type_value]
     /// Default max burn token.
     pub fn DefaultDifficulty<T: Config>() -> u64 {
         T::InitialDifficulty::get()
@@ -415,6 +641,11 @@ pub mod pallet {
         T::InitialNetworkRateLimit::get()
     }
     #[pallet::type_value]
+    /// Default value for the subnet emission ramp length in tempos.
+    pub fn DefaultSubnetEmissionRampTempos<T: Config>() -> u16 {
+        T::InitialSubnetEmissionRampTempos::get()
+    }
+    #[pallet::type_value]
     /// Default value for emission values.
     pub fn DefaultEmissionValues<T: Config>() -> u64 {
         0
@@ -456,6 +687,11 @@ pub mod pallet {
         100
     }
     #[pallet::type_value]
+    /// Default number of blocks after registration before a neuron may set weights.
+    pub fn DefaultWeightsImmunityPeriod<T: Config>() -> u64 {
+        0
+    }
+    #[pallet::type_value]
     /// Default block number at registration.
     pub fn DefaultBlockAtRegistration<T: Config>() -> u64 {
         0
@@ -486,6 +722,11 @@ pub mod pallet {
         T::InitialActivityCutoff::get()
     }
     #[pallet::type_value]
+    /// Default number of UIDs sharing the same weights for them to be flagged.
+    pub fn DefaultWeightFlagDuplicateKeys<T: Config>() -> u16 {
+        3
+    }
+    #[pallet::type_value]
     /// Default maximum weights limit.
     pub fn DefaultMaxWeightsLimit<T: Config>() -> u16 {
         T::InitialMaxWeightsLimit::get()
@@ -501,6 +742,11 @@ pub mod pallet {
         T::InitialMinAllowedWeights::get()
     }
     #[pallet::type_value]
+    /// Default maximum allowed weights, leaving the subnet size as the only cap.
+    pub fn DefaultMaxAllowedWeights<T: Config>() -> u16 {
+        u16::MAX
+    }
+    #[pallet::type_value]
     /// Default maximum allowed validators.
     pub fn DefaultMaxAllowedValidators<T: Config>() -> u16 {
         T::InitialMaxAllowedValidators::get()
@@ -557,13 +803,18 @@ pub mod pallet {
     }
     #[pallet::type_value]
     /// Value definition for bonds with type vector of (u16, u16).
-    pub fn DefaultBonds<T: Config>() -> Vec<(u16, u16)> {
-        vec![]
+    pub fn DefaultBonds<T: Config>() -> WeightsRowOf<T> {
+        BoundedVec::new()
     }
     #[pallet::type_value]
     /// Value definition for weights with vector of (u16, u16).
-    pub fn DefaultWeights<T: Config>() -> Vec<(u16, u16)> {
-        vec![]
+    pub fn DefaultWeights<T: Config>() -> WeightsRowOf<T> {
+        BoundedVec::new()
+    }
+    #[pallet::type_value]
+    /// Default stake checkpoints, empty.
+    pub fn DefaultStakeCheckpoints<T: Config>() -> StakeCheckpointsOf<T> {
+        BoundedVec::new()
     }
     #[pallet::type_value]
     /// Default value for key with type T::AccountId derived from trailing zeroes.
@@ -612,6 +863,16 @@ pub mod pallet {
         false
     }
     #[pallet::type_value]
+    /// Default minimum axon version, showing every axon.
+    pub fn DefaultMinAxonVersion<T: Config>() -> u32 {
+        0
+    }
+    #[pallet::type_value]
+    /// Default value for burning, rather than paying out, the pending emission of pruned neurons.
+    pub fn DefaultPruneEmissionBurn<T: Config>() -> bool {
+        false
+    }
+    #[pallet::type_value]
     /// Senate requirements
     pub fn DefaultSenateRequiredStakePercentage<T: Config>() -> u64 {
         T::InitialSenateRequiredStakePercentage::get()
@@ -674,6 +935,8 @@ pub mod pallet {
     pub type TotalStake<T> = StorageValue<_, u64, ValueQuery>;
     #[pallet::storage] // --- ITEM ( default_delegate_take )
     pub type MaxDelegateTake<T> = StorageValue<_, u16, ValueQuery, DefaultDelegateTake<T>>;
+    #[pallet::storage] // --- ITEM ( default_take ) | Take given to new delegates and to hotkeys that never set one.
+    pub type DefaultTake<T> = StorageValue<_, u16, ValueQuery, DefaultDelegateTake<T>>;
     #[pallet::storage] // --- ITEM ( min_delegate_take )
     pub type MinDelegateTake<T> = StorageValue<_, u16, ValueQuery, DefaultMinDelegateTake<T>>;
     #[pallet::storage] // --- ITEM ( default_childkey_take )
@@ -694,6 +957,9 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( cold ) --> stake | Returns the total amount of stake under a coldkey.
     pub type TotalColdkeyStake<T: Config> =
         StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultAccountTake<T>>;
+    #[pallet::storage] // --- MAP ( cold ) --> held | Whether stake provides for the account.
+    pub type StakeProviders<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
     #[pallet::storage]
     /// MAP (hot, cold) --> stake | Returns a tuple (u64: stakes, u64: block_number)
     pub type TotalHotkeyColdkeyStakesThisInterval<T: Config> = StorageDoubleMap<
@@ -711,9 +977,9 @@ pub mod pallet {
     pub type Owner<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, ValueQuery, DefaultAccount<T>>;
     #[pallet::storage]
-    /// MAP ( hot ) --> take | Returns the hotkey delegation take. And signals that this key is open for delegation.
+    /// MAP ( hot ) --> take | Returns the hotkey delegation take, the default take for hotkeys that never set one.
     pub type Delegates<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::AccountId, u16, ValueQuery, DefaultDelegateTake<T>>;
+        StorageMap<_, Blake2_128Concat, T::AccountId, u16, ValueQuery, DefaultHotkeyTake<T>>;
     #[pallet::storage]
     /// DMAP ( hot, netuid ) --> take | Returns the hotkey childkey take for a specific subnet
     pub type ChildkeyTake<T: Config> = StorageDoubleMap<
@@ -763,6 +1029,71 @@ pub mod pallet {
         DefaultAccumulatedEmission<T>,
     >;
     #[pallet::storage]
+    /// ITEM ( deferred_hotkey_drains ) | Hotkeys whose due emission drain did not fit in the block step budget.
+    pub type DeferredHotkeyDrains<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> deferrals | Blocks in a row the due epoch of a subnet was deferred.
+    pub type EpochDeferrals<T> = StorageMap<_, Identity, u16, u32, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( hot ) --> count | Number of Stake entries under a hotkey.
+    pub type HotkeyNominatorCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+    #[pallet::storage]
+    /// DMAP ( netuid, hot ) --> emission | Total emission credited to a hotkey by each subnet.
+    pub type HotkeyEmissionByNetuid<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        ValueQuery,
+        DefaultAccumulatedEmission<T>,
+    >;
+    #[pallet::storage]
+    /// --- MAP ( hot ) --> checkpoints | Stake and emission of a delegate every StakeCheckpointInterval blocks.
+    pub type StakeCheckpoints<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        StakeCheckpointsOf<T>,
+        ValueQuery,
+        DefaultStakeCheckpoints<T>,
+    >;
+    #[pallet::storage]
+    /// ITEM( (block, cursor) ) | Checkpoint pass in progress, raw Delegates key it resumes at.
+    pub type StakeCheckpointPass<T> = StorageValue<_, (u64, Option<Vec<u8>>), OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( (block, cursor) ) | Delegate statistics pass in progress, raw key it resumes at.
+    pub type DelegateStatsPass<T> = StorageValue<_, (u64, Option<Vec<u8>>), OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( (hot, cursor, nominators, stake) ) | Nominations read so far by the stats pass.
+    pub type DelegateStatsNominations<T: Config> =
+        StorageValue<_, (T::AccountId, Vec<u8>, u32, u64), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( hot ) --> stats | Delegate statistics of the last refresh pass.
+    pub type DelegateStatsCache<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, DelegateStats, OptionQuery>;
+    #[pallet::storage]
+    /// DMAP ( netuid, hot ) --> yield | Delegate yield realized in the subnet's last epoch.
+    pub type DelegateYields<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Blake2_128Concat,
+        T::AccountId,
+        DelegateYield,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- MAP ( hot ) --> block | Block at which a delegate lost its last subnet registration.
+    pub type DelegateDeregisteredAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( block ) --> Vec<hot> | Delegates whose inactivity grace period ends at a block.
+    pub type DelegateInactivityExpirations<T: Config> =
+        StorageMap<_, Identity, u64, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage]
     /// Map ( hot, cold ) --> block_number | Last add stake increase.
     pub type LastAddStakeIncrease<T: Config> = StorageDoubleMap<
         _,
@@ -817,10 +1148,28 @@ pub mod pallet {
     pub type OwnedHotkeys<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
 
+    #[pallet::storage]
+    /// ITEM( strict_key_usage_separation ) | Also reject accounts used as their own hotkey and coldkey.
+    pub type StrictKeyUsageSeparation<T> = StorageValue<_, bool, ValueQuery>;
+
     #[pallet::storage] // --- DMAP ( cold ) --> () | Maps coldkey to if a coldkey swap is scheduled.
     pub type ColdkeySwapScheduled<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, (), ValueQuery>;
 
+    #[pallet::storage] // --- DMAP ( cold, id ) --> ( operation, block, address ) | Scheduled ops.
+    pub type ScheduledOperations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        u64,
+        (ScheduledOperation<T::AccountId>, u64, SchedulerAddressOf<T>),
+        OptionQuery,
+    >;
+
+    #[pallet::storage] // --- ITEM ( next_scheduled_operation_id ) | Id of the next scheduled op.
+    pub type NextScheduledOperationId<T> = StorageValue<_, u64, ValueQuery>;
+
     /// ============================
     /// ==== Global Parameters =====
     /// ============================
@@ -864,9 +1213,38 @@ pub mod pallet {
     /// ITEM( subnet_owner_cut )
     pub type SubnetOwnerCut<T> = StorageValue<_, u16, ValueQuery, DefaultSubnetOwnerCut<T>>;
     #[pallet::storage]
+    /// ITEM( bootstrap_grant_share ) | Share of a new subnet's owner cut escrowed as its grant.
+    pub type BootstrapGrantShare<T> = StorageValue<_, u16, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( bootstrap_grant_tempos ) | Tempos a new subnet's owner cut is escrowed for.
+    pub type BootstrapGrantTempos<T> = StorageValue<_, u16, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( bootstrap_grant_min_validators ) | Active validators kept every grant tempo.
+    pub type BootstrapGrantMinValidators<T> = StorageValue<_, u16, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( bootstrap_grant_min_miners ) | Active miners kept every grant tempo.
+    pub type BootstrapGrantMinMiners<T> = StorageValue<_, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> (escrowed, tempos_left) | Open bootstrap grants.
+    pub type BootstrapGrants<T> = StorageMap<_, Identity, u16, (u64, u16), OptionQuery>;
+    #[pallet::storage]
     /// ITEM( network_rate_limit )
     pub type NetworkRateLimit<T> = StorageValue<_, u64, ValueQuery, DefaultNetworkRateLimit<T>>;
     #[pallet::storage]
+    /// ITEM( emission_tithe ) | Fraction of block emission (in u16::MAX) routed to the treasury.
+    pub type EmissionTithe<T> = StorageValue<_, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( proposer ) --> (beneficiary, amount, deposit) | Open treasury spend proposals.
+    pub type TreasurySpendProposals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (T::AccountId, u64, u64), OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( treasury_deposits_held ) | Proposal deposits held in the treasury, not spendable.
+    pub type TreasuryDepositsHeld<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( subnet_emission_ramp_tempos ) | Tempos before a new subnet receives its full emission.
+    pub type SubnetEmissionRampTempos<T> =
+        StorageValue<_, u16, ValueQuery, DefaultSubnetEmissionRampTempos<T>>;
+    #[pallet::storage]
     /// ITEM( nominator_min_required_stake )
     pub type NominatorMinRequiredStake<T> =
         StorageValue<_, u64, ValueQuery, DefaultNominatorMinRequiredStake<T>>;
@@ -912,10 +1290,33 @@ pub mod pallet {
     /// --- MAP ( netuid ) --> tempo
     pub type Tempo<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultTempo<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> next_epoch_block | Block of the next epoch, set once the tempo has been changed.
+    pub type NextEpochBlock<T> = StorageMap<_, Identity, u16, u64, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> block | Block the tempo of the subnet was last changed at.
+    pub type LastTempoChangeBlock<T> = StorageMap<_, Identity, u16, u64, OptionQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> emission_values
     pub type EmissionValues<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultEmissionValues<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> (block, randomness) | Beacon value drawn at the subnet's last epoch.
+    pub type SubnetRandomness<T> = StorageMap<_, Identity, u16, (u64, H256), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> (block, weights_hash, result_hash) | Checksums of the last epoch.
+    pub type EpochChecksums<T> = StorageMap<_, Identity, u16, (u64, H256, H256), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> reenable_block | Subnets whose emission is burned until reenable_block, u64::MAX for indefinitely.
+    pub type EmissionDisabled<T> = StorageMap<_, Identity, u16, u64, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( hot ) --> expiry_block | Hotkeys whose emission is burned until the expiry block.
+    pub type SanctionedHotkeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( block ) --> Vec<hot> | Hotkeys whose sanction expires at a block.
+    pub type SanctionExpirations<T: Config> =
+        StorageMap<_, Identity, u64, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> pending_emission
     pub type PendingEmission<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultPendingEmission<T>>;
@@ -932,6 +1333,10 @@ pub mod pallet {
     pub type SubnetOwner<T: Config> =
         StorageMap<_, Identity, u16, T::AccountId, ValueQuery, DefaultSubnetOwner<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> (lessee, end_block) | Coldkey controlling a leased subnet until end_block.
+    pub type SubnetLease<T: Config> =
+        StorageMap<_, Identity, u16, (T::AccountId, u64), OptionQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> subnet_locked
     pub type SubnetLocked<T: Config> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultSubnetLocked<T>>;
@@ -972,6 +1377,24 @@ pub mod pallet {
     pub type ActivityCutoff<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultActivityCutoff<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> active_permits_only | Whether validator permits only go to neurons active within the activity cutoff.
+    pub type ActivePermitsOnly<T> = StorageMap<_, Identity, u16, bool, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> tempos | Tempos of degenerate weights flagging a UID, 0 disables.
+    pub type WeightFlagTempos<T> = StorageMap<_, Identity, u16, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> duplicate_keys | UIDs sharing weights for them to be flagged.
+    pub type WeightFlagDuplicateKeys<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultWeightFlagDuplicateKeys<T>>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> tempos | Consecutive tempos a UID's weights were degenerate.
+    pub type DegenerateWeightStreaks<T> =
+        StorageDoubleMap<_, Identity, u16, Identity, u16, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> (reason, block) | UIDs flagged for degenerate weights.
+    pub type FlaggedUids<T> =
+        StorageDoubleMap<_, Identity, u16, Identity, u16, (DegenerateWeights, u64), OptionQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> max_weight_limit
     pub type MaxWeightsLimit<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxWeightsLimit<T>>;
@@ -984,6 +1407,10 @@ pub mod pallet {
     pub type MinAllowedWeights<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMinAllowedWeights<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> max_allowed_weights
+    pub type MaxAllowedWeights<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxAllowedWeights<T>>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> max_allowed_validators
     pub type MaxAllowedValidators<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxAllowedValidators<T>>;
@@ -1000,6 +1427,10 @@ pub mod pallet {
     pub type WeightsSetRateLimit<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultWeightsSetRateLimit<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> weights_immunity_period | Blocks after registration before weights may be set.
+    pub type WeightsImmunityPeriod<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultWeightsImmunityPeriod<T>>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> validator_prune_len
     pub type ValidatorPruneLen<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultValidatorPruneLen<T>>;
@@ -1024,6 +1455,10 @@ pub mod pallet {
     pub type CommitRevealWeightsEnabled<T> =
         StorageMap<_, Identity, u16, bool, ValueQuery, DefaultCommitRevealWeightsEnabled<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> burn | Burn instead of paying out the pending emission of pruned neurons.
+    pub type PruneEmissionBurn<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultPruneEmissionBurn<T>>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> Burn
     pub type Burn<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultBurn<T>>;
     #[pallet::storage]
@@ -1036,6 +1471,23 @@ pub mod pallet {
     /// --- MAP ( netuid ) --> MaxBurn
     pub type MaxBurn<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultMaxBurn<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> min_stake_to_register | Stake the registering coldkey must hold.
+    pub type MinStakeToRegister<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( max_min_stake_to_register ) | Upper bound on a subnet's MinStakeToRegister.
+    pub type MaxMinStakeToRegister<T> =
+        StorageValue<_, u64, ValueQuery, DefaultMaxMinStakeToRegister<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> registration_challenge_commit | Hash registrations must echo.
+    pub type RegistrationChallengeCommit<T> = StorageMap<_, Identity, u16, H256, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> reserved_hotkeys | Hotkeys holding uids that are never pruned.
+    pub type ReservedHotkeys<T: Config> =
+        StorageMap<_, Identity, u16, ReservedHotkeysOf<T>, ValueQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( max_reserved_uids ) | Number of hotkeys a subnet owner may reserve.
+    pub type MaxReservedUids<T> = StorageValue<_, u16, ValueQuery, DefaultMaxReservedUids<T>>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> MinDifficulty
     pub type MinDifficulty<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultMinDifficulty<T>>;
@@ -1056,6 +1508,14 @@ pub mod pallet {
     pub type RAORecycledForRegistration<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultRAORecycledForRegistration<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> mechanism | How burn registrations are priced on the subnet.
+    pub type SubnetRegistrationMechanism<T> =
+        StorageMap<_, Identity, u16, RegistrationMechanism, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> bids | Sealed bids of the current interval, in arrival order.
+    pub type RegistrationBids<T: Config> =
+        StorageMap<_, Identity, u16, RegistrationBidsOf<T>, ValueQuery>;
+    #[pallet::storage]
     /// --- ITEM ( tx_rate_limit )
     pub type TxRateLimit<T> = StorageValue<_, u64, ValueQuery, DefaultTxRateLimit<T>>;
     #[pallet::storage]
@@ -1074,6 +1534,19 @@ pub mod pallet {
     ///  MAP ( netuid ) --> (alpha_low, alpha_high)
     pub type AlphaValues<T> =
         StorageMap<_, Identity, u16, (u16, u16), ValueQuery, DefaultAlphaValues<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> kappa | Kappa applied at the subnet's next tempo boundary.
+    pub type PendingKappa<T> = StorageMap<_, Identity, u16, u16, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> max_weights_limit | Max weights limit applied at the subnet's next tempo boundary.
+    pub type PendingMaxWeightsLimit<T> = StorageMap<_, Identity, u16, u16, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> (alpha_low, alpha_high) | Alpha values applied at the subnet's next tempo boundary.
+    pub type PendingAlphaValues<T> = StorageMap<_, Identity, u16, (u16, u16), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> bundle | Hyperparameter bundle applied at the subnet's next tempo boundary.
+    pub type PendingHyperparamBundle<T> =
+        StorageMap<_, Identity, u16, HyperparamBundle, OptionQuery>;
     /// MAP ( netuid ) --> max stake allowed on a subnet.
     #[pallet::storage]
     pub type NetworkMaxStake<T> =
@@ -1140,6 +1613,14 @@ pub mod pallet {
     pub type ValidatorPermit<T: Config> =
         StorageMap<_, Identity, u16, Vec<bool>, ValueQuery, EmptyBoolVec<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> uids registered since the last epoch digest
+    pub type MetagraphRegisteredUids<T: Config> =
+        StorageMap<_, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> uids pruned since the last epoch digest
+    pub type MetagraphPrunedUids<T: Config> =
+        StorageMap<_, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
+    #[pallet::storage]
     /// --- DMAP ( netuid, uid ) --> weights
     pub type Weights<T: Config> = StorageDoubleMap<
         _,
@@ -1147,7 +1628,7 @@ pub mod pallet {
         u16,
         Identity,
         u16,
-        Vec<(u16, u16)>,
+        WeightsRowOf<T>,
         ValueQuery,
         DefaultWeights<T>,
     >;
@@ -1159,11 +1640,23 @@ pub mod pallet {
         u16,
         Identity,
         u16,
-        Vec<(u16, u16)>,
+        WeightsRowOf<T>,
         ValueQuery,
         DefaultBonds<T>,
     >;
     #[pallet::storage]
+    /// --- NMAP ( netuid, uid, kind ) --> scores | Auxiliary scores a validator published this tempo.
+    pub type AuxScores<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, u16>, // netuid
+            NMapKey<Identity, u16>, // validator uid
+            NMapKey<Identity, u16>, // kind
+        ),
+        WeightsRowOf<T>,
+        OptionQuery,
+    >;
+    #[pallet::storage]
     /// --- DMAP ( netuid, uid ) --> block_at_registration
     pub type BlockAtRegistration<T: Config> = StorageDoubleMap<
         _,
@@ -1180,6 +1673,10 @@ pub mod pallet {
     pub type Axons<T: Config> =
         StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, AxonInfoOf, OptionQuery>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> min_axon_version | Axons below this version are hidden from metagraph queries.
+    pub type MinAxonVersion<T> =
+        StorageMap<_, Identity, u16, u32, ValueQuery, DefaultMinAxonVersion<T>>;
+    #[pallet::storage]
     /// --- MAP ( netuid, hotkey ) --> prometheus_info
     pub type Prometheus<T: Config> = StorageDoubleMap<
         _,
@@ -1221,6 +1718,45 @@ pub mod pallet {
     pub type LastTxBlockChildKeyTake<T: Config> =
         StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultLastTxBlock<T>>;
     #[pallet::storage]
+    /// --- DMAP ( hot, cold ) --> (amount, conviction, unlock_block) | Stake locked for governance weight.
+    pub type StakeLocks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        T::AccountId,
+        (u64, u8, u64),
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- MAP ( hot ) --> bonus | Sum of amount * conviction over the stake locks on a hotkey.
+    pub type HotkeyConvictionBonus<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( block ) --> Vec<(hot, cold)> | Stake locks scheduled to unlock at a block.
+    pub type StakeLockExpirations<T: Config> =
+        StorageMap<_, Identity, u64, Vec<(T::AccountId, T::AccountId)>, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( hot ) --> (take, expiry_block) | Take change awaiting confirmation by the owning coldkey.
+    pub type PendingTakeChanges<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u16, u64), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( hot ) --> manager | Key allowed to change the take of the hotkey besides its owning coldkey.
+    pub type DelegateManagers<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( hot ) --> release_block | Public good delegates, take locked until then.
+    pub type PublicGoodDelegates<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( hot ) --> opted_out | Whether the hotkey rejects stake from coldkeys other than its owner.
+    pub type DelegationOptOut<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( cold ) --> Vec<(hot, weight)> | Target allocation of the coldkey's stake across hotkeys, by relative weight.
+    pub type StakePortfolios<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<(T::AccountId, u16)>, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( key ) --> last_tx_block_delegate_take
     pub type LastTxBlockDelegateTake<T: Config> =
         StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultLastTxBlock<T>>;
@@ -1228,16 +1764,80 @@ pub mod pallet {
     /// ITEM( weights_min_stake )
     pub type WeightsMinStake<T> = StorageValue<_, u64, ValueQuery, DefaultWeightsMinStake<T>>;
     #[pallet::storage]
-    /// --- MAP (netuid, who) --> (hash, weight) | Returns the hash and weight committed by an account for a given netuid.
+    /// --- MAP ( hot ) --> signer | Key allowed to set, commit and reveal weights on behalf of the hotkey.
+    pub type WeightSigners<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( signer ) --> hot | Reverse lookup of WeightSigners.
+    pub type WeightSignerHotkey<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( hot ) --> nonce | Next nonce a hotkey must sign into unsigned set_weights.
+    pub type WeightsNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP (netuid, who) --> (hash, block, tempo_index) | Returns the commit of an account for a given netuid.
+    /// The tempo index is None for commits made before commit hashes were bound to it.
     pub type WeightCommits<T: Config> = StorageDoubleMap<
         _,
         Twox64Concat,
         u16,
         Twox64Concat,
         T::AccountId,
-        (H256, u64),
+        (H256, u64, Option<u64>),
         OptionQuery,
     >;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> block | Last block a validator set or revealed weights
+    /// without paying a fee.
+    pub type FreeWeightsSetBlock<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, u16, u64, OptionQuery>;
+
+    /// =========================
+    /// ==== Orphan Cleanup =====
+    /// =========================
+    #[pallet::storage]
+    /// ITEM( orphan_cleanup_phase ) | The storage map currently scanned by the on_idle cleanup.
+    pub type OrphanCleanupPhase<T> = StorageValue<_, u8, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( orphan_cleanup_cursor ) | Raw key of the last record visited in the current phase.
+    pub type OrphanCleanupCursor<T> = StorageValue<_, Vec<u8>, OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( orphaned_stake_removed ) | Number of orphaned Stake entries removed.
+    pub type OrphanedStakeRemoved<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( orphaned_axons_removed ) | Number of orphaned Axons entries removed.
+    pub type OrphanedAxonsRemoved<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( orphaned_delegates_removed ) | Number of orphaned Delegates entries removed.
+    pub type OrphanedDelegatesRemoved<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( small_nominations_cursor ) | Raw Stake key small nominations are cleared from next.
+    pub type SmallNominationsCursor<T> = StorageValue<_, Vec<u8>, OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( hotkey_unstake_queue ) | Hotkeys whose coldkeys are being unstaked, oldest first.
+    pub type HotkeyUnstakeQueue<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( hotkey_unstake_cursor ) | Raw Stake key the first queued hotkey is unstaked from next.
+    pub type HotkeyUnstakeCursor<T> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+    /// =================
+    /// ==== Metrics ====
+    /// =================
+    #[pallet::storage]
+    /// --- MAP ( block ) --> metrics | Operation counters for the last MetricsRetentionBlocks blocks.
+    pub type BlockMetrics<T> = StorageMap<_, Identity, u64, BlockMetricsInfo, ValueQuery>;
+
+    /// ==========================
+    /// ==== Safe Math Audit =====
+    /// ==========================
+    #[pallet::storage]
+    /// --- MAP ( site ) --> (operations, saturations) | Audited arithmetic counters per code path.
+    pub type SafeMathAuditCounters<T> =
+        StorageMap<_, Identity, SaturationSite, (u64, u64), ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( safe_math_audit_events ) | Whether audited saturations emit ArithmeticSaturated.
+    pub type SafeMathAuditEvents<T> = StorageValue<_, bool, ValueQuery>;
 
     /// ==================
     /// ==== Genesis =====
@@ -1251,6 +1851,8 @@ pub mod pallet {
         pub stakes: Vec<(T::AccountId, Vec<(T::AccountId, (u64, u16))>)>,
         /// The total issued balance in genesis
         pub balances_issuance: u64,
+        /// Subnets created in genesis, in addition to the default subnet 3.
+        pub subnets: Vec<GenesisSubnet<T::AccountId>>,
     }
 
     impl<T: Config> Default for GenesisConfig<T> {
@@ -1258,6 +1860,7 @@ pub mod pallet {
             Self {
                 stakes: Default::default(),
                 balances_issuance: 0,
+                subnets: Default::default(),
             }
         }
     }
@@ -1411,8 +2014,9 @@ where
     ) -> TransactionValidity {
         match call.is_sub_type() {
             Some(Call::commit_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+                let hotkey = Pallet::<T>::get_weights_hotkey(who, *netuid);
+                if Self::check_weights_min_stake(&hotkey, *netuid) {
+                    let priority: u64 = Self::get_priority_set_weights(&hotkey, *netuid);
                     Ok(ValidTransaction {
                         priority,
                         longevity: 1,
@@ -1423,8 +2027,9 @@ where
                 }
             }
             Some(Call::reveal_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+                let hotkey = Pallet::<T>::get_weights_hotkey(who, *netuid);
+                if Self::check_weights_min_stake(&hotkey, *netuid) {
+                    let priority: u64 = Self::get_priority_set_weights(&hotkey, *netuid);
                     Ok(ValidTransaction {
                         priority,
                         longevity: 1,
@@ -1435,8 +2040,9 @@ where
                 }
             }
             Some(Call::set_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+                let hotkey = Pallet::<T>::get_weights_hotkey(who, *netuid);
+                if Self::check_weights_min_stake(&hotkey, *netuid) {
+                    let priority: u64 = Self::get_priority_set_weights(&hotkey, *netuid);
                     Ok(ValidTransaction {
                         priority,
                         longevity: 1,
@@ -1462,11 +2068,18 @@ where
                 priority: Self::get_priority_vanilla(),
                 ..Default::default()
             }),
-            Some(Call::remove_stake { .. }) => Ok(ValidTransaction {
-                priority: Self::get_priority_vanilla(),
-                ..Default::default()
-            }),
-            Some(Call::register { netuid, .. } | Call::burned_register { netuid, .. }) => {
+            Some(Call::remove_stake { .. } | Call::unstake_all_and_reap { .. }) => {
+                Ok(ValidTransaction {
+                    priority: Self::get_priority_vanilla(),
+                    ..Default::default()
+                })
+            }
+            Some(
+                Call::register { netuid, .. }
+                | Call::burned_register { netuid, .. }
+                | Call::register_with_challenge { netuid, .. }
+                | Call::burned_register_with_challenge { netuid, .. },
+            ) => {
                 let registrations_this_interval =
                     Pallet::<T>::get_registrations_this_interval(*netuid);
                 let max_registrations_per_interval =
@@ -1531,7 +2144,7 @@ where
                 let transaction_fee = 100000;
                 Ok((CallType::AddStake, transaction_fee, who.clone()))
             }
-            Some(Call::remove_stake { .. }) => {
+            Some(Call::remove_stake { .. } | Call::unstake_all_and_reap { .. }) => {
                 let transaction_fee = 0;
                 Ok((CallType::RemoveStake, transaction_fee, who.clone()))
             }
@@ -1547,11 +2160,11 @@ where
                 let transaction_fee = 0;
                 Ok((CallType::SetWeights, transaction_fee, who.clone()))
             }
-            Some(Call::register { .. }) => {
+            Some(Call::register { .. } | Call::register_with_challenge { .. }) => {
                 let transaction_fee = 0;
                 Ok((CallType::Register, transaction_fee, who.clone()))
             }
-            Some(Call::serve_axon { .. }) => {
+            Some(Call::serve_axon { .. } | Call::serve_axon_with_capabilities { .. }) => {
                 let transaction_fee = 0;
                 Ok((CallType::Serve, transaction_fee, who.clone()))
             }
@@ -1678,3 +2291,28 @@ impl<T, H, P> CollectiveInterface<T, H, P> for () {
         Ok(true)
     }
 }
+
+/// Trait for runtime pallets observing or redirecting the emission of subnets
+pub trait OnEmission {
+    /// Called with the emission a subnet earns in a block, before it accumulates on the subnet.
+    /// Returns the part redirected away from the subnet, capped at `emission`. The redirected
+    /// part is never minted by this pallet, so an implementor paying it out mints it itself.
+    fn on_subnet_emission(netuid: u16, emission: u64) -> u64;
+}
+
+impl OnEmission for () {
+    fn on_subnet_emission(_: u16, _: u64) -> u64 {
+        0
+    }
+}
+
+/// Trait for runtime pallets reacting when a delegate changes its take
+pub trait OnTakeChanged<AccountId> {
+    /// Called after the take of `hotkey` changed from `old_take` to `new_take` through the
+    /// increase or decrease take extrinsics, so nominators can be notified or unstaked.
+    fn on_take_changed(hotkey: &AccountId, old_take: u16, new_take: u16);
+}
+
+impl<AccountId> OnTakeChanged<AccountId> for () {
+    fn on_take_changed(_: &AccountId, _: u16, _: u16) {}
+}
@@ -26,6 +26,9 @@ mod config {
         /// Origin checking for council majority
         type CouncilOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+        /// Origin checking for senate majority
+        type SenateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         ///  Currency type that will be used to place deposits on neurons
         type Currency: fungible::Balanced<Self::AccountId, Balance = u64>
             + fungible::Mutate<Self::AccountId>;
@@ -47,6 +50,20 @@ mod config {
         /// the preimage to store the call data.
         type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
 
+        /// Signature a hotkey embeds in weights submitted as an unsigned transaction.
+        type WeightsSignature: Verify<Signer = Self::WeightsSigner> + Parameter;
+
+        /// Public key of a weights signature, identifying the hotkey that signed it.
+        type WeightsSigner: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// Source of the randomness behind each subnet's per-tempo beacon. Should be backed by
+        /// VRF output (e.g. BABE) where the runtime can provide it.
+        type Randomness: Randomness<H256, BlockNumberFor<Self>>;
+
+        /// Whether subnet randomness beacons are drawn. Keep it off unless Randomness cannot be
+        /// biased by block authors; a collective flip over parent hashes can be.
+        type RandomnessBeaconEnabled: Get<bool>;
+
         /// =================================
         /// ==== Initial Value Constants ====
         /// =================================
@@ -183,6 +200,9 @@ mod config {
         /// Initial network creation rate limit
         #[pallet::constant]
         type InitialNetworkRateLimit: Get<u64>;
+        /// Initial number of tempos over which a new subnet's emission ramps up to its full value.
+        #[pallet::constant]
+        type InitialSubnetEmissionRampTempos: Get<u16>;
         /// Initial target stakes per interval issuance.
         #[pallet::constant]
         type InitialTargetStakesPerInterval: Get<u64>;
@@ -210,5 +230,85 @@ mod config {
         /// Dissolve network schedule duration
         #[pallet::constant]
         type InitialDissolveNetworkScheduleDuration: Get<BlockNumberFor<Self>>;
+        /// Blocks a scheduled take increase waits before it is applied.
+        #[pallet::constant]
+        type TakeIncreaseScheduleDuration: Get<BlockNumberFor<Self>>;
+        /// Whether a coldkey holding stake keeps its account alive below the existential deposit.
+        #[pallet::constant]
+        type KeepStakingAccountsAlive: Get<bool>;
+        /// Blocks a public good delegate stays locked at its take after opting out.
+        #[pallet::constant]
+        type PublicGoodReleaseDelay: Get<u64>;
+        /// Lowest tempo a subnet owner may set.
+        #[pallet::constant]
+        type MinOwnerTempo: Get<u16>;
+        /// Blocks a subnet owner must wait between tempo changes.
+        #[pallet::constant]
+        type OwnerTempoChangeRateLimit: Get<u64>;
+        /// Maximum number of (uid, value) pairs stored in a single weights or bonds row.
+        /// Subnets may not allow more uids than this.
+        #[pallet::constant]
+        type MaxWeightsRowLength: Get<u32>;
+        /// Number of blocks a proposed take change remains confirmable.
+        #[pallet::constant]
+        type TakeChangeProposalExpiry: Get<u64>;
+        /// Number of blocks per-block metrics are kept before being pruned.
+        #[pallet::constant]
+        type MetricsRetentionBlocks: Get<u64>;
+        /// Number of blocks stake stays locked per conviction level.
+        #[pallet::constant]
+        type ConvictionLockPeriod: Get<u64>;
+        /// Number of blocks between delegate stake checkpoints.
+        #[pallet::constant]
+        type StakeCheckpointInterval: Get<u64>;
+        /// Number of stake checkpoints kept per delegate.
+        #[pallet::constant]
+        type MaxStakeCheckpoints: Get<u32>;
+        /// Number of blocks in a year, used to annualize delegate returns.
+        #[pallet::constant]
+        type BlocksPerYear: Get<u64>;
+        /// Number of blocks a delegate deregistered from every subnet keeps advertising and
+        /// accepting nominations before it becomes inactive.
+        #[pallet::constant]
+        type DelegateInactivityGracePeriod: Get<u64>;
+        /// Number of auxiliary score kinds a validator can publish, kinds ranging from 0 to
+        /// this value exclusive.
+        #[pallet::constant]
+        type MaxAuxScoreKinds: Get<u16>;
+        /// Weight block_step may use in a block. Registration adjustments, epochs and hotkey
+        /// emission drains beyond it are deferred to the next block.
+        #[pallet::constant]
+        type BlockStepWeightLimit: Get<Weight>;
+        /// Number of blocks between the starts of the refresh passes of the delegate statistics
+        /// served to the runtime APIs. A pass may span several blocks of block step budget.
+        #[pallet::constant]
+        type DelegateStatsInterval: Get<u64>;
+        /// Initial upper bound on the minimum stake a subnet owner may require of registering
+        /// coldkeys.
+        #[pallet::constant]
+        type InitialMaxMinStakeToRegister: Get<u64>;
+        /// Initial number of hotkeys a subnet owner may reserve against pruning.
+        #[pallet::constant]
+        type InitialMaxReservedUids: Get<u16>;
+        /// Upper bound on the number of hotkeys a subnet owner may reserve, capping
+        /// MaxReservedUids.
+        #[pallet::constant]
+        type MaxReservedHotkeys: Get<u32>;
+        /// Number of hotkeys a coldkey may allocate its stake across in a stake portfolio.
+        #[pallet::constant]
+        type MaxPortfolioHotkeys: Get<u32>;
+        /// Deposit held in the treasury while a treasury spend proposal is open.
+        #[pallet::constant]
+        type TreasuryProposalDeposit: Get<u64>;
+        /// Number of sealed bids a subnet registration auction accepts per interval.
+        #[pallet::constant]
+        type MaxRegistrationBids: Get<u32>;
+        /// Deposit escrowed with a registration bid, forfeited if the bid is never revealed.
+        #[pallet::constant]
+        type RegistrationBidDeposit: Get<u64>;
+        /// Interface letting runtime pallets observe or redirect subnet emission.
+        type OnEmission: crate::OnEmission;
+        /// Interface letting runtime pallets react to a delegate changing its take.
+        type OnTakeChanged: crate::OnTakeChanged<Self::AccountId>;
     }
 }
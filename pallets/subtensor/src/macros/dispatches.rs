@@ -5,9 +5,7 @@ use frame_support::pallet_macros::pallet_section;
 #[pallet_section]
 mod dispatches {
     use frame_support::traits::schedule::v3::Anon as ScheduleAnon;
-    use frame_support::traits::schedule::DispatchTime;
     use frame_system::pallet_prelude::BlockNumberFor;
-    use sp_runtime::traits::Saturating;
     /// Dispatchable functions allow users to interact with the pallet and invoke state changes.
     /// These functions materialize as "extrinsics", which are often compared to transactions.
     /// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
@@ -69,24 +67,33 @@ mod dispatches {
         /// * 'WeightVecLengthIsLow':
         /// 	- Attempting to set weights with fewer weights than min.
         ///
+        /// * 'WeightVecLengthIsHigh':
+        /// 	- Attempting to set weights with more weights than max.
+        ///
         /// * 'MaxWeightExceeded':
         /// 	- Attempting to set weights with max value exceeding limit.
+        ///
+        /// # Fees:
+        /// A successful call is free for a hotkey holding a validator permit on the subnet, once
+        /// per weights rate-limit window. Other calls pay the usual fee.
         #[pallet::call_index(0)]
         #[pallet::weight((Weight::from_parts(22_060_000_000, 0)
         .saturating_add(T::DbWeight::get().reads(4106))
-        .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        .saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
         pub fn set_weights(
             origin: OriginFor<T>,
             netuid: u16,
             dests: Vec<u16>,
             weights: Vec<u16>,
             version_key: u64,
-        ) -> DispatchResult {
-            if !Self::get_commit_reveal_weights_enabled(netuid) {
-                return Self::do_set_weights(origin, netuid, dests, weights, version_key);
+        ) -> DispatchResultWithPostInfo {
+            if Self::get_commit_reveal_weights_enabled(netuid) {
+                return Err(Error::<T>::CommitRevealEnabled.into());
             }
 
-            Err(Error::<T>::CommitRevealEnabled.into())
+            let hotkey = Self::ensure_weights_origin(origin.clone(), netuid)?;
+            Self::do_set_weights(origin, netuid, dests, weights, version_key)?;
+            Ok(Self::use_free_weights_quota(&hotkey, netuid).into())
         }
 
         /// ---- Used to commit a hash of your weight values to later be revealed.
@@ -99,22 +106,28 @@ mod dispatches {
         ///   - The u16 network identifier.
         ///
         /// * `commit_hash` (`H256`):
-        ///   - The hash representing the committed weights.
+        ///   - The hash representing the committed weights. It binds the hotkey, netuid and
+        ///     tempo index of the commit block, see `get_commit_hash`.
         ///
         /// # Raises:
         /// * `WeightsCommitNotAllowed`:
         ///   - Attempting to commit when it is not allowed.
         ///
+        /// # Fees:
+        /// A successful call is free for a hotkey holding a validator permit on the subnet.
+        /// Other calls pay the usual fee.
         #[pallet::call_index(96)]
         #[pallet::weight((Weight::from_parts(46_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
         pub fn commit_weights(
             origin: T::RuntimeOrigin,
             netuid: u16,
             commit_hash: H256,
-        ) -> DispatchResult {
-            Self::do_commit_weights(origin, netuid, commit_hash)
+        ) -> DispatchResultWithPostInfo {
+            let hotkey = Self::ensure_weights_origin(origin.clone(), netuid)?;
+            Self::do_commit_weights(origin, netuid, commit_hash)?;
+            Ok(Self::commit_weights_pays(&hotkey, netuid).into())
         }
 
         /// ---- Used to reveal the weights for a previously committed hash.
@@ -145,13 +158,18 @@ mod dispatches {
         /// * `InvalidRevealCommitHashNotMatchTempo`:
         ///   - Attempting to reveal weights outside the valid tempo.
         ///
+        /// * `CommitHashTempoMismatch`:
+        ///   - The committed hash was computed for another tempo index.
+        ///
         /// * `InvalidRevealCommitHashNotMatch`:
         ///   - The revealed hash does not match the committed hash.
         ///
+        /// # Fees:
+        /// As for set_weights, with which it shares the free quota.
         #[pallet::call_index(97)]
         #[pallet::weight((Weight::from_parts(103_000_000, 0)
 		.saturating_add(T::DbWeight::get().reads(11))
-		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
         pub fn reveal_weights(
             origin: T::RuntimeOrigin,
             netuid: u16,
@@ -159,8 +177,51 @@ mod dispatches {
             values: Vec<u16>,
             salt: Vec<u16>,
             version_key: u64,
-        ) -> DispatchResult {
-            Self::do_reveal_weights(origin, netuid, uids, values, salt, version_key)
+        ) -> DispatchResultWithPostInfo {
+            let hotkey = Self::ensure_weights_origin(origin.clone(), netuid)?;
+            Self::do_reveal_weights(origin, netuid, uids, values, salt, version_key)?;
+            Ok(Self::use_free_weights_quota(&hotkey, netuid).into())
+        }
+
+        /// ---- Appoints a key allowed to call set_weights, commit_weights and reveal_weights
+        /// on behalf of the calling hotkey, so the hotkey itself does not need to live on the
+        /// validator machine. Replaces any previous signer.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- The signature of the delegating hotkey.
+        ///
+        /// * `signer` (T::AccountId):
+        /// 	- The key allowed to set weights for the hotkey.
+        ///
+        /// # Raises:
+        /// * 'WeightsSignerAlreadyInUse':
+        /// 	- The signer is a registered hotkey or already signs for another hotkey.
+        ///
+        #[pallet::call_index(83)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_weights_signer(origin: OriginFor<T>, signer: T::AccountId) -> DispatchResult {
+            Self::do_set_weights_signer(origin, signer)
+        }
+
+        /// ---- Removes the weights signer of the calling hotkey.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- The signature of the delegating hotkey.
+        ///
+        /// # Raises:
+        /// * 'NoWeightsSigner':
+        /// 	- The hotkey has no weights signer.
+        ///
+        #[pallet::call_index(84)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn remove_weights_signer(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_remove_weights_signer(origin)
         }
 
         /// # Args:
@@ -312,7 +373,9 @@ mod dispatches {
             Self::do_decrease_take(origin, hotkey, take)
         }
 
-        /// --- Allows delegates to increase its take value. This call is rate-limited.
+        /// --- Allows delegates to increase its take value. The increase is queued in the
+        /// scheduler and applied after TakeIncreaseScheduleDuration blocks, so nominators can
+        /// react beforehand. Applying it is rate-limited.
         ///
         /// # Args:
         /// * 'origin': (<T as frame_system::Config>::Origin):
@@ -329,8 +392,11 @@ mod dispatches {
         ///        [0.01 * 65535] = [655.35] = 655
         ///
         /// # Event:
+        /// * TakeIncreaseScheduled;
+        /// 	- On successfully queueing the increase.
+        ///
         /// * TakeIncreased;
-        /// 	- On successfully setting a increased take for this hotkey.
+        /// 	- Once the increased take is applied.
         ///
         /// # Raises:
         /// * 'NotRegistered':
@@ -343,13 +409,166 @@ mod dispatches {
         /// 	- The delegate is setting a take which is not greater than the previous.
         ///
         #[pallet::call_index(66)]
-        #[pallet::weight((0, DispatchClass::Normal, Pays::No))]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(5)), DispatchClass::Normal, Pays::Yes))]
         pub fn increase_take(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
             take: u16,
         ) -> DispatchResult {
-            Self::do_increase_take(origin, hotkey, take)
+            Self::do_schedule_increase_take(origin, hotkey, take)
+        }
+
+        /// --- Proposes a take change for a hotkey that only applies once confirmed with
+        /// `confirm_take_change` before the proposal expires. Intended for multisig coldkeys
+        /// whose co-signers review the change; single-signer coldkeys can keep using
+        /// `increase_take` and `decrease_take` directly.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey we are delegating (must be owned by the coldkey.)
+        ///
+        /// * 'take' (u16):
+        /// 	- The proposed stake proportion that this hotkey takes from delegations.
+        ///
+        /// # Event:
+        /// * TakeChangeProposed;
+        /// 	- On successfully recording the proposal.
+        ///
+        #[pallet::call_index(81)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn propose_take_change(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            take: u16,
+        ) -> DispatchResult {
+            Self::do_propose_take_change(origin, hotkey, take)
+        }
+
+        /// --- Applies the take change previously proposed with `propose_take_change`.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey with a pending take change.
+        ///
+        /// # Raises:
+        /// * 'NoPendingTakeChange':
+        /// 	- There is no proposal for this hotkey.
+        ///
+        /// * 'TakeChangeProposalExpired':
+        /// 	- The proposal has passed its expiry block.
+        ///
+        #[pallet::call_index(82)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
+        pub fn confirm_take_change(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            Self::do_confirm_take_change(origin, hotkey)
+        }
+
+        /// --- Locks stake on a hotkey for `conviction` lock periods. While locked the stake
+        /// cannot be removed and counts (1 + conviction) times towards the hotkey's senate and
+        /// root network ranking. Locks are released automatically once they expire.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey the stake is on.
+        ///
+        /// * 'amount' (u64):
+        /// 	- The amount of stake to lock.
+        ///
+        /// * 'conviction' (u8):
+        /// 	- The conviction multiplier, between 1 and 6.
+        ///
+        /// # Event:
+        /// * StakeLocked;
+        /// 	- On successfully locking the stake.
+        ///
+        #[pallet::call_index(85)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn lock_stake(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            amount: u64,
+            conviction: u8,
+        ) -> DispatchResult {
+            Self::do_lock_stake(origin, hotkey, amount, conviction)
+        }
+
+        /// --- Proposes paying `amount` from the treasury to `beneficiary`. Each coldkey may
+        /// have one open proposal; a new one replaces it. Opening a proposal holds
+        /// TreasuryProposalDeposit in the treasury until the proposal is approved (refunded) or
+        /// rejected (kept).
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the proposing coldkey.
+        ///
+        /// * 'beneficiary' (T::AccountId):
+        /// 	- The account to be paid.
+        ///
+        /// * 'amount' (u64):
+        /// 	- The amount to be paid.
+        ///
+        #[pallet::call_index(86)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(5))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn propose_treasury_spend(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_propose_treasury_spend(origin, beneficiary, amount)
+        }
+
+        /// --- Approves and pays out the open treasury spend proposal of `proposer`.
+        /// Only callable by root (governance).
+        ///
+        /// # Raises:
+        /// * 'TreasuryProposalNotFound':
+        /// 	- The proposer has no open proposal.
+        ///
+        /// * 'InsufficientTreasuryBalance':
+        /// 	- The treasury cannot cover the spend.
+        ///
+        #[pallet::call_index(87)]
+        #[pallet::weight((Weight::from_parts(60_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(5))
+		.saturating_add(T::DbWeight::get().writes(5)), DispatchClass::Operational, Pays::No))]
+        pub fn approve_treasury_spend(
+            origin: OriginFor<T>,
+            proposer: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_approve_treasury_spend(origin, proposer)
+        }
+
+        /// --- Rejects the open treasury spend proposal of `proposer`.
+        /// Only callable by root (governance).
+        ///
+        #[pallet::call_index(88)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Operational, Pays::No))]
+        pub fn reject_treasury_spend(
+            origin: OriginFor<T>,
+            proposer: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_reject_treasury_spend(origin, proposer)
         }
 
         /// --- Adds stake to a hotkey. The call is made from the
@@ -466,6 +685,9 @@ mod dispatches {
         /// * 'placeholder2' (u8):
         /// 	- Placeholder for further extra params.
         ///
+        /// The capabilities already advertised by the axon are kept, see
+        /// serve_axon_with_capabilities to change them.
+        ///
         /// # Event:
         /// * AxonServed;
         /// 	- On successfully serving the axon info.
@@ -511,6 +733,7 @@ mod dispatches {
                 protocol,
                 placeholder1,
                 placeholder2,
+                None,
             )
         }
 
@@ -609,7 +832,55 @@ mod dispatches {
             hotkey: T::AccountId,
             coldkey: T::AccountId,
         ) -> DispatchResult {
-            Self::do_registration(origin, netuid, block_number, nonce, work, hotkey, coldkey)
+            Self::do_registration(
+                origin,
+                netuid,
+                block_number,
+                nonce,
+                work,
+                hotkey,
+                coldkey,
+                None,
+            )
+        }
+
+        /// ---- Registers a new neuron to the subnetwork by POW, echoing the subnet's
+        /// registration challenge commit.
+        ///
+        /// # Args:
+        /// * 'challenge' ( H256 ):
+        /// 	- The subnet's RegistrationChallengeCommit.
+        ///
+        /// The other arguments are those of `register`.
+        ///
+        /// # Raises:
+        /// * 'RegistrationChallengeMismatch':
+        /// 	- The challenge does not equal the subnet's commit.
+        ///
+        #[pallet::call_index(98)]
+        #[pallet::weight((Weight::from_parts(192_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(25))
+		.saturating_add(T::DbWeight::get().writes(22)), DispatchClass::Normal, Pays::No))]
+        pub fn register_with_challenge(
+            origin: OriginFor<T>,
+            netuid: u16,
+            block_number: u64,
+            nonce: u64,
+            work: Vec<u8>,
+            hotkey: T::AccountId,
+            coldkey: T::AccountId,
+            challenge: H256,
+        ) -> DispatchResult {
+            Self::do_registration(
+                origin,
+                netuid,
+                block_number,
+                nonce,
+                work,
+                hotkey,
+                coldkey,
+                Some(challenge),
+            )
         }
 
         /// Register the hotkey to root network
@@ -640,7 +911,22 @@ mod dispatches {
             netuid: u16,
             hotkey: T::AccountId,
         ) -> DispatchResult {
-            Self::do_burned_registration(origin, netuid, hotkey)
+            Self::do_burned_registration(origin, netuid, hotkey, None)
+        }
+
+        /// User register a new subnetwork via burning token, echoing the subnet's registration
+        /// challenge commit.
+        #[pallet::call_index(95)]
+        #[pallet::weight((Weight::from_parts(177_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(27))
+		.saturating_add(T::DbWeight::get().writes(24)), DispatchClass::Normal, Pays::No))]
+        pub fn burned_register_with_challenge(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+            challenge: H256,
+        ) -> DispatchResult {
+            Self::do_burned_registration(origin, netuid, hotkey, Some(challenge))
         }
 
         /// The extrinsic for user to change its hotkey
@@ -1045,26 +1331,13 @@ mod dispatches {
                 Error::<T>::SwapAlreadyScheduled
             );
 
-            let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
-            let duration: BlockNumberFor<T> = ColdkeySwapScheduleDuration::<T>::get();
-            let when: BlockNumberFor<T> = current_block.saturating_add(duration);
-
-            let call = Call::<T>::swap_coldkey {
-                old_coldkey: who.clone(),
-                new_coldkey: new_coldkey.clone(),
-            };
-
-            let bound_call = T::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
-                .map_err(|_| Error::<T>::FailedToSchedule)?;
-
-            T::Scheduler::schedule(
-                DispatchTime::At(when),
-                None,
-                63,
-                frame_system::RawOrigin::Root.into(),
-                bound_call,
-            )
-            .map_err(|_| Error::<T>::FailedToSchedule)?;
+            let when: BlockNumberFor<T> = Self::schedule_operation(
+                &who,
+                ScheduledOperation::ColdkeySwap {
+                    new_coldkey: new_coldkey.clone(),
+                },
+                ColdkeySwapScheduleDuration::<T>::get(),
+            )?;
 
             ColdkeySwapScheduled::<T>::insert(&who, ());
             // Emit the SwapScheduled event
@@ -1102,26 +1375,11 @@ mod dispatches {
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
-            let duration: BlockNumberFor<T> = DissolveNetworkScheduleDuration::<T>::get();
-            let when: BlockNumberFor<T> = current_block.saturating_add(duration);
-
-            let call = Call::<T>::dissolve_network {
-                coldkey: who.clone(),
-                netuid,
-            };
-
-            let bound_call = T::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
-                .map_err(|_| Error::<T>::FailedToSchedule)?;
-
-            T::Scheduler::schedule(
-                DispatchTime::At(when),
-                None,
-                63,
-                frame_system::RawOrigin::Root.into(),
-                bound_call,
-            )
-            .map_err(|_| Error::<T>::FailedToSchedule)?;
+            let when: BlockNumberFor<T> = Self::schedule_operation(
+                &who,
+                ScheduledOperation::DissolveNetwork { netuid },
+                DissolveNetworkScheduleDuration::<T>::get(),
+            )?;
 
             // Emit the SwapScheduled event
             Self::deposit_event(Event::DissolveNetworkScheduled {
@@ -1210,5 +1468,474 @@ mod dispatches {
         ) -> DispatchResult {
             Self::user_add_network(origin, identity)
         }
+
+        /// Lease control of a subnet's hyperparameters and owner cut to another coldkey
+        /// until `end_block`, after which control reverts to the owner.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, must be the subnet owner.
+        /// * `netuid` - The subnet being leased.
+        /// * `lessee` - The coldkey receiving control of the subnet.
+        /// * `end_block` - The block at which the lease ends.
+        #[pallet::call_index(80)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::Yes))]
+        pub fn grant_subnet_lease(
+            origin: OriginFor<T>,
+            netuid: u16,
+            lessee: T::AccountId,
+            end_block: u64,
+        ) -> DispatchResult {
+            Self::do_grant_subnet_lease(origin, netuid, lessee, end_block)
+        }
+
+        /// Removes the caller's entire stake from a hotkey and closes out the staking account.
+        /// Stake that cannot be credited because it is below the existential deposit of a
+        /// non-existent coldkey is burned.
+        ///
+        /// # Args:
+        /// * `origin` - The signature of the caller's coldkey.
+        /// * `hotkey` - The hotkey to unstake from.
+        #[pallet::call_index(89)]
+        #[pallet::weight((Weight::from_parts(111_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(10))
+		.saturating_add(T::DbWeight::get().writes(9)), DispatchClass::Normal, Pays::No))]
+        pub fn unstake_all_and_reap(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            Self::do_unstake_all_and_reap(origin, hotkey)
+        }
+
+        /// Commits a sealed bid for a UID on a subnet that registers by auction, escrowing
+        /// `escrow` and the bid deposit. Bids are revealed with reveal_registration_bid in the
+        /// last quarter of the adjustment interval; at its end the highest revealed bids are
+        /// burned and registered, the rest refunded. Unrevealed bids forfeit their deposit.
+        ///
+        /// # Args:
+        /// * `origin` - The signature of the caller's coldkey.
+        /// * `netuid` - The subnet to register on.
+        /// * `hotkey` - The hotkey to register if the bid wins.
+        /// * `commitment` - The hash of (coldkey, hotkey, netuid, amount, salt).
+        /// * `escrow` - The amount escrowed, at least the amount to reveal.
+        #[pallet::call_index(90)]
+        #[pallet::weight((Weight::from_parts(60_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(8))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn bid_for_registration(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+            commitment: H256,
+            escrow: u64,
+        ) -> DispatchResult {
+            Self::do_bid_for_registration(origin, netuid, hotkey, commitment, escrow)
+        }
+
+        /// Sets weights from an unsigned transaction carrying the hotkey's signature over
+        /// `weights_signature_payload`, so the hotkey needs no balance to submit it. The nonce
+        /// must equal the hotkey's `WeightsNonce` and is consumed on inclusion.
+        ///
+        /// # Args:
+        /// * `origin` - Must be none.
+        /// * `hotkey` - The hotkey setting weights, which signed the payload.
+        /// * `netuid`, `dests`, `weights`, `version_key` - As in set_weights.
+        /// * `nonce` - The hotkey's current weights nonce.
+        /// * `signature` - The hotkey's signature over the payload.
+        #[pallet::call_index(91)]
+        #[pallet::weight((Weight::from_parts(22_100_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4106))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
+        #[allow(clippy::too_many_arguments)]
+        pub fn set_weights_unsigned(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: u16,
+            dests: Vec<u16>,
+            weights: Vec<u16>,
+            version_key: u64,
+            nonce: u64,
+            signature: T::WeightsSignature,
+        ) -> DispatchResult {
+            Self::do_set_weights_unsigned(
+                origin,
+                hotkey,
+                netuid,
+                dests,
+                weights,
+                version_key,
+                nonce,
+                signature,
+            )
+        }
+
+        /// Publishes a validator's auxiliary scores of one kind for uids on a subnet. The
+        /// scores are separate from weights and are cleared at the subnet's next epoch.
+        ///
+        /// # Args:
+        /// * `origin` - The signature of the validator's hotkey or of its weights signer.
+        /// * `netuid` - The subnet the scores are for.
+        /// * `kind` - The kind of score, below `MaxAuxScoreKinds`.
+        /// * `uids` - The uids being scored.
+        /// * `values` - The score of each uid.
+        #[pallet::call_index(92)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_aux_scores(
+            origin: OriginFor<T>,
+            netuid: u16,
+            kind: u16,
+            uids: Vec<u16>,
+            values: Vec<u16>,
+        ) -> DispatchResult {
+            Self::do_set_aux_scores(origin, netuid, kind, uids, values)
+        }
+
+        /// ---- Appoints a manager allowed to change the take of a hotkey owned by the calling
+        /// coldkey, through increase_take, decrease_take, propose_take_change and
+        /// confirm_take_change. Replaces any previous manager.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- The signature of the owning coldkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        /// 	- The delegate hotkey.
+        ///
+        /// * `manager` (T::AccountId):
+        /// 	- The key allowed to change the take.
+        ///
+        /// # Raises:
+        /// * 'NonAssociatedColdKey':
+        /// 	- The hotkey is not owned by the calling coldkey.
+        ///
+        #[pallet::call_index(93)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_delegate_manager(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            manager: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_set_delegate_manager(origin, hotkey, manager)
+        }
+
+        /// ---- Revokes the manager of a hotkey owned by the calling coldkey.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- The signature of the owning coldkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        /// 	- The delegate hotkey.
+        ///
+        /// # Raises:
+        /// * 'NoDelegateManager':
+        /// 	- The hotkey has no manager.
+        ///
+        #[pallet::call_index(94)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn remove_delegate_manager(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_remove_delegate_manager(origin, hotkey)
+        }
+
+        /// ---- Opens or closes a hotkey owned by the calling coldkey to stake from other
+        /// coldkeys. Every hotkey accepts delegated stake at the default take until it opts out.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- The signature of the owning coldkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        /// 	- The hotkey.
+        ///
+        /// * `available` (bool):
+        /// 	- Whether other coldkeys may add stake to the hotkey.
+        ///
+        /// # Raises:
+        /// * 'NonAssociatedColdKey':
+        /// 	- The hotkey is not owned by the calling coldkey.
+        ///
+        #[pallet::call_index(99)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_delegate_availability(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            available: bool,
+        ) -> DispatchResult {
+            Self::do_set_delegate_availability(origin, hotkey, available)
+        }
+
+        /// ---- Sets the target allocation of the calling coldkey's stake across hotkeys, each
+        /// hotkey receiving a share of the stake proportional to its weight. An empty list
+        /// clears the portfolio.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey.
+        ///
+        /// * `targets` (Vec<(T::AccountId, u16)>):
+        /// 	- The hotkeys and their relative weights.
+        ///
+        /// # Raises:
+        /// * 'TooManyPortfolioHotkeys':
+        /// 	- More hotkeys are given than MaxPortfolioHotkeys.
+        ///
+        /// * 'InvalidStakePortfolio':
+        /// 	- A hotkey is repeated or the weights sum to zero.
+        ///
+        #[pallet::call_index(100)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(33))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_stake_portfolio(
+            origin: OriginFor<T>,
+            targets: Vec<(T::AccountId, u16)>,
+        ) -> DispatchResult {
+            Self::do_set_stake_portfolio(origin, targets)
+        }
+
+        /// ---- Moves the calling coldkey's stake between the hotkeys of its portfolio toward
+        /// their targets. Locked stake is never moved, and every move is checked as
+        /// add_stake and remove_stake check it.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey.
+        ///
+        /// # Raises:
+        /// * 'NoStakePortfolio':
+        /// 	- The coldkey has no stake portfolio.
+        ///
+        /// * 'NoPortfolioHotkeyTakesStake':
+        /// 	- No portfolio hotkey can take the stake that was moved.
+        ///
+        #[pallet::call_index(101)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().reads_writes(14, 12)
+			.saturating_mul(T::MaxPortfolioHotkeys::get() as u64)), DispatchClass::Normal, Pays::Yes))]
+        pub fn rebalance_portfolio(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_rebalance_portfolio(origin)
+        }
+
+        /// ---- Burns the emission of a hotkey until `expiry_block`, pending an investigation
+        /// such as a confirmed weight-copy ring. Sanctioning a sanctioned hotkey moves its expiry.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- A senate majority.
+        ///
+        /// * `hotkey` (T::AccountId):
+        /// 	- The hotkey to sanction.
+        ///
+        /// * `expiry_block` (u64):
+        /// 	- The block the emission resumes at.
+        ///
+        /// # Raises:
+        /// * 'HotKeyAccountNotExists':
+        /// 	- The hotkey does not exist.
+        ///
+        /// * 'InvalidSanctionExpiry':
+        /// 	- The expiry block is not in the future.
+        ///
+        #[pallet::call_index(102)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Operational, Pays::No))]
+        pub fn sanction_hotkey(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            expiry_block: u64,
+        ) -> DispatchResult {
+            T::SenateOrigin::ensure_origin(origin)?;
+            Self::do_sanction_hotkey(hotkey, expiry_block)
+        }
+
+        /// ---- Lifts the sanction of a hotkey before it expires.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- A senate majority.
+        ///
+        /// * `hotkey` (T::AccountId):
+        /// 	- The sanctioned hotkey.
+        ///
+        /// # Raises:
+        /// * 'HotkeyNotSanctioned':
+        /// 	- The hotkey is not sanctioned.
+        ///
+        #[pallet::call_index(103)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn lift_hotkey_sanction(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            T::SenateOrigin::ensure_origin(origin)?;
+            Self::do_lift_hotkey_sanction(hotkey)
+        }
+
+        /// ---- Runs a delayed operation of a coldkey. Only dispatched by the scheduler at the
+        /// execution block of the operation.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- Root, as dispatched by the scheduler.
+        ///
+        /// * `coldkey` (T::AccountId):
+        /// 	- The coldkey that scheduled the operation.
+        ///
+        /// * `id` (u64):
+        /// 	- The id of the operation.
+        ///
+        /// # Raises:
+        /// * 'ScheduledOperationNotFound':
+        /// 	- The operation was cancelled or has already run.
+        ///
+        #[pallet::call_index(104)]
+        #[pallet::weight((Weight::from_parts(127_713_000, 0)
+		.saturating_add(T::DbWeight::get().reads(18))
+		.saturating_add(T::DbWeight::get().writes(31)), DispatchClass::Operational, Pays::No))]
+        pub fn execute_scheduled_operation(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            id: u64,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            Self::do_execute_scheduled_operation(coldkey, id)
+        }
+
+        /// ---- Cancels a delayed operation of the calling coldkey and removes it from the
+        /// scheduler queue.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey that scheduled the operation.
+        ///
+        /// * `id` (u64):
+        /// 	- The id of the operation.
+        ///
+        /// # Raises:
+        /// * 'ScheduledOperationNotFound':
+        /// 	- The coldkey has no pending operation with this id.
+        ///
+        #[pallet::call_index(105)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn cancel_scheduled_operation(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            Self::do_cancel_scheduled_operation(origin, id)
+        }
+
+        /// ---- Opts a delegate in or out of being a public good delegate. A public good
+        /// delegate may decrease its take to zero, below the min delegate take, and cannot
+        /// increase it until PublicGoodReleaseDelay blocks after opting out.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- The signature of the owning coldkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        /// 	- The delegate hotkey.
+        ///
+        /// * `enabled` (bool):
+        /// 	- Whether to opt in or out.
+        ///
+        /// # Event:
+        /// * PublicGoodDelegateSet;
+        /// 	- On successfully opting in or starting to opt out.
+        ///
+        /// # Raises:
+        /// * `NonAssociatedColdKey`:
+        /// 	- The hotkey is not owned by the calling coldkey.
+        ///
+        /// * `NotPublicGoodDelegate`:
+        /// 	- Opting out a hotkey that never opted in.
+        ///
+        #[pallet::call_index(109)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn set_public_good_delegate(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::do_set_public_good_delegate(origin, hotkey, enabled)
+        }
+
+        /// Reveals a sealed registration bid during the reveal window, the last quarter of the
+        /// subnet's adjustment interval.
+        ///
+        /// # Args:
+        /// * `origin` - The signature of the coldkey that placed the bid.
+        /// * `netuid` - The subnet bid on.
+        /// * `hotkey` - The hotkey of the bid.
+        /// * `amount` - The committed bid amount.
+        /// * `salt` - The committed salt.
+        #[pallet::call_index(110)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn reveal_registration_bid(
+            origin: OriginFor<T>,
+            netuid: u16,
+            hotkey: T::AccountId,
+            amount: u64,
+            salt: H256,
+        ) -> DispatchResult {
+            Self::do_reveal_registration_bid(origin, netuid, hotkey, amount, salt)
+        }
+
+        /// Serves or updates axon information like serve_axon, also setting the capabilities
+        /// advertised by the axon.
+        ///
+        /// # Args:
+        /// * `origin` - The signature of the calling hotkey.
+        /// * `netuid`, `version`, `ip`, `port`, `ip_type`, `protocol`, `placeholder1`,
+        ///   `placeholder2` - As for serve_axon.
+        /// * `capabilities` - The capability bitfield advertised by the axon.
+        ///
+        /// # Event:
+        /// * AxonServed;
+        /// 	- On successfully serving the axon info.
+        ///
+        /// # Raises:
+        /// * As for serve_axon.
+        #[pallet::call_index(111)]
+        #[pallet::weight((Weight::from_parts(46_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn serve_axon_with_capabilities(
+            origin: OriginFor<T>,
+            netuid: u16,
+            version: u32,
+            ip: u128,
+            port: u16,
+            ip_type: u8,
+            protocol: u8,
+            placeholder1: u8,
+            placeholder2: u8,
+            capabilities: u64,
+        ) -> DispatchResult {
+            Self::do_serve_axon(
+                origin,
+                netuid,
+                version,
+                ip,
+                port,
+                ip_type,
+                protocol,
+                placeholder1,
+                placeholder2,
+                Some(capabilities),
+            )
+        }
     }
 }
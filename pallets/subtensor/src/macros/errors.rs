@@ -184,5 +184,100 @@ mod errors {
         TxChildkeyTakeRateLimitExceeded,
         /// Invalid identity.
         InvalidIdentity,
+        /// The number of weights exceeds the row length allowed on the subnet.
+        WeightsRowTooLong,
+        /// The subnet is currently leased to another coldkey.
+        SubnetLeaseActive,
+        /// The lease end block must be in the future.
+        InvalidSubnetLeaseEnd,
+        /// There is no pending take change for this hotkey.
+        NoPendingTakeChange,
+        /// The pending take change has passed its expiry block.
+        TakeChangeProposalExpired,
+        /// The weights signer is already a registered hotkey or is assigned to another hotkey.
+        WeightsSignerAlreadyInUse,
+        /// The hotkey has no weights signer.
+        NoWeightsSigner,
+        /// The conviction must be between 1 and the maximum conviction.
+        InvalidConviction,
+        /// A stake lock cannot be replaced by a smaller or shorter one.
+        StakeLockCannotBeReduced,
+        /// The stake to withdraw is locked.
+        StakeIsLocked,
+        /// The proposer has no open treasury spend proposal.
+        TreasuryProposalNotFound,
+        /// The treasury balance cannot cover the spend.
+        InsufficientTreasuryBalance,
+        /// The proposer cannot pay the treasury proposal deposit.
+        NotEnoughBalanceForTreasuryDeposit,
+        /// The amount cannot be credited to a coldkey that does not exist because it is below the
+        /// existential deposit.
+        AmountBelowExistentialDeposit,
+        /// The subnet registers by auction; use bid_for_registration instead.
+        RegistrationByAuctionOnly,
+        /// The subnet does not register by auction.
+        RegistrationAuctionNotActive,
+        /// The hotkey already has a bid in the current registration auction.
+        RegistrationBidAlreadyPlaced,
+        /// The registration bid is below the subnet's minimum burn.
+        RegistrationBidTooLow,
+        /// A subnet owner cannot set a tempo below MinOwnerTempo.
+        TempoTooLow,
+        /// A subnet owner changed the tempo less than OwnerTempoChangeRateLimit blocks ago.
+        TempoChangeRateLimitExceeded,
+        /// The registration auction already holds the maximum number of bids.
+        TooManyRegistrationBids,
+        /// Registration bids cannot be placed during the reveal window.
+        RegistrationBidCommitClosed,
+        /// Registration bids can only be revealed during the reveal window.
+        RegistrationBidRevealNotOpen,
+        /// The coldkey has no unrevealed registration bid for the hotkey.
+        RegistrationBidNotFound,
+        /// The revealed bid does not match its commitment or is out of range.
+        InvalidRegistrationBidReveal,
+        /// The nonce of an unsigned set_weights is not the hotkey's current weights nonce.
+        InvalidWeightsNonce,
+        /// The signature of an unsigned set_weights does not verify against the hotkey.
+        InvalidWeightsSignature,
+        /// The delegate is not registered on any subnet and its grace period has ended.
+        DelegateInactive,
+        /// The neuron was registered less than the subnet's weights immunity period ago.
+        WeightsImmunityPeriodActive,
+        /// The coldkey is registered as a hotkey.
+        ColdkeyIsRegisteredHotkey,
+        /// The hotkey already owns hotkeys or holds stake as a coldkey.
+        HotkeyIsInUseAsColdkey,
+        /// The auxiliary score kind is not below MaxAuxScoreKinds.
+        InvalidAuxScoreKind,
+        /// The hotkey has no delegate manager.
+        NoDelegateManager,
+        /// The coldkey holds less stake than the subnet's MinStakeToRegister.
+        NotEnoughStakeToRegister,
+        /// The registration does not echo the subnet's RegistrationChallengeCommit.
+        RegistrationChallengeMismatch,
+        /// The stake portfolio has more hotkeys than MaxPortfolioHotkeys.
+        TooManyPortfolioHotkeys,
+        /// The stake portfolio repeats a hotkey or its weights sum to zero.
+        InvalidStakePortfolio,
+        /// The coldkey has no stake portfolio.
+        NoStakePortfolio,
+        /// The dispatch is attempting to set weights on chain with more elements than are allowed.
+        WeightVecLengthIsHigh,
+        /// The sanction expiry block is not in the future.
+        InvalidSanctionExpiry,
+        /// The hotkey is not sanctioned.
+        HotkeyNotSanctioned,
+        /// The revealed weights were committed for another tempo.
+        CommitHashTempoMismatch,
+        /// The coldkey has no pending scheduled operation with this id.
+        ScheduledOperationNotFound,
+        /// The hotkey is not a public good delegate.
+        NotPublicGoodDelegate,
+        /// The take of a public good delegate cannot increase before its release block.
+        PublicGoodTakeLocked,
+        /// No hotkey of the stake portfolio can take the stake moved by a rebalance.
+        NoPortfolioHotkeyTakesStake,
+        /// The coldkeys of the hotkey are still being unstaked.
+        HotkeyUnstakeInProgress,
     }
 }
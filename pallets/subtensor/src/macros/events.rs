@@ -11,10 +11,15 @@ mod events {
         NetworkAdded(u16, u16),
         /// a network is removed.
         NetworkRemoved(u16),
-        /// stake has been transferred from the a coldkey account onto the hotkey staking account.
-        StakeAdded(T::AccountId, u64),
-        /// stake has been removed from the hotkey staking account onto the coldkey account.
-        StakeRemoved(T::AccountId, u64),
+        /// stake has been transferred from the a coldkey account onto the hotkey staking account:
+        /// (coldkey, hotkey, amount, new hotkey total stake, new coldkey total stake).
+        /// Stake is not yet subnet-scoped, so the event carries no netuid; one is added with
+        /// subnet-scoped staking.
+        StakeAdded(T::AccountId, T::AccountId, u64, u64, u64),
+        /// stake has been removed from the hotkey staking account onto the coldkey account:
+        /// (coldkey, hotkey, amount, new hotkey total stake, new coldkey total stake).
+        /// Carries no netuid until stake is subnet-scoped, as for StakeAdded.
+        StakeRemoved(T::AccountId, T::AccountId, u64, u64, u64),
         /// a caller successfully sets their weights on a subnetwork.
         WeightsSet(u16, u16),
         /// a new neuron account has been registered to the chain.
@@ -121,10 +126,10 @@ mod events {
         SubnetLimitSet(u16),
         /// the lock cost reduction is set
         NetworkLockCostReductionIntervalSet(u64),
-        /// the take for a delegate is decreased.
-        TakeDecreased(T::AccountId, T::AccountId, u16),
-        /// the take for a delegate is increased.
-        TakeIncreased(T::AccountId, T::AccountId, u16),
+        /// the take for a delegate is decreased. (coldkey, hotkey, old_take, new_take)
+        TakeDecreased(T::AccountId, T::AccountId, u16, u16),
+        /// the take for a delegate is increased. (coldkey, hotkey, old_take, new_take)
+        TakeIncreased(T::AccountId, T::AccountId, u16, u16),
         /// the hotkey is swapped
         HotkeySwapped {
             /// the account ID of coldkey
@@ -204,5 +209,409 @@ mod events {
         ColdkeySwapScheduleDurationSet(BlockNumberFor<T>),
         /// The duration of dissolve network has been set
         DissolveNetworkScheduleDurationSet(BlockNumberFor<T>),
+        /// Per-tempo summary of the uids that changed in a subnet's metagraph
+        MetagraphDigest {
+            /// the subnet the digest belongs to
+            netuid: u16,
+            /// uids that received a new hotkey since the last epoch
+            registered: Vec<u16>,
+            /// uids whose previous hotkey was pruned since the last epoch
+            pruned: Vec<u16>,
+            /// uids that gained a validator permit in this epoch
+            permits_granted: Vec<u16>,
+            /// uids that lost a validator permit in this epoch
+            permits_revoked: Vec<u16>,
+        },
+        /// A subnet owner has leased control of the subnet to another coldkey
+        SubnetLeaseGranted {
+            /// the leased subnet
+            netuid: u16,
+            /// the coldkey controlling the subnet during the lease
+            lessee: T::AccountId,
+            /// the block at which control reverts to the owner
+            end_block: u64,
+        },
+        /// A subnet lease has ended and control has reverted to the owner
+        SubnetLeaseExpired {
+            /// the subnet whose lease ended
+            netuid: u16,
+            /// the coldkey that held the lease
+            lessee: T::AccountId,
+        },
+        /// A take change has been proposed and awaits confirmation
+        TakeChangeProposed {
+            /// the coldkey owning the hotkey
+            coldkey: T::AccountId,
+            /// the hotkey whose take will change
+            hotkey: T::AccountId,
+            /// the proposed take
+            take: u16,
+            /// the last block at which the proposal can be confirmed
+            expiry_block: u64,
+        },
+        /// A hotkey has appointed a key to set weights on its behalf
+        WeightsSignerSet {
+            /// the delegating hotkey
+            hotkey: T::AccountId,
+            /// the key allowed to set, commit and reveal weights
+            signer: T::AccountId,
+        },
+        /// A hotkey has removed its weights signer
+        WeightsSignerRemoved {
+            /// the delegating hotkey
+            hotkey: T::AccountId,
+            /// the key that was removed
+            signer: T::AccountId,
+        },
+        /// A delegate has been added or its take has changed
+        DelegateUpdated {
+            /// the coldkey owning the delegate hotkey
+            coldkey: T::AccountId,
+            /// the delegate hotkey
+            hotkey: T::AccountId,
+            /// the take before the change, None if the hotkey was not a delegate
+            old_take: Option<u16>,
+            /// the take after the change
+            new_take: u16,
+        },
+        /// The pending emission share of a pruned neuron has been paid out or burned
+        PrunedNeuronEmissionSettled {
+            /// the subnet the neuron was pruned from
+            netuid: u16,
+            /// the uid that was pruned
+            uid: u16,
+            /// the hotkey that was pruned
+            hotkey: T::AccountId,
+            /// the amount of pending emission settled
+            amount: u64,
+            /// true if the amount was burned rather than paid to the hotkey owner
+            burned: bool,
+        },
+        /// the number of tempos a new subnet's emission ramps over is set
+        SubnetEmissionRampTemposSet(u16),
+        /// Stake has been locked for governance weight
+        StakeLocked {
+            /// the coldkey owning the stake
+            coldkey: T::AccountId,
+            /// the hotkey the stake is on
+            hotkey: T::AccountId,
+            /// the locked amount
+            amount: u64,
+            /// the conviction multiplier of the locked amount
+            conviction: u8,
+            /// the block at which the stake unlocks
+            unlock_block: u64,
+        },
+        /// A stake lock has expired
+        StakeUnlocked {
+            /// the coldkey owning the stake
+            coldkey: T::AccountId,
+            /// the hotkey the stake is on
+            hotkey: T::AccountId,
+            /// the amount that was locked
+            amount: u64,
+        },
+        /// the fraction of block emission routed to the treasury is set
+        EmissionTitheSet(u16),
+        /// A treasury spend has been proposed
+        TreasurySpendProposed {
+            /// the proposing coldkey
+            proposer: T::AccountId,
+            /// the account to be paid
+            beneficiary: T::AccountId,
+            /// the amount to be paid
+            amount: u64,
+        },
+        /// A treasury spend has been approved and paid
+        TreasurySpendApproved {
+            /// the proposing coldkey
+            proposer: T::AccountId,
+            /// the account that was paid
+            beneficiary: T::AccountId,
+            /// the amount paid
+            amount: u64,
+        },
+        /// A treasury spend proposal has been rejected
+        TreasurySpendRejected {
+            /// the proposing coldkey
+            proposer: T::AccountId,
+        },
+        /// Stake below the existential deposit has been burned while closing out a staking account
+        StakeDustReaped {
+            /// the coldkey that owned the stake
+            coldkey: T::AccountId,
+            /// the hotkey the stake was on
+            hotkey: T::AccountId,
+            /// the amount burned
+            amount: u64,
+        },
+        /// An audited arithmetic operation saturated, recorded with the `safe-math-audit` feature
+        ArithmeticSaturated {
+            /// the code path where the operation saturated
+            site: SaturationSite,
+        },
+        /// A bid for a UID has been escrowed in a subnet registration auction
+        RegistrationBidPlaced {
+            /// the subnet being bid on
+            netuid: u16,
+            /// the coldkey paying the bid
+            coldkey: T::AccountId,
+            /// the hotkey to register
+            hotkey: T::AccountId,
+            /// the escrowed amount
+            amount: u64,
+        },
+        /// A sealed registration bid has been revealed
+        RegistrationBidRevealed {
+            /// the subnet bid on
+            netuid: u16,
+            /// the coldkey paying the bid
+            coldkey: T::AccountId,
+            /// the hotkey to register
+            hotkey: T::AccountId,
+            /// the revealed bid
+            amount: u64,
+        },
+        /// A losing or ineligible registration bid has been refunded
+        RegistrationBidRefunded {
+            /// the subnet bid on
+            netuid: u16,
+            /// the coldkey refunded
+            coldkey: T::AccountId,
+            /// the hotkey of the bid
+            hotkey: T::AccountId,
+            /// the refunded amount
+            amount: u64,
+        },
+        /// An escrowed registration amount has been recycled: a forfeited deposit or a refund
+        /// the coldkey could not receive
+        RegistrationBidRecycled {
+            /// the subnet bid on
+            netuid: u16,
+            /// the coldkey of the bid
+            coldkey: T::AccountId,
+            /// the hotkey of the bid
+            hotkey: T::AccountId,
+            /// the recycled amount
+            amount: u64,
+        },
+        /// The registration mechanism of a subnet has been set
+        RegistrationMechanismSet {
+            /// the subnet
+            netuid: u16,
+            /// the new mechanism
+            mechanism: RegistrationMechanism,
+        },
+        /// An authentic unsigned set_weights failed a weights check; its nonce is consumed
+        UnsignedWeightsRejected {
+            /// the hotkey that signed the weights
+            hotkey: T::AccountId,
+            /// the subnet the weights were for
+            netuid: u16,
+            /// the error raised by the weights check
+            error: DispatchError,
+        },
+        /// A delegate deregistered from every subnet has passed its grace period and no longer
+        /// accepts nominations or take changes
+        DelegateInactive {
+            /// the inactive delegate hotkey
+            hotkey: T::AccountId,
+        },
+        /// A subnet's emission is burned until reenable_block, u64::MAX for indefinitely
+        SubnetEmissionDisabled {
+            /// the sanctioned subnet
+            netuid: u16,
+            /// the block the emission resumes at
+            reenable_block: u64,
+        },
+        /// A subnet's emission is no longer burned
+        SubnetEmissionEnabled {
+            /// the subnet
+            netuid: u16,
+        },
+        /// A validator published auxiliary scores of one kind for the current tempo
+        AuxScoresSet {
+            /// the subnet
+            netuid: u16,
+            /// the validator's uid
+            uid: u16,
+            /// the kind of score
+            kind: u16,
+        },
+        /// A subnet's due epoch did not fit in the block step budget and moved to the next block
+        EpochDeferred {
+            /// the subnet
+            netuid: u16,
+        },
+        /// A weights set was applied except for the listed uids, whose weights are either not
+        /// stored or not counted by the epoch
+        WeightsFiltered {
+            /// the subnet
+            netuid: u16,
+            /// the uid of the neuron setting weights
+            uid: u16,
+            /// the uids whose weights were filtered, with the reason for each
+            filtered_uids: Vec<(u16, WeightsFilter)>,
+        },
+        /// A coldkey appointed a manager allowed to change the take of its hotkey
+        DelegateManagerSet {
+            /// the owning coldkey
+            coldkey: T::AccountId,
+            /// the delegate hotkey
+            hotkey: T::AccountId,
+            /// the appointed manager
+            manager: T::AccountId,
+        },
+        /// A coldkey revoked the manager of its hotkey
+        DelegateManagerRemoved {
+            /// the owning coldkey
+            coldkey: T::AccountId,
+            /// the delegate hotkey
+            hotkey: T::AccountId,
+            /// the revoked manager
+            manager: T::AccountId,
+        },
+        /// A coldkey opened or closed its hotkey to stake from other coldkeys
+        DelegateAvailabilitySet {
+            /// the owning coldkey
+            coldkey: T::AccountId,
+            /// the hotkey
+            hotkey: T::AccountId,
+            /// whether other coldkeys may add stake
+            available: bool,
+        },
+        /// Whether validator permits only go to active neurons is set for a subnet
+        ActivePermitsOnlySet(u16, bool),
+        /// A coldkey set the target allocation of its stake
+        StakePortfolioSet {
+            /// the coldkey
+            coldkey: T::AccountId,
+            /// the hotkeys and their relative weights, empty when cleared
+            targets: Vec<(T::AccountId, u16)>,
+        },
+        /// A coldkey moved its stake toward its portfolio targets
+        StakePortfolioRebalanced {
+            /// the coldkey
+            coldkey: T::AccountId,
+            /// the stake moved between hotkeys
+            moved: u64,
+        },
+        /// The maximum number of weights a neuron may set is set for a subnet
+        MaxAllowedWeightsSet(u16, u16),
+        /// A hotkey's emission is burned until expiry_block
+        HotkeySanctioned {
+            /// the sanctioned hotkey
+            hotkey: T::AccountId,
+            /// the block the sanction expires at
+            expiry_block: u64,
+        },
+        /// A hotkey's sanction was lifted or expired
+        HotkeySanctionLifted {
+            /// the hotkey
+            hotkey: T::AccountId,
+        },
+        /// The emission a sanctioned hotkey earned in an epoch was burned
+        SanctionedHotkeyEmissionBurned {
+            /// the sanctioned hotkey
+            hotkey: T::AccountId,
+            /// the subnet the emission was earned on
+            netuid: u16,
+            /// the burned amount, mining and validating
+            amount: u64,
+        },
+        /// A hyperparameter bundle is staged for a subnet's next tempo boundary
+        HyperparamBundleScheduled(u16),
+        /// A subnet's staged hyperparameter bundle was applied
+        HyperparamBundleApplied(u16),
+        /// An increase of a delegate take has been scheduled
+        TakeIncreaseScheduled {
+            /// the coldkey owning the hotkey
+            coldkey: T::AccountId,
+            /// the delegate hotkey
+            hotkey: T::AccountId,
+            /// the increased take
+            take: u16,
+            /// the block the take is applied at
+            execution_block: BlockNumberFor<T>,
+        },
+        /// A scheduled operation has been cancelled
+        ScheduledOperationCancelled {
+            /// the coldkey that scheduled the operation
+            coldkey: T::AccountId,
+            /// the id of the operation
+            id: u64,
+        },
+        /// A scheduled operation failed at its execution block and has been dropped
+        ScheduledOperationFailed {
+            /// the coldkey that scheduled the operation
+            coldkey: T::AccountId,
+            /// the id of the operation
+            id: u64,
+            /// the error raised by the operation
+            error: DispatchError,
+        },
+        /// A delegate opted in or out of being a public good delegate.
+        PublicGoodDelegateSet {
+            /// the owning coldkey
+            coldkey: T::AccountId,
+            /// the delegate hotkey
+            hotkey: T::AccountId,
+            /// block from which the take may increase, u64::MAX while opted in
+            release_block: u64,
+        },
+        /// The bootstrap grant parameters for new subnets were set.
+        BootstrapGrantParamsSet {
+            /// share of the owner cut escrowed, normalized to u16::MAX
+            share: u16,
+            /// tempos the owner cut is escrowed for
+            tempos: u16,
+            /// active validators needed on every grant tempo
+            min_validators: u16,
+            /// active miners needed on every grant tempo
+            min_miners: u16,
+        },
+        /// The bootstrap grant of a subnet was released to its controller.
+        BootstrapGrantReleased {
+            /// the subnet
+            netuid: u16,
+            /// the coldkey paid
+            coldkey: T::AccountId,
+            /// the amount released
+            amount: u64,
+        },
+        /// The bootstrap grant of a subnet that fell below the minimum activity was burned.
+        BootstrapGrantClawedBack {
+            /// the subnet
+            netuid: u16,
+            /// the amount burned
+            amount: u64,
+        },
+        /// The degenerate weight flagging of a subnet was configured.
+        WeightFlaggingSet {
+            /// the subnet
+            netuid: u16,
+            /// consecutive tempos of degenerate weights that flag a uid, 0 when disabled
+            tempos: u16,
+            /// uids sharing the same weights for them to be flagged
+            duplicate_keys: u16,
+        },
+        /// A uid was flagged for setting degenerate weights for consecutive tempos.
+        UidFlagged {
+            /// the subnet
+            netuid: u16,
+            /// the flagged uid
+            uid: u16,
+            /// the hotkey of the uid
+            hotkey: T::AccountId,
+            /// how its weights were degenerate
+            reason: DegenerateWeights,
+        },
+        /// A flagged uid set weights that are no longer degenerate.
+        UidUnflagged {
+            /// the subnet
+            netuid: u16,
+            /// the uid
+            uid: u16,
+        },
     }
 }
@@ -110,11 +110,12 @@ mod genesis {
                         coldkey.clone(),
                         TotalColdkeyStake::<T>::get(coldkey).saturating_add(*stake),
                     );
+                    Pallet::<T>::update_stake_provider(coldkey);
 
                     // Update total issuance value
                     TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(*stake));
 
-                    Stake::<T>::insert(hotkey.clone(), coldkey.clone(), stake);
+                    Pallet::<T>::insert_stake_entry(hotkey, coldkey, *stake);
 
                     next_uid = next_uid.saturating_add(1);
                 }
@@ -158,6 +159,11 @@ mod genesis {
 
             // Set target registrations for validators as 1 per block.
             TargetRegistrationsPerInterval::<T>::insert(root_netuid, 1);
+
+            // Create the configured subnets with their neurons and stake.
+            for subnet in self.subnets.iter() {
+                Pallet::<T>::init_genesis_subnet(subnet);
+            }
         }
     }
 }
@@ -15,23 +15,26 @@ mod hooks {
         // 	* 'n': (BlockNumberFor<T>):
         // 		- The number of the block we are initializing.
         fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
-            let block_step_result = Self::block_step();
-            match block_step_result {
-                Ok(_) => {
-                    // --- If the block step was successful, return the weight.
-                    log::debug!("Successfully ran block step.");
-                    Weight::from_parts(110_634_229_000_u64, 0)
-                        .saturating_add(T::DbWeight::get().reads(8304_u64))
-                        .saturating_add(T::DbWeight::get().writes(110_u64))
-                }
-                Err(e) => {
-                    // --- If the block step was unsuccessful, return the weight anyway.
-                    log::error!("Error while stepping block: {:?}", e);
-                    Weight::from_parts(110_634_229_000_u64, 0)
-                        .saturating_add(T::DbWeight::get().reads(8304_u64))
-                        .saturating_add(T::DbWeight::get().writes(110_u64))
-                }
-            }
+            // --- The block step meters itself against BlockStepWeightLimit.
+            let used_weight: Weight = Self::block_step();
+            log::debug!("Successfully ran block step using {:?}.", used_weight);
+            used_weight
+        }
+
+        // ---- Called with the weight left over at the end of the block.
+        //
+        // # Args:
+        // 	* 'remaining_weight': (Weight):
+        // 		- The weight still available in this block.
+        fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut used_weight: Weight =
+                Self::continue_clearing_small_nominations(remaining_weight);
+            used_weight.saturating_accrue(Self::continue_unstaking_hotkeys(
+                remaining_weight.saturating_sub(used_weight),
+            ));
+            used_weight.saturating_add(Self::clean_orphaned_storage(
+                remaining_weight.saturating_sub(used_weight),
+            ))
         }
 
         fn on_runtime_upgrade() -> frame_support::weights::Weight {
@@ -70,7 +73,21 @@ mod hooks {
                 // Storage version v8 -> v9
                 .saturating_add(migrations::migrate_fix_total_coldkey_stake::migrate_fix_total_coldkey_stake::<T>())
                 // Migrate Delegate Ids on chain
-                .saturating_add(migrations::migrate_chain_identity::migrate_set_hotkey_identities::<T>());
+                .saturating_add(migrations::migrate_chain_identity::migrate_set_hotkey_identities::<T>())
+                // Drop zero weights and sort weights rows by uid.
+                .saturating_add(migrations::migrate_compress_weights::migrate_compress_weights::<T>())
+                // Add the capability bitfield to stored axons.
+                .saturating_add(migrations::migrate_axon_capabilities::migrate_axon_capabilities::<T>())
+                // Clamp delegate takes down to the max delegate take.
+                .saturating_add(migrations::migrate_clamp_delegate_takes::migrate_clamp_delegate_takes::<T>())
+                // Keep the accounts of existing stakers alive below the existential deposit.
+                .saturating_add(migrations::migrate_stake_provider_refs::migrate_stake_provider_refs::<T>())
+                // Start the inactivity grace period of delegates registered on no subnet.
+                .saturating_add(migrations::migrate_seed_delegate_inactivity::migrate_seed_delegate_inactivity::<T>())
+                // Count the stake entries under every hotkey for the emission drain estimates.
+                .saturating_add(migrations::migrate_count_hotkey_nominators::migrate_count_hotkey_nominators::<T>())
+                // Store the tempo index with pending weight commits.
+                .saturating_add(migrations::migrate_commit_tempo_index::migrate_commit_tempo_index::<T>());
             weight
         }
 
@@ -80,4 +97,36 @@ mod hooks {
             Ok(())
         }
     }
+
+    // =========================
+    // ==== Unsigned Calls =====
+    // =========================
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        // ---- Accepts set_weights_unsigned carrying a valid hotkey signature and nonce.
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            match call {
+                Call::set_weights_unsigned {
+                    hotkey,
+                    netuid,
+                    dests,
+                    weights,
+                    version_key,
+                    nonce,
+                    signature,
+                } => Self::validate_unsigned_set_weights(
+                    hotkey,
+                    *netuid,
+                    dests,
+                    weights,
+                    *version_key,
+                    *nonce,
+                    signature,
+                ),
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
+    }
 }
@@ -0,0 +1,92 @@
+use super::*;
+use alloc::string::String;
+use frame_support::{pallet_prelude::*, traits::Get, weights::Weight};
+
+/// Module containing the axon format without the capability bitfield
+pub mod deprecated_axon_info_format {
+    use super::*;
+
+    /// Axon information as stored before the capability bitfield was added.
+    #[crate::freeze_struct("fc8ee957df9b859")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct OldAxonInfo {
+        ///  Axon serving block.
+        pub block: u64,
+        ///  Axon version
+        pub version: u32,
+        ///  Axon u128 encoded ip address of type v6 or v4.
+        pub ip: u128,
+        ///  Axon u16 encoded port.
+        pub port: u16,
+        ///  Axon ip type, 4 for ipv4 and 6 for ipv6.
+        pub ip_type: u8,
+        ///  Axon protocol. TCP, UDP, other.
+        pub protocol: u8,
+        ///  Axon proto placeholder 1.
+        pub placeholder1: u8,
+        ///  Axon proto placeholder 2.
+        pub placeholder2: u8,
+    }
+}
+
+/// Rewrites every stored axon into the current format with an empty capability bitfield.
+///
+/// # Returns
+/// The weight of the migration process.
+pub fn do_migrate_axon_capabilities<T: Config>() -> Weight {
+    let mut migrated: u64 = 0;
+
+    Axons::<T>::translate::<deprecated_axon_info_format::OldAxonInfo, _>(|_, _, old| {
+        migrated = migrated.saturating_add(1);
+        Some(AxonInfo {
+            block: old.block,
+            version: old.version,
+            ip: old.ip,
+            port: old.port,
+            ip_type: old.ip_type,
+            protocol: old.protocol,
+            placeholder1: old.placeholder1,
+            placeholder2: old.placeholder2,
+            capabilities: 0,
+        })
+    });
+
+    T::DbWeight::get().reads_writes(migrated, migrated)
+}
+
+// Public migrate function to be called by Lib.rs on upgrade.
+pub fn migrate_axon_capabilities<T: Config>() -> Weight {
+    let migration_name = b"migrate_axon_capabilities".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Run the migration
+    weight = weight.saturating_add(do_migrate_axon_capabilities::<T>());
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{}' completed.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Return the migration weight.
+    weight
+}
@@ -0,0 +1,68 @@
+use super::*;
+use alloc::string::String;
+use frame_support::{traits::Get, weights::Weight};
+
+/// Clamps every stored delegate take down to MaxDelegateTake.
+///
+/// Takes written before every path validated them may sit above the max delegate take. Takes
+/// below MinDelegateTake are kept, raising them would raise the nominators' costs.
+///
+/// # Returns
+/// The weight of the migration process.
+pub fn do_migrate_clamp_delegate_takes<T: Config>() -> Weight {
+    let max_take: u16 = MaxDelegateTake::<T>::get();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    Delegates::<T>::translate::<u16, _>(|hotkey, take| {
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+        let clamped: u16 = take.min(max_take);
+        if clamped != take {
+            log::info!(
+                "Clamped take of delegate {:?} from {:?} to {:?}",
+                hotkey,
+                take,
+                clamped
+            );
+        }
+        Some(clamped)
+    });
+
+    weight
+}
+
+// Public migrate function to be called by Lib.rs on upgrade.
+pub fn migrate_clamp_delegate_takes<T: Config>() -> Weight {
+    let migration_name = b"migrate_clamp_delegate_takes".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Run the migration
+    weight = weight.saturating_add(do_migrate_clamp_delegate_takes::<T>());
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{}' completed.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Return the migration weight.
+    weight
+}
@@ -0,0 +1,58 @@
+use super::*;
+use alloc::string::String;
+use frame_support::{traits::Get, weights::Weight};
+use sp_core::H256;
+
+/// Rewrites every pending weight commit into the format carrying its tempo index. Commits made
+/// before the upgrade are stored without one, so they are still revealed against the hash they
+/// were committed with.
+///
+/// # Returns
+/// The weight of the migration process.
+pub fn do_migrate_commit_tempo_index<T: Config>() -> Weight {
+    let mut migrated: u64 = 0;
+
+    WeightCommits::<T>::translate::<(H256, u64), _>(|_, _, (commit_hash, commit_block)| {
+        migrated = migrated.saturating_add(1);
+        Some((commit_hash, commit_block, None))
+    });
+
+    T::DbWeight::get().reads_writes(migrated, migrated)
+}
+
+// Public migrate function to be called by Lib.rs on upgrade.
+pub fn migrate_commit_tempo_index<T: Config>() -> Weight {
+    let migration_name = b"migrate_commit_tempo_index".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Run the migration
+    weight = weight.saturating_add(do_migrate_commit_tempo_index::<T>());
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{}' completed.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Return the migration weight.
+    weight
+}
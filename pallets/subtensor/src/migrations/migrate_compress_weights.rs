@@ -0,0 +1,72 @@
+use super::*;
+use alloc::string::String;
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::vec::Vec;
+
+/// Rewrites every stored weights row into the compressed format: zero-valued pairs are
+/// dropped and the remaining (uid, weight) pairs are sorted by uid.
+///
+/// Only rows that actually change are written back.
+///
+/// # Returns
+/// The weight of the migration process.
+pub fn do_migrate_compress_weights<T: Config>() -> Weight {
+    let mut weight = Weight::zero();
+
+    // Collect first so the map is not mutated while it is being iterated.
+    let rows: Vec<(u16, u16, WeightsRowOf<T>)> = Weights::<T>::iter().collect();
+    weight = weight.saturating_add(T::DbWeight::get().reads(rows.len() as u64));
+
+    for (netuid, uid, row) in rows {
+        let row: Vec<(u16, u16)> = row.into_inner();
+        let compressed: Vec<(u16, u16)> = Pallet::<T>::compress_weights_row(row.clone());
+        if compressed == row {
+            continue;
+        }
+        if compressed.is_empty() {
+            Weights::<T>::remove(netuid, uid);
+        } else {
+            // Compression only drops entries, so the row stays within its bound.
+            Weights::<T>::insert(netuid, uid, WeightsRowOf::<T>::truncate_from(compressed));
+        }
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+    weight
+}
+
+// Public migrate function to be called by Lib.rs on upgrade.
+pub fn migrate_compress_weights<T: Config>() -> Weight {
+    let migration_name = b"migrate_compress_weights".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Run the migration
+    weight = weight.saturating_add(do_migrate_compress_weights::<T>());
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{}' completed.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Return the migration weight.
+    weight
+}
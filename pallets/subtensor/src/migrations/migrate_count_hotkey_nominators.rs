@@ -0,0 +1,65 @@
+use super::*;
+use alloc::string::String;
+use frame_support::{traits::Get, weights::Weight};
+
+/// Counts the Stake entries under every hotkey into HotkeyNominatorCount.
+///
+/// The block step estimates the weight of a hotkey emission drain from this count, so it must
+/// cover the stake written before the count was maintained.
+///
+/// # Returns
+/// The weight of the migration process.
+pub fn do_migrate_count_hotkey_nominators<T: Config>() -> Weight {
+    let mut weight = T::DbWeight::get().reads(1);
+
+    let cleared = HotkeyNominatorCount::<T>::clear(u32::MAX, None);
+    weight = weight.saturating_add(T::DbWeight::get().writes(cleared.backend as u64));
+
+    let mut entries: u64 = 0;
+    for (hotkey, _) in Stake::<T>::iter_keys() {
+        HotkeyNominatorCount::<T>::mutate(&hotkey, |count| *count = count.saturating_add(1));
+        entries = entries.saturating_add(1);
+    }
+    weight =
+        weight.saturating_add(T::DbWeight::get().reads_writes(entries.saturating_mul(2), entries));
+    log::info!("Counted {:?} stake entries by hotkey", entries);
+
+    weight
+}
+
+// Public migrate function to be called by Lib.rs on upgrade.
+pub fn migrate_count_hotkey_nominators<T: Config>() -> Weight {
+    let migration_name = b"migrate_count_hotkey_nominators".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Run the migration
+    weight = weight.saturating_add(do_migrate_count_hotkey_nominators::<T>());
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{}' completed.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Return the migration weight.
+    weight
+}
@@ -34,7 +34,7 @@ fn migrate_pending_emissions_including_null_stake<T: Config>(
     let null_stake = Stake::<T>::get(old_hotkey, null_account);
     weight.saturating_accrue(T::DbWeight::get().reads(1));
     // Remove
-    Stake::<T>::remove(old_hotkey, null_account);
+    Pallet::<T>::remove_stake_entry(old_hotkey, null_account);
     weight.saturating_accrue(T::DbWeight::get().writes(1));
 
     let new_total_coldkey_stake =
@@ -55,7 +55,7 @@ fn migrate_pending_emissions_including_null_stake<T: Config>(
     weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 
     // Insert the stake from the null account to the MIGRATION account under the OLD hotkey
-    Stake::<T>::insert(old_hotkey, migration_account, null_stake);
+    Pallet::<T>::insert_stake_entry(old_hotkey, migration_account, null_stake);
     TotalColdkeyStake::<T>::insert(
         migration_account,
         TotalColdkeyStake::<T>::get(migration_account).saturating_add(null_stake),
@@ -0,0 +1,70 @@
+use super::*;
+use alloc::string::String;
+use frame_support::{traits::Get, weights::Weight};
+
+/// Starts the inactivity grace period of every delegate that is registered on no subnet.
+///
+/// Delegates that lost their last registration before the grace period was tracked have no
+/// DelegateDeregisteredAt entry and would be treated as inactive immediately. They get the full
+/// grace period from the upgrade block instead.
+///
+/// # Returns
+/// The weight of the migration process.
+pub fn do_migrate_seed_delegate_inactivity<T: Config>() -> Weight {
+    let current_block: u64 = Pallet::<T>::get_current_block_as_u64();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    for hotkey in Delegates::<T>::iter_keys() {
+        weight = weight.saturating_add(T::DbWeight::get().reads(2));
+        if DelegateDeregisteredAt::<T>::contains_key(&hotkey)
+            || Pallet::<T>::is_hotkey_registered_on_any_network(&hotkey)
+        {
+            continue;
+        }
+        Pallet::<T>::note_hotkey_deregistered(&hotkey, current_block);
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+        log::info!(
+            "Started the inactivity grace period of delegate {:?}",
+            hotkey
+        );
+    }
+
+    weight
+}
+
+// Public migrate function to be called by Lib.rs on upgrade.
+pub fn migrate_seed_delegate_inactivity<T: Config>() -> Weight {
+    let migration_name = b"migrate_seed_delegate_inactivity".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Run the migration
+    weight = weight.saturating_add(do_migrate_seed_delegate_inactivity::<T>());
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{}' completed.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Return the migration weight.
+    weight
+}
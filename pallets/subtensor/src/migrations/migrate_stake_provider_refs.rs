@@ -0,0 +1,59 @@
+use super::*;
+use alloc::string::String;
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::vec::Vec;
+
+/// Gives every coldkey holding stake the provider reference that keeps its account alive.
+///
+/// Accounts that staked before KeepStakingAccountsAlive existed hold no such reference.
+///
+/// # Returns
+/// The weight of the migration process.
+pub fn do_migrate_stake_provider_refs<T: Config>() -> Weight {
+    let mut weight = T::DbWeight::get().reads(1);
+
+    let coldkeys: Vec<T::AccountId> = TotalColdkeyStake::<T>::iter_keys().collect();
+    for coldkey in coldkeys {
+        Pallet::<T>::update_stake_provider(&coldkey);
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 2));
+    }
+
+    weight
+}
+
+// Public migrate function to be called by Lib.rs on upgrade.
+pub fn migrate_stake_provider_refs<T: Config>() -> Weight {
+    let migration_name = b"migrate_stake_provider_refs".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            migration_name
+        );
+        return weight;
+    }
+
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Run the migration
+    weight = weight.saturating_add(do_migrate_stake_provider_refs::<T>());
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{}' completed.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Return the migration weight.
+    weight
+}
@@ -1,5 +1,10 @@
 use super::*;
+pub mod migrate_axon_capabilities;
 pub mod migrate_chain_identity;
+pub mod migrate_clamp_delegate_takes;
+pub mod migrate_commit_tempo_index;
+pub mod migrate_compress_weights;
+pub mod migrate_count_hotkey_nominators;
 pub mod migrate_create_root_network;
 pub mod migrate_delete_subnet_21;
 pub mod migrate_delete_subnet_3;
@@ -8,6 +13,8 @@ pub mod migrate_fix_total_coldkey_stake;
 pub mod migrate_init_total_issuance;
 pub mod migrate_populate_owned_hotkeys;
 pub mod migrate_populate_staking_hotkeys;
+pub mod migrate_seed_delegate_inactivity;
+pub mod migrate_stake_provider_refs;
 pub mod migrate_to_v1_separate_emission;
 pub mod migrate_to_v2_fixed_total_stake;
 pub mod migrate_total_issuance;
@@ -0,0 +1,73 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+/// Everything a coldkey holds or has pending, for wallet overview screens.
+#[freeze_struct("c15206a1c41578f0")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ColdkeyReport<T: Config> {
+    pub coldkey: T::AccountId,
+    pub balance: Compact<u64>,
+    pub total_stake: Compact<u64>,
+    /// Nonzero stake of the coldkey on each hotkey.
+    pub stakes: Vec<(T::AccountId, Compact<u64>)>,
+    pub owned_hotkeys: Vec<T::AccountId>,
+    pub owned_subnets: Vec<Compact<u16>>,
+    /// (hotkey, amount, unlock_block) of each stake lock of the coldkey.
+    pub stake_locks: Vec<(T::AccountId, Compact<u64>, Compact<u64>)>,
+    /// (hotkey, take, expiry_block) of each take change awaiting confirmation on an owned hotkey.
+    pub pending_take_changes: Vec<(T::AccountId, Compact<u16>, Compact<u64>)>,
+    pub coldkey_swap_scheduled: bool,
+    pub identity: Option<ChainIdentity>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the report of an encoded coldkey, or None if it does not decode.
+    pub fn get_coldkey_report(coldkey_account_vec: Vec<u8>) -> Option<ColdkeyReport<T>> {
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+
+        let staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(&coldkey);
+        let stakes: Vec<(T::AccountId, Compact<u64>)> = staking_hotkeys
+            .iter()
+            .map(|hotkey| (hotkey.clone(), Stake::<T>::get(hotkey, &coldkey)))
+            .filter(|(_, stake)| *stake > 0)
+            .map(|(hotkey, stake)| (hotkey, stake.into()))
+            .collect();
+        let stake_locks: Vec<(T::AccountId, Compact<u64>, Compact<u64>)> = staking_hotkeys
+            .iter()
+            .filter_map(|hotkey| {
+                StakeLocks::<T>::get(hotkey, &coldkey).map(|(amount, _, unlock_block)| {
+                    (hotkey.clone(), amount.into(), unlock_block.into())
+                })
+            })
+            .collect();
+
+        let owned_hotkeys: Vec<T::AccountId> = OwnedHotkeys::<T>::get(&coldkey);
+        let pending_take_changes: Vec<(T::AccountId, Compact<u16>, Compact<u64>)> = owned_hotkeys
+            .iter()
+            .filter_map(|hotkey| {
+                PendingTakeChanges::<T>::get(hotkey)
+                    .map(|(take, expiry_block)| (hotkey.clone(), take.into(), expiry_block.into()))
+            })
+            .collect();
+        let owned_subnets: Vec<Compact<u16>> = SubnetOwner::<T>::iter()
+            .filter(|(_, owner)| *owner == coldkey)
+            .map(|(netuid, _)| netuid.into())
+            .collect();
+
+        Some(ColdkeyReport {
+            balance: Self::get_coldkey_balance(&coldkey).into(),
+            total_stake: Self::get_total_stake_for_coldkey(&coldkey).into(),
+            stakes,
+            owned_hotkeys,
+            owned_subnets,
+            stake_locks,
+            pending_take_changes,
+            coldkey_swap_scheduled: ColdkeySwapScheduled::<T>::contains_key(&coldkey),
+            identity: Identities::<T>::get(&coldkey),
+            coldkey,
+        })
+    }
+}
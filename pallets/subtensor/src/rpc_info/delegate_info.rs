@@ -1,11 +1,21 @@
 use super::*;
 use frame_support::pallet_prelude::{Decode, Encode};
 use frame_support::storage::IterableStorageMap;
+use frame_support::traits::Get;
 use frame_support::IterableStorageDoubleMap;
 use substrate_fixed::types::U64F64;
 extern crate alloc;
 use codec::Compact;
 use sp_core::hexdisplay::AsBytesRef;
+use sp_std::cmp::Ordering;
+
+/// Most nominators get_nominators returns in one page.
+pub const MAX_NOMINATORS_PAGE: u32 = 256;
+
+/// Orders nominators by stake, largest first, then by coldkey.
+fn by_rank<AccountId: Ord>(a: &(AccountId, u64), b: &(AccountId, u64)) -> Ordering {
+    b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0))
+}
 
 #[freeze_struct("5752e4c650a83e0d")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
@@ -38,7 +48,6 @@ impl<T: Config> Pallet<T> {
 
         let registrations = Self::get_registered_networks_for_hotkey(&delegate.clone());
         let mut validator_permits = Vec::<Compact<u16>>::new();
-        let mut emissions_per_day: U64F64 = U64F64::from_num(0);
 
         for netuid in registrations.iter() {
             if let Ok(uid) = Self::get_uid_for_net_and_hotkey(*netuid, &delegate.clone()) {
@@ -46,29 +55,14 @@ impl<T: Config> Pallet<T> {
                 if validator_permit {
                     validator_permits.push((*netuid).into());
                 }
-
-                let emission: U64F64 = Self::get_emission_for_uid(*netuid, uid).into();
-                let tempo: U64F64 = Self::get_tempo(*netuid).into();
-                if tempo > U64F64::from_num(0) {
-                    let epochs_per_day: U64F64 = U64F64::from_num(7200).saturating_div(tempo);
-                    emissions_per_day =
-                        emissions_per_day.saturating_add(emission.saturating_mul(epochs_per_day));
-                }
             }
         }
 
         let owner = Self::get_owning_coldkey_for_hotkey(&delegate.clone());
         let take: Compact<u16> = <Delegates<T>>::get(delegate.clone()).into();
 
-        let total_stake: U64F64 = Self::get_total_stake_for_hotkey(&delegate.clone()).into();
-
-        let return_per_1000: U64F64 = if total_stake > U64F64::from_num(0) {
-            emissions_per_day
-                .saturating_mul(U64F64::from_num(0.82))
-                .saturating_div(total_stake.saturating_div(U64F64::from_num(1000)))
-        } else {
-            U64F64::from_num(0)
-        };
+        // Returns are served from the statistics of the last refresh pass.
+        let stats: DelegateStats = Self::get_delegate_stats_for_hotkey(&delegate);
 
         DelegateInfo {
             delegate_ss58: delegate.clone(),
@@ -77,8 +71,8 @@ impl<T: Config> Pallet<T> {
             owner_ss58: owner.clone(),
             registrations: registrations.iter().map(|x| x.into()).collect(),
             validator_permits,
-            return_per_1000: U64F64::to_num::<u64>(return_per_1000).into(),
-            total_daily_return: U64F64::to_num::<u64>(emissions_per_day).into(),
+            return_per_1000: stats.return_per_1000.into(),
+            total_daily_return: stats.total_daily_return.into(),
         }
     }
 
@@ -103,6 +97,10 @@ impl<T: Config> Pallet<T> {
     pub fn get_delegates() -> Vec<DelegateInfo<T>> {
         let mut delegates = Vec::<DelegateInfo<T>>::new();
         for delegate in <Delegates<T> as IterableStorageMap<T::AccountId, u16>>::iter_keys() {
+            // Inactive delegates are no longer advertised.
+            if Self::is_delegate_inactive(&delegate) {
+                continue;
+            }
             let delegate_info = Self::get_delegate_by_existing_account(delegate.clone());
             delegates.push(delegate_info);
         }
@@ -132,6 +130,128 @@ impl<T: Config> Pallet<T> {
         delegates
     }
 
+    /// Returns a page of the delegate's nominators as (coldkey, stake) pairs, sorted by stake
+    /// from largest to smallest (ties by coldkey), skipping the first `start` and returning at
+    /// most `limit`, itself capped at MAX_NOMINATORS_PAGE.
+    ///
+    /// Only the page is sorted: the nominators ranked before and after it are partitioned off
+    /// in linear time, so paging through a large delegate does not sort its prefix every call.
+    pub fn get_nominators(
+        delegate_account_vec: Vec<u8>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<(T::AccountId, Compact<u64>)> {
+        if delegate_account_vec.len() != 32 {
+            return Vec::new(); // Invalid delegate
+        }
+
+        let Ok(delegate) = T::AccountId::decode(&mut delegate_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        let mut nominators: Vec<(T::AccountId, u64)> = Stake::<T>::iter_prefix(&delegate)
+            .filter(|(_, stake)| *stake > 0)
+            .collect();
+        let start: usize = start as usize;
+        if start >= nominators.len() || limit == 0 {
+            return Vec::new();
+        }
+        let end: usize = start
+            .saturating_add(limit.min(MAX_NOMINATORS_PAGE) as usize)
+            .min(nominators.len());
+
+        // Keep the nominators ranked before `end`, then drop those ranked before `start`.
+        if end < nominators.len() {
+            nominators.select_nth_unstable_by(end, by_rank);
+            nominators.truncate(end);
+        }
+        if start > 0 {
+            nominators.select_nth_unstable_by(start, by_rank);
+            nominators.drain(..start);
+        }
+        nominators.sort_by(by_rank);
+
+        nominators
+            .into_iter()
+            .map(|(nominator, stake)| (nominator, stake.into()))
+            .collect()
+    }
+
+    /// Returns the yearly return per 1000 staked of a delegate's nominators, measured between
+    /// the latest stake checkpoint and the one `window` checkpoints before it (or the oldest).
+    /// Returns zero until the delegate has two checkpoints.
+    pub fn get_delegate_apr(delegate_account_vec: Vec<u8>, window: u32) -> u64 {
+        if delegate_account_vec.len() != 32 {
+            return 0; // Invalid delegate
+        }
+
+        let Ok(delegate) = T::AccountId::decode(&mut delegate_account_vec.as_bytes_ref()) else {
+            return 0;
+        };
+
+        let checkpoints = StakeCheckpoints::<T>::get(&delegate);
+        let Some(&(end_block, end_stake, _, _)) = checkpoints.last() else {
+            return 0;
+        };
+        let start_index: usize = checkpoints
+            .len()
+            .saturating_sub(1)
+            .saturating_sub(window as usize);
+        let Some(&(start_block, start_stake, _, _)) = checkpoints.get(start_index) else {
+            return 0;
+        };
+
+        let blocks: U64F64 = U64F64::from_num(end_block.saturating_sub(start_block));
+        let average_stake: U64F64 = U64F64::from_num(start_stake)
+            .saturating_add(U64F64::from_num(end_stake))
+            .saturating_div(U64F64::from_num(2));
+        if blocks == U64F64::from_num(0) || average_stake == U64F64::from_num(0) {
+            return 0;
+        }
+
+        // Nominators receive the emission net of the delegate take, which is the take recorded
+        // at the start of each interval between two checkpoints.
+        let emission: U64F64 = checkpoints
+            .iter()
+            .skip(start_index)
+            .zip(checkpoints.iter().skip(start_index.saturating_add(1)))
+            .fold(
+                U64F64::from_num(0),
+                |total, (&(_, _, from_emission, take), &(_, _, to_emission, _))| {
+                    let nominator_share: U64F64 = U64F64::from_num(1).saturating_sub(
+                        U64F64::from_num(take).saturating_div(U64F64::from_num(u16::MAX)),
+                    );
+                    total.saturating_add(
+                        U64F64::from_num(to_emission.saturating_sub(from_emission))
+                            .saturating_mul(nominator_share),
+                    )
+                },
+            );
+        let blocks_per_year: U64F64 = U64F64::from_num(T::BlocksPerYear::get());
+
+        emission
+            .saturating_mul(blocks_per_year)
+            .saturating_div(blocks)
+            .saturating_div(average_stake.saturating_div(U64F64::from_num(1000)))
+            .to_num::<u64>()
+    }
+
+    /// Returns the aggregate statistics of a delegate as of the last refresh, or None if the
+    /// account is not a delegate.
+    pub fn get_delegate_stats(delegate_account_vec: Vec<u8>) -> Option<DelegateStats> {
+        if delegate_account_vec.len() != 32 {
+            return None;
+        }
+
+        let delegate: AccountIdOf<T> =
+            T::AccountId::decode(&mut delegate_account_vec.as_bytes_ref()).ok()?;
+        if !Delegates::<T>::contains_key(&delegate) {
+            return None;
+        }
+
+        Some(Self::get_delegate_stats_for_hotkey(&delegate))
+    }
+
     pub fn get_total_delegated_stake(coldkey: &T::AccountId) -> u64 {
         let mut total_delegated = 0u64;
 
@@ -0,0 +1,78 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use sp_core::hexdisplay::AsBytesRef;
+extern crate alloc;
+use codec::Compact;
+
+/// Per-uid results of an epoch run against hypothetical state, indexed by uid.
+#[freeze_struct("92ae06afd9b1554d")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct EpochSimulation {
+    pub netuid: Compact<u16>,
+    pub block: Compact<u64>,
+    /// Stake of each uid as seen by the epoch, overrides applied.
+    pub stake: Vec<Compact<u64>>,
+    pub rank: Vec<Compact<u16>>,
+    pub trust: Vec<Compact<u16>>,
+    pub consensus: Vec<Compact<u16>>,
+    pub incentive: Vec<Compact<u16>>,
+    pub dividends: Vec<Compact<u16>>,
+    pub validator_trust: Vec<Compact<u16>>,
+    pub validator_permit: Vec<bool>,
+    pub emission: Vec<Compact<u64>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Runs the epoch of a subnet on live state with the total stake of some hotkeys replaced,
+    /// then rolls back every state change, so subnet designers can study how consensus reacts
+    /// to stake without forking the chain. To ask what a hotkey would get with more stake,
+    /// override it with its current total plus the extra amount.
+    ///
+    /// `stake_overrides_vec` is an encoded `Vec<(AccountId, u64)>` of hotkeys and their new
+    /// total stake. Returns None if the subnet does not exist, is the root network, or the
+    /// overrides do not decode.
+    pub fn simulate_epoch(
+        netuid: u16,
+        rao_emission: u64,
+        stake_overrides_vec: Vec<u8>,
+    ) -> Option<EpochSimulation> {
+        if netuid == Self::get_root_netuid() || !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+        let stake_overrides: Vec<(T::AccountId, u64)> =
+            Decode::decode(&mut stake_overrides_vec.as_bytes_ref()).ok()?;
+
+        with_transaction(|| {
+            for (hotkey, stake) in stake_overrides.iter() {
+                TotalHotkeyStake::<T>::insert(hotkey, stake);
+            }
+            let stake: Vec<Compact<u64>> = (0..Self::get_subnetwork_n(netuid))
+                .map(|uid| Keys::<T>::get(netuid, uid))
+                .map(|hotkey| Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid).into())
+                .collect();
+
+            Self::epoch(netuid, rao_emission);
+
+            let u16_compact = |v: Vec<u16>| v.into_iter().map(Compact).collect::<Vec<_>>();
+            let simulation = EpochSimulation {
+                netuid: netuid.into(),
+                block: Self::get_current_block_as_u64().into(),
+                stake,
+                rank: u16_compact(Self::get_rank(netuid)),
+                trust: u16_compact(Self::get_trust(netuid)),
+                consensus: u16_compact(Self::get_consensus(netuid)),
+                incentive: u16_compact(Self::get_incentive(netuid)),
+                dividends: u16_compact(Self::get_dividends(netuid)),
+                validator_trust: u16_compact(Self::get_validator_trust(netuid)),
+                validator_permit: Self::get_validator_permit(netuid),
+                emission: Self::get_emission(netuid)
+                    .into_iter()
+                    .map(Compact)
+                    .collect(),
+            };
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(simulation))
+        })
+        .ok()
+    }
+}
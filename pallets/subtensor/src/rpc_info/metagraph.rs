@@ -0,0 +1,119 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+/// Bitmask flags selecting which per-uid vectors are returned by `get_metagraph_fields`.
+pub mod metagraph_fields {
+    pub const HOTKEYS: u32 = 0x0001;
+    pub const COLDKEYS: u32 = 0x0002;
+    pub const ACTIVE: u32 = 0x0004;
+    pub const STAKE: u32 = 0x0008;
+    pub const RANK: u32 = 0x0010;
+    pub const TRUST: u32 = 0x0020;
+    pub const CONSENSUS: u32 = 0x0040;
+    pub const INCENTIVE: u32 = 0x0080;
+    pub const DIVIDENDS: u32 = 0x0100;
+    pub const EMISSION: u32 = 0x0200;
+    pub const VALIDATOR_TRUST: u32 = 0x0400;
+    pub const VALIDATOR_PERMIT: u32 = 0x0800;
+    pub const LAST_UPDATE: u32 = 0x1000;
+    pub const PRUNING_SCORE: u32 = 0x2000;
+    pub const AXONS: u32 = 0x4000;
+    pub const ALL: u32 = 0x7FFF;
+}
+
+#[freeze_struct("3fdb680e45e366f6")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct MetagraphFields<T: Config> {
+    pub netuid: Compact<u16>,
+    pub n: Compact<u16>,
+    pub block: Compact<u64>,
+    // Each vector is None when its bit is not set in the requested mask.
+    pub hotkeys: Option<Vec<T::AccountId>>,
+    pub coldkeys: Option<Vec<T::AccountId>>,
+    pub active: Option<Vec<bool>>,
+    pub stake: Option<Vec<Compact<u64>>>,
+    pub rank: Option<Vec<Compact<u16>>>,
+    pub trust: Option<Vec<Compact<u16>>>,
+    pub consensus: Option<Vec<Compact<u16>>>,
+    pub incentive: Option<Vec<Compact<u16>>>,
+    pub dividends: Option<Vec<Compact<u16>>>,
+    pub emission: Option<Vec<Compact<u64>>>,
+    pub validator_trust: Option<Vec<Compact<u16>>>,
+    pub validator_permit: Option<Vec<bool>>,
+    pub last_update: Option<Vec<Compact<u64>>>,
+    pub pruning_score: Option<Vec<Compact<u16>>>,
+    pub axons: Option<Vec<AxonInfo>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns only the metagraph vectors selected by `fields` (see `metagraph_fields`),
+    /// or None if the subnet does not exist.
+    pub fn get_metagraph_fields(netuid: u16, fields: u32) -> Option<MetagraphFields<T>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let n = Self::get_subnetwork_n(netuid);
+        let wants = |flag: u32| (fields & flag) != 0;
+
+        let hotkeys: Vec<T::AccountId> = if wants(
+            metagraph_fields::HOTKEYS
+                | metagraph_fields::COLDKEYS
+                | metagraph_fields::STAKE
+                | metagraph_fields::AXONS,
+        ) {
+            (0..n).map(|uid| Keys::<T>::get(netuid, uid)).collect()
+        } else {
+            Vec::new()
+        };
+
+        let u16_compact = |v: Vec<u16>| v.into_iter().map(Compact).collect::<Vec<Compact<u16>>>();
+        let u64_compact = |v: Vec<u64>| v.into_iter().map(Compact).collect::<Vec<Compact<u64>>>();
+
+        Some(MetagraphFields {
+            netuid: netuid.into(),
+            n: n.into(),
+            block: Self::get_current_block_as_u64().into(),
+            coldkeys: wants(metagraph_fields::COLDKEYS).then(|| {
+                hotkeys
+                    .iter()
+                    .map(|hotkey| Owner::<T>::get(hotkey))
+                    .collect()
+            }),
+            stake: wants(metagraph_fields::STAKE).then(|| {
+                hotkeys
+                    .iter()
+                    .map(|hotkey| Self::get_stake_for_hotkey_on_subnet(hotkey, netuid).into())
+                    .collect()
+            }),
+            axons: wants(metagraph_fields::AXONS).then(|| {
+                hotkeys
+                    .iter()
+                    .map(|hotkey| Self::get_visible_axon_info(netuid, hotkey))
+                    .collect()
+            }),
+            active: wants(metagraph_fields::ACTIVE).then(|| Active::<T>::get(netuid)),
+            rank: wants(metagraph_fields::RANK).then(|| u16_compact(Rank::<T>::get(netuid))),
+            trust: wants(metagraph_fields::TRUST).then(|| u16_compact(Trust::<T>::get(netuid))),
+            consensus: wants(metagraph_fields::CONSENSUS)
+                .then(|| u16_compact(Consensus::<T>::get(netuid))),
+            incentive: wants(metagraph_fields::INCENTIVE)
+                .then(|| u16_compact(Incentive::<T>::get(netuid))),
+            dividends: wants(metagraph_fields::DIVIDENDS)
+                .then(|| u16_compact(Dividends::<T>::get(netuid))),
+            emission: wants(metagraph_fields::EMISSION)
+                .then(|| u64_compact(Emission::<T>::get(netuid))),
+            validator_trust: wants(metagraph_fields::VALIDATOR_TRUST)
+                .then(|| u16_compact(ValidatorTrust::<T>::get(netuid))),
+            validator_permit: wants(metagraph_fields::VALIDATOR_PERMIT)
+                .then(|| ValidatorPermit::<T>::get(netuid)),
+            last_update: wants(metagraph_fields::LAST_UPDATE)
+                .then(|| u64_compact(LastUpdate::<T>::get(netuid))),
+            pruning_score: wants(metagraph_fields::PRUNING_SCORE)
+                .then(|| u16_compact(PruningScores::<T>::get(netuid))),
+            hotkeys: wants(metagraph_fields::HOTKEYS).then_some(hotkeys),
+        })
+    }
+}
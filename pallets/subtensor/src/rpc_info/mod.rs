@@ -1,5 +1,12 @@
 use super::*;
+pub mod coldkey_report;
 pub mod delegate_info;
+pub mod epoch_simulation;
+pub mod metagraph;
 pub mod neuron_info;
+pub mod preflight;
+pub mod scheduled_operations;
+pub mod stake_distribution;
 pub mod stake_info;
 pub mod subnet_info;
+pub mod subnet_state;
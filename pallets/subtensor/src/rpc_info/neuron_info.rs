@@ -2,6 +2,7 @@ use super::*;
 use frame_support::pallet_prelude::{Decode, Encode};
 extern crate alloc;
 use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
 
 #[freeze_struct("45e69321f5c74b4b")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
@@ -77,7 +78,7 @@ impl<T: Config> Pallet<T> {
             Err(_) => return None,
         };
 
-        let axon_info = Self::get_axon_info(netuid, &hotkey.clone());
+        let axon_info = Self::get_visible_axon_info(netuid, &hotkey.clone());
 
         let prometheus_info = Self::get_prometheus_info(netuid, &hotkey.clone());
 
@@ -160,7 +161,7 @@ impl<T: Config> Pallet<T> {
             Err(_) => return None,
         };
 
-        let axon_info = Self::get_axon_info(netuid, &hotkey.clone());
+        let axon_info = Self::get_visible_axon_info(netuid, &hotkey.clone());
 
         let prometheus_info = Self::get_prometheus_info(netuid, &hotkey.clone());
 
@@ -232,4 +233,24 @@ impl<T: Config> Pallet<T> {
 
         Self::get_neuron_lite_subnet_exists(netuid, uid)
     }
+
+    /// Returns every subnet the hotkey is registered on, paired with its UID there.
+    pub fn get_netuids_for_hotkey(
+        hotkey_account_vec: Vec<u8>,
+    ) -> Vec<(Compact<u16>, Compact<u16>)> {
+        if hotkey_account_vec.len() != 32 {
+            return Vec::new(); // Invalid hotkey
+        }
+
+        let Ok(hotkey) = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        Self::get_registered_networks_for_hotkey(&hotkey)
+            .into_iter()
+            .filter_map(|netuid| {
+                Uids::<T>::get(netuid, &hotkey).map(|uid| (netuid.into(), uid.into()))
+            })
+            .collect()
+    }
 }
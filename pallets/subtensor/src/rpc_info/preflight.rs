@@ -0,0 +1,78 @@
+use super::*;
+use frame_support::dispatch::GetDispatchInfo;
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use frame_support::traits::IsType;
+use sp_core::hexdisplay::AsBytesRef;
+use sp_runtime::traits::{Dispatchable, SignedExtension};
+extern crate alloc;
+
+/// Outcome of dispatching a call without keeping its state changes.
+#[freeze_struct("94aad7f92040c609")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct PreflightResult {
+    /// The error SubtensorSignedExtension rejects the call with, such as a rate limit. The call
+    /// is not dispatched then.
+    pub invalid: Option<TransactionValidityError>,
+    /// The error the call would fail with, None if it would succeed.
+    pub error: Option<DispatchError>,
+    /// Name of the error variant, such as `NotEnoughStakeToWithdraw`. Empty on success.
+    pub error_name: Vec<u8>,
+}
+
+impl<T: Config + Send + Sync + TypeInfo + pallet_balances::Config> Pallet<T>
+where
+    <T as frame_system::Config>::RuntimeCall:
+        Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    <T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+    <T as frame_system::Config>::RuntimeCall: IsSubType<BalancesCall<T>>,
+{
+    /// Validates an encoded call signed by `signer_account_vec` with SubtensorSignedExtension,
+    /// then dispatches it and rolls back every state change, so wallets can tell which
+    /// ownership, rate limit or bounds check a call would fail before broadcasting it. Other
+    /// transaction pool checks such as fees and nonces are not run.
+    /// Returns None if the call or the signer does not decode.
+    pub fn preflight_call(
+        call_vec: Vec<u8>,
+        signer_account_vec: Vec<u8>,
+    ) -> Option<PreflightResult> {
+        let call = <T as Config>::RuntimeCall::decode(&mut call_vec.as_bytes_ref()).ok()?;
+        let signer = T::AccountId::decode(&mut signer_account_vec.as_bytes_ref()).ok()?;
+
+        let system_call: &<T as frame_system::Config>::RuntimeCall = call.into_ref();
+        if let Err(invalid) = SubtensorSignedExtension::<T>::new().validate(
+            &signer,
+            system_call,
+            &system_call.get_dispatch_info(),
+            call_vec.len(),
+        ) {
+            return Some(PreflightResult {
+                invalid: Some(invalid),
+                error: None,
+                error_name: Vec::new(),
+            });
+        }
+
+        let result: Result<(), DispatchError> = with_transaction(|| {
+            let result = call
+                .dispatch(frame_system::RawOrigin::Signed(signer).into())
+                .map(|_| ())
+                .map_err(|e| e.error);
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(result))
+        })
+        .unwrap_or_else(Err);
+
+        Some(match result {
+            Ok(()) => PreflightResult {
+                invalid: None,
+                error: None,
+                error_name: Vec::new(),
+            },
+            Err(error) => PreflightResult {
+                invalid: None,
+                error: Some(error),
+                error_name: <&'static str>::from(error).as_bytes().to_vec(),
+            },
+        })
+    }
+}
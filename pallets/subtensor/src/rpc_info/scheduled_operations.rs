@@ -0,0 +1,35 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+/// A delayed operation of a coldkey waiting in the scheduler queue.
+#[freeze_struct("385aaf700b711c93")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ScheduledOperationInfo<T: Config> {
+    /// Id to cancel the operation with.
+    pub id: Compact<u64>,
+    pub operation: ScheduledOperation<T::AccountId>,
+    pub execution_block: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the pending scheduled operations of an encoded coldkey, or None if it does not
+    /// decode.
+    pub fn get_scheduled_operations(
+        coldkey_account_vec: Vec<u8>,
+    ) -> Option<Vec<ScheduledOperationInfo<T>>> {
+        let coldkey = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()).ok()?;
+        Some(
+            Self::get_scheduled_operations_for_coldkey(&coldkey)
+                .into_iter()
+                .map(|(id, operation, execution_block)| ScheduledOperationInfo {
+                    id: id.into(),
+                    operation,
+                    execution_block: execution_block.into(),
+                })
+                .collect(),
+        )
+    }
+}
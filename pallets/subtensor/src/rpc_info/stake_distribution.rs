@@ -0,0 +1,99 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+/// Percentages of the largest holders whose share of the stake is reported.
+pub const STAKE_DISTRIBUTION_TOP_PERCENTS: [u16; 4] = [1, 10, 25, 50];
+
+/// How concentrated a set of stake holders is. Ratios are normalized to u16::MAX.
+#[freeze_struct("fe85cfcd39407815")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct StakeDistribution {
+    /// Number of holders, including those without stake.
+    pub holders: Compact<u32>,
+    pub total_stake: Compact<u64>,
+    /// Gini coefficient of the stake, 0 when evenly spread.
+    pub gini: Compact<u16>,
+    /// Share of the stake held by the largest holders, for each of
+    /// STAKE_DISTRIBUTION_TOP_PERCENTS.
+    pub top_shares: Vec<(Compact<u16>, Compact<u16>)>,
+    pub median_stake: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the distribution of the stake of the neurons of a subnet, or None if the subnet
+    /// does not exist.
+    pub fn get_stake_distribution(netuid: u16) -> Option<StakeDistribution> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let stakes: Vec<u64> = (0..Self::get_subnetwork_n(netuid))
+            .map(|uid| Self::get_stake_for_uid_and_subnetwork(netuid, uid))
+            .collect();
+        Some(Self::compute_stake_distribution(stakes))
+    }
+
+    /// Returns the distribution of the total stake of every hotkey.
+    pub fn get_global_stake_distribution() -> StakeDistribution {
+        Self::compute_stake_distribution(TotalHotkeyStake::<T>::iter_values().collect())
+    }
+
+    pub fn compute_stake_distribution(mut stakes: Vec<u64>) -> StakeDistribution {
+        stakes.sort_unstable();
+        let n: u128 = stakes.len() as u128;
+        let total: u128 = stakes
+            .iter()
+            .fold(0, |acc, stake| acc.saturating_add(*stake as u128));
+        let normalize = |part: u128| -> u16 {
+            part.saturating_mul(u16::MAX as u128)
+                .checked_div(total)
+                .unwrap_or(0) as u16
+        };
+
+        // G = (2 * sum(i * x_i)) / (n * sum(x)) - (n + 1) / n, over the stakes in ascending
+        // order with i counted from 1.
+        let weighted: u128 = stakes
+            .iter()
+            .zip(1u128..)
+            .map(|(stake, i)| i.saturating_mul(*stake as u128))
+            .fold(0, |acc, x| acc.saturating_add(x));
+        let gini: u16 = weighted
+            .saturating_mul(2)
+            .saturating_sub(n.saturating_add(1).saturating_mul(total))
+            .saturating_mul(u16::MAX as u128)
+            .checked_div(n.saturating_mul(total))
+            .unwrap_or(0) as u16;
+
+        let top_shares: Vec<(Compact<u16>, Compact<u16>)> = STAKE_DISTRIBUTION_TOP_PERCENTS
+            .iter()
+            .map(|percent| {
+                let count: usize = stakes
+                    .len()
+                    .saturating_mul(*percent as usize)
+                    .div_ceil(100)
+                    .max(1);
+                let top: u128 = stakes
+                    .iter()
+                    .rev()
+                    .take(count)
+                    .fold(0, |acc, stake| acc.saturating_add(*stake as u128));
+                ((*percent).into(), normalize(top).into())
+            })
+            .collect();
+
+        let median_stake: u64 = stakes
+            .get(stakes.len().saturating_div(2))
+            .copied()
+            .unwrap_or(0);
+
+        StakeDistribution {
+            holders: (stakes.len() as u32).into(),
+            total_stake: (total as u64).into(),
+            gini: gini.into(),
+            top_shares,
+            median_stake: median_stake.into(),
+        }
+    }
+}
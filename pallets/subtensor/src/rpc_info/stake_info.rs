@@ -81,4 +81,45 @@ impl<T: Config> Pallet<T> {
             first.1.clone()
         }
     }
+
+    /// Returns the total emission credited to the hotkey, broken down by the subnet that generated it.
+    pub fn get_emission_by_netuid(
+        hotkey_account_vec: Vec<u8>,
+    ) -> Vec<(Compact<u16>, Compact<u64>)> {
+        if hotkey_account_vec.len() != 32 {
+            return Vec::new(); // Invalid hotkey
+        }
+
+        let Ok(hotkey) = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        Self::get_hotkey_emission_by_netuid(&hotkey)
+            .into_iter()
+            .map(|(netuid, emission)| (netuid.into(), emission.into()))
+            .collect()
+    }
+
+    /// Returns the (netuid, emission) of every subnet that credited emission to the hotkey.
+    pub fn get_hotkey_emission_by_netuid(hotkey: &T::AccountId) -> Vec<(u16, u64)> {
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .map(|netuid| (netuid, HotkeyEmissionByNetuid::<T>::get(netuid, hotkey)))
+            .filter(|(_, emission)| *emission > 0)
+            .collect()
+    }
+
+    /// Returns the emission accrued to the hotkey that has not yet been drained to its
+    /// nominators.
+    pub fn get_hotkey_pending_emission(hotkey_account_vec: Vec<u8>) -> u64 {
+        if hotkey_account_vec.len() != 32 {
+            return 0; // Invalid hotkey
+        }
+
+        let Ok(hotkey) = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()) else {
+            return 0;
+        };
+
+        PendingdHotkeyEmission::<T>::get(hotkey)
+    }
 }
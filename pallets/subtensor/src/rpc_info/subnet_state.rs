@@ -0,0 +1,246 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use sp_core::H256;
+
+/// Layout version of `SubnetState`. Bump whenever a field is added, removed or reordered so
+/// off-chain tooling can tell snapshots apart.
+pub const SUBNET_STATE_VERSION: u32 = 13;
+
+/// Snapshot of every per-subnet storage item, holding the raw storage values so a subnet can
+/// be replayed off-chain or recreated on another chain. Per-uid vectors are indexed by uid.
+#[freeze_struct("acc8731cd88be64b")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct SubnetState<T: Config> {
+    pub version: u32,
+    pub netuid: u16,
+    pub block: u64,
+
+    // Hyperparameters.
+    pub rho: u16,
+    pub kappa: u16,
+    pub tempo: u16,
+    pub network_modality: u16,
+    pub immunity_period: u16,
+    pub activity_cutoff: u16,
+    pub active_permits_only: bool,
+    pub max_allowed_uids: u16,
+    pub max_allowed_validators: u16,
+    pub min_allowed_weights: u16,
+    pub max_allowed_weights: u16,
+    pub max_weights_limit: u16,
+    pub scaling_law_power: u16,
+    pub weights_version_key: u64,
+    pub weights_set_rate_limit: u64,
+    pub weights_immunity_period: u64,
+    pub commit_reveal_weights_interval: u64,
+    pub commit_reveal_weights_enabled: bool,
+    pub adjustment_interval: u16,
+    pub adjustment_alpha: u64,
+    pub target_registrations_per_interval: u16,
+    pub max_registrations_per_block: u16,
+    pub registration_allowed: bool,
+    pub pow_registration_allowed: bool,
+    pub registration_mechanism: RegistrationMechanism,
+    pub burn: u64,
+    pub min_burn: u64,
+    pub max_burn: u64,
+    pub min_stake_to_register: u64,
+    pub registration_challenge_commit: Option<H256>,
+    pub reserved_hotkeys: Vec<T::AccountId>,
+    pub difficulty: u64,
+    pub min_difficulty: u64,
+    pub max_difficulty: u64,
+    pub bonds_moving_average: u64,
+    pub serving_rate_limit: u64,
+    pub validator_prune_len: u64,
+    pub liquid_alpha_enabled: bool,
+    pub alpha_values: (u16, u16),
+    pub network_max_stake: u64,
+    pub min_axon_version: u32,
+    pub prune_emission_burn: bool,
+    pub pending_kappa: Option<u16>,
+    pub pending_max_weights_limit: Option<u16>,
+    pub pending_alpha_values: Option<(u16, u16)>,
+    pub pending_hyperparam_bundle: Option<HyperparamBundle>,
+
+    // Subnet bookkeeping.
+    pub owner: T::AccountId,
+    pub lease: Option<(T::AccountId, u64)>,
+    pub locked: u64,
+    pub identity: Option<SubnetIdentity>,
+    pub registered_at: u64,
+    pub emission_value: u64,
+    pub pending_emission: u64,
+    pub emission_disabled_until: Option<u64>,
+    pub blocks_since_last_step: u64,
+    pub last_mechanism_step_block: u64,
+    pub next_epoch_block: Option<u64>,
+    pub last_tempo_change_block: Option<u64>,
+    pub last_adjustment_block: u64,
+    pub randomness: Option<(u64, H256)>,
+    pub registrations_this_block: u16,
+    pub registrations_this_interval: u16,
+    pub pow_registrations_this_interval: u16,
+    pub burn_registrations_this_interval: u16,
+    pub rao_recycled_for_registration: u64,
+    pub neuron_to_prune_at_next_epoch: u16,
+    pub registration_bids: Vec<(T::AccountId, T::AccountId, u64)>,
+    pub weight_commits: Vec<(T::AccountId, H256, u64)>,
+
+    // Per-uid state.
+    pub hotkeys: Vec<T::AccountId>,
+    pub coldkeys: Vec<T::AccountId>,
+    pub total_stake: Vec<u64>,
+    pub children: Vec<Vec<(u64, T::AccountId)>>,
+    pub block_at_registration: Vec<u64>,
+    pub active: Vec<bool>,
+    pub rank: Vec<u16>,
+    pub trust: Vec<u16>,
+    pub consensus: Vec<u16>,
+    pub incentive: Vec<u16>,
+    pub dividends: Vec<u16>,
+    pub emission: Vec<u64>,
+    pub validator_trust: Vec<u16>,
+    pub validator_permit: Vec<bool>,
+    pub last_update: Vec<u64>,
+    pub pruning_scores: Vec<u16>,
+    pub axons: Vec<Option<AxonInfoOf>>,
+    pub prometheus: Vec<Option<PrometheusInfoOf>>,
+    pub weights: Vec<Vec<(u16, u16)>>,
+    pub bonds: Vec<Vec<(u16, u16)>>,
+    pub aux_scores: Vec<(u16, u16, Vec<(u16, u16)>)>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns a versioned snapshot of all storage of a subnet, or None if the subnet does
+    /// not exist.
+    pub fn get_subnet_state(netuid: u16) -> Option<SubnetState<T>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let n: u16 = SubnetworkN::<T>::get(netuid);
+        let hotkeys: Vec<T::AccountId> = (0..n).map(|uid| Keys::<T>::get(netuid, uid)).collect();
+
+        Some(SubnetState {
+            version: SUBNET_STATE_VERSION,
+            netuid,
+            block: Self::get_current_block_as_u64(),
+
+            rho: Rho::<T>::get(netuid),
+            kappa: Kappa::<T>::get(netuid),
+            tempo: Tempo::<T>::get(netuid),
+            network_modality: NetworkModality::<T>::get(netuid),
+            immunity_period: ImmunityPeriod::<T>::get(netuid),
+            activity_cutoff: ActivityCutoff::<T>::get(netuid),
+            active_permits_only: ActivePermitsOnly::<T>::get(netuid),
+            max_allowed_uids: MaxAllowedUids::<T>::get(netuid),
+            max_allowed_validators: MaxAllowedValidators::<T>::get(netuid),
+            min_allowed_weights: MinAllowedWeights::<T>::get(netuid),
+            max_allowed_weights: MaxAllowedWeights::<T>::get(netuid),
+            max_weights_limit: MaxWeightsLimit::<T>::get(netuid),
+            scaling_law_power: ScalingLawPower::<T>::get(netuid),
+            weights_version_key: WeightsVersionKey::<T>::get(netuid),
+            weights_set_rate_limit: WeightsSetRateLimit::<T>::get(netuid),
+            weights_immunity_period: WeightsImmunityPeriod::<T>::get(netuid),
+            commit_reveal_weights_interval: WeightCommitRevealInterval::<T>::get(netuid),
+            commit_reveal_weights_enabled: CommitRevealWeightsEnabled::<T>::get(netuid),
+            adjustment_interval: AdjustmentInterval::<T>::get(netuid),
+            adjustment_alpha: AdjustmentAlpha::<T>::get(netuid),
+            target_registrations_per_interval: TargetRegistrationsPerInterval::<T>::get(netuid),
+            max_registrations_per_block: MaxRegistrationsPerBlock::<T>::get(netuid),
+            registration_allowed: NetworkRegistrationAllowed::<T>::get(netuid),
+            pow_registration_allowed: NetworkPowRegistrationAllowed::<T>::get(netuid),
+            registration_mechanism: SubnetRegistrationMechanism::<T>::get(netuid),
+            burn: Burn::<T>::get(netuid),
+            min_burn: MinBurn::<T>::get(netuid),
+            max_burn: MaxBurn::<T>::get(netuid),
+            min_stake_to_register: MinStakeToRegister::<T>::get(netuid),
+            registration_challenge_commit: RegistrationChallengeCommit::<T>::get(netuid),
+            reserved_hotkeys: ReservedHotkeys::<T>::get(netuid).into_inner(),
+            difficulty: Difficulty::<T>::get(netuid),
+            min_difficulty: MinDifficulty::<T>::get(netuid),
+            max_difficulty: MaxDifficulty::<T>::get(netuid),
+            bonds_moving_average: BondsMovingAverage::<T>::get(netuid),
+            serving_rate_limit: ServingRateLimit::<T>::get(netuid),
+            validator_prune_len: ValidatorPruneLen::<T>::get(netuid),
+            liquid_alpha_enabled: LiquidAlphaOn::<T>::get(netuid),
+            alpha_values: AlphaValues::<T>::get(netuid),
+            network_max_stake: NetworkMaxStake::<T>::get(netuid),
+            min_axon_version: MinAxonVersion::<T>::get(netuid),
+            prune_emission_burn: PruneEmissionBurn::<T>::get(netuid),
+            pending_kappa: PendingKappa::<T>::get(netuid),
+            pending_max_weights_limit: PendingMaxWeightsLimit::<T>::get(netuid),
+            pending_alpha_values: PendingAlphaValues::<T>::get(netuid),
+            pending_hyperparam_bundle: PendingHyperparamBundle::<T>::get(netuid),
+
+            owner: SubnetOwner::<T>::get(netuid),
+            lease: SubnetLease::<T>::get(netuid),
+            locked: SubnetLocked::<T>::get(netuid),
+            identity: SubnetIdentities::<T>::get(netuid),
+            registered_at: NetworkRegisteredAt::<T>::get(netuid),
+            emission_value: EmissionValues::<T>::get(netuid),
+            pending_emission: PendingEmission::<T>::get(netuid),
+            emission_disabled_until: EmissionDisabled::<T>::get(netuid),
+            blocks_since_last_step: BlocksSinceLastStep::<T>::get(netuid),
+            last_mechanism_step_block: LastMechansimStepBlock::<T>::get(netuid),
+            next_epoch_block: NextEpochBlock::<T>::get(netuid),
+            last_tempo_change_block: LastTempoChangeBlock::<T>::get(netuid),
+            last_adjustment_block: LastAdjustmentBlock::<T>::get(netuid),
+            randomness: SubnetRandomness::<T>::get(netuid),
+            registrations_this_block: RegistrationsThisBlock::<T>::get(netuid),
+            registrations_this_interval: RegistrationsThisInterval::<T>::get(netuid),
+            pow_registrations_this_interval: POWRegistrationsThisInterval::<T>::get(netuid),
+            burn_registrations_this_interval: BurnRegistrationsThisInterval::<T>::get(netuid),
+            rao_recycled_for_registration: RAORecycledForRegistration::<T>::get(netuid),
+            neuron_to_prune_at_next_epoch: NeuronsToPruneAtNextEpoch::<T>::get(netuid),
+            registration_bids: RegistrationBids::<T>::get(netuid)
+                .into_iter()
+                .map(|bid| (bid.coldkey, bid.hotkey, bid.escrow))
+                .collect(),
+            weight_commits: WeightCommits::<T>::iter_prefix(netuid)
+                .map(|(who, (hash, block, _))| (who, hash, block))
+                .collect(),
+
+            coldkeys: hotkeys.iter().map(Owner::<T>::get).collect(),
+            total_stake: hotkeys.iter().map(TotalHotkeyStake::<T>::get).collect(),
+            children: hotkeys
+                .iter()
+                .map(|hotkey| ChildKeys::<T>::get(hotkey, netuid))
+                .collect(),
+            block_at_registration: (0..n)
+                .map(|uid| BlockAtRegistration::<T>::get(netuid, uid))
+                .collect(),
+            active: Active::<T>::get(netuid),
+            rank: Rank::<T>::get(netuid),
+            trust: Trust::<T>::get(netuid),
+            consensus: Consensus::<T>::get(netuid),
+            incentive: Incentive::<T>::get(netuid),
+            dividends: Dividends::<T>::get(netuid),
+            emission: Emission::<T>::get(netuid),
+            validator_trust: ValidatorTrust::<T>::get(netuid),
+            validator_permit: ValidatorPermit::<T>::get(netuid),
+            last_update: LastUpdate::<T>::get(netuid),
+            pruning_scores: PruningScores::<T>::get(netuid),
+            axons: hotkeys
+                .iter()
+                .map(|hotkey| Axons::<T>::get(netuid, hotkey))
+                .collect(),
+            prometheus: hotkeys
+                .iter()
+                .map(|hotkey| Prometheus::<T>::get(netuid, hotkey))
+                .collect(),
+            weights: (0..n)
+                .map(|uid| Weights::<T>::get(netuid, uid).into_inner())
+                .collect(),
+            bonds: (0..n)
+                .map(|uid| Bonds::<T>::get(netuid, uid).into_inner())
+                .collect(),
+            aux_scores: AuxScores::<T>::iter_prefix((netuid,))
+                .map(|((uid, kind), scores)| (uid, kind, scores.into_inner()))
+                .collect(),
+            hotkeys,
+        })
+    }
+}
@@ -24,6 +24,9 @@ impl<T: Config> Pallet<T> {
     /// * 'NonAssociatedColdKey':
     ///     -  The calling coldkey is not associated with this hotkey.
     ///
+    /// * 'ColdkeyIsRegisteredHotkey' / 'HotkeyIsInUseAsColdkey':
+    ///     -  The keys are not used in their own roles.
+    ///
     /// * 'BalanceWithdrawalError':
     ///     -  Errors stemming from transaction pallet.
     ///
@@ -50,41 +53,17 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotEnoughBalanceToStake
         );
 
-        // Ensure that the hotkey account exists this is only possible through registration.
-        ensure!(
-            Self::hotkey_account_exists(&hotkey),
-            Error::<T>::HotKeyAccountNotExists
-        );
-
-        // Ensure that the hotkey allows delegation or that the hotkey is owned by the calling coldkey.
-        ensure!(
-            Self::hotkey_is_delegate(&hotkey) || Self::coldkey_owns_hotkey(&coldkey, &hotkey),
-            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
-        );
-
-        // Ensure we don't exceed stake rate limit
+        // Ensure the hotkey takes the stake, within the rate limit and the nominator minimum.
         let stakes_this_interval =
-            Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey);
-        ensure!(
-            stakes_this_interval < Self::get_target_stakes_per_interval(),
-            Error::<T>::StakeRateLimitExceeded
-        );
+            Self::ensure_can_add_stake(&coldkey, &hotkey, stake_to_be_added)?;
 
         // Track this addition in the stake delta.
         StakeDeltaSinceLastEmissionDrain::<T>::mutate(&hotkey, &coldkey, |stake_delta| {
             *stake_delta = stake_delta.saturating_add_unsigned(stake_to_be_added as u128);
         });
 
-        // If coldkey is not owner of the hotkey, it's a nomination stake.
-        if !Self::coldkey_owns_hotkey(&coldkey, &hotkey) {
-            let total_stake_after_add =
-                Stake::<T>::get(&hotkey, &coldkey).saturating_add(stake_to_be_added);
-
-            ensure!(
-                total_stake_after_add >= NominatorMinRequiredStake::<T>::get(),
-                Error::<T>::NomStakeBelowMinimumThreshold
-            );
-        }
+        // A hotkey staked to by another coldkey becomes a delegate at the default take.
+        Self::delegate_hotkey_if_implicit(&coldkey, &hotkey)?;
 
         // Ensure the remove operation from the coldkey is a success.
         let actual_amount_to_stake =
@@ -109,9 +88,60 @@ impl<T: Config> Pallet<T> {
             hotkey,
             actual_amount_to_stake
         );
-        Self::deposit_event(Event::StakeAdded(hotkey, actual_amount_to_stake));
+        Self::record_block_metric(|m| m.stake_ops = m.stake_ops.saturating_add(1));
 
         // Ok and return.
         Ok(())
     }
+
+    /// Ensures `coldkey` may add `amount` of stake to `hotkey` as add_stake checks it: the
+    /// hotkey exists and takes stake from the coldkey, the keys are used in their own roles,
+    /// the pair is within the stake rate limit and a nomination stays above the nominator
+    /// minimum. Returns the stakes of the pair this interval.
+    pub fn ensure_can_add_stake(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+    ) -> Result<u64, DispatchError> {
+        // Ensure that the hotkey account exists this is only possible through registration.
+        ensure!(
+            Self::hotkey_account_exists(hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        // Ensure that the hotkey allows delegation or that the hotkey is owned by the calling coldkey.
+        ensure!(
+            Self::hotkey_accepts_delegation(hotkey) || Self::coldkey_owns_hotkey(coldkey, hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        // Ensure the keys are used in their own roles.
+        Self::ensure_key_usage_separation(coldkey, hotkey)?;
+
+        // Ensure nominators do not stake to a delegate that has been inactive past its grace period.
+        ensure!(
+            Self::coldkey_owns_hotkey(coldkey, hotkey) || !Self::is_delegate_inactive(hotkey),
+            Error::<T>::DelegateInactive
+        );
+
+        // Ensure we don't exceed stake rate limit
+        let stakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(coldkey, hotkey);
+        ensure!(
+            stakes_this_interval < Self::get_target_stakes_per_interval(),
+            Error::<T>::StakeRateLimitExceeded
+        );
+
+        // If coldkey is not owner of the hotkey, it's a nomination stake.
+        if !Self::coldkey_owns_hotkey(coldkey, hotkey) {
+            let total_stake_after_add = Stake::<T>::get(hotkey, coldkey).saturating_add(amount);
+
+            ensure!(
+                total_stake_after_add >= NominatorMinRequiredStake::<T>::get(),
+                Error::<T>::NomStakeBelowMinimumThreshold
+            );
+        }
+
+        Ok(stakes_this_interval)
+    }
 }
@@ -3,6 +3,10 @@ use super::*;
 impl<T: Config> Pallet<T> {
     /// ---- The implementation for the extrinsic become_delegate: signals that this hotkey allows delegated stake.
     ///
+    /// Deprecated: every hotkey already accepts delegated stake at the default take unless it
+    /// opted out with set_delegate_availability. Becoming a delegate records the take and opts
+    /// the hotkey back in.
+    ///
     /// # Args:
     /// *  'origin': (<T as frame_system::Config>RuntimeOrigin):
     ///     - The signature of the caller's coldkey.
@@ -59,13 +63,11 @@ impl<T: Config> Pallet<T> {
         );
 
         // --- 5.1 Ensure take is within the min ..= InitialDefaultDelegateTake (18%) range
-        let min_take = MinDelegateTake::<T>::get();
-        let max_take = MaxDelegateTake::<T>::get();
-        ensure!(take >= min_take, Error::<T>::DelegateTakeTooLow);
-        ensure!(take <= max_take, Error::<T>::DelegateTakeTooHigh);
+        Self::ensure_delegate_take_in_range(take)?;
 
         // --- 6. Delegate the key.
         Self::delegate_hotkey(&hotkey, take);
+        DelegationOptOut::<T>::remove(&hotkey);
 
         // Set last block for rate limiting
         Self::set_last_tx_block(&coldkey, block);
@@ -78,9 +80,75 @@ impl<T: Config> Pallet<T> {
             hotkey,
             take
         );
-        Self::deposit_event(Event::DelegateAdded(coldkey, hotkey, take));
+        Self::deposit_event(Event::DelegateAdded(coldkey.clone(), hotkey.clone(), take));
+        Self::deposit_event(Event::DelegateUpdated {
+            coldkey,
+            hotkey,
+            old_take: None,
+            new_take: take,
+        });
 
         // --- 8. Ok and return.
         Ok(())
     }
+
+    /// ---- The implementation for the extrinsic set_delegate_availability.
+    ///
+    /// # Args:
+    /// *  'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the owning coldkey.
+    ///
+    /// *  'hotkey' (T::AccountId):
+    ///     - The hotkey to open or close to delegated stake.
+    ///
+    /// *  'available' (bool):
+    ///     - Whether coldkeys other than the owner may add stake to the hotkey. Existing
+    ///       nominators can always remove their stake.
+    ///
+    /// # Event:
+    /// *  DelegateAvailabilitySet;
+    ///     - On successfully changing the availability.
+    ///
+    /// # Raises:
+    /// *  'HotKeyAccountNotExists':
+    ///     - The hotkey does not exist.
+    ///
+    /// *  'NonAssociatedColdKey':
+    ///     - The hotkey is not owned by the calling coldkey.
+    ///
+    pub fn do_set_delegate_availability(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        available: bool,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        if available {
+            DelegationOptOut::<T>::remove(&hotkey);
+        } else {
+            DelegationOptOut::<T>::insert(&hotkey, true);
+        }
+
+        log::debug!(
+            "DelegateAvailabilitySet( coldkey:{:?}, hotkey:{:?}, available:{:?} )",
+            coldkey,
+            hotkey,
+            available
+        );
+        Self::deposit_event(Event::DelegateAvailabilitySet {
+            coldkey,
+            hotkey,
+            available,
+        });
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Args:
     /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
-    ///     - The signature of the caller's coldkey.
+    ///     - The signature of the caller's coldkey, or of the hotkey's delegate manager.
     ///
     /// * 'hotkey' (T::AccountId):
     ///     - The hotkey we are delegating (must be owned by the coldkey.)
@@ -27,13 +27,19 @@ impl<T: Config> Pallet<T> {
     /// * 'DelegateTakeTooLow':
     ///     - The delegate is setting a take which is not lower than the previous.
     ///
+    /// * 'DelegateTakeTooHigh':
+    ///     - The take is still above the max delegate take.
+    ///
+    /// The take may be zero, below the min delegate take, if the hotkey is a public good
+    /// delegate.
+    ///
     pub fn do_decrease_take(
         origin: T::RuntimeOrigin,
         hotkey: T::AccountId,
         take: u16,
     ) -> dispatch::DispatchResult {
-        // --- 1. We check the coldkey signature.
-        let coldkey = ensure_signed(origin)?;
+        // --- 1. We check the coldkey signature, or that of the hotkey's manager.
+        let coldkey = Self::ensure_take_origin(origin, &hotkey)?;
         log::debug!(
             "do_decrease_take( origin:{:?} hotkey:{:?}, take:{:?} )",
             coldkey,
@@ -50,21 +56,35 @@ impl<T: Config> Pallet<T> {
             ensure!(take < current_take, Error::<T>::DelegateTakeTooLow);
         }
 
-        // --- 3.1 Ensure take is within the min ..= InitialDefaultDelegateTake (18%) range
-        let min_take = MinDelegateTake::<T>::get();
-        ensure!(take >= min_take, Error::<T>::DelegateTakeTooLow);
+        // --- 3.1 Ensure take is within the min ..= InitialDefaultDelegateTake (18%) range,
+        //         or zero for a public good delegate.
+        Self::ensure_take_in_range_for(&hotkey, take)?;
 
         // --- 4. Set the new take value.
+        let old_take: u16 = Delegates::<T>::get(&hotkey);
         Delegates::<T>::insert(hotkey.clone(), take);
 
         // --- 5. Emit the take value.
         log::debug!(
-            "TakeDecreased( coldkey:{:?}, hotkey:{:?}, take:{:?} )",
+            "TakeDecreased( coldkey:{:?}, hotkey:{:?}, old_take:{:?}, take:{:?} )",
             coldkey,
             hotkey,
+            old_take,
             take
         );
-        Self::deposit_event(Event::TakeDecreased(coldkey, hotkey, take));
+        Self::deposit_event(Event::TakeDecreased(
+            coldkey.clone(),
+            hotkey.clone(),
+            old_take,
+            take,
+        ));
+        T::OnTakeChanged::on_take_changed(&hotkey, old_take, take);
+        Self::deposit_event(Event::DelegateUpdated {
+            coldkey,
+            hotkey,
+            old_take: Some(old_take),
+            new_take: take,
+        });
 
         // --- 6. Ok and return.
         Ok(())
@@ -0,0 +1,74 @@
+use super::*;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// Starts the inactivity grace period of a delegate that has just lost its last subnet
+    /// registration.
+    pub fn note_hotkey_deregistered(hotkey: &T::AccountId, block: u64) {
+        if Self::hotkey_is_delegate(hotkey) && !Self::is_hotkey_registered_on_any_network(hotkey) {
+            DelegateDeregisteredAt::<T>::insert(hotkey, block);
+            DelegateInactivityExpirations::<T>::append(
+                block.saturating_add(T::DelegateInactivityGracePeriod::get()),
+                hotkey.clone(),
+            );
+        }
+    }
+
+    /// Ends the inactivity grace period of a hotkey that has registered again. Its entry in
+    /// DelegateInactivityExpirations is skipped when the grace period ends.
+    pub fn note_hotkey_registered(hotkey: &T::AccountId) {
+        DelegateDeregisteredAt::<T>::remove(hotkey);
+    }
+
+    /// Returns true if the delegate has been deregistered from every subnet for at least
+    /// DelegateInactivityGracePeriod blocks.
+    pub fn is_delegate_inactive(hotkey: &T::AccountId) -> bool {
+        DelegateDeregisteredAt::<T>::get(hotkey).is_some_and(|block| {
+            Self::get_current_block_as_u64()
+                >= block.saturating_add(T::DelegateInactivityGracePeriod::get())
+        })
+    }
+
+    /// Ensures the hotkey may advertise itself as a delegate: it is registered on a subnet or
+    /// still within the grace period after losing its last registration.
+    pub fn ensure_delegate_active(hotkey: &T::AccountId) -> Result<(), Error<T>> {
+        ensure!(
+            Self::is_hotkey_registered_on_any_network(hotkey)
+                || (DelegateDeregisteredAt::<T>::contains_key(hotkey)
+                    && !Self::is_delegate_inactive(hotkey)),
+            Error::<T>::DelegateInactive
+        );
+        Ok(())
+    }
+
+    /// Emits DelegateInactive for every delegate whose grace period ends at `block`. Entries
+    /// of hotkeys that registered again or restarted their grace period are skipped, and
+    /// hotkeys that are no longer delegates are pruned from DelegateDeregisteredAt.
+    ///
+    /// Returns the weight consumed.
+    pub fn report_inactive_delegates(block: u64) -> Weight {
+        let grace_period: u64 = T::DelegateInactivityGracePeriod::get();
+        let expirations: Vec<T::AccountId> = DelegateInactivityExpirations::<T>::take(block);
+        let entries: u64 = expirations.len() as u64;
+        for hotkey in expirations {
+            let expires: bool =
+                DelegateDeregisteredAt::<T>::get(&hotkey).is_some_and(|deregistered_at| {
+                    deregistered_at.saturating_add(grace_period) == block
+                });
+            if !expires {
+                continue;
+            }
+            if !Self::hotkey_is_delegate(&hotkey) {
+                DelegateDeregisteredAt::<T>::remove(&hotkey);
+                continue;
+            }
+            log::debug!("DelegateInactive( hotkey:{:?} )", hotkey);
+            Self::deposit_event(Event::DelegateInactive { hotkey });
+        }
+        T::DbWeight::get().reads_writes(
+            entries.saturating_mul(2).saturating_add(1),
+            entries.saturating_add(1),
+        )
+    }
+}
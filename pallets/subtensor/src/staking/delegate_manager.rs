@@ -0,0 +1,121 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_delegate_manager.
+    ///
+    /// Appoints `manager` as the only other key allowed to change the take of `hotkey`, so the
+    /// owning coldkey can stay in cold storage. Any previous manager is replaced.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the owning coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The delegate hotkey.
+    ///
+    /// * 'manager' (T::AccountId):
+    ///     - The key allowed to change the take.
+    ///
+    /// # Event:
+    /// * DelegateManagerSet;
+    ///     - On successfully appointing the manager.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     - The hotkey does not exist.
+    ///
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey is not owned by the calling coldkey.
+    ///
+    pub fn do_set_delegate_manager(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        manager: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        DelegateManagers::<T>::insert(&hotkey, &manager);
+
+        log::debug!(
+            "DelegateManagerSet( coldkey:{:?}, hotkey:{:?}, manager:{:?} )",
+            coldkey,
+            hotkey,
+            manager
+        );
+        Self::deposit_event(Event::DelegateManagerSet {
+            coldkey,
+            hotkey,
+            manager,
+        });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic remove_delegate_manager.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the owning coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The delegate hotkey.
+    ///
+    /// # Event:
+    /// * DelegateManagerRemoved;
+    ///     - On successfully revoking the manager.
+    ///
+    /// # Raises:
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey is not owned by the calling coldkey.
+    ///
+    /// * 'NoDelegateManager':
+    ///     - The hotkey has no manager.
+    ///
+    pub fn do_remove_delegate_manager(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        let manager = DelegateManagers::<T>::take(&hotkey).ok_or(Error::<T>::NoDelegateManager)?;
+
+        log::debug!(
+            "DelegateManagerRemoved( coldkey:{:?}, hotkey:{:?}, manager:{:?} )",
+            coldkey,
+            hotkey,
+            manager
+        );
+        Self::deposit_event(Event::DelegateManagerRemoved {
+            coldkey,
+            hotkey,
+            manager,
+        });
+        Ok(())
+    }
+
+    /// Checks the signature of a take call for `hotkey` and resolves it to the coldkey it
+    /// acts for: the owning coldkey if the caller is the hotkey's manager, otherwise the
+    /// caller itself.
+    pub fn ensure_take_origin(
+        origin: T::RuntimeOrigin,
+        hotkey: &T::AccountId,
+    ) -> Result<T::AccountId, DispatchError> {
+        let who = ensure_signed(origin)?;
+        if DelegateManagers::<T>::get(hotkey).as_ref() == Some(&who) {
+            return Ok(Self::get_owning_coldkey_for_hotkey(hotkey));
+        }
+        Ok(who)
+    }
+}
@@ -0,0 +1,171 @@
+use super::*;
+use crate::utils::storage_pages::STORAGE_PAGE_SIZE;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use substrate_fixed::types::U64F64;
+
+impl<T: Config> Pallet<T> {
+    /// Recomputes the cached statistics of every delegate once every DelegateStatsInterval
+    /// blocks, so the runtime APIs read the returns of a delegate instead of recomputing them.
+    ///
+    /// Delegates are refreshed one at a time within the block step budget, reading their
+    /// nominations a page at a time; a pass that does not fit continues in the following blocks
+    /// under the block it started at, from the stored nomination cursor. A pass still unfinished
+    /// at the next interval is replaced by the new one.
+    pub fn refresh_delegate_stats(block: u64, used_weight: &mut Weight) {
+        let interval: u64 = T::DelegateStatsInterval::get();
+        if interval > 0 && block.checked_rem(interval) == Some(0) {
+            DelegateStatsPass::<T>::put((block, None::<Vec<u8>>));
+            DelegateStatsNominations::<T>::kill();
+        }
+
+        while let Some((pass_block, cursor)) = DelegateStatsPass::<T>::get() {
+            let (page, next) = Self::delegates_page(cursor, 1);
+            let Some((hotkey, _)) = page.first() else {
+                DelegateStatsPass::<T>::kill();
+                return;
+            };
+
+            // Resume the nominations of the delegate where the last block left them.
+            let (mut stake_cursor, mut nominators, mut nominated_stake) =
+                match DelegateStatsNominations::<T>::get() {
+                    Some((delegate, raw_key, nominators, nominated_stake))
+                        if delegate == *hotkey =>
+                    {
+                        (Some(raw_key), nominators, nominated_stake)
+                    }
+                    _ => (None, 0, 0),
+                };
+            loop {
+                if !Self::try_charge_block_step(used_weight, Self::delegate_stats_page_weight()) {
+                    match stake_cursor {
+                        Some(raw_key) => DelegateStatsNominations::<T>::put((
+                            hotkey.clone(),
+                            raw_key,
+                            nominators,
+                            nominated_stake,
+                        )),
+                        None => DelegateStatsNominations::<T>::kill(),
+                    }
+                    return;
+                }
+                let (stakes, next_stake) =
+                    Self::stake_prefix_page(hotkey, stake_cursor, STORAGE_PAGE_SIZE);
+                (nominators, nominated_stake) =
+                    Self::count_nominations(hotkey, &stakes, nominators, nominated_stake);
+                match next_stake {
+                    Some(raw_key) => stake_cursor = Some(raw_key),
+                    None => break,
+                }
+            }
+
+            DelegateStatsNominations::<T>::kill();
+            DelegateStatsCache::<T>::insert(
+                hotkey,
+                Self::delegate_stats_from_nominations(
+                    hotkey,
+                    pass_block,
+                    nominators,
+                    nominated_stake,
+                ),
+            );
+            match next {
+                Some(raw_key) => DelegateStatsPass::<T>::put((pass_block, Some(raw_key))),
+                None => DelegateStatsPass::<T>::kill(),
+            }
+        }
+    }
+
+    /// Estimated weight of reading a page of the nominations of a delegate, including finishing
+    /// its statistics from its emission on every subnet.
+    pub fn delegate_stats_page_weight() -> Weight {
+        let nominators: u64 = STORAGE_PAGE_SIZE as u64;
+        let subnets: u64 = Self::get_all_subnet_netuids().len() as u64;
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(nominators))
+            .saturating_add(
+                T::DbWeight::get().reads_writes(
+                    nominators
+                        .saturating_add(subnets.saturating_mul(4))
+                        .saturating_add(6),
+                    2,
+                ),
+            )
+    }
+
+    /// Returns the cached statistics of a delegate, or computes them at the current block if
+    /// they have not been cached yet.
+    pub fn get_delegate_stats_for_hotkey(hotkey: &T::AccountId) -> DelegateStats {
+        DelegateStatsCache::<T>::get(hotkey).unwrap_or_else(|| {
+            Self::compute_delegate_stats(hotkey, Self::get_current_block_as_u64())
+        })
+    }
+
+    /// Computes the aggregate statistics of a delegate from its nominations and the emission
+    /// it earns on each subnet it is registered on.
+    pub fn compute_delegate_stats(hotkey: &T::AccountId, block: u64) -> DelegateStats {
+        let stakes: Vec<(T::AccountId, u64)> = Stake::<T>::iter_prefix(hotkey).collect();
+        let (nominators, nominated_stake) = Self::count_nominations(hotkey, &stakes, 0, 0);
+        Self::delegate_stats_from_nominations(hotkey, block, nominators, nominated_stake)
+    }
+
+    /// Adds a page of the nominations of a delegate to the running number of nominators and
+    /// stake nominated by accounts other than its owner.
+    fn count_nominations(
+        hotkey: &T::AccountId,
+        stakes: &[(T::AccountId, u64)],
+        mut nominators: u32,
+        mut nominated_stake: u64,
+    ) -> (u32, u64) {
+        let owner: T::AccountId = Self::get_owning_coldkey_for_hotkey(hotkey);
+        for (nominator, stake) in stakes {
+            if *stake == 0 {
+                continue;
+            }
+            nominators = nominators.saturating_add(1);
+            if *nominator != owner {
+                nominated_stake = nominated_stake.saturating_add(*stake);
+            }
+        }
+        (nominators, nominated_stake)
+    }
+
+    /// Completes the statistics of a delegate from its nomination totals and the emission it
+    /// earns on each subnet it is registered on.
+    fn delegate_stats_from_nominations(
+        hotkey: &T::AccountId,
+        block: u64,
+        nominators: u32,
+        nominated_stake: u64,
+    ) -> DelegateStats {
+        let mut emissions_per_day: U64F64 = U64F64::from_num(0);
+        for netuid in Self::get_registered_networks_for_hotkey(hotkey) {
+            if let Ok(uid) = Self::get_uid_for_net_and_hotkey(netuid, hotkey) {
+                let emission: U64F64 = Self::get_emission_for_uid(netuid, uid).into();
+                let tempo: U64F64 = Self::get_tempo(netuid).into();
+                if tempo > U64F64::from_num(0) {
+                    let epochs_per_day: U64F64 = U64F64::from_num(7200).saturating_div(tempo);
+                    emissions_per_day =
+                        emissions_per_day.saturating_add(emission.saturating_mul(epochs_per_day));
+                }
+            }
+        }
+
+        let total_stake: U64F64 = Self::get_total_stake_for_hotkey(hotkey).into();
+        let return_per_1000: U64F64 = if total_stake > U64F64::from_num(0) {
+            emissions_per_day
+                .saturating_mul(U64F64::from_num(0.82))
+                .saturating_div(total_stake.saturating_div(U64F64::from_num(1000)))
+        } else {
+            U64F64::from_num(0)
+        };
+
+        DelegateStats {
+            block,
+            nominators,
+            nominated_stake,
+            total_daily_return: emissions_per_day.to_num::<u64>(),
+            return_per_1000: return_per_1000.to_num::<u64>(),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use super::*;
+use sp_core::hexdisplay::AsBytesRef;
+
+/// Rao in one TAO, the stake unit yields are quoted per.
+const RAO_PER_TAO: u128 = 1_000_000_000;
+
+impl<T: Config> Pallet<T> {
+    /// Records the yield a delegate's neuron realized in the epoch just run on a subnet,
+    /// replacing the previous epoch's. Hotkeys that are not delegates are skipped.
+    pub fn record_delegate_yield(netuid: u16, hotkey: &T::AccountId, emission: u64) {
+        if !Self::hotkey_is_delegate(hotkey) {
+            return;
+        }
+
+        let stake: u64 = Self::get_stake_for_hotkey_on_subnet(hotkey, netuid);
+        let emission_per_tao: u64 = (emission as u128)
+            .saturating_mul(RAO_PER_TAO)
+            .checked_div(stake as u128)
+            .unwrap_or(0) as u64;
+        let nominator_share: u128 =
+            (u16::MAX as u128).saturating_sub(Self::get_hotkey_take(hotkey) as u128);
+        let nominator_emission_per_tao: u64 = (emission_per_tao as u128)
+            .saturating_mul(nominator_share)
+            .checked_div(u16::MAX as u128)
+            .unwrap_or(0) as u64;
+
+        DelegateYields::<T>::insert(
+            netuid,
+            hotkey,
+            DelegateYield {
+                block: Self::get_current_block_as_u64(),
+                emission,
+                stake,
+                emission_per_tao,
+                nominator_emission_per_tao,
+            },
+        );
+    }
+
+    /// Returns the yield a delegate realized in the last epoch of a subnet, or None if it has
+    /// not earned on the subnet as a delegate since registering.
+    pub fn get_delegate_yield(delegate_account_vec: Vec<u8>, netuid: u16) -> Option<DelegateYield> {
+        if delegate_account_vec.len() != 32 {
+            return None;
+        }
+
+        let delegate: T::AccountId =
+            T::AccountId::decode(&mut delegate_account_vec.as_bytes_ref()).ok()?;
+        DelegateYields::<T>::get(netuid, delegate)
+    }
+}
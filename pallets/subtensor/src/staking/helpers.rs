@@ -1,14 +1,12 @@
 use super::*;
-use frame_support::{
-    storage::IterableStorageDoubleMap,
-    traits::{
-        tokens::{
-            fungible::{Balanced as _, Inspect as _, Mutate as _},
-            Fortitude, Precision, Preservation,
-        },
-        Imbalance,
+use frame_support::traits::{
+    tokens::{
+        fungible::{Balanced as _, Inspect as _, Mutate as _},
+        DepositConsequence, Fortitude, Precision, Preservation, Provenance,
     },
+    Get, Imbalance,
 };
+use frame_support::weights::Weight;
 
 impl<T: Config> Pallet<T> {
     // Returns true if the passed hotkey allow delegative staking.
@@ -17,12 +15,67 @@ impl<T: Config> Pallet<T> {
         Delegates::<T>::contains_key(hotkey)
     }
 
+    // Returns true if the hotkey accepts stake from coldkeys other than its owner. Every
+    // hotkey does at its take unless it opted out with set_delegate_availability.
+    //
+    pub fn hotkey_accepts_delegation(hotkey: &T::AccountId) -> bool {
+        !DelegationOptOut::<T>::get(hotkey)
+    }
+
     // Sets the hotkey as a delegate with take.
     //
     pub fn delegate_hotkey(hotkey: &T::AccountId, take: u16) {
         Delegates::<T>::insert(hotkey, take);
     }
 
+    // Records a hotkey that accepts delegated stake as a delegate at the default take the first
+    // time it takes stake from a coldkey other than its owner. From then on its take is its own
+    // and no longer follows DefaultTake.
+    //
+    pub fn delegate_hotkey_if_implicit(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+    ) -> dispatch::DispatchResult {
+        if Self::hotkey_is_delegate(hotkey) || Self::coldkey_owns_hotkey(coldkey, hotkey) {
+            return Ok(());
+        }
+        let take: u16 = Self::get_default_delegate_take();
+        Self::ensure_delegate_take_in_range(take)?;
+        Self::delegate_hotkey(hotkey, take);
+
+        let owner: T::AccountId = Self::get_owning_coldkey_for_hotkey(hotkey);
+        log::debug!(
+            "DelegateAdded( coldkey:{:?}, hotkey:{:?}, take:{:?} )",
+            owner,
+            hotkey,
+            take
+        );
+        Self::deposit_event(Event::DelegateAdded(owner.clone(), hotkey.clone(), take));
+        Self::deposit_event(Event::DelegateUpdated {
+            coldkey: owner,
+            hotkey: hotkey.clone(),
+            old_take: None,
+            new_take: take,
+        });
+        Ok(())
+    }
+
+    // Ensures a delegate take, normalized to u16::MAX == 100%, is within the
+    // MinDelegateTake ..= MaxDelegateTake range. Every path that sets a new take goes through
+    // it, hotkey swaps carry the existing take over unchanged.
+    //
+    pub fn ensure_delegate_take_in_range(take: u16) -> dispatch::DispatchResult {
+        ensure!(
+            take >= MinDelegateTake::<T>::get(),
+            Error::<T>::DelegateTakeTooLow
+        );
+        ensure!(
+            take <= MaxDelegateTake::<T>::get(),
+            Error::<T>::DelegateTakeTooHigh
+        );
+        Ok(())
+    }
+
     // Returns the total amount of stake in the staking table.
     //
     pub fn get_total_stake() -> u64 {
@@ -103,7 +156,7 @@ impl<T: Config> Pallet<T> {
     //
     pub fn create_account_if_non_existent(coldkey: &T::AccountId, hotkey: &T::AccountId) {
         if !Self::hotkey_account_exists(hotkey) {
-            Stake::<T>::insert(hotkey, coldkey, 0);
+            Self::insert_stake_entry(hotkey, coldkey, 0);
             Owner::<T>::insert(hotkey, coldkey);
 
             // Update OwnedHotkeys map
@@ -171,6 +224,46 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Ensures `coldkey` and `hotkey` are used in their own roles when registering or staking.
+    ///
+    /// A hotkey owned by another coldkey can never sign as a coldkey. Accounts acting as both
+    /// their own hotkey and coldkey are grandfathered until `StrictKeyUsageSeparation` is
+    /// enabled, which also rejects hotkeys that already own hotkeys or hold stake as a coldkey.
+    ///
+    /// # Arguments
+    /// * `coldkey` - The coldkey account ID.
+    /// * `hotkey` - The hotkey account ID.
+    ///
+    /// # Errors
+    /// * `ColdkeyIsRegisteredHotkey` - The coldkey is in use as a hotkey.
+    /// * `HotkeyIsInUseAsColdkey` - The hotkey is in use as a coldkey.
+    pub fn ensure_key_usage_separation(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+    ) -> Result<(), Error<T>> {
+        let strict: bool = StrictKeyUsageSeparation::<T>::get();
+        if Self::hotkey_account_exists(coldkey) {
+            ensure!(
+                !strict && Owner::<T>::get(coldkey) == *coldkey,
+                Error::<T>::ColdkeyIsRegisteredHotkey
+            );
+        }
+        if strict {
+            ensure!(coldkey != hotkey, Error::<T>::ColdkeyIsRegisteredHotkey);
+            ensure!(
+                OwnedHotkeys::<T>::get(hotkey).is_empty()
+                    && StakingHotkeys::<T>::get(hotkey).is_empty(),
+                Error::<T>::HotkeyIsInUseAsColdkey
+            );
+        }
+        Ok(())
+    }
+
+    /// Sets whether `ensure_key_usage_separation` also rejects self-owned combined keys.
+    pub fn set_strict_key_usage_separation(strict: bool) {
+        StrictKeyUsageSeparation::<T>::put(strict);
+    }
+
     /// Returns true if the cold-hot staking account has enough balance to fulfill the decrement.
     ///
     /// # Arguments
@@ -229,11 +322,12 @@ impl<T: Config> Pallet<T> {
             coldkey,
             TotalColdkeyStake::<T>::get(coldkey).saturating_add(increment),
         );
+        Self::update_stake_provider(coldkey);
         TotalHotkeyStake::<T>::insert(
             hotkey,
             TotalHotkeyStake::<T>::get(hotkey).saturating_add(increment),
         );
-        Stake::<T>::insert(
+        Self::insert_stake_entry(
             hotkey,
             coldkey,
             Stake::<T>::get(hotkey, coldkey).saturating_add(increment),
@@ -246,6 +340,8 @@ impl<T: Config> Pallet<T> {
             staking_hotkeys.push(hotkey.clone());
             StakingHotkeys::<T>::insert(coldkey, staking_hotkeys);
         }
+
+        Self::deposit_stake_added_event(coldkey, hotkey, increment);
     }
 
     // Decreases the stake on the cold - hot pairing by the decrement while decreasing other counters.
@@ -256,18 +352,108 @@ impl<T: Config> Pallet<T> {
         decrement: u64,
     ) {
         TotalColdkeyStake::<T>::mutate(coldkey, |old| *old = old.saturating_sub(decrement));
+        Self::update_stake_provider(coldkey);
         TotalHotkeyStake::<T>::insert(
             hotkey,
             TotalHotkeyStake::<T>::get(hotkey).saturating_sub(decrement),
         );
-        Stake::<T>::insert(
-            hotkey,
-            coldkey,
-            Stake::<T>::get(hotkey, coldkey).saturating_sub(decrement),
-        );
+        let new_stake: u64 = Stake::<T>::get(hotkey, coldkey).saturating_sub(decrement);
+        Self::insert_stake_entry(hotkey, coldkey, new_stake);
         TotalStake::<T>::put(TotalStake::<T>::get().saturating_sub(decrement));
+        Self::clamp_stake_lock(hotkey, coldkey);
+
+        // A pair without stake has no emission for its delta to hold back.
+        if new_stake == 0 {
+            StakeDeltaSinceLastEmissionDrain::<T>::remove(hotkey, coldkey);
+        }
 
         // TODO: Tech debt: Remove StakingHotkeys entry if stake goes to 0
+
+        Self::deposit_stake_removed_event(coldkey, hotkey, decrement);
+    }
+
+    /// Emits StakeAdded for stake credited to a hotkey-coldkey pair, with the totals after
+    /// the change. Nothing is emitted for a zero amount.
+    pub fn deposit_stake_added_event(coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64) {
+        if amount == 0 {
+            return;
+        }
+        Self::deposit_event(Event::StakeAdded(
+            coldkey.clone(),
+            hotkey.clone(),
+            amount,
+            Self::get_total_stake_for_hotkey(hotkey),
+            Self::get_total_stake_for_coldkey(coldkey),
+        ));
+    }
+
+    /// Emits StakeRemoved for stake debited from a hotkey-coldkey pair, with the totals after
+    /// the change. Nothing is emitted for a zero amount.
+    pub fn deposit_stake_removed_event(coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64) {
+        if amount == 0 {
+            return;
+        }
+        Self::deposit_event(Event::StakeRemoved(
+            coldkey.clone(),
+            hotkey.clone(),
+            amount,
+            Self::get_total_stake_for_hotkey(hotkey),
+            Self::get_total_stake_for_coldkey(coldkey),
+        ));
+    }
+
+    /// Weight of one StakeAdded or StakeRemoved event: the two totals it reports and the
+    /// event deposit itself.
+    pub fn stake_event_weight() -> Weight {
+        T::DbWeight::get().reads_writes(3, 2)
+    }
+
+    /// Writes the Stake entry of a hotkey-coldkey pair, counting a new pair in
+    /// HotkeyNominatorCount.
+    pub fn insert_stake_entry(hotkey: &T::AccountId, coldkey: &T::AccountId, stake: u64) {
+        if !Stake::<T>::contains_key(hotkey, coldkey) {
+            HotkeyNominatorCount::<T>::mutate(hotkey, |count| *count = count.saturating_add(1));
+        }
+        Stake::<T>::insert(hotkey, coldkey, stake);
+    }
+
+    /// Removes the Stake entry of a hotkey-coldkey pair along with its count in
+    /// HotkeyNominatorCount.
+    pub fn remove_stake_entry(hotkey: &T::AccountId, coldkey: &T::AccountId) {
+        if Stake::<T>::contains_key(hotkey, coldkey) {
+            Stake::<T>::remove(hotkey, coldkey);
+            HotkeyNominatorCount::<T>::mutate(hotkey, |count| *count = count.saturating_sub(1));
+        }
+    }
+
+    /// Holds a provider reference on the coldkey's account while it has stake and
+    /// KeepStakingAccountsAlive is set, so the account is not reaped when its free balance
+    /// drops below the existential deposit. The reference is released once the stake is gone,
+    /// burning any free balance left below the existential deposit.
+    pub fn update_stake_provider(coldkey: &T::AccountId) {
+        let provide: bool =
+            T::KeepStakingAccountsAlive::get() && TotalColdkeyStake::<T>::get(coldkey) > 0;
+        let held: bool = StakeProviders::<T>::get(coldkey);
+        if provide && !held {
+            frame_system::Pallet::<T>::inc_providers(coldkey);
+            StakeProviders::<T>::insert(coldkey, true);
+        } else if !provide && held {
+            // A free balance below the existential deposit survives only through this
+            // provider. Sweep it through the currency first, so the account is not reaped
+            // with the dust left out of the total issuance.
+            if frame_system::Pallet::<T>::providers(coldkey) == 1
+                && frame_system::Pallet::<T>::consumers(coldkey) == 0
+            {
+                let free: u64 = T::Currency::balance(coldkey);
+                if free > 0 && free < T::Currency::minimum_balance() {
+                    let _ = Self::kill_coldkey_account(coldkey, free);
+                }
+            }
+            // Kept while the account has consumers depending on this last provider.
+            if frame_system::Pallet::<T>::dec_providers(coldkey).is_ok() {
+                StakeProviders::<T>::remove(coldkey);
+            }
+        }
     }
 
     /// Empties the stake associated with a given coldkey-hotkey account pairing.
@@ -288,9 +474,11 @@ impl<T: Config> Pallet<T> {
     ) -> u64 {
         let current_stake: u64 = Stake::<T>::get(hotkey, coldkey);
         TotalColdkeyStake::<T>::mutate(coldkey, |old| *old = old.saturating_sub(current_stake));
+        Self::update_stake_provider(coldkey);
         TotalHotkeyStake::<T>::mutate(hotkey, |stake| *stake = stake.saturating_sub(current_stake));
-        Stake::<T>::remove(hotkey, coldkey);
+        Self::remove_stake_entry(hotkey, coldkey);
         TotalStake::<T>::mutate(|stake| *stake = stake.saturating_sub(current_stake));
+        Self::clamp_stake_lock(hotkey, coldkey);
 
         // Update StakingHotkeys map
         let mut staking_hotkeys = StakingHotkeys::<T>::get(coldkey);
@@ -300,17 +488,22 @@ impl<T: Config> Pallet<T> {
         // Update stake delta
         StakeDeltaSinceLastEmissionDrain::<T>::remove(hotkey, coldkey);
 
+        Self::deposit_stake_removed_event(coldkey, hotkey, current_stake);
+
         current_stake
     }
 
     /// Clears the nomination for an account, if it is a nominator account and the stake is below the minimum required threshold.
+    /// Nominations with locked stake are kept until the lock expires.
     pub fn clear_small_nomination_if_required(
         hotkey: &T::AccountId,
         coldkey: &T::AccountId,
         stake: u64,
     ) {
         // Verify if the account is a nominator account by checking ownership of the hotkey by the coldkey.
-        if !Self::coldkey_owns_hotkey(coldkey, hotkey) {
+        if !Self::coldkey_owns_hotkey(coldkey, hotkey)
+            && Self::get_locked_stake(hotkey, coldkey) == 0
+        {
             // If the stake is below the minimum required, it's considered a small nomination and needs to be cleared.
             if stake < Self::get_nominator_min_required_stake() {
                 // Remove the stake from the nominator account. (this is a more forceful unstake operation which )
@@ -324,13 +517,10 @@ impl<T: Config> Pallet<T> {
 
     /// Clears small nominations for all accounts.
     ///
-    /// WARN: This is an O(N) operation, where N is the number of staking accounts. It should be
-    /// used with caution.
+    /// Only the first page of the Stake map is cleared here, the rest is cleared from the
+    /// stored cursor in on_idle. Calling it again restarts the pass from the beginning.
     pub fn clear_small_nominations() {
-        // Loop through all staking accounts to identify and clear nominations below the minimum stake.
-        for (hotkey, coldkey, stake) in Stake::<T>::iter() {
-            Self::clear_small_nomination_if_required(&hotkey, &coldkey, stake);
-        }
+        Self::clear_small_nominations_page(None);
     }
 
     pub fn add_balance_to_coldkey_account(
@@ -341,6 +531,15 @@ impl<T: Config> Pallet<T> {
         let _ = T::Currency::deposit(coldkey, amount, Precision::BestEffort);
     }
 
+    /// Returns true if `amount` can be credited to the coldkey without being lost, i.e. the
+    /// account exists or the amount reaches the existential deposit.
+    pub fn can_add_balance_to_coldkey_account(
+        coldkey: &T::AccountId,
+        amount: <<T as Config>::Currency as fungible::Inspect<<T as system::Config>::AccountId>>::Balance,
+    ) -> bool {
+        T::Currency::can_deposit(coldkey, amount, Provenance::Extant) == DepositConsequence::Success
+    }
+
     pub fn set_balance_on_coldkey_account(
         coldkey: &T::AccountId,
         amount: <<T as Config>::Currency as fungible::Inspect<<T as system::Config>::AccountId>>::Balance,
@@ -422,21 +621,21 @@ impl<T: Config> Pallet<T> {
         Ok(credit)
     }
 
-    pub fn unstake_all_coldkeys_from_hotkey_account(hotkey: &T::AccountId) {
-        // Iterate through all coldkeys that have a stake on this hotkey account.
-        for (delegate_coldkey_i, stake_i) in
-            <Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter_prefix(
-                hotkey,
-            )
-        {
-            // Remove the stake from the coldkey - hotkey pairing.
-            Self::decrease_stake_on_coldkey_hotkey_account(&delegate_coldkey_i, hotkey, stake_i);
+    /// Returns the stake of a coldkey on the hotkey to the coldkey, except for stake that is
+    /// locked, which stays on the hotkey until its lock expires.
+    pub fn return_unlocked_stake(coldkey: &T::AccountId, hotkey: &T::AccountId, stake: u64) {
+        let unlocked: u64 = stake.saturating_sub(Self::get_locked_stake(hotkey, coldkey));
+        if unlocked == 0 {
+            return;
+        }
 
-            // Add the balance to the coldkey account.
-            Self::add_balance_to_coldkey_account(&delegate_coldkey_i, stake_i);
+        // Remove the stake from the coldkey - hotkey pairing.
+        Self::decrease_stake_on_coldkey_hotkey_account(coldkey, hotkey, unlocked);
 
-            // Remove stake delta
-            StakeDeltaSinceLastEmissionDrain::<T>::remove(hotkey, &delegate_coldkey_i);
-        }
+        // Add the balance to the coldkey account.
+        Self::add_balance_to_coldkey_account(coldkey, unlocked);
+
+        // Remove stake delta
+        StakeDeltaSinceLastEmissionDrain::<T>::remove(hotkey, coldkey);
     }
 }
@@ -5,7 +5,7 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Args:
     /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
-    ///     - The signature of the caller's coldkey.
+    ///     - The signature of the caller's coldkey, or of the hotkey's delegate manager.
     ///
     /// * 'hotkey' (T::AccountId):
     ///     - The hotkey we are delegating (must be owned by the coldkey.)
@@ -30,13 +30,16 @@ impl<T: Config> Pallet<T> {
     /// * 'DelegateTakeTooLow':
     ///     - The delegate is setting a take which is not greater than the previous.
     ///
+    /// * 'PublicGoodTakeLocked':
+    ///     - The hotkey is a public good delegate not yet released from its take.
+    ///
     pub fn do_increase_take(
         origin: T::RuntimeOrigin,
         hotkey: T::AccountId,
         take: u16,
     ) -> dispatch::DispatchResult {
-        // --- 1. We check the coldkey signature.
-        let coldkey = ensure_signed(origin)?;
+        // --- 1. We check the coldkey signature, or that of the hotkey's manager.
+        let coldkey = Self::ensure_take_origin(origin, &hotkey)?;
         log::debug!(
             "do_increase_take( origin:{:?} hotkey:{:?}, take:{:?} )",
             coldkey,
@@ -54,8 +57,10 @@ impl<T: Config> Pallet<T> {
         }
 
         // --- 4. Ensure take is within the min ..= InitialDefaultDelegateTake (18%) range
-        let max_take = MaxDelegateTake::<T>::get();
-        ensure!(take <= max_take, Error::<T>::DelegateTakeTooHigh);
+        Self::ensure_delegate_take_in_range(take)?;
+
+        // --- 4.1 Ensure a public good delegate has been released from its take.
+        Self::ensure_take_increase_unlocked(&hotkey)?;
 
         // --- 5. Enforce the rate limit (independently on do_add_stake rate limits)
         let block: u64 = Self::get_current_block_as_u64();
@@ -71,16 +76,30 @@ impl<T: Config> Pallet<T> {
         Self::set_last_tx_block_delegate_take(&coldkey, block);
 
         // --- 6. Set the new take value.
+        let old_take: u16 = Delegates::<T>::get(&hotkey);
         Delegates::<T>::insert(hotkey.clone(), take);
 
         // --- 7. Emit the take value.
         log::debug!(
-            "TakeIncreased( coldkey:{:?}, hotkey:{:?}, take:{:?} )",
+            "TakeIncreased( coldkey:{:?}, hotkey:{:?}, old_take:{:?}, take:{:?} )",
             coldkey,
             hotkey,
+            old_take,
             take
         );
-        Self::deposit_event(Event::TakeIncreased(coldkey, hotkey, take));
+        Self::deposit_event(Event::TakeIncreased(
+            coldkey.clone(),
+            hotkey.clone(),
+            old_take,
+            take,
+        ));
+        T::OnTakeChanged::on_take_changed(&hotkey, old_take, take);
+        Self::deposit_event(Event::DelegateUpdated {
+            coldkey,
+            hotkey,
+            old_take: Some(old_take),
+            new_take: take,
+        });
 
         // --- 8. Ok and return.
         Ok(())
@@ -0,0 +1,196 @@
+use super::*;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+/// Highest conviction a stake lock can carry.
+pub const MAX_CONVICTION: u8 = 6;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic lock_stake.
+    ///
+    /// Locks `amount` of the caller's stake on `hotkey` for `conviction` lock periods. While
+    /// locked, the stake counts (1 + conviction) times towards the hotkey's governance weight.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey the stake is on.
+    ///
+    /// * 'amount' (u64):
+    ///     - The amount of stake to lock.
+    ///
+    /// * 'conviction' (u8):
+    ///     - The conviction multiplier, between 1 and MAX_CONVICTION.
+    ///
+    /// # Event:
+    /// * StakeLocked;
+    ///     - On successfully locking the stake.
+    ///
+    /// # Raises:
+    /// * 'InvalidConviction':
+    ///     - The conviction is out of range.
+    ///
+    /// * 'NotEnoughStakeToWithdraw':
+    ///     - The caller has less stake on the hotkey than the amount to lock.
+    ///
+    /// * 'StakeLockCannotBeReduced':
+    ///     - An existing lock is larger or unlocks later than the new one.
+    ///
+    pub fn do_lock_stake(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        amount: u64,
+        conviction: u8,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            (1..=MAX_CONVICTION).contains(&conviction),
+            Error::<T>::InvalidConviction
+        );
+        ensure!(
+            Self::has_enough_stake(&coldkey, &hotkey, amount),
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+
+        let unlock_block: u64 = Self::get_current_block_as_u64()
+            .saturating_add(T::ConvictionLockPeriod::get().saturating_mul(conviction as u64));
+
+        // An existing lock may only be extended, never reduced.
+        if let Some((old_amount, old_conviction, old_unlock_block)) =
+            StakeLocks::<T>::get(&hotkey, &coldkey)
+        {
+            ensure!(
+                amount >= old_amount
+                    && conviction >= old_conviction
+                    && unlock_block >= old_unlock_block,
+                Error::<T>::StakeLockCannotBeReduced
+            );
+            Self::remove_conviction_bonus(&hotkey, old_amount, old_conviction);
+        }
+
+        Self::insert_stake_lock(&hotkey, &coldkey, (amount, conviction, unlock_block));
+
+        log::debug!(
+            "StakeLocked( coldkey:{:?}, hotkey:{:?}, amount:{:?}, conviction:{:?}, unlock_block:{:?} )",
+            coldkey,
+            hotkey,
+            amount,
+            conviction,
+            unlock_block
+        );
+        Self::deposit_event(Event::StakeLocked {
+            coldkey,
+            hotkey,
+            amount,
+            conviction,
+            unlock_block,
+        });
+        Ok(())
+    }
+
+    /// Returns the stake a coldkey has locked on a hotkey.
+    pub fn get_locked_stake(hotkey: &T::AccountId, coldkey: &T::AccountId) -> u64 {
+        StakeLocks::<T>::get(hotkey, coldkey)
+            .map(|(amount, _, _)| amount)
+            .unwrap_or(0)
+    }
+
+    /// Returns the stake of a hotkey used to rank senate and root membership: its total stake
+    /// plus `amount * conviction` for every lock on it.
+    pub fn get_governance_stake_for_hotkey(hotkey: &T::AccountId) -> u64 {
+        Self::get_total_stake_for_hotkey(hotkey)
+            .saturating_add(HotkeyConvictionBonus::<T>::get(hotkey))
+    }
+
+    /// Releases every stake lock scheduled to unlock at `block`.
+    ///
+    /// Returns the weight consumed.
+    pub fn unlock_expired_stake_locks(block: u64) -> Weight {
+        let expirations: Vec<(T::AccountId, T::AccountId)> = StakeLockExpirations::<T>::take(block);
+        let entries: u64 = expirations.len() as u64;
+        for (hotkey, coldkey) in expirations {
+            // Locks that were extended or moved by a swap are rescheduled under another block.
+            match StakeLocks::<T>::get(&hotkey, &coldkey) {
+                Some((amount, conviction, unlock_block)) if unlock_block == block => {
+                    StakeLocks::<T>::remove(&hotkey, &coldkey);
+                    Self::remove_conviction_bonus(&hotkey, amount, conviction);
+                    Self::deposit_event(Event::StakeUnlocked {
+                        coldkey,
+                        hotkey,
+                        amount,
+                    });
+                }
+                _ => continue,
+            }
+        }
+        T::DbWeight::get().reads_writes(
+            entries.saturating_mul(2).saturating_add(1),
+            entries.saturating_mul(2).saturating_add(1),
+        )
+    }
+
+    /// Moves a stake lock along with its stake during a hotkey or coldkey swap. If the
+    /// destination already holds a lock, the two are merged: the amounts add up, the later
+    /// unlock block is kept and the lower conviction applies to the merged amount.
+    pub fn move_stake_lock(
+        old_hotkey: &T::AccountId,
+        old_coldkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+    ) {
+        let Some(lock) = StakeLocks::<T>::take(old_hotkey, old_coldkey) else {
+            return;
+        };
+        let (amount, conviction, unlock_block) = lock;
+        Self::remove_conviction_bonus(old_hotkey, amount, conviction);
+        let merged = match StakeLocks::<T>::take(new_hotkey, new_coldkey) {
+            Some((dest_amount, dest_conviction, dest_unlock_block)) => {
+                Self::remove_conviction_bonus(new_hotkey, dest_amount, dest_conviction);
+                (
+                    amount.saturating_add(dest_amount),
+                    conviction.min(dest_conviction),
+                    unlock_block.max(dest_unlock_block),
+                )
+            }
+            None => lock,
+        };
+        Self::insert_stake_lock(new_hotkey, new_coldkey, merged);
+    }
+
+    /// Shrinks the lock of a coldkey on a hotkey to the stake it has left, after the stake was
+    /// decreased, and lowers the conviction bonus of the hotkey to match.
+    pub fn clamp_stake_lock(hotkey: &T::AccountId, coldkey: &T::AccountId) {
+        let Some((amount, conviction, unlock_block)) = StakeLocks::<T>::get(hotkey, coldkey) else {
+            return;
+        };
+        let stake: u64 = Stake::<T>::get(hotkey, coldkey);
+        if stake >= amount {
+            return;
+        }
+        Self::remove_conviction_bonus(hotkey, amount.saturating_sub(stake), conviction);
+        if stake == 0 {
+            // The scheduled expiry finds no lock and is skipped.
+            StakeLocks::<T>::remove(hotkey, coldkey);
+        } else {
+            StakeLocks::<T>::insert(hotkey, coldkey, (stake, conviction, unlock_block));
+        }
+    }
+
+    fn insert_stake_lock(hotkey: &T::AccountId, coldkey: &T::AccountId, lock: (u64, u8, u64)) {
+        let (amount, conviction, unlock_block) = lock;
+        StakeLocks::<T>::insert(hotkey, coldkey, lock);
+        HotkeyConvictionBonus::<T>::mutate(hotkey, |bonus| {
+            *bonus = bonus.saturating_add(amount.saturating_mul(conviction as u64))
+        });
+        StakeLockExpirations::<T>::append(unlock_block, (hotkey.clone(), coldkey.clone()));
+    }
+
+    fn remove_conviction_bonus(hotkey: &T::AccountId, amount: u64, conviction: u8) {
+        HotkeyConvictionBonus::<T>::mutate(hotkey, |bonus| {
+            *bonus = bonus.saturating_sub(amount.saturating_mul(conviction as u64))
+        });
+    }
+}
@@ -2,7 +2,16 @@ use super::*;
 pub mod add_stake;
 pub mod become_delegate;
 pub mod decrease_take;
+pub mod delegate_activity;
+pub mod delegate_manager;
+pub mod delegate_stats;
+pub mod delegate_yield;
 pub mod helpers;
 pub mod increase_take;
+pub mod lock_stake;
+pub mod propose_take;
+pub mod public_good;
 pub mod remove_stake;
 pub mod set_children;
+pub mod stake_checkpoints;
+pub mod stake_portfolio;
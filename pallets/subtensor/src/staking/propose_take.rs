@@ -0,0 +1,136 @@
+use super::*;
+use frame_support::traits::Get;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic propose_take_change
+    ///
+    /// Records a take change that only applies once the owning coldkey confirms it with
+    /// `confirm_take_change`, giving co-signers of a multisig coldkey a chance to review it.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the caller's coldkey, or of the hotkey's delegate manager.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey we are delegating (must be owned by the coldkey.)
+    ///
+    /// * 'take' (u16):
+    ///     - The proposed stake proportion that this hotkey takes from delegations.
+    ///
+    /// # Event:
+    /// * TakeChangeProposed;
+    ///     - On successfully recording the proposal.
+    ///
+    /// # Raises:
+    /// * 'NotRegistered':
+    ///     - The hotkey we are delegating is not registered on the network.
+    ///
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey we are delegating is not owned by the calling coldkey.
+    ///
+    /// * 'DelegateTakeTooLow' / 'DelegateTakeTooHigh':
+    ///     - The take is outside the min ..= max range.
+    ///
+    pub fn do_propose_take_change(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        take: u16,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the coldkey signature, or that of the hotkey's manager.
+        let coldkey = Self::ensure_take_origin(origin, &hotkey)?;
+        log::debug!(
+            "do_propose_take_change( origin:{:?} hotkey:{:?}, take:{:?} )",
+            coldkey,
+            hotkey,
+            take
+        );
+
+        // --- 2. Ensure we are delegating a known key.
+        //        Ensure that the coldkey is the owner.
+        Self::do_take_checks(&coldkey, &hotkey)?;
+
+        // --- 3. Ensure take is within the min ..= max range, or zero for a public good delegate.
+        Self::ensure_take_in_range_for(&hotkey, take)?;
+
+        // --- 4. Record the proposal, replacing any previous one.
+        let expiry_block: u64 =
+            Self::get_current_block_as_u64().saturating_add(T::TakeChangeProposalExpiry::get());
+        PendingTakeChanges::<T>::insert(&hotkey, (take, expiry_block));
+
+        // --- 5. Emit the proposal.
+        log::debug!(
+            "TakeChangeProposed( coldkey:{:?}, hotkey:{:?}, take:{:?}, expiry_block:{:?} )",
+            coldkey,
+            hotkey,
+            take,
+            expiry_block
+        );
+        Self::deposit_event(Event::TakeChangeProposed {
+            coldkey,
+            hotkey,
+            take,
+            expiry_block,
+        });
+
+        // --- 6. Ok and return.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic confirm_take_change
+    ///
+    /// Applies the pending take change through the regular increase/decrease path, so the
+    /// usual monotonicity and rate limit checks still hold. A proposal of the current take is
+    /// cleared without changing anything.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the caller's coldkey, or of the hotkey's delegate manager.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey with a pending take change.
+    ///
+    /// # Event:
+    /// * TakeIncreaseScheduled / TakeDecreased;
+    ///     - On successfully queueing an increase or applying a decrease.
+    ///
+    /// # Raises:
+    /// * 'NoPendingTakeChange':
+    ///     - There is no proposal for this hotkey.
+    ///
+    /// * 'TakeChangeProposalExpired':
+    ///     - The proposal has passed its expiry block.
+    ///
+    pub fn do_confirm_take_change(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the coldkey signature, or that of the hotkey's manager.
+        let coldkey = Self::ensure_take_origin(origin.clone(), &hotkey)?;
+
+        // --- 2. Ensure that the coldkey is the owner.
+        Self::do_take_checks(&coldkey, &hotkey)?;
+
+        // --- 3. Fetch the proposal and ensure it has not expired.
+        let (take, expiry_block) =
+            PendingTakeChanges::<T>::get(&hotkey).ok_or(Error::<T>::NoPendingTakeChange)?;
+        ensure!(
+            Self::get_current_block_as_u64() <= expiry_block,
+            Error::<T>::TakeChangeProposalExpired
+        );
+
+        // --- 4. Apply the take through the direct path, increases through the scheduler queue.
+        // Confirming the current take is a no-op.
+        let current_take: u16 = Delegates::<T>::get(&hotkey);
+        if take > current_take {
+            Self::do_schedule_increase_take(origin, hotkey.clone(), take)?;
+        } else if take < current_take {
+            Self::do_decrease_take(origin, hotkey.clone(), take)?;
+        }
+
+        // --- 5. Clear the proposal.
+        PendingTakeChanges::<T>::remove(&hotkey);
+
+        // --- 6. Ok and return.
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+use frame_support::traits::Get;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_public_good_delegate.
+    ///
+    /// Opting in lets the delegate decrease its take to zero, below the min delegate take, and
+    /// locks its take from increasing. Opting out releases the lock only once
+    /// PublicGoodReleaseDelay blocks have passed, so delegators can rely on the take until then.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the owning coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The delegate hotkey.
+    ///
+    /// * 'enabled' (bool):
+    ///     - Whether to opt in or out.
+    ///
+    /// # Event:
+    /// * PublicGoodDelegateSet;
+    ///     - On successfully opting in or starting to opt out.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     - The hotkey is not a delegate.
+    ///
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey is not owned by the calling coldkey.
+    ///
+    /// * 'NotPublicGoodDelegate':
+    ///     - Opting out a hotkey that never opted in.
+    ///
+    pub fn do_set_public_good_delegate(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        enabled: bool,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        Self::do_take_checks(&coldkey, &hotkey)?;
+
+        let release_block: u64 = if enabled {
+            u64::MAX
+        } else {
+            let current: u64 =
+                PublicGoodDelegates::<T>::get(&hotkey).ok_or(Error::<T>::NotPublicGoodDelegate)?;
+            // Opting out again does not restart the delay.
+            current.min(
+                Self::get_current_block_as_u64().saturating_add(T::PublicGoodReleaseDelay::get()),
+            )
+        };
+        PublicGoodDelegates::<T>::insert(&hotkey, release_block);
+
+        log::debug!(
+            "PublicGoodDelegateSet( coldkey:{:?}, hotkey:{:?}, release_block:{:?} )",
+            coldkey,
+            hotkey,
+            release_block
+        );
+        Self::deposit_event(Event::PublicGoodDelegateSet {
+            coldkey,
+            hotkey,
+            release_block,
+        });
+        Ok(())
+    }
+
+    /// Ensures `take` is within the min ..= max delegate take range, or is zero for a public
+    /// good delegate.
+    pub fn ensure_take_in_range_for(hotkey: &T::AccountId, take: u16) -> dispatch::DispatchResult {
+        if take == 0 && PublicGoodDelegates::<T>::contains_key(hotkey) {
+            return Ok(());
+        }
+        Self::ensure_delegate_take_in_range(take)
+    }
+
+    /// Ensures the take of `hotkey` may increase: it is not a public good delegate, or its
+    /// release block has passed, in which case the flag is cleared.
+    pub fn ensure_take_increase_unlocked(hotkey: &T::AccountId) -> dispatch::DispatchResult {
+        if let Some(release_block) = PublicGoodDelegates::<T>::get(hotkey) {
+            ensure!(
+                Self::get_current_block_as_u64() >= release_block,
+                Error::<T>::PublicGoodTakeLocked
+            );
+            PublicGoodDelegates::<T>::remove(hotkey);
+        }
+        Ok(())
+    }
+}
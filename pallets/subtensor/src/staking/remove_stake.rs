@@ -27,6 +27,9 @@ impl<T: Config> Pallet<T> {
     /// * 'NotEnoughStakeToWithdraw':
     ///     -  Thrown if there is not enough stake on the hotkey to withdwraw this amount.
     ///
+    /// * 'AmountBelowExistentialDeposit':
+    ///     -  Thrown if the coldkey does not exist and the amount is below the existential deposit.
+    ///
     /// * 'TxRateLimitExceeded':
     ///     -  Thrown if key has hit transaction rate limit
     ///
@@ -44,43 +47,25 @@ impl<T: Config> Pallet<T> {
             stake_to_be_removed
         );
 
-        // Ensure that the hotkey account exists this is only possible through registration.
-        ensure!(
-            Self::hotkey_account_exists(&hotkey),
-            Error::<T>::HotKeyAccountNotExists
-        );
-
-        // Ensure that the hotkey allows delegation or that the hotkey is owned by the calling coldkey.
-        ensure!(
-            Self::hotkey_is_delegate(&hotkey) || Self::coldkey_owns_hotkey(&coldkey, &hotkey),
-            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
-        );
-
-        // Ensure that the stake amount to be removed is above zero.
-        ensure!(stake_to_be_removed > 0, Error::<T>::StakeToWithdrawIsZero);
-
-        // Ensure that the hotkey has enough stake to withdraw.
-        ensure!(
-            Self::has_enough_stake(&coldkey, &hotkey, stake_to_be_removed),
-            Error::<T>::NotEnoughStakeToWithdraw
-        );
-
-        // Ensure we don't exceed stake rate limit
+        // Ensure the stake can leave the hotkey, within the rate limit and any lock.
         let unstakes_this_interval =
-            Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey);
+            Self::ensure_can_remove_stake(&coldkey, &hotkey, stake_to_be_removed)?;
+
+        // Ensure the unstaked amount can be credited without vanishing below the existential deposit.
         ensure!(
-            unstakes_this_interval < Self::get_target_stakes_per_interval(),
-            Error::<T>::UnstakeRateLimitExceeded
+            Self::can_add_balance_to_coldkey_account(&coldkey, stake_to_be_removed),
+            Error::<T>::AmountBelowExistentialDeposit
         );
 
-        // We remove the balance from the hotkey.
-        Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake_to_be_removed);
-
-        // Track this removal in the stake delta.
+        // Track this removal in the stake delta, before the stake is decreased so a
+        // removal down to zero drops the delta with the stake.
         StakeDeltaSinceLastEmissionDrain::<T>::mutate(&hotkey, &coldkey, |stake_delta| {
             *stake_delta = stake_delta.saturating_sub_unsigned(stake_to_be_removed as u128);
         });
 
+        // We remove the balance from the hotkey.
+        Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake_to_be_removed);
+
         // We add the balance to the coldkey.  If the above fails we will not credit this coldkey.
         Self::add_balance_to_coldkey_account(&coldkey, stake_to_be_removed);
 
@@ -106,9 +91,160 @@ impl<T: Config> Pallet<T> {
             hotkey,
             stake_to_be_removed
         );
-        Self::deposit_event(Event::StakeRemoved(hotkey, stake_to_be_removed));
+        Self::record_block_metric(|m| m.stake_ops = m.stake_ops.saturating_add(1));
 
         // Done and ok.
         Ok(())
     }
+
+    /// ---- The implementation for the extrinsic unstake_all_and_reap: Removes the caller's
+    /// entire stake from a hotkey and closes out the staking account.
+    ///
+    /// The stake is credited to the coldkey when possible. If the coldkey does not exist and
+    /// the stake is below the existential deposit, the dust is burned instead so no stake is
+    /// left stranded on the hotkey.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The associated hotkey account.
+    ///
+    /// # Event:
+    /// * StakeRemoved;
+    ///     -  On crediting the stake to the coldkey.
+    ///
+    /// * StakeDustReaped;
+    ///     -  On burning stake below the existential deposit.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if the hotkey is not registered.
+    ///
+    /// * 'StakeToWithdrawIsZero':
+    ///     -  Thrown if the caller has no stake on the hotkey.
+    ///
+    /// * 'StakeIsLocked':
+    ///     -  Thrown if any of the stake is locked.
+    ///
+    /// * 'UnstakeRateLimitExceeded':
+    ///     -  Thrown if key has hit the unstake rate limit.
+    ///
+    pub fn do_unstake_all_and_reap(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_unstake_all_and_reap( origin:{:?} hotkey:{:?} )",
+            coldkey,
+            hotkey
+        );
+
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        let stake: u64 = Self::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
+        ensure!(stake > 0, Error::<T>::StakeToWithdrawIsZero);
+        ensure!(
+            Self::get_locked_stake(&hotkey, &coldkey) == 0,
+            Error::<T>::StakeIsLocked
+        );
+
+        let unstakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &hotkey);
+        ensure!(
+            unstakes_this_interval < Self::get_target_stakes_per_interval(),
+            Error::<T>::UnstakeRateLimitExceeded
+        );
+
+        // Clear the staking account. Its stake delta goes with it, so no delta is left for a
+        // pair without stake.
+        let removed: u64 = Self::empty_stake_on_coldkey_hotkey_account(&coldkey, &hotkey);
+
+        if Self::can_add_balance_to_coldkey_account(&coldkey, removed) {
+            Self::add_balance_to_coldkey_account(&coldkey, removed);
+            log::debug!(
+                "StakeRemoved( hotkey:{:?}, stake_to_be_removed:{:?} )",
+                hotkey,
+                removed
+            );
+        } else {
+            Self::burn_tokens(removed);
+            log::debug!(
+                "StakeDustReaped( coldkey:{:?}, hotkey:{:?}, amount:{:?} )",
+                coldkey,
+                hotkey,
+                removed
+            );
+            Self::deposit_event(Event::StakeDustReaped {
+                coldkey: coldkey.clone(),
+                hotkey: hotkey.clone(),
+                amount: removed,
+            });
+        }
+
+        let block: u64 = Self::get_current_block_as_u64();
+        Self::set_last_tx_block(&coldkey, block);
+        Self::set_stakes_this_interval_for_coldkey_hotkey(
+            &coldkey,
+            &hotkey,
+            unstakes_this_interval.saturating_add(1),
+            block,
+        );
+        Self::record_block_metric(|m| m.stake_ops = m.stake_ops.saturating_add(1));
+
+        Ok(())
+    }
+
+    /// Ensures `coldkey` may remove `amount` of stake from `hotkey` as remove_stake checks it:
+    /// the hotkey exists and is a delegate or owned by the coldkey, the pair holds the amount
+    /// above any lock and is within the unstake rate limit. Returns the stakes of the pair this
+    /// interval.
+    pub fn ensure_can_remove_stake(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+    ) -> Result<u64, DispatchError> {
+        // Ensure that the hotkey account exists this is only possible through registration.
+        ensure!(
+            Self::hotkey_account_exists(hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        // Ensure that the hotkey allows delegation or that the hotkey is owned by the calling coldkey.
+        ensure!(
+            Self::hotkey_is_delegate(hotkey) || Self::coldkey_owns_hotkey(coldkey, hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        // Ensure that the stake amount to be removed is above zero.
+        ensure!(amount > 0, Error::<T>::StakeToWithdrawIsZero);
+
+        // Ensure that the hotkey has enough stake to withdraw.
+        ensure!(
+            Self::has_enough_stake(coldkey, hotkey, amount),
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+
+        // Ensure the stake left after the withdrawal still covers any lock.
+        ensure!(
+            Self::get_stake_for_coldkey_and_hotkey(coldkey, hotkey).saturating_sub(amount)
+                >= Self::get_locked_stake(hotkey, coldkey),
+            Error::<T>::StakeIsLocked
+        );
+
+        // Ensure we don't exceed stake rate limit
+        let unstakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(coldkey, hotkey);
+        ensure!(
+            unstakes_this_interval < Self::get_target_stakes_per_interval(),
+            Error::<T>::UnstakeRateLimitExceeded
+        );
+
+        Ok(unstakes_this_interval)
+    }
 }
@@ -0,0 +1,64 @@
+use super::*;
+use crate::utils::storage_pages::STORAGE_PAGE_SIZE;
+use frame_support::{traits::Get, weights::Weight};
+
+impl<T: Config> Pallet<T> {
+    /// Appends a (block, total_stake, total_emission, take) checkpoint for every delegate once
+    /// every StakeCheckpointInterval blocks, dropping the oldest beyond MaxStakeCheckpoints.
+    ///
+    /// Delegates are checkpointed a page at a time within the block step budget; a pass that
+    /// does not fit continues in the following blocks under the block it started at. A pass
+    /// still unfinished at the next interval is replaced by the new one.
+    pub fn record_stake_checkpoints(block: u64, used_weight: &mut Weight) {
+        let interval: u64 = T::StakeCheckpointInterval::get();
+        if interval > 0 && block.checked_rem(interval) == Some(0) {
+            StakeCheckpointPass::<T>::put((block, None::<Vec<u8>>));
+        }
+
+        while let Some((pass_block, cursor)) = StakeCheckpointPass::<T>::get() {
+            if !Self::try_charge_block_step(used_weight, Self::stake_checkpoint_page_weight()) {
+                return;
+            }
+            let (page, next) = Self::delegates_page(cursor, STORAGE_PAGE_SIZE);
+            for (hotkey, take) in page {
+                Self::record_stake_checkpoint(&hotkey, pass_block, take);
+            }
+            match next {
+                Some(raw_key) => StakeCheckpointPass::<T>::put((pass_block, Some(raw_key))),
+                None => StakeCheckpointPass::<T>::kill(),
+            }
+        }
+    }
+
+    /// Appends a checkpoint taken at `block` to the ring buffer of a delegate.
+    fn record_stake_checkpoint(hotkey: &T::AccountId, block: u64, take: u16) {
+        let max_checkpoints: usize = T::MaxStakeCheckpoints::get() as usize;
+        let total_emission: u64 = Self::get_hotkey_emission_by_netuid(hotkey)
+            .into_iter()
+            .fold(0, |total, (_, emission)| total.saturating_add(emission));
+        let checkpoint = (
+            block,
+            Self::get_total_stake_for_hotkey(hotkey),
+            total_emission,
+            take,
+        );
+
+        StakeCheckpoints::<T>::mutate(hotkey, |checkpoints| {
+            let mut entries: Vec<(u64, u64, u64, u16)> = checkpoints.clone().into_inner();
+            entries.push(checkpoint);
+            let excess: usize = entries.len().saturating_sub(max_checkpoints);
+            *checkpoints =
+                StakeCheckpointsOf::<T>::truncate_from(entries.into_iter().skip(excess).collect());
+        });
+    }
+
+    /// Estimated weight of checkpointing one page of delegates, reading the emission of each
+    /// on every subnet.
+    pub fn stake_checkpoint_page_weight() -> Weight {
+        let subnets: u64 = Self::get_all_subnet_netuids().len() as u64;
+        T::DbWeight::get()
+            .reads_writes(subnets.saturating_add(3), 1)
+            .saturating_mul(STORAGE_PAGE_SIZE as u64)
+            .saturating_add(T::DbWeight::get().reads_writes(1, 1))
+    }
+}
@@ -0,0 +1,224 @@
+use super::*;
+use frame_support::traits::Get;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_stake_portfolio.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the coldkey.
+    ///
+    /// * 'targets' (Vec<(T::AccountId, u16)>):
+    ///     - The hotkeys and their relative weights. Empty to clear the portfolio.
+    ///
+    /// # Event:
+    /// * StakePortfolioSet;
+    ///     - On successfully setting the portfolio.
+    ///
+    /// # Raises:
+    /// * 'TooManyPortfolioHotkeys':
+    ///     - More hotkeys are given than MaxPortfolioHotkeys.
+    ///
+    /// * 'InvalidStakePortfolio':
+    ///     - A hotkey is repeated or the weights sum to zero.
+    ///
+    /// * 'HotKeyAccountNotExists':
+    ///     - A hotkey does not exist.
+    ///
+    /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+    ///     - A hotkey not owned by the coldkey does not accept delegated stake.
+    ///
+    pub fn do_set_stake_portfolio(
+        origin: T::RuntimeOrigin,
+        targets: Vec<(T::AccountId, u16)>,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            targets.len() <= T::MaxPortfolioHotkeys::get() as usize,
+            Error::<T>::TooManyPortfolioHotkeys
+        );
+        if !targets.is_empty() {
+            ensure!(
+                targets.iter().any(|(_, weight)| *weight > 0),
+                Error::<T>::InvalidStakePortfolio
+            );
+        }
+        for (i, (hotkey, _)) in targets.iter().enumerate() {
+            ensure!(
+                !targets
+                    .iter()
+                    .skip(i.saturating_add(1))
+                    .any(|(other, _)| other == hotkey),
+                Error::<T>::InvalidStakePortfolio
+            );
+            ensure!(
+                Self::hotkey_account_exists(hotkey),
+                Error::<T>::HotKeyAccountNotExists
+            );
+            ensure!(
+                Self::hotkey_accepts_delegation(hotkey)
+                    || Self::coldkey_owns_hotkey(&coldkey, hotkey),
+                Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+            );
+        }
+
+        if targets.is_empty() {
+            StakePortfolios::<T>::remove(&coldkey);
+        } else {
+            StakePortfolios::<T>::insert(&coldkey, targets.clone());
+        }
+
+        log::debug!(
+            "StakePortfolioSet( coldkey:{:?}, targets:{:?} )",
+            coldkey,
+            targets
+        );
+        Self::deposit_event(Event::StakePortfolioSet { coldkey, targets });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic rebalance_portfolio.
+    ///
+    /// Splits the coldkey's stake on the portfolio hotkeys by their weights, then moves the
+    /// unlocked stake above each target to the hotkeys below theirs, in portfolio order. Every
+    /// move is checked as remove_stake and add_stake check it and counts toward the rate limit
+    /// of its pair. A nomination that would be left below the nominator minimum is moved in
+    /// full. Hotkeys that cannot take the stake are left below target, and the stake that could
+    /// not be placed, including rounding dust, goes to the first hotkey that takes it.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the coldkey.
+    ///
+    /// # Event:
+    /// * StakePortfolioRebalanced;
+    ///     - On successfully rebalancing the portfolio.
+    ///
+    /// # Raises:
+    /// * 'NoStakePortfolio':
+    ///     - The coldkey has no stake portfolio.
+    ///
+    /// * 'UnstakeRateLimitExceeded' / 'StakeIsLocked':
+    ///     - Stake above a target cannot leave its hotkey.
+    ///
+    /// * 'NoPortfolioHotkeyTakesStake':
+    ///     - No portfolio hotkey can take the stake that was moved.
+    ///
+    pub fn do_rebalance_portfolio(origin: T::RuntimeOrigin) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        let targets: Vec<(T::AccountId, u16)> = StakePortfolios::<T>::get(&coldkey);
+        ensure!(!targets.is_empty(), Error::<T>::NoStakePortfolio);
+
+        let total_weight: u128 = targets
+            .iter()
+            .fold(0, |acc, (_, weight)| acc.saturating_add(*weight as u128));
+        let stakes: Vec<u64> = targets
+            .iter()
+            .map(|(hotkey, _)| Self::get_stake_for_coldkey_and_hotkey(&coldkey, hotkey))
+            .collect();
+        let total_stake: u128 = stakes
+            .iter()
+            .fold(0, |acc, stake| acc.saturating_add(*stake as u128));
+        let block: u64 = Self::get_current_block_as_u64();
+
+        // Take the unlocked stake above target off each hotkey.
+        let mut surplus: u64 = 0;
+        let mut deficits: Vec<(T::AccountId, u64)> = Vec::new();
+        for ((hotkey, weight), stake) in targets.iter().zip(stakes) {
+            let target: u64 = total_stake
+                .saturating_mul(*weight as u128)
+                .checked_div(total_weight)
+                .unwrap_or(0) as u64;
+            if stake > target {
+                let locked: u64 = Self::get_locked_stake(hotkey, &coldkey);
+                let mut amount: u64 = stake
+                    .saturating_sub(target)
+                    .min(stake.saturating_sub(locked));
+                if locked == 0
+                    && !Self::coldkey_owns_hotkey(&coldkey, hotkey)
+                    && stake.saturating_sub(amount) < Self::get_nominator_min_required_stake()
+                {
+                    // Do not leave a dust nomination behind.
+                    amount = stake;
+                }
+                if amount > 0 {
+                    let unstakes_this_interval =
+                        Self::ensure_can_remove_stake(&coldkey, hotkey, amount)?;
+                    Self::move_portfolio_stake(&coldkey, hotkey, amount, false);
+                    Self::set_stakes_this_interval_for_coldkey_hotkey(
+                        &coldkey,
+                        hotkey,
+                        unstakes_this_interval.saturating_add(1),
+                        block,
+                    );
+                    surplus = surplus.saturating_add(amount);
+                }
+            } else if stake < target {
+                deficits.push((hotkey.clone(), target.saturating_sub(stake)));
+            }
+        }
+
+        // Fill the hotkeys below target that take the stake with it.
+        let mut placements: Vec<(T::AccountId, u64)> = Vec::new();
+        let mut moved: u64 = 0;
+        for (hotkey, deficit) in deficits {
+            let amount: u64 = deficit.min(surplus.saturating_sub(moved));
+            if amount > 0 && Self::ensure_can_add_stake(&coldkey, &hotkey, amount).is_ok() {
+                placements.push((hotkey, amount));
+                moved = moved.saturating_add(amount);
+            }
+        }
+        let leftover: u64 = surplus.saturating_sub(moved);
+        if leftover > 0 {
+            if let Some((_, amount)) = placements.first_mut() {
+                *amount = amount.saturating_add(leftover);
+            } else {
+                let (hotkey, _) = targets
+                    .iter()
+                    .find(|(hotkey, _)| {
+                        Self::ensure_can_add_stake(&coldkey, hotkey, leftover).is_ok()
+                    })
+                    .ok_or(Error::<T>::NoPortfolioHotkeyTakesStake)?;
+                placements.push((hotkey.clone(), leftover));
+            }
+        }
+        for (hotkey, amount) in placements {
+            let stakes_this_interval = Self::ensure_can_add_stake(&coldkey, &hotkey, amount)?;
+            Self::delegate_hotkey_if_implicit(&coldkey, &hotkey)?;
+            Self::move_portfolio_stake(&coldkey, &hotkey, amount, true);
+            Self::set_stakes_this_interval_for_coldkey_hotkey(
+                &coldkey,
+                &hotkey,
+                stakes_this_interval.saturating_add(1),
+                block,
+            );
+        }
+
+        log::debug!(
+            "StakePortfolioRebalanced( coldkey:{:?}, moved:{:?} )",
+            coldkey,
+            moved
+        );
+        Self::deposit_event(Event::StakePortfolioRebalanced { coldkey, moved });
+        Ok(())
+    }
+
+    /// Adds or removes stake of a portfolio hotkey, tracking it in the stake delta like
+    /// add_stake and remove_stake do.
+    fn move_portfolio_stake(coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64, add: bool) {
+        StakeDeltaSinceLastEmissionDrain::<T>::mutate(hotkey, coldkey, |stake_delta| {
+            *stake_delta = if add {
+                stake_delta.saturating_add_unsigned(amount as u128)
+            } else {
+                stake_delta.saturating_sub_unsigned(amount as u128)
+            };
+        });
+        if add {
+            Self::increase_stake_on_coldkey_hotkey_account(coldkey, hotkey, amount);
+        } else {
+            Self::decrease_stake_on_coldkey_hotkey_account(coldkey, hotkey, amount);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use super::*;
+use frame_support::traits::Get;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_aux_scores.
+    ///
+    /// Publishes a validator's auxiliary scores of one kind for uids on a subnet. The scores
+    /// are kept apart from weights, have no effect on consensus, and are cleared at the
+    /// subnet's next epoch. What each kind measures is up to the subnet.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the validator's hotkey or of its weights signer.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The subnet the scores are for.
+    ///
+    /// * 'kind' (u16):
+    ///     - The kind of score, below MaxAuxScoreKinds.
+    ///
+    /// * 'uids' (Vec<u16>):
+    ///     - The uids being scored.
+    ///
+    /// * 'values' (Vec<u16>):
+    ///     - The score of each uid.
+    ///
+    /// # Event:
+    /// * AuxScoresSet;
+    ///     - On successfully publishing the scores.
+    ///
+    /// # Raises:
+    /// * 'CanNotSetRootNetworkWeights':
+    ///     - The netuid is the root network.
+    ///
+    /// * 'InvalidAuxScoreKind':
+    ///     - The kind is not below MaxAuxScoreKinds.
+    ///
+    /// * 'WeightVecNotEqualSize':
+    ///     - The uids and values have different lengths.
+    ///
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'WeightsRowTooLong':
+    ///     - More uids than fit in a weights row.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey is not registered on the subnet.
+    ///
+    /// * 'NeuronNoValidatorPermit':
+    ///     - The hotkey holds no validator permit on the subnet.
+    ///
+    /// * 'DuplicateUids':
+    ///     - A uid is scored more than once.
+    ///
+    /// * 'UidVecContainInvalidOne':
+    ///     - A uid does not exist on the subnet.
+    ///
+    pub fn do_set_aux_scores(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        kind: u16,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+    ) -> dispatch::DispatchResult {
+        let hotkey = Self::ensure_weights_origin(origin, netuid)?;
+
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::CanNotSetRootNetworkWeights
+        );
+        ensure!(
+            kind < T::MaxAuxScoreKinds::get(),
+            Error::<T>::InvalidAuxScoreKind
+        );
+        ensure!(
+            Self::uids_match_values(&uids, &values),
+            Error::<T>::WeightVecNotEqualSize
+        );
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::check_weights_row_length(&uids),
+            Error::<T>::WeightsRowTooLong
+        );
+        let neuron_uid: u16 = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
+        ensure!(
+            Self::get_validator_permit_for_uid(netuid, neuron_uid),
+            Error::<T>::NeuronNoValidatorPermit
+        );
+        ensure!(!Self::has_duplicate_uids(&uids), Error::<T>::DuplicateUids);
+        ensure!(
+            !Self::contains_invalid_uids(netuid, &uids),
+            Error::<T>::UidVecContainInvalidOne
+        );
+
+        let scores: WeightsRowOf<T> =
+            WeightsRowOf::<T>::try_from(uids.into_iter().zip(values).collect::<Vec<_>>())
+                .map_err(|_| Error::<T>::WeightsRowTooLong)?;
+        AuxScores::<T>::insert((netuid, neuron_uid, kind), scores);
+
+        log::debug!(
+            "AuxScoresSet( netuid:{:?}, uid:{:?}, kind:{:?} )",
+            netuid,
+            neuron_uid,
+            kind
+        );
+        Self::deposit_event(Event::AuxScoresSet {
+            netuid,
+            uid: neuron_uid,
+            kind,
+        });
+        Ok(())
+    }
+
+    /// Returns the (uid, score) pairs of one kind a validator published this tempo.
+    pub fn get_aux_scores(netuid: u16, uid: u16, kind: u16) -> Vec<(u16, u16)> {
+        AuxScores::<T>::get((netuid, uid, kind))
+            .map(|scores| scores.into_inner())
+            .unwrap_or_default()
+    }
+
+    /// Drops the auxiliary scores published by a uid, when its slot changes hands.
+    pub fn clear_aux_scores_for_uid(netuid: u16, uid: u16) {
+        let _ = AuxScores::<T>::clear_prefix((netuid, uid), u32::MAX, None);
+    }
+
+    /// Drops every auxiliary score published on a subnet, at the end of each tempo.
+    pub fn clear_aux_scores(netuid: u16) {
+        let _ = AuxScores::<T>::clear_prefix((netuid,), u32::MAX, None);
+    }
+}
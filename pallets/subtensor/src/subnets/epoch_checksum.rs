@@ -0,0 +1,31 @@
+use super::*;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    /// Hashes the weight rows of every uid of the subnet, in uid order. Uids without weights
+    /// contribute an empty row, so the hash covers the matrix exactly as epoch() consumes it.
+    pub fn get_weights_checksum(netuid: u16) -> H256 {
+        let rows: Vec<WeightsRowOf<T>> = (0..Self::get_subnetwork_n(netuid))
+            .map(|uid| Weights::<T>::get(netuid, uid))
+            .collect();
+        BlakeTwo256::hash_of(&rows)
+    }
+
+    /// Stores the checksums of the epoch that just ran on the subnet: the hash of the weight
+    /// matrix it consumed, and the hash of the (hotkey, server, validator) emission it produced.
+    /// Must be called after epoch() and before the weights of the subnet are modified.
+    pub fn record_epoch_checksums(netuid: u16, hotkey_emission: &[(T::AccountId, u64, u64)]) {
+        let block: u64 = Self::get_current_block_as_u64();
+        let weights_hash: H256 = Self::get_weights_checksum(netuid);
+        let result_hash: H256 = BlakeTwo256::hash_of(&hotkey_emission);
+        EpochChecksums::<T>::insert(netuid, (block, weights_hash, result_hash));
+    }
+
+    /// Returns the block of the subnet's last epoch with the hashes of the weight matrix it
+    /// consumed and of the emission it produced.
+    pub fn get_epoch_checksums(netuid: u16) -> Option<(u64, H256, H256)> {
+        EpochChecksums::<T>::get(netuid)
+    }
+}
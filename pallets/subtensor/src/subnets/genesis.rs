@@ -0,0 +1,72 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Creates a subnet from the genesis config: initializes it like a newly registered
+    /// network, applies the configured hyperparameters, then registers and stakes its neurons
+    /// in uid order. Panics on a config that could not have been reached by extrinsics.
+    pub fn init_genesis_subnet(subnet: &GenesisSubnet<T::AccountId>) {
+        let netuid: u16 = subnet.netuid;
+        assert!(
+            netuid != Self::get_root_netuid(),
+            "genesis subnet cannot be the root network"
+        );
+        assert!(
+            !Self::if_subnet_exist(netuid),
+            "genesis subnet {} is defined twice",
+            netuid
+        );
+
+        Self::init_new_network(netuid, subnet.tempo);
+        NetworkRegisteredAt::<T>::insert(netuid, 0);
+        SubnetOwner::<T>::insert(netuid, subnet.owner.clone());
+
+        if let Some(max_allowed_uids) = subnet.max_allowed_uids {
+            Self::set_max_allowed_uids(netuid, max_allowed_uids);
+        }
+        if let Some(max_allowed_validators) = subnet.max_allowed_validators {
+            Self::set_max_allowed_validators(netuid, max_allowed_validators);
+        }
+        if let Some(immunity_period) = subnet.immunity_period {
+            Self::set_immunity_period(netuid, immunity_period);
+        }
+        if let Some(min_allowed_weights) = subnet.min_allowed_weights {
+            Self::set_min_allowed_weights(netuid, min_allowed_weights);
+        }
+        if let Some(max_weights_limit) = subnet.max_weights_limit {
+            Self::set_max_weight_limit(netuid, max_weights_limit);
+        }
+        if let Some(kappa) = subnet.kappa {
+            Self::set_kappa(netuid, kappa);
+        }
+        if let Some(weights_set_rate_limit) = subnet.weights_set_rate_limit {
+            Self::set_weights_set_rate_limit(netuid, weights_set_rate_limit);
+        }
+        if let Some(burn) = subnet.burn {
+            Self::set_burn(netuid, burn);
+        }
+        if let Some(difficulty) = subnet.difficulty {
+            Self::set_difficulty(netuid, difficulty);
+        }
+
+        assert!(
+            subnet.neurons.len() <= Self::get_max_allowed_uids(netuid) as usize,
+            "genesis subnet {} has more neurons than max_allowed_uids",
+            netuid
+        );
+        for (coldkey, hotkey, stake) in subnet.neurons.iter() {
+            assert!(
+                !Uids::<T>::contains_key(netuid, hotkey),
+                "genesis subnet {} registers a hotkey twice",
+                netuid
+            );
+            Self::create_account_if_non_existent(coldkey, hotkey);
+            assert!(
+                Self::coldkey_owns_hotkey(coldkey, hotkey),
+                "genesis hotkey is owned by another coldkey"
+            );
+            Self::append_neuron(netuid, hotkey, 0);
+            Self::increase_stake_on_coldkey_hotkey_account(coldkey, hotkey, *stake);
+            TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(*stake));
+        }
+    }
+}
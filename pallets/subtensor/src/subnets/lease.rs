@@ -0,0 +1,110 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic grant_subnet_lease.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the subnet owner coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The subnet being leased.
+    ///
+    /// * 'lessee' (T::AccountId):
+    ///     - The coldkey receiving control of the subnet hyperparameters and owner cut.
+    ///
+    /// * 'end_block' (u64):
+    ///     - The block at which control reverts to the owner.
+    ///
+    /// # Event:
+    /// * SubnetLeaseGranted;
+    ///     - On successfully granting the lease.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'NotSubnetOwner':
+    ///     - The caller does not own the subnet.
+    ///
+    /// * 'SubnetLeaseActive':
+    ///     - The subnet is already leased.
+    ///
+    /// * 'InvalidSubnetLeaseEnd':
+    ///     - The end block is not in the future.
+    ///
+    pub fn do_grant_subnet_lease(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        lessee: T::AccountId,
+        end_block: u64,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::get_subnet_owner(netuid) == coldkey,
+            Error::<T>::NotSubnetOwner
+        );
+        ensure!(
+            !Self::is_subnet_leased(netuid),
+            Error::<T>::SubnetLeaseActive
+        );
+        ensure!(
+            end_block > Self::get_current_block_as_u64(),
+            Error::<T>::InvalidSubnetLeaseEnd
+        );
+
+        SubnetLease::<T>::insert(netuid, (lessee.clone(), end_block));
+
+        log::debug!(
+            "SubnetLeaseGranted( netuid:{:?}, lessee:{:?}, end_block:{:?} )",
+            netuid,
+            lessee,
+            end_block
+        );
+        Self::deposit_event(Event::SubnetLeaseGranted {
+            netuid,
+            lessee,
+            end_block,
+        });
+        Ok(())
+    }
+
+    /// Returns true if the subnet has a lease that has not yet reached its end block.
+    pub fn is_subnet_leased(netuid: u16) -> bool {
+        SubnetLease::<T>::get(netuid)
+            .is_some_and(|(_, end_block)| end_block > Self::get_current_block_as_u64())
+    }
+
+    /// Returns the coldkey currently controlling the subnet hyperparameters and receiving the
+    /// owner cut: the lessee while a lease is active, otherwise the subnet owner.
+    pub fn get_subnet_controller(netuid: u16) -> T::AccountId {
+        match SubnetLease::<T>::get(netuid) {
+            Some((lessee, end_block)) if end_block > Self::get_current_block_as_u64() => lessee,
+            _ => SubnetOwner::<T>::get(netuid),
+        }
+    }
+
+    /// Removes every lease whose end block has been reached, returning control to the owner.
+    pub fn expire_subnet_leases() {
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let expired: Vec<(u16, T::AccountId)> = SubnetLease::<T>::iter()
+            .filter(|(_, (_, end_block))| *end_block <= current_block)
+            .map(|(netuid, (lessee, _))| (netuid, lessee))
+            .collect();
+
+        for (netuid, lessee) in expired {
+            SubnetLease::<T>::remove(netuid);
+            log::debug!(
+                "SubnetLeaseExpired( netuid:{:?}, lessee:{:?} )",
+                netuid,
+                lessee
+            );
+            Self::deposit_event(Event::SubnetLeaseExpired { netuid, lessee });
+        }
+    }
+}
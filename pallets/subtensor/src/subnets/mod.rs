@@ -1,5 +1,14 @@
 use super::*;
+pub mod aux_scores;
+pub mod epoch_checksum;
+pub mod genesis;
+pub mod lease;
+pub mod randomness;
 pub mod registration;
+pub mod registration_auction;
 pub mod serving;
 pub mod uids;
+pub mod weight_flags;
 pub mod weights;
+pub mod weights_signer;
+pub mod weights_unsigned;
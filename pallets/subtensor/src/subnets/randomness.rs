@@ -0,0 +1,27 @@
+use super::*;
+use frame_support::traits::{Get, Randomness};
+use sp_core::H256;
+
+/// Domain separator for the subject the subnet randomness beacon is drawn with.
+pub const SUBNET_RANDOMNESS_CONTEXT: &[u8] = b"subtensor/subnet_randomness";
+
+impl<T: Config> Pallet<T> {
+    /// Draws the subnet's beacon value for the tempo starting at the current block. Called
+    /// once per epoch, so every validator on the subnet reads the same value for the whole
+    /// tempo instead of relying on local randomness. Does nothing unless the runtime enables
+    /// RandomnessBeaconEnabled: the beacon is only as unbiased as T::Randomness.
+    pub fn update_subnet_randomness(netuid: u16) {
+        if !T::RandomnessBeaconEnabled::get() {
+            return;
+        }
+        let block: u64 = Self::get_current_block_as_u64();
+        let subject: Vec<u8> = (SUBNET_RANDOMNESS_CONTEXT, netuid, block).encode();
+        let (randomness, _) = T::Randomness::random(&subject);
+        SubnetRandomness::<T>::insert(netuid, (block, randomness));
+    }
+
+    /// Returns the subnet's current beacon value and the block it was drawn at.
+    pub fn get_subnet_randomness(netuid: u16) -> Option<(u64, H256)> {
+        SubnetRandomness::<T>::get(netuid)
+    }
+}
@@ -20,6 +20,9 @@ impl<T: Config> Pallet<T> {
     /// * 'hotkey' ( T::AccountId ):
     ///     - Hotkey to be registered to the network.
     ///
+    /// * 'challenge' ( Option<H256> ):
+    ///     - The subnet's registration challenge commit, if it has one.
+    ///
     /// # Event:
     /// * NeuronRegistered;
     ///     - On successfully registereing a uid to a neuron slot on a subnetwork.
@@ -34,10 +37,20 @@ impl<T: Config> Pallet<T> {
     /// * 'HotKeyAlreadyRegisteredInSubNet':
     ///     - The hotkey is already registered on this network.
     ///
+    /// * 'ColdkeyIsRegisteredHotkey' / 'HotkeyIsInUseAsColdkey':
+    ///     - The keys are not used in their own roles.
+    ///
+    /// * 'NotEnoughStakeToRegister':
+    ///     - The coldkey holds less than the subnet's MinStakeToRegister.
+    ///
+    /// * 'RegistrationChallengeMismatch':
+    ///     - The challenge does not equal the subnet's RegistrationChallengeCommit.
+    ///
     pub fn do_burned_registration(
         origin: T::RuntimeOrigin,
         netuid: u16,
         hotkey: T::AccountId,
+        challenge: Option<H256>,
     ) -> DispatchResult {
         // --- 1. Check that the caller has signed the transaction. (the coldkey of the pairing)
         let coldkey = ensure_signed(origin)?;
@@ -63,6 +76,10 @@ impl<T: Config> Pallet<T> {
             Self::get_network_registration_allowed(netuid),
             Error::<T>::SubNetRegistrationDisabled
         );
+        ensure!(
+            SubnetRegistrationMechanism::<T>::get(netuid) == RegistrationMechanism::FixedBurn,
+            Error::<T>::RegistrationByAuctionOnly
+        );
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
         ensure!(
@@ -84,6 +101,15 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
 
+        // --- 5. Ensure the keys are used in their own roles.
+        Self::ensure_key_usage_separation(&coldkey, &hotkey)?;
+
+        // --- 5a. Ensure the coldkey holds the subnet's minimum stake to register.
+        Self::ensure_min_stake_to_register(netuid, &coldkey)?;
+
+        // --- 5b. Ensure the subnet's registration challenge is echoed.
+        Self::ensure_registration_challenge(netuid, challenge)?;
+
         // DEPRECATED --- 6. Ensure that the key passes the registration requirement
         // ensure!(
         //     Self::passes_network_connection_requirement(netuid, &hotkey),
@@ -124,7 +150,7 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NoNeuronIdAvailable
         );
 
-        if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
+        if Self::has_open_neuron_slot(netuid, &hotkey) {
             // --- 12.1.1 No replacement required, the uid appends the subnetwork.
             // We increment the subnetwork count here but not below.
             subnetwork_uid = current_subnetwork_n;
@@ -135,6 +161,10 @@ impl<T: Config> Pallet<T> {
         } else {
             // --- 13.1.1 Replacement required.
             // We take the neuron with the lowest pruning score here.
+            ensure!(
+                Self::has_prunable_neuron(netuid),
+                Error::<T>::NoNeuronIdAvailable
+            );
             subnetwork_uid = Self::get_neuron_to_prune(netuid);
 
             // --- 13.1.1 Replace the neuron account with the new info.
@@ -146,6 +176,7 @@ impl<T: Config> Pallet<T> {
         BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
+        Self::record_block_metric(|m| m.registrations = m.registrations.saturating_add(1));
         Self::increase_rao_recycled(netuid, Self::get_burn_as_u64(netuid));
 
         // --- 15. Deposit successful event.
@@ -185,6 +216,9 @@ impl<T: Config> Pallet<T> {
     /// *'coldkey' ( T::AccountId ):
     ///     - Associated coldkey account.
     ///
+    /// *'challenge' ( Option<H256> ):
+    ///     - The subnet's registration challenge commit, if it has one.
+    ///
     /// # Event:
     /// *NeuronRegistered;
     ///     - On successfully registereing a uid to a neuron slot on a subnetwork.
@@ -208,6 +242,12 @@ impl<T: Config> Pallet<T> {
     /// *'InvalidSeal':
     ///     - The seal is incorrect.
     ///
+    /// *'NotEnoughStakeToRegister':
+    ///     - The coldkey holds less than the subnet's MinStakeToRegister.
+    ///
+    /// *'RegistrationChallengeMismatch':
+    ///     - The challenge does not equal the subnet's RegistrationChallengeCommit.
+    ///
     pub fn do_registration(
         origin: T::RuntimeOrigin,
         netuid: u16,
@@ -216,6 +256,7 @@ impl<T: Config> Pallet<T> {
         work: Vec<u8>,
         hotkey: T::AccountId,
         coldkey: T::AccountId,
+        challenge: Option<H256>,
     ) -> DispatchResult {
         // --- 1. Check that the caller has signed the transaction.
         // TODO( const ): This not be the hotkey signature or else an exterior actor can register the hotkey and potentially control it?
@@ -269,6 +310,15 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
 
+        // --- 6a. Ensure the keys are used in their own roles.
+        Self::ensure_key_usage_separation(&coldkey, &hotkey)?;
+
+        // --- 6b. Ensure the coldkey holds the subnet's minimum stake to register.
+        Self::ensure_min_stake_to_register(netuid, &coldkey)?;
+
+        // --- 6c. Ensure the subnet's registration challenge is echoed.
+        Self::ensure_registration_challenge(netuid, challenge)?;
+
         // --- 7. Ensure the passed block number is valid, not in the future or too old.
         // Work must have been done within 3 blocks (stops long range attacks).
         let current_block_number: u64 = Self::get_current_block_as_u64();
@@ -319,7 +369,7 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NoNeuronIdAvailable
         );
 
-        if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
+        if Self::has_open_neuron_slot(netuid, &hotkey) {
             // --- 11.1.1 No replacement required, the uid appends the subnetwork.
             // We increment the subnetwork count here but not below.
             subnetwork_uid = current_subnetwork_n;
@@ -330,6 +380,10 @@ impl<T: Config> Pallet<T> {
         } else {
             // --- 11.1.1 Replacement required.
             // We take the neuron with the lowest pruning score here.
+            ensure!(
+                Self::has_prunable_neuron(netuid),
+                Error::<T>::NoNeuronIdAvailable
+            );
             subnetwork_uid = Self::get_neuron_to_prune(netuid);
 
             // --- 11.1.1 Replace the neuron account with the new info.
@@ -341,6 +395,7 @@ impl<T: Config> Pallet<T> {
         POWRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
+        Self::record_block_metric(|m| m.registrations = m.registrations.saturating_add(1));
 
         // --- 13. Deposit successful event.
         log::debug!(
@@ -355,6 +410,29 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Ensures the registering coldkey holds at least the subnet's MinStakeToRegister in
+    /// stake. The stake is only checked, never spent, so mass registration needs capital held
+    /// on top of the burn.
+    pub fn ensure_min_stake_to_register(netuid: u16, coldkey: &T::AccountId) -> DispatchResult {
+        ensure!(
+            Self::get_total_stake_for_coldkey(coldkey) >= Self::get_min_stake_to_register(netuid),
+            Error::<T>::NotEnoughStakeToRegister
+        );
+        Ok(())
+    }
+
+    /// Ensures a registration echoes the subnet's RegistrationChallengeCommit. Subnets without
+    /// a commit accept any challenge, including none.
+    pub fn ensure_registration_challenge(netuid: u16, challenge: Option<H256>) -> DispatchResult {
+        if let Some(commit) = RegistrationChallengeCommit::<T>::get(netuid) {
+            ensure!(
+                challenge == Some(commit),
+                Error::<T>::RegistrationChallengeMismatch
+            );
+        }
+        Ok(())
+    }
+
     pub fn do_faucet(
         origin: T::RuntimeOrigin,
         block_number: u64,
@@ -418,11 +496,38 @@ impl<T: Config> Pallet<T> {
         real_hash
     }
 
+    /// Returns whether a hotkey registering on a subnet takes a new uid rather than replacing a
+    /// neuron. The slots of reserved hotkeys that are not registered yet are held for them, so
+    /// every other hotkey sees the subnet as full once only those slots are left.
+    pub fn has_open_neuron_slot(netuid: u16, hotkey: &T::AccountId) -> bool {
+        let reserved: ReservedHotkeysOf<T> = ReservedHotkeys::<T>::get(netuid);
+        let held: usize = if reserved.contains(hotkey) {
+            0
+        } else {
+            reserved
+                .iter()
+                .filter(|reserved_hotkey| !Uids::<T>::contains_key(netuid, reserved_hotkey))
+                .count()
+        };
+        (Self::get_subnetwork_n(netuid) as usize).saturating_add(held)
+            < Self::get_max_allowed_uids(netuid) as usize
+    }
+
+    /// Returns whether a subnet has a neuron get_neuron_to_prune may replace, that is one not
+    /// registered by a reserved hotkey.
+    pub fn has_prunable_neuron(netuid: u16) -> bool {
+        let reserved: ReservedHotkeysOf<T> = ReservedHotkeys::<T>::get(netuid);
+        (0..Self::get_subnetwork_n(netuid))
+            .any(|uid| !reserved.contains(&Keys::<T>::get(netuid, uid)))
+    }
+
     /// Determine which peer to prune from the network by finding the element with the lowest pruning score out of
     /// immunity period. If there is a tie for lowest pruning score, the neuron registered earliest is pruned.
     /// If all neurons are in immunity period, the neuron with the lowest pruning score is pruned. If there is a tie for
     /// the lowest pruning score, the immune neuron registered earliest is pruned.
     /// Ties for earliest registration are broken by the neuron with the lowest uid.
+    /// Neurons of the subnet's reserved hotkeys are never pruned; callers check
+    /// has_prunable_neuron first, as a subnet whose neurons are all reserved has none to prune.
     pub fn get_neuron_to_prune(netuid: u16) -> u16 {
         let mut min_score: u16 = u16::MAX;
         let mut min_score_in_immunity: u16 = u16::MAX;
@@ -441,7 +546,13 @@ impl<T: Config> Pallet<T> {
             return 0; // If there are no neurons in this network.
         }
 
+        let reserved: ReservedHotkeysOf<T> = ReservedHotkeys::<T>::get(netuid);
+        let mut found_prunable = false;
         for neuron_uid in 0..neurons_n {
+            if reserved.contains(&Keys::<T>::get(netuid, neuron_uid)) {
+                continue;
+            }
+            found_prunable = true;
             let pruning_score: u16 = Self::get_pruning_score_for_uid(netuid, neuron_uid);
             let block_at_registration: u64 =
                 Self::get_neuron_block_at_registration(netuid, neuron_uid);
@@ -474,7 +585,9 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        if found_non_immune {
+        if !found_prunable {
+            0
+        } else if found_non_immune {
             Self::set_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
             uid_to_prune
         } else {
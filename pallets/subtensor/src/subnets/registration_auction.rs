@@ -0,0 +1,411 @@
+use super::*;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_std::cmp::Reverse;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic bid_for_registration.
+    ///
+    /// Commits a sealed bid for a UID on a subnet running the registration auction. The bid
+    /// amount stays hidden behind `commitment` until it is revealed with reveal_registration_bid
+    /// in the reveal window at the end of the adjustment interval. `escrow`, an upper bound on the
+    /// amount, and RegistrationBidDeposit are withdrawn from the coldkey. A bid is binding: it
+    /// cannot be changed or withdrawn until the auction settles.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the calling coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The subnet to register on.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey to register if the bid wins.
+    ///
+    /// * 'commitment' (H256):
+    ///     - The hash of (coldkey, hotkey, netuid, amount, salt), see registration_bid_hash.
+    ///
+    /// * 'escrow' (u64):
+    ///     - The amount escrowed, at least the subnet's MinBurn and the amount to reveal.
+    ///
+    /// # Event:
+    /// * RegistrationBidPlaced;
+    ///     - On successfully escrowing the bid.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist':
+    ///     - The subnet does not exist.
+    ///
+    /// * 'RegistrationAuctionNotActive':
+    ///     - The subnet does not register by auction.
+    ///
+    /// * 'RegistrationBidCommitClosed':
+    ///     - The reveal window of the interval is open.
+    ///
+    /// * 'HotKeyAlreadyRegisteredInSubNet':
+    ///     - The hotkey is already registered on the subnet.
+    ///
+    /// * 'ColdkeyIsRegisteredHotkey' / 'HotkeyIsInUseAsColdkey':
+    ///     - The keys are not used in their own roles.
+    ///
+    /// * 'NotEnoughStakeToRegister':
+    ///     - The coldkey holds less than the subnet's MinStakeToRegister.
+    ///
+    /// * 'RegistrationChallengeMismatch':
+    ///     - The subnet has a RegistrationChallengeCommit.
+    ///
+    /// * 'RegistrationBidAlreadyPlaced':
+    ///     - The hotkey already has a bid in the current auction.
+    ///
+    /// * 'TooManyRegistrationBids':
+    ///     - The auction already holds MaxRegistrationBids bids.
+    ///
+    /// * 'RegistrationBidTooLow':
+    ///     - The escrow is below the subnet's MinBurn.
+    ///
+    /// * 'NotEnoughBalanceToStake':
+    ///     - The coldkey cannot pay the escrow and deposit.
+    ///
+    pub fn do_bid_for_registration(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+        commitment: H256,
+        escrow: u64,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::get_network_registration_allowed(netuid),
+            Error::<T>::SubNetRegistrationDisabled
+        );
+        ensure!(
+            SubnetRegistrationMechanism::<T>::get(netuid) == RegistrationMechanism::Auction,
+            Error::<T>::RegistrationAuctionNotActive
+        );
+        ensure!(
+            !Self::is_registration_bid_reveal_open(netuid),
+            Error::<T>::RegistrationBidCommitClosed
+        );
+        ensure!(
+            !Uids::<T>::contains_key(netuid, &hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet
+        );
+        ensure!(
+            !Self::hotkey_account_exists(&hotkey) || Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        Self::ensure_key_usage_separation(&coldkey, &hotkey)?;
+        Self::ensure_min_stake_to_register(netuid, &coldkey)?;
+        // Bids carry no challenge, so a subnet gating registration by challenge rejects them.
+        Self::ensure_registration_challenge(netuid, None)?;
+        let mut bids = RegistrationBids::<T>::get(netuid);
+        ensure!(
+            !bids.iter().any(|bid| bid.hotkey == hotkey),
+            Error::<T>::RegistrationBidAlreadyPlaced
+        );
+        ensure!(
+            (bids.len() as u32) < T::MaxRegistrationBids::get(),
+            Error::<T>::TooManyRegistrationBids
+        );
+        ensure!(
+            escrow >= Self::get_min_burn_as_u64(netuid),
+            Error::<T>::RegistrationBidTooLow
+        );
+        let deposit: u64 = T::RegistrationBidDeposit::get();
+        let total: u64 = escrow.saturating_add(deposit);
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, total),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+
+        let withdrawn: u64 = Self::remove_balance_from_coldkey_account(&coldkey, total)?;
+        let escrow: u64 = withdrawn.saturating_sub(deposit);
+        bids.try_push(RegistrationBid {
+            coldkey: coldkey.clone(),
+            hotkey: hotkey.clone(),
+            escrow,
+            deposit,
+            commitment,
+            revealed: None,
+        })
+        .map_err(|_| Error::<T>::TooManyRegistrationBids)?;
+        RegistrationBids::<T>::insert(netuid, bids);
+
+        log::debug!(
+            "RegistrationBidPlaced( netuid:{:?}, coldkey:{:?}, hotkey:{:?}, escrow:{:?} )",
+            netuid,
+            coldkey,
+            hotkey,
+            escrow
+        );
+        Self::deposit_event(Event::RegistrationBidPlaced {
+            netuid,
+            coldkey,
+            hotkey,
+            amount: escrow,
+        });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic reveal_registration_bid.
+    ///
+    /// Reveals the amount of a sealed registration bid during the reveal window. Bids that are
+    /// not revealed by settlement lose their deposit.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the coldkey that placed the bid.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The subnet bid on.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey of the bid.
+    ///
+    /// * 'amount' (u64):
+    ///     - The bid amount committed to.
+    ///
+    /// * 'salt' (H256):
+    ///     - The salt committed to.
+    ///
+    /// # Event:
+    /// * RegistrationBidRevealed;
+    ///     - On successfully revealing the bid.
+    ///
+    /// # Raises:
+    /// * 'RegistrationBidRevealNotOpen':
+    ///     - The reveal window of the interval is not open.
+    ///
+    /// * 'RegistrationBidNotFound':
+    ///     - The coldkey has no unrevealed bid for the hotkey.
+    ///
+    /// * 'InvalidRegistrationBidReveal':
+    ///     - The amount and salt do not match the commitment, or the amount is below MinBurn or
+    ///       above the escrow.
+    ///
+    pub fn do_reveal_registration_bid(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        hotkey: T::AccountId,
+        amount: u64,
+        salt: H256,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::is_registration_bid_reveal_open(netuid),
+            Error::<T>::RegistrationBidRevealNotOpen
+        );
+        let mut bids = RegistrationBids::<T>::get(netuid);
+        let bid = bids
+            .iter_mut()
+            .find(|bid| bid.coldkey == coldkey && bid.hotkey == hotkey && bid.revealed.is_none())
+            .ok_or(Error::<T>::RegistrationBidNotFound)?;
+        ensure!(
+            bid.commitment == Self::registration_bid_hash(&coldkey, &hotkey, netuid, amount, salt)
+                && amount <= bid.escrow
+                && amount >= Self::get_min_burn_as_u64(netuid),
+            Error::<T>::InvalidRegistrationBidReveal
+        );
+        bid.revealed = Some(amount);
+        RegistrationBids::<T>::insert(netuid, bids);
+
+        log::debug!(
+            "RegistrationBidRevealed( netuid:{:?}, coldkey:{:?}, hotkey:{:?}, amount:{:?} )",
+            netuid,
+            coldkey,
+            hotkey,
+            amount
+        );
+        Self::deposit_event(Event::RegistrationBidRevealed {
+            netuid,
+            coldkey,
+            hotkey,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Returns the commitment of a sealed registration bid.
+    pub fn registration_bid_hash(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: u16,
+        amount: u64,
+        salt: H256,
+    ) -> H256 {
+        BlakeTwo256::hash_of(&(coldkey, hotkey, netuid, amount, salt))
+    }
+
+    /// Returns true during the reveal window of a subnet registration auction: the last
+    /// quarter (at least one block) of the adjustment interval. Bids are committed before it.
+    pub fn is_registration_bid_reveal_open(netuid: u16) -> bool {
+        let interval: u64 = Self::get_adjustment_interval(netuid) as u64;
+        let reveal_blocks: u64 = interval.checked_div(4).unwrap_or(0).max(1);
+        Self::get_current_block_as_u64()
+            .saturating_sub(Self::get_last_adjustment_block(netuid))
+            .saturating_add(reveal_blocks)
+            >= interval
+    }
+
+    /// Settles the registration auction of a subnet at the end of its adjustment interval.
+    /// The highest TargetRegistrationsPerInterval revealed bids are burned and registered,
+    /// earlier bids winning ties, and the rest of their escrow and deposit refunded. Other
+    /// revealed bids are refunded in full; unrevealed bids are refunded their escrow and lose
+    /// their deposit. The work is bounded by MaxRegistrationBids.
+    pub fn settle_registration_auction(netuid: u16) {
+        let (mut revealed, unrevealed): (Vec<_>, Vec<_>) = RegistrationBids::<T>::take(netuid)
+            .into_iter()
+            .partition(|bid| bid.revealed.is_some());
+
+        for bid in unrevealed {
+            Self::refund_registration_bid(netuid, &bid.coldkey, &bid.hotkey, bid.escrow);
+            Self::recycle_registration_bid(netuid, &bid.coldkey, &bid.hotkey, bid.deposit);
+        }
+
+        // Stable sort, so equal bids keep their arrival order.
+        revealed.sort_by_key(|bid| Reverse(bid.revealed.unwrap_or(0)));
+
+        let slots: usize = Self::get_target_registrations_per_interval(netuid) as usize;
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let mut winners: usize = 0;
+
+        for bid in revealed {
+            let RegistrationBid {
+                coldkey,
+                hotkey,
+                escrow,
+                deposit,
+                revealed,
+                ..
+            } = bid;
+            let amount: u64 = revealed.unwrap_or(0).min(escrow);
+            let eligible: bool = winners < slots
+                && Self::get_max_allowed_uids(netuid) != 0
+                && !Uids::<T>::contains_key(netuid, &hotkey)
+                && (!Self::hotkey_account_exists(&hotkey)
+                    || Self::coldkey_owns_hotkey(&coldkey, &hotkey))
+                && (Self::has_open_neuron_slot(netuid, &hotkey)
+                    || Self::has_prunable_neuron(netuid));
+            if !eligible {
+                Self::refund_registration_bid(
+                    netuid,
+                    &coldkey,
+                    &hotkey,
+                    escrow.saturating_add(deposit),
+                );
+                continue;
+            }
+            winners = winners.saturating_add(1);
+
+            Self::burn_tokens(amount);
+            Self::increase_rao_recycled(netuid, amount);
+            Self::refund_registration_bid(
+                netuid,
+                &coldkey,
+                &hotkey,
+                escrow.saturating_sub(amount).saturating_add(deposit),
+            );
+            Self::create_account_if_non_existent(&coldkey, &hotkey);
+
+            let subnetwork_uid: u16;
+            if Self::has_open_neuron_slot(netuid, &hotkey) {
+                subnetwork_uid = Self::get_subnetwork_n(netuid);
+                Self::append_neuron(netuid, &hotkey, current_block);
+            } else {
+                subnetwork_uid = Self::get_neuron_to_prune(netuid);
+                Self::replace_neuron(netuid, subnetwork_uid, &hotkey, current_block);
+            }
+
+            BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
+            RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
+            Self::record_block_metric(|m| m.registrations = m.registrations.saturating_add(1));
+
+            log::debug!(
+                "NeuronRegistered( netuid:{:?} uid:{:?} hotkey:{:?} bid:{:?} ) ",
+                netuid,
+                subnetwork_uid,
+                hotkey,
+                amount
+            );
+            Self::deposit_event(Event::NeuronRegistered(netuid, subnetwork_uid, hotkey));
+        }
+    }
+
+    /// Refunds every outstanding registration bid on a subnet, escrow and deposit.
+    pub fn refund_registration_bids(netuid: u16) {
+        for bid in RegistrationBids::<T>::take(netuid) {
+            Self::refund_registration_bid(
+                netuid,
+                &bid.coldkey,
+                &bid.hotkey,
+                bid.escrow.saturating_add(bid.deposit),
+            );
+        }
+    }
+
+    /// Credits `amount` of an escrowed bid back to its coldkey. A refund the coldkey cannot
+    /// receive, e.g. below the existential deposit of a reaped account, is recycled instead so
+    /// that it leaves the total issuance.
+    fn refund_registration_bid(
+        netuid: u16,
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+    ) {
+        if amount == 0 {
+            return;
+        }
+        if !Self::can_add_balance_to_coldkey_account(coldkey, amount) {
+            Self::recycle_registration_bid(netuid, coldkey, hotkey, amount);
+            return;
+        }
+        Self::add_balance_to_coldkey_account(coldkey, amount);
+        Self::deposit_event(Event::RegistrationBidRefunded {
+            netuid,
+            coldkey: coldkey.clone(),
+            hotkey: hotkey.clone(),
+            amount,
+        });
+    }
+
+    /// Recycles `amount` of an escrowed bid: it was withdrawn when the bid was placed and is
+    /// now removed from the total issuance.
+    fn recycle_registration_bid(
+        netuid: u16,
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+    ) {
+        if amount == 0 {
+            return;
+        }
+        Self::burn_tokens(amount);
+        Self::increase_rao_recycled(netuid, amount);
+        Self::deposit_event(Event::RegistrationBidRecycled {
+            netuid,
+            coldkey: coldkey.clone(),
+            hotkey: hotkey.clone(),
+            amount,
+        });
+    }
+
+    /// Sets how burn registrations are priced on a subnet. Leaving the auction refunds the
+    /// bids of the interval in progress.
+    pub fn set_registration_mechanism(netuid: u16, mechanism: RegistrationMechanism) {
+        if mechanism != RegistrationMechanism::Auction {
+            Self::refund_registration_bids(netuid);
+        }
+        SubnetRegistrationMechanism::<T>::insert(netuid, mechanism);
+        Self::deposit_event(Event::RegistrationMechanismSet { netuid, mechanism });
+    }
+}
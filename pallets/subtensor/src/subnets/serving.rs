@@ -31,6 +31,9 @@ impl<T: Config> Pallet<T> {
     /// * 'placeholder2' (u8):
     ///     - Placeholder for further extra params.
     ///
+    /// * 'capabilities' (Option<u64>):
+    ///     - The capability bitfield advertised by the axon, None keeping the current one.
+    ///
     /// # Event:
     /// * AxonServed;
     ///     - On successfully serving the axon info.
@@ -61,6 +64,7 @@ impl<T: Config> Pallet<T> {
         protocol: u8,
         placeholder1: u8,
         placeholder2: u8,
+        capabilities: Option<u64>,
     ) -> dispatch::DispatchResult {
         // We check the callers (hotkey) signature.
         let hotkey_id = ensure_signed(origin)?;
@@ -95,6 +99,9 @@ impl<T: Config> Pallet<T> {
         prev_axon.protocol = protocol;
         prev_axon.placeholder1 = placeholder1;
         prev_axon.placeholder2 = placeholder2;
+        if let Some(capabilities) = capabilities {
+            prev_axon.capabilities = capabilities;
+        }
 
         // Validate axon data with delegate func
         let axon_validated = Self::validate_axon_data(&prev_axon);
@@ -256,10 +263,21 @@ impl<T: Config> Pallet<T> {
                 protocol: 0,
                 placeholder1: 0,
                 placeholder2: 0,
+                capabilities: 0,
             }
         }
     }
 
+    /// Returns the axon info shown in metagraph queries: the stored axon, or an empty axon if
+    /// its version is below the subnet's MinAxonVersion.
+    pub fn get_visible_axon_info(netuid: u16, hotkey: &T::AccountId) -> AxonInfoOf {
+        let axon_info = Self::get_axon_info(netuid, hotkey);
+        if axon_info.version < Self::get_min_axon_version(netuid) {
+            return AxonInfo::default();
+        }
+        axon_info
+    }
+
     pub fn get_prometheus_info(netuid: u16, hotkey: &T::AccountId) -> PrometheusInfoOf {
         if let Some(prometheus) = Prometheus::<T>::get(netuid, hotkey) {
             prometheus
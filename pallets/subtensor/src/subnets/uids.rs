@@ -2,6 +2,7 @@ use super::*;
 use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::storage::IterableStorageMap;
 use sp_std::vec;
+use substrate_fixed::types::I96F32;
 
 impl<T: Config> Pallet<T> {
     /// Returns the number of filled slots on a network.
@@ -26,10 +27,18 @@ impl<T: Config> Pallet<T> {
         // 1. Get the old hotkey under this position.
         let old_hotkey: T::AccountId = Keys::<T>::get(netuid, uid_to_replace);
 
+        // 1a. Settle the pruned neuron's share of the emission pending for this tempo.
+        Self::settle_pruned_neuron_emission(netuid, uid_to_replace, &old_hotkey);
+
         // 2. Remove previous set memberships.
         Uids::<T>::remove(netuid, old_hotkey.clone());
         IsNetworkMember::<T>::remove(old_hotkey.clone(), netuid);
         Keys::<T>::remove(netuid, uid_to_replace);
+        Self::clear_aux_scores_for_uid(netuid, uid_to_replace);
+        Self::clear_weight_flag_for_uid(netuid, uid_to_replace);
+        DelegateYields::<T>::remove(netuid, &old_hotkey);
+        WeightCommits::<T>::remove(netuid, &old_hotkey);
+        FreeWeightsSetBlock::<T>::remove(netuid, uid_to_replace);
 
         // 2a. Check if the uid is registered in any other subnetworks.
         let hotkey_is_registered_on_any_network: bool =
@@ -37,6 +46,8 @@ impl<T: Config> Pallet<T> {
         if !hotkey_is_registered_on_any_network {
             // If not, unstake all coldkeys under this hotkey.
             Self::unstake_all_coldkeys_from_hotkey_account(&old_hotkey.clone());
+            // A delegate now starts its inactivity grace period.
+            Self::note_hotkey_deregistered(&old_hotkey, block_number);
         }
 
         // 3. Create new set memberships.
@@ -45,6 +56,58 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::insert(netuid, new_hotkey.clone(), uid_to_replace); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, uid_to_replace, block_number); // Fill block at registration.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
+        Self::note_hotkey_registered(new_hotkey);
+
+        // 4. Record the change for the next metagraph digest.
+        MetagraphPrunedUids::<T>::mutate(netuid, |v| Self::push_unique_uid(v, uid_to_replace));
+        MetagraphRegisteredUids::<T>::mutate(netuid, |v| Self::push_unique_uid(v, uid_to_replace));
+    }
+
+    /// Removes the pruned neuron's share of the subnet's pending emission, estimated from its
+    /// share of the last epoch's emission, and either adds it to the pending emission of the hotkey
+    /// or burns it depending on `PruneEmissionBurn`.
+    pub fn settle_pruned_neuron_emission(netuid: u16, uid: u16, hotkey: &T::AccountId) {
+        let emission: Vec<u64> = Emission::<T>::get(netuid);
+        let uid_emission: u64 = emission.get(uid as usize).copied().unwrap_or(0);
+        let total_emission: u64 = emission.iter().fold(0u64, |acc, e| acc.saturating_add(*e));
+        let pending: u64 = PendingEmission::<T>::get(netuid);
+        if uid_emission == 0 || total_emission == 0 || pending == 0 {
+            return;
+        }
+
+        let amount: u64 = I96F32::from_num(pending)
+            .saturating_mul(I96F32::from_num(uid_emission))
+            .checked_div(I96F32::from_num(total_emission))
+            .unwrap_or(I96F32::from_num(0))
+            .to_num::<u64>()
+            .min(pending);
+        if amount == 0 {
+            return;
+        }
+        PendingEmission::<T>::insert(netuid, pending.saturating_sub(amount));
+
+        // Pending emission is not minted yet, so burning only requires dropping it.
+        let burned: bool = Self::get_prune_emission_burn(netuid);
+        if !burned {
+            // The drain of the hotkey pays its stakers pro rata, after the hotkey take.
+            Self::accrue_hotkey_emission(hotkey, netuid, amount);
+        }
+
+        log::debug!(
+            "PrunedNeuronEmissionSettled( netuid:{:?}, uid:{:?}, hotkey:{:?}, amount:{:?}, burned:{:?} )",
+            netuid,
+            uid,
+            hotkey,
+            amount,
+            burned
+        );
+        Self::deposit_event(Event::PrunedNeuronEmissionSettled {
+            netuid,
+            uid,
+            hotkey: hotkey.clone(),
+            amount,
+            burned,
+        });
     }
 
     /// Appends the uid to the network.
@@ -79,6 +142,40 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::insert(netuid, new_hotkey.clone(), next_uid); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, next_uid, block_number); // Fill block at registration.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
+        Self::note_hotkey_registered(new_hotkey);
+
+        // 5. Record the change for the next metagraph digest.
+        MetagraphRegisteredUids::<T>::mutate(netuid, |v| Self::push_unique_uid(v, next_uid));
+    }
+
+    fn push_unique_uid(uids: &mut Vec<u16>, uid: u16) {
+        if !uids.contains(&uid) {
+            uids.push(uid);
+        }
+    }
+
+    /// Emits the per-tempo metagraph digest for a subnet and resets the change tracking.
+    /// Must be called after epoch() has written the new validator permits.
+    pub fn emit_metagraph_digest(netuid: u16, permits_before: &[bool]) {
+        let permits_after: Vec<bool> = ValidatorPermit::<T>::get(netuid);
+        let mut permits_granted: Vec<u16> = vec![];
+        let mut permits_revoked: Vec<u16> = vec![];
+        for (uid, after) in permits_after.iter().enumerate() {
+            let before: bool = permits_before.get(uid).copied().unwrap_or(false);
+            if *after && !before {
+                permits_granted.push(uid as u16);
+            } else if !*after && before {
+                permits_revoked.push(uid as u16);
+            }
+        }
+
+        Self::deposit_event(Event::MetagraphDigest {
+            netuid,
+            registered: MetagraphRegisteredUids::<T>::take(netuid),
+            pruned: MetagraphPrunedUids::<T>::take(netuid),
+            permits_granted,
+            permits_revoked,
+        });
     }
 
     /// Returns true if the uid is set on the network.
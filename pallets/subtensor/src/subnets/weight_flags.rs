@@ -0,0 +1,131 @@
+use super::*;
+use frame_support::weights::Weight;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    /// Configures the degenerate weight flagging of a subnet. A UID is flagged once its weights
+    /// were degenerate for `tempos` consecutive epochs, 0 disabling the flagging and dropping
+    /// its state. At least two UIDs must share weights for them to count as duplicates.
+    pub fn set_weight_flagging(netuid: u16, tempos: u16, duplicate_keys: u16) {
+        let duplicate_keys: u16 = duplicate_keys.max(2);
+        WeightFlagTempos::<T>::insert(netuid, tempos);
+        WeightFlagDuplicateKeys::<T>::insert(netuid, duplicate_keys);
+        if tempos == 0 {
+            // Both maps are keyed by uid, so neither holds more entries than a subnet has uids.
+            let limit: u32 = T::MaxWeightsRowLength::get();
+            let _ = DegenerateWeightStreaks::<T>::clear_prefix(netuid, limit, None);
+            let _ = FlaggedUids::<T>::clear_prefix(netuid, limit, None);
+        }
+        Self::deposit_event(Event::WeightFlaggingSet {
+            netuid,
+            tempos,
+            duplicate_keys,
+        });
+    }
+
+    /// Classifies the weights of every UID of the subnet after its epoch, extending the
+    /// streaks of the degenerate ones and flagging those reaching `WeightFlagTempos`. UIDs
+    /// setting sound weights are reset and unflagged, and UIDs setting no weights lose their
+    /// streak but keep any flag. Returns the weight used.
+    pub fn update_weight_flags(netuid: u16) -> Weight {
+        let tempos: u16 = WeightFlagTempos::<T>::get(netuid);
+        if tempos == 0 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        // Weights of every UID that set weights, and how many UIDs set each. Stored rows hold
+        // only non-zero weights, so a UID that set all-zero weights has an empty row.
+        let rows: Vec<(u16, Vec<(u16, u16)>)> = Weights::<T>::iter_prefix(netuid)
+            .map(|(uid, row)| (uid, row.into_inner()))
+            .collect();
+        let mut setters: BTreeMap<&Vec<(u16, u16)>, u16> = BTreeMap::new();
+        for (_, row) in rows.iter().filter(|(_, row)| !row.is_empty()) {
+            let count: &mut u16 = setters.entry(row).or_insert(0);
+            *count = count.saturating_add(1);
+        }
+        let duplicate_keys: u16 = WeightFlagDuplicateKeys::<T>::get(netuid);
+        let block: u64 = Self::get_current_block_as_u64();
+        let mut reads: u64 = (rows.len() as u64).saturating_add(3);
+        let mut writes: u64 = 0;
+
+        for (uid, row) in rows.iter() {
+            let degenerate: Option<DegenerateWeights> = match row.as_slice() {
+                [] => Some(DegenerateWeights::AllZero),
+                [(target, _)] if target == uid => Some(DegenerateWeights::SelfOnly),
+                _ if setters.get(&row).copied().unwrap_or(0) >= duplicate_keys => {
+                    Some(DegenerateWeights::Duplicate)
+                }
+                _ => None,
+            };
+            reads = reads.saturating_add(2);
+            writes = writes.saturating_add(2);
+            let Some(reason) = degenerate else {
+                Self::reset_weight_flag(netuid, *uid);
+                continue;
+            };
+
+            let streak: u16 = DegenerateWeightStreaks::<T>::get(netuid, uid).saturating_add(1);
+            DegenerateWeightStreaks::<T>::insert(netuid, uid, streak);
+            if streak >= tempos && !FlaggedUids::<T>::contains_key(netuid, uid) {
+                FlaggedUids::<T>::insert(netuid, uid, (reason, block));
+                let hotkey: T::AccountId = Keys::<T>::get(netuid, uid);
+                reads = reads.saturating_add(1);
+                log::debug!(
+                    "UidFlagged( netuid:{:?}, uid:{:?}, hotkey:{:?}, reason:{:?} )",
+                    netuid,
+                    uid,
+                    hotkey,
+                    reason
+                );
+                Self::deposit_event(Event::UidFlagged {
+                    netuid,
+                    uid: *uid,
+                    hotkey,
+                    reason,
+                });
+            }
+        }
+
+        // A UID that stopped setting weights is no longer on a streak.
+        let setting: BTreeSet<u16> = rows.iter().map(|(uid, _)| *uid).collect();
+        let streaks: Vec<u16> = DegenerateWeightStreaks::<T>::iter_prefix(netuid)
+            .map(|(uid, _)| uid)
+            .collect();
+        reads = reads.saturating_add(streaks.len() as u64);
+        let stale: Vec<u16> = streaks
+            .into_iter()
+            .filter(|uid| !setting.contains(uid))
+            .collect();
+        writes = writes.saturating_add(stale.len() as u64);
+        for uid in stale {
+            DegenerateWeightStreaks::<T>::remove(netuid, uid);
+        }
+
+        Weight::from_parts(2_000_000, 0)
+            .saturating_mul(rows.len() as u64)
+            .saturating_add(T::DbWeight::get().reads_writes(reads, writes))
+    }
+
+    /// Ends the streak of a UID whose weights are sound again, unflagging it.
+    fn reset_weight_flag(netuid: u16, uid: u16) {
+        DegenerateWeightStreaks::<T>::remove(netuid, uid);
+        if FlaggedUids::<T>::take(netuid, uid).is_some() {
+            log::debug!("UidUnflagged( netuid:{:?}, uid:{:?} )", netuid, uid);
+            Self::deposit_event(Event::UidUnflagged { netuid, uid });
+        }
+    }
+
+    /// Drops the streak and flag of a UID, when its neuron is replaced.
+    pub fn clear_weight_flag_for_uid(netuid: u16, uid: u16) {
+        DegenerateWeightStreaks::<T>::remove(netuid, uid);
+        FlaggedUids::<T>::remove(netuid, uid);
+    }
+
+    /// Returns the (uid, reason, block) of every UID flagged on the subnet.
+    pub fn get_flagged_uids(netuid: u16) -> Vec<(u16, DegenerateWeights, u64)> {
+        FlaggedUids::<T>::iter_prefix(netuid)
+            .map(|(uid, (reason, block))| (uid, reason, block))
+            .collect()
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::epoch::math::*;
+use frame_support::dispatch::Pays;
 use sp_core::H256;
 use sp_runtime::traits::{BlakeTwo256, Hash};
 use sp_std::vec;
@@ -15,7 +16,8 @@ impl<T: Config> Pallet<T> {
     ///   - The u16 network identifier.
     ///
     /// * `commit_hash` (`H256`):
-    ///   - The hash representing the committed weights.
+    ///   - The hash representing the committed weights, as computed by `get_commit_hash` for
+    ///     the tempo index of the commit block.
     ///
     /// # Raises:
     /// * `WeightsCommitNotAllowed`:
@@ -26,7 +28,7 @@ impl<T: Config> Pallet<T> {
         netuid: u16,
         commit_hash: H256,
     ) -> DispatchResult {
-        let who = ensure_signed(origin)?;
+        let who = Self::ensure_weights_origin(origin, netuid)?;
 
         log::debug!("do_commit_weights( hotkey:{:?} netuid:{:?})", who, netuid);
 
@@ -40,10 +42,15 @@ impl<T: Config> Pallet<T> {
             Error::<T>::WeightsCommitNotAllowed
         );
 
+        let current_block: u64 = Self::get_current_block_as_u64();
         WeightCommits::<T>::insert(
             netuid,
             &who,
-            (commit_hash, Self::get_current_block_as_u64()),
+            (
+                commit_hash,
+                current_block,
+                Some(Self::get_commit_tempo_index(netuid, current_block)),
+            ),
         );
         Ok(())
     }
@@ -76,6 +83,9 @@ impl<T: Config> Pallet<T> {
     /// * `InvalidRevealCommitHashNotMatchTempo`:
     ///   - Attempting to reveal weights outside the valid tempo.
     ///
+    /// * `CommitHashTempoMismatch`:
+    ///   - The committed hash was computed for another tempo index.
+    ///
     /// * `InvalidRevealCommitHashNotMatch`:
     ///   - The revealed hash does not match the committed hash.
     ///
@@ -87,7 +97,7 @@ impl<T: Config> Pallet<T> {
         salt: Vec<u16>,
         version_key: u64,
     ) -> DispatchResult {
-        let who = ensure_signed(origin.clone())?;
+        let who = Self::ensure_weights_origin(origin.clone(), netuid)?;
 
         log::debug!("do_reveal_weights( hotkey:{:?} netuid:{:?})", who, netuid);
 
@@ -97,7 +107,7 @@ impl<T: Config> Pallet<T> {
         );
 
         WeightCommits::<T>::try_mutate_exists(netuid, &who, |maybe_commit| -> DispatchResult {
-            let (commit_hash, commit_block) = maybe_commit
+            let (commit_hash, commit_block, tempo_index) = maybe_commit
                 .as_ref()
                 .ok_or(Error::<T>::NoWeightsCommitFound)?;
 
@@ -106,18 +116,38 @@ impl<T: Config> Pallet<T> {
                 Error::<T>::InvalidRevealCommitTempo
             );
 
-            let provided_hash: H256 = BlakeTwo256::hash_of(&(
-                who.clone(),
-                netuid,
-                uids.clone(),
-                values.clone(),
-                salt.clone(),
-                version_key,
-            ));
-            ensure!(
-                provided_hash == *commit_hash,
-                Error::<T>::InvalidRevealCommitHashNotMatch
-            );
+            // The tempo index is stored with the commit, so a change of the interval between
+            // the commit and the reveal does not change the hash.
+            let hash_for = |tempo_index: u64| -> H256 {
+                Self::get_commit_hash(
+                    &who,
+                    netuid,
+                    tempo_index,
+                    &uids,
+                    &values,
+                    &salt,
+                    version_key,
+                )
+            };
+            match *tempo_index {
+                Some(tempo_index) => {
+                    if hash_for(tempo_index) != *commit_hash {
+                        // Tell a commit made for an adjacent tempo apart from a wrong reveal.
+                        let other_tempo: bool =
+                            [tempo_index.saturating_sub(1), tempo_index.saturating_add(1)]
+                                .into_iter()
+                                .filter(|index| *index != tempo_index)
+                                .any(|index| hash_for(index) == *commit_hash);
+                        ensure!(!other_tempo, Error::<T>::CommitHashTempoMismatch);
+                        return Err(Error::<T>::InvalidRevealCommitHashNotMatch.into());
+                    }
+                }
+                None => ensure!(
+                    Self::get_legacy_commit_hash(&who, netuid, &uids, &values, &salt, version_key)
+                        == *commit_hash,
+                    Error::<T>::InvalidRevealCommitHashNotMatch
+                ),
+            }
 
             Self::do_set_weights(origin, netuid, uids, values, version_key)
         })
@@ -158,6 +188,9 @@ impl<T: Config> Pallet<T> {
     ///  * 'SettingWeightsTooFast':
     ///    - Attempting to set weights faster than the weights_set_rate_limit.
     ///
+    ///  * 'WeightsImmunityPeriodActive':
+    ///    - Attempting to set weights within the weights immunity period after registration.
+    ///
     ///  * 'NeuronNoValidatorPermit':
     ///    - Attempting to set non-self weights without a validator permit.
     ///
@@ -176,6 +209,9 @@ impl<T: Config> Pallet<T> {
     /// * 'WeightVecLengthIsLow':
     ///    - Attempting to set weights with fewer weights than min.
     ///
+    /// * 'WeightVecLengthIsHigh':
+    ///    - Attempting to set weights with more weights than max.
+    ///
     /// * 'MaxWeightExceeded':
     ///    - Attempting to set weights with max value exceeding limit.
     ///
@@ -186,8 +222,22 @@ impl<T: Config> Pallet<T> {
         values: Vec<u16>,
         version_key: u64,
     ) -> dispatch::DispatchResult {
-        // --- 1. Check the caller's signature. This is the hotkey of a registered account.
-        let hotkey = ensure_signed(origin)?;
+        // --- 1. Check the caller's signature. This is the hotkey of a registered account,
+        // or the weights signer it has appointed.
+        let hotkey = Self::ensure_weights_origin(origin, netuid)?;
+        Self::set_weights_for_hotkey(hotkey, netuid, uids, values, version_key)
+    }
+
+    /// Sets the weights of `hotkey` once the caller has been authenticated, either by
+    /// signature in do_set_weights or by the embedded hotkey signature in
+    /// do_set_weights_unsigned. Raises the same errors as do_set_weights.
+    pub fn set_weights_for_hotkey(
+        hotkey: T::AccountId,
+        netuid: u16,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        version_key: u64,
+    ) -> dispatch::DispatchResult {
         log::debug!(
             "do_set_weights( origin:{:?} netuid:{:?}, uids:{:?}, values:{:?})",
             hotkey,
@@ -220,6 +270,18 @@ impl<T: Config> Pallet<T> {
             Error::<T>::UidsLengthExceedUidsInSubNet
         );
 
+        // --- 4a. Check to see if the number of uids fits in a stored weights row.
+        ensure!(
+            Self::check_weights_row_length(&uids),
+            Error::<T>::WeightsRowTooLong
+        );
+
+        // --- 4b. Check to see if the number of uids is within the subnet's max allowed weights.
+        ensure!(
+            Self::check_max_allowed_weights(netuid, &uids),
+            Error::<T>::WeightVecLengthIsHigh
+        );
+
         // --- 5. Check to see if the hotkey is registered to the passed network.
         ensure!(
             Self::is_hotkey_registered_on_network(netuid, &hotkey),
@@ -246,6 +308,14 @@ impl<T: Config> Pallet<T> {
             Error::<T>::SettingWeightsTooFast
         );
 
+        // --- 9a. Ensure the neuron has been registered for at least the weights immunity period.
+        ensure!(
+            current_block
+                .saturating_sub(Self::get_neuron_block_at_registration(netuid, neuron_uid))
+                >= Self::get_weights_immunity_period(netuid),
+            Error::<T>::WeightsImmunityPeriodActive
+        );
+
         // --- 10. Check that the neuron uid is an allowed validator permitted to set non-self weights.
         ensure!(
             Self::check_validator_permit(netuid, neuron_uid, &uids, &values),
@@ -283,7 +353,11 @@ impl<T: Config> Pallet<T> {
         }
 
         // --- 17. Set weights under netuid, uid double map entry.
-        Weights::<T>::insert(netuid, neuron_uid, zipped_weights);
+        let filtered_uids: Vec<(u16, WeightsFilter)> =
+            Self::filtered_weight_uids(netuid, neuron_uid, &zipped_weights);
+        let bounded_weights: WeightsRowOf<T> =
+            Self::bound_weights_row(netuid, Self::compress_weights_row(zipped_weights))?;
+        Weights::<T>::insert(netuid, neuron_uid, bounded_weights);
 
         // --- 18. Set the activity for the weights on this network.
         Self::set_last_update_for_uid(netuid, neuron_uid, current_block);
@@ -295,6 +369,8 @@ impl<T: Config> Pallet<T> {
             neuron_uid
         );
         Self::deposit_event(Event::WeightsSet(netuid, neuron_uid));
+        Self::record_block_metric(|m| m.weight_sets = m.weight_sets.saturating_add(1));
+        Self::deposit_weights_filtered(netuid, neuron_uid, filtered_uids);
 
         // --- 20. Return ok.
         Ok(())
@@ -304,6 +380,39 @@ impl<T: Config> Pallet<T> {
     // ==== Helper functions ====
     // ==========================
 
+    /// Returns whether a successful set_weights or reveal_weights by `hotkey` pays its fee. It
+    /// is free once per weights rate-limit window (at least one block) for a hotkey holding a
+    /// validator permit on the subnet; granting the free call records it.
+    pub fn use_free_weights_quota(hotkey: &T::AccountId, netuid: u16) -> Pays {
+        let Ok(uid) = Self::get_uid_for_net_and_hotkey(netuid, hotkey) else {
+            return Pays::Yes;
+        };
+        if !Self::get_validator_permit_for_uid(netuid, uid) {
+            return Pays::Yes;
+        }
+
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let window: u64 = Self::get_weights_set_rate_limit(netuid).max(1);
+        let quota_used: bool = FreeWeightsSetBlock::<T>::get(netuid, uid)
+            .is_some_and(|last| current_block.saturating_sub(last) < window);
+        if quota_used {
+            return Pays::Yes;
+        }
+
+        FreeWeightsSetBlock::<T>::insert(netuid, uid, current_block);
+        Pays::No
+    }
+
+    /// Returns whether a successful commit_weights by `hotkey` pays its fee. It is free for a
+    /// hotkey holding a validator permit on the subnet, as can_commit already allows a single
+    /// commit per commit interval.
+    pub fn commit_weights_pays(hotkey: &T::AccountId, netuid: u16) -> Pays {
+        match Self::get_uid_for_net_and_hotkey(netuid, hotkey) {
+            Ok(uid) if Self::get_validator_permit_for_uid(netuid, uid) => Pays::No,
+            _ => Pays::Yes,
+        }
+    }
+
     /// Returns true if version_key is up-to-date.
     ///
     pub fn check_version_key(netuid: u16, version_key: u64) -> bool {
@@ -452,9 +561,93 @@ impl<T: Config> Pallet<T> {
         uids.len() <= subnetwork_n as usize
     }
 
+    /// Returns False if the number of uids exceeds the subnet's max allowed weights.
+    pub fn check_max_allowed_weights(netuid: u16, uids: &[u16]) -> bool {
+        uids.len() <= Self::get_max_allowed_weights(netuid) as usize
+    }
+
+    /// Returns False if the number of uids exceeds the per-row weights storage bound.
+    pub fn check_weights_row_length(uids: &[u16]) -> bool {
+        uids.len() <= T::MaxWeightsRowLength::get() as usize
+    }
+
+    /// Returns the uids of a weights row set by `neuron_uid` that are filtered, with the reason
+    /// for each: zero weights are dropped by compress_weights_row, and on a subnet the epoch
+    /// masks self-weights and rows below the weights floor as get_weights_below_floor does.
+    pub fn filtered_weight_uids(
+        netuid: u16,
+        neuron_uid: u16,
+        row: &[(u16, u16)],
+    ) -> Vec<(u16, WeightsFilter)> {
+        let mut filtered: Vec<(u16, WeightsFilter)> = row
+            .iter()
+            .filter(|(_, weight)| *weight == 0)
+            .map(|(uid, _)| (*uid, WeightsFilter::ZeroWeight))
+            .collect();
+        if netuid == Self::get_root_netuid() {
+            return filtered;
+        }
+
+        let weighted_uids: Vec<u16> = row
+            .iter()
+            .filter(|(_, weight)| *weight > 0)
+            .map(|(uid, _)| *uid)
+            .collect();
+        let floor: usize =
+            Self::get_min_allowed_weights(netuid).min(Self::get_subnetwork_n(netuid)) as usize;
+        if weighted_uids.len() < floor && weighted_uids.as_slice() != [neuron_uid] {
+            filtered.extend(
+                weighted_uids
+                    .into_iter()
+                    .map(|uid| (uid, WeightsFilter::BelowWeightFloor)),
+            );
+        } else if weighted_uids.contains(&neuron_uid) {
+            filtered.push((neuron_uid, WeightsFilter::SelfWeight));
+        }
+        filtered
+    }
+
+    /// Reports the uids left out of an otherwise applied weights set, if there are any.
+    pub fn deposit_weights_filtered(
+        netuid: u16,
+        uid: u16,
+        filtered_uids: Vec<(u16, WeightsFilter)>,
+    ) {
+        if filtered_uids.is_empty() {
+            return;
+        }
+        Self::deposit_event(Event::WeightsFiltered {
+            netuid,
+            uid,
+            filtered_uids,
+        });
+    }
+
+    /// Compresses a weights row for storage: drops zero values and sorts the pairs by uid.
+    pub fn compress_weights_row(mut row: Vec<(u16, u16)>) -> Vec<(u16, u16)> {
+        row.retain(|(_, weight)| *weight > 0);
+        row.sort_by_key(|(uid, _)| *uid);
+        row
+    }
+
+    /// Bounds a compressed weights row by the limit of its subnet: the max allowed uids, or
+    /// the number of subnets on the root network. Both fit within MaxWeightsRowLength.
+    pub fn bound_weights_row(
+        netuid: u16,
+        row: Vec<(u16, u16)>,
+    ) -> Result<WeightsRowOf<T>, Error<T>> {
+        let limit: u16 = if netuid == Self::get_root_netuid() {
+            Self::get_num_subnets()
+        } else {
+            Self::get_max_allowed_uids(netuid)
+        };
+        ensure!(row.len() <= limit as usize, Error::<T>::WeightsRowTooLong);
+        WeightsRowOf::<T>::try_from(row).map_err(|_| Error::<T>::WeightsRowTooLong)
+    }
+
     #[allow(clippy::arithmetic_side_effects)]
     pub fn can_commit(netuid: u16, who: &T::AccountId) -> bool {
-        if let Some((_hash, commit_block)) = WeightCommits::<T>::get(netuid, who) {
+        if let Some((_hash, commit_block, _)) = WeightCommits::<T>::get(netuid, who) {
             let interval: u64 = Self::get_commit_reveal_weights_interval(netuid);
             if interval == 0 {
                 return true; //prevent division by 0
@@ -498,4 +691,38 @@ impl<T: Config> Pallet<T> {
 
         false
     }
+
+    /// Returns the index of the commit-reveal interval `block` falls in on `netuid`, which a
+    /// commit hash is bound to.
+    pub fn get_commit_tempo_index(netuid: u16, block: u64) -> u64 {
+        block
+            .checked_div(Self::get_commit_reveal_weights_interval(netuid))
+            .unwrap_or(0)
+    }
+
+    /// Returns the hash a hotkey commits to reveal `uids` and `values` on `netuid`, bound to
+    /// the tempo index of the commit so it cannot be replayed on another subnet or interval.
+    pub fn get_commit_hash(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        tempo_index: u64,
+        uids: &[u16],
+        values: &[u16],
+        salt: &[u16],
+        version_key: u64,
+    ) -> H256 {
+        BlakeTwo256::hash_of(&(hotkey, netuid, tempo_index, uids, values, salt, version_key))
+    }
+
+    /// Returns the hash of a commit made before commit hashes were bound to a tempo index.
+    pub fn get_legacy_commit_hash(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        uids: &[u16],
+        values: &[u16],
+        salt: &[u16],
+        version_key: u64,
+    ) -> H256 {
+        BlakeTwo256::hash_of(&(hotkey, netuid, uids, values, salt, version_key))
+    }
 }
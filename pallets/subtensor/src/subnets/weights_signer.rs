@@ -0,0 +1,106 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_weights_signer.
+    ///
+    /// Appoints `signer` as the only other key allowed to call set_weights, commit_weights and
+    /// reveal_weights on behalf of the calling hotkey. Any previous signer is replaced.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the delegating hotkey.
+    ///
+    /// * 'signer' (T::AccountId):
+    ///     - The key allowed to set weights for the hotkey.
+    ///
+    /// # Event:
+    /// * WeightsSignerSet;
+    ///     - On successfully appointing the signer.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     - The calling hotkey is not registered.
+    ///
+    /// * 'WeightsSignerAlreadyInUse':
+    ///     - The signer is a registered hotkey or already signs for a hotkey.
+    ///
+    pub fn do_set_weights_signer(
+        origin: T::RuntimeOrigin,
+        signer: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            !Self::hotkey_account_exists(&signer)
+                && !WeightSignerHotkey::<T>::contains_key(&signer),
+            Error::<T>::WeightsSignerAlreadyInUse
+        );
+
+        if let Some(previous) = WeightSigners::<T>::get(&hotkey) {
+            WeightSignerHotkey::<T>::remove(previous);
+        }
+        WeightSigners::<T>::insert(&hotkey, &signer);
+        WeightSignerHotkey::<T>::insert(&signer, &hotkey);
+
+        log::debug!(
+            "WeightsSignerSet( hotkey:{:?}, signer:{:?} )",
+            hotkey,
+            signer
+        );
+        Self::deposit_event(Event::WeightsSignerSet { hotkey, signer });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic remove_weights_signer.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the delegating hotkey.
+    ///
+    /// # Event:
+    /// * WeightsSignerRemoved;
+    ///     - On successfully removing the signer.
+    ///
+    /// # Raises:
+    /// * 'NoWeightsSigner':
+    ///     - The hotkey has no weights signer.
+    ///
+    pub fn do_remove_weights_signer(origin: T::RuntimeOrigin) -> dispatch::DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+
+        let signer = WeightSigners::<T>::take(&hotkey).ok_or(Error::<T>::NoWeightsSigner)?;
+        WeightSignerHotkey::<T>::remove(&signer);
+
+        log::debug!(
+            "WeightsSignerRemoved( hotkey:{:?}, signer:{:?} )",
+            hotkey,
+            signer
+        );
+        Self::deposit_event(Event::WeightsSignerRemoved { hotkey, signer });
+        Ok(())
+    }
+
+    /// Returns the hotkey `who` sets weights for on the subnet: `who` itself if it is
+    /// registered there, so a signer that later registers still signs for itself, otherwise
+    /// the hotkey it signs for, if any.
+    pub fn get_weights_hotkey(who: &T::AccountId, netuid: u16) -> T::AccountId {
+        if Self::is_hotkey_registered_on_network(netuid, who) {
+            return who.clone();
+        }
+        WeightSignerHotkey::<T>::get(who).unwrap_or_else(|| who.clone())
+    }
+
+    /// Checks the signature of a weights call on the subnet and resolves it to the hotkey it
+    /// acts for.
+    pub fn ensure_weights_origin(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+    ) -> Result<T::AccountId, DispatchError> {
+        let who = ensure_signed(origin)?;
+        Ok(Self::get_weights_hotkey(&who, netuid))
+    }
+}
@@ -0,0 +1,172 @@
+use super::*;
+use frame_support::pallet_prelude::{InvalidTransaction, TransactionValidity, ValidTransaction};
+use frame_support::storage::with_storage_layer;
+use frame_system::ensure_none;
+use sp_runtime::traits::Verify;
+use sp_std::vec::Vec;
+
+/// Domain separator prefixed to the payload a hotkey signs for unsigned set_weights.
+pub const UNSIGNED_WEIGHTS_CONTEXT: &[u8] = b"subtensor/set_weights_unsigned";
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_weights_unsigned.
+    ///
+    /// Sets weights from an unsigned transaction authenticated by the hotkey's signature over
+    /// weights_signature_payload, so the hotkey needs no balance to submit it. The nonce is
+    /// consumed even if the weights are rejected, which then emits UnsignedWeightsRejected
+    /// instead of failing, so a rejected payload cannot be replayed.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - Must be none.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey setting weights, which signed the payload.
+    ///
+    /// * 'nonce' (u64):
+    ///     - The hotkey's current WeightsNonce.
+    ///
+    /// * 'signature' (T::WeightsSignature):
+    ///     - The hotkey's signature over weights_signature_payload.
+    ///
+    /// The remaining args are those of set_weights.
+    ///
+    /// # Event:
+    /// * WeightsSet;
+    ///     - On successfully setting the weights.
+    ///
+    /// * UnsignedWeightsRejected;
+    ///     - When the payload is authentic but the weights fail a set_weights check.
+    ///
+    /// # Raises:
+    /// * 'InvalidWeightsNonce':
+    ///     - The nonce is not the hotkey's current WeightsNonce.
+    ///
+    /// * 'InvalidWeightsSignature':
+    ///     - The signature does not verify against the hotkey.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn do_set_weights_unsigned(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: u16,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        version_key: u64,
+        nonce: u64,
+        signature: T::WeightsSignature,
+    ) -> dispatch::DispatchResult {
+        ensure_none(origin)?;
+
+        Self::check_unsigned_weights(
+            &hotkey,
+            netuid,
+            &uids,
+            &values,
+            version_key,
+            nonce,
+            &signature,
+        )?;
+        WeightsNonce::<T>::insert(&hotkey, nonce.saturating_add(1));
+
+        let result: dispatch::DispatchResult = with_storage_layer(|| {
+            Self::set_weights_for_hotkey(hotkey.clone(), netuid, uids, values, version_key)
+        });
+        if let Err(error) = result {
+            log::debug!(
+                "UnsignedWeightsRejected( hotkey:{:?}, netuid:{:?}, error:{:?} )",
+                hotkey,
+                netuid,
+                error
+            );
+            Self::deposit_event(Event::UnsignedWeightsRejected {
+                hotkey,
+                netuid,
+                error,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the bytes a hotkey signs to submit weights with set_weights_unsigned. The
+    /// genesis hash binds the signature to this chain, so it cannot be replayed on another
+    /// network where the hotkey has the same nonce.
+    pub fn weights_signature_payload(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        uids: &[u16],
+        values: &[u16],
+        version_key: u64,
+        nonce: u64,
+    ) -> Vec<u8> {
+        (
+            UNSIGNED_WEIGHTS_CONTEXT,
+            Self::get_block_hash_from_u64(0),
+            hotkey,
+            netuid,
+            uids,
+            values,
+            version_key,
+            nonce,
+        )
+            .encode()
+    }
+
+    /// Checks the nonce and signature of an unsigned set_weights payload.
+    pub fn check_unsigned_weights(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        uids: &[u16],
+        values: &[u16],
+        version_key: u64,
+        nonce: u64,
+        signature: &T::WeightsSignature,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            nonce == WeightsNonce::<T>::get(hotkey),
+            Error::<T>::InvalidWeightsNonce
+        );
+        let payload: Vec<u8> =
+            Self::weights_signature_payload(hotkey, netuid, uids, values, version_key, nonce);
+        ensure!(
+            signature.verify(&payload[..], hotkey),
+            Error::<T>::InvalidWeightsSignature
+        );
+        Ok(())
+    }
+
+    /// Validates an unsigned set_weights transaction for the pool and block inclusion. Only
+    /// authentic payloads from hotkeys with enough stake to set weights are accepted.
+    pub fn validate_unsigned_set_weights(
+        hotkey: &T::AccountId,
+        netuid: u16,
+        uids: &[u16],
+        values: &[u16],
+        version_key: u64,
+        nonce: u64,
+        signature: &T::WeightsSignature,
+    ) -> TransactionValidity {
+        let current_nonce: u64 = WeightsNonce::<T>::get(hotkey);
+        if nonce < current_nonce {
+            return Err(InvalidTransaction::Stale.into());
+        }
+        if nonce > current_nonce {
+            return Err(InvalidTransaction::Future.into());
+        }
+        if Self::check_unsigned_weights(hotkey, netuid, uids, values, version_key, nonce, signature)
+            .is_err()
+        {
+            return Err(InvalidTransaction::BadProof.into());
+        }
+        if !Self::check_weights_min_stake(hotkey, netuid) {
+            return Err(InvalidTransaction::Custom(3).into());
+        }
+
+        ValidTransaction::with_tag_prefix("SubtensorUnsignedWeights")
+            .priority(Self::get_priority_set_weights(hotkey, netuid))
+            .and_provides((hotkey, nonce))
+            .longevity(1)
+            .propagate(true)
+            .build()
+    }
+}
@@ -120,6 +120,7 @@ impl<T: Config> Pallet<T> {
     /// 6. Swap hotkey owners:
     ///    - For each hotkey owned by the old coldkey, transfer ownership to the new coldkey.
     ///    - Update the list of owned hotkeys for both old and new coldkeys.
+    ///    - Revoke the delegate managers appointed by the old coldkey.
     ///
     /// 7. Transfer remaining balance:
     ///    - Transfer any remaining balance from the old coldkey to the new coldkey.
@@ -154,17 +155,31 @@ impl<T: Config> Pallet<T> {
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
 
+        // 2a. Swap subnet lessee.
+        // SubnetLease: MAP ( netuid ) --> (lessee, end_block) | Coldkey controlling a leased subnet.
+        for (netuid, (lessee, end_block)) in SubnetLease::<T>::iter() {
+            if lessee == *old_coldkey {
+                SubnetLease::<T>::insert(netuid, (new_coldkey.clone(), end_block));
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
         // 3. Swap Stake.
         // Stake: MAP ( hotkey, coldkey ) --> u64 | Stake of the hotkey for the coldkey.
+        let mut moved_stakes: Vec<(T::AccountId, u64)> = Vec::new();
         for hotkey in StakingHotkeys::<T>::get(old_coldkey) {
             // Get the stake on the old (hot,coldkey) account.
             let old_stake: u64 = Stake::<T>::get(&hotkey, old_coldkey);
+            moved_stakes.push((hotkey.clone(), old_stake));
             // Get the stake on the new (hot,coldkey) account.
             let new_stake: u64 = Stake::<T>::get(&hotkey, new_coldkey);
             // Add the stake to new account.
-            Stake::<T>::insert(&hotkey, new_coldkey, new_stake.saturating_add(old_stake));
+            Self::insert_stake_entry(&hotkey, new_coldkey, new_stake.saturating_add(old_stake));
             // Remove the value from the old account.
-            Stake::<T>::remove(&hotkey, old_coldkey);
+            Self::remove_stake_entry(&hotkey, old_coldkey);
+            // Move any stake lock to the new account.
+            Self::move_stake_lock(&hotkey, old_coldkey, &hotkey, new_coldkey);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 6));
             // Add the weight for the read and write.
             weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
         }
@@ -194,7 +209,17 @@ impl<T: Config> Pallet<T> {
             new_coldkey,
             new_coldkey_stake.saturating_add(old_coldkey_stake),
         );
-        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        // The stake now provides for the new account instead of the old one.
+        Self::update_stake_provider(old_coldkey);
+        Self::update_stake_provider(new_coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(6, 6));
+
+        // Report the moved stake once both coldkey totals are settled.
+        for (hotkey, stake) in moved_stakes {
+            Self::deposit_stake_removed_event(old_coldkey, &hotkey, stake);
+            Self::deposit_stake_added_event(new_coldkey, &hotkey, stake);
+            weight.saturating_accrue(Self::stake_event_weight().saturating_mul(2));
+        }
 
         // 6. Swap StakingHotkeys.
         // StakingHotkeys: MAP ( coldkey ) --> Vec<hotkeys> | Hotkeys staking for the coldkey.
@@ -220,6 +245,8 @@ impl<T: Config> Pallet<T> {
             Owner::<T>::remove(owned_hotkey);
             // Add the hotkey to the new coldkey.
             Owner::<T>::insert(owned_hotkey, new_coldkey.clone());
+            // Revoke any manager appointed by the old coldkey.
+            DelegateManagers::<T>::remove(owned_hotkey);
             // Addd the owned hotkey to the new set of owned hotkeys.
             if !new_owned_hotkeys.contains(owned_hotkey) {
                 new_owned_hotkeys.push(owned_hotkey.clone());
@@ -228,6 +255,16 @@ impl<T: Config> Pallet<T> {
         OwnedHotkeys::<T>::remove(old_coldkey);
         OwnedHotkeys::<T>::insert(new_coldkey, new_owned_hotkeys);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        weight.saturating_accrue(T::DbWeight::get().writes(old_owned_hotkeys.len() as u64));
+
+        // 7a. Swap the stake portfolio.
+        // StakePortfolios: MAP ( coldkey ) --> Vec<(hotkey, weight)> | Target allocation of the coldkey's stake.
+        if StakePortfolios::<T>::contains_key(old_coldkey) {
+            let targets = StakePortfolios::<T>::take(old_coldkey);
+            StakePortfolios::<T>::insert(new_coldkey, targets);
+            weight.saturating_accrue(T::DbWeight::get().writes(2));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
 
         // 8. Transfer remaining balance.
         // Balance: MAP ( coldkey ) --> u64 | Balance of the coldkey.
@@ -22,6 +22,7 @@ impl<T: Config> Pallet<T> {
     /// * `NewHotKeyIsSameWithOld` - If the new hotkey is the same as the old hotkey.
     /// * `HotKeyAlreadyRegisteredInSubNet` - If the new hotkey is already registered in the subnet.
     /// * `NotEnoughBalanceToPaySwapHotKey` - If there is not enough balance to pay for the swap.
+    /// * `HotkeyUnstakeInProgress` - If the coldkeys of the old hotkey are still being unstaked.
     pub fn do_swap_hotkey(
         origin: T::RuntimeOrigin,
         old_hotkey: &T::AccountId,
@@ -51,6 +52,13 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NonAssociatedColdKey
         );
 
+        // 6a. Ensure the stake of the old hotkey is not half way through being returned
+        ensure!(
+            !HotkeyUnstakeQueue::<T>::get().contains(old_hotkey),
+            Error::<T>::HotkeyUnstakeInProgress
+        );
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+
         // 7. Get the current block number
         let block: u64 = Self::get_current_block_as_u64();
 
@@ -204,7 +212,8 @@ impl<T: Config> Pallet<T> {
             let old_delegate_take = Delegates::<T>::get(old_hotkey);
             Delegates::<T>::remove(old_hotkey);
             Delegates::<T>::insert(new_hotkey, old_delegate_take);
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+            DelegateStatsCache::<T>::remove(old_hotkey);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 3));
         }
 
         // 9. swap PendingdHotkeyEmission
@@ -289,9 +298,12 @@ impl<T: Config> Pallet<T> {
 
         // 11. Swap Stake.
         // Stake( hotkey, coldkey ) -> stake -- the stake that the hotkey controls on behalf of the coldkey.
+        // The whole prefix moves within the swap, as no stake may be left on the old hotkey;
+        // the weight counts every entry moved.
         let stakes: Vec<(T::AccountId, u64)> = Stake::<T>::iter_prefix(old_hotkey).collect();
         // Clear the entire old prefix here.
         let _ = Stake::<T>::clear_prefix(old_hotkey, stakes.len() as u32, None);
+        HotkeyNominatorCount::<T>::remove(old_hotkey);
         // Iterate over all the staking rows and insert them into the new hotkey.
         for (coldkey, old_stake_amount) in stakes {
             weight.saturating_accrue(T::DbWeight::get().reads(1));
@@ -301,12 +313,19 @@ impl<T: Config> Pallet<T> {
             // Get the new stake value.
             let new_stake_value: u64 = Stake::<T>::get(new_hotkey, &coldkey);
             // Insert the new stake value.
-            Stake::<T>::insert(
+            Self::insert_stake_entry(
                 new_hotkey,
                 &coldkey,
                 new_stake_value.saturating_add(old_stake_amount),
             );
+            Self::deposit_stake_removed_event(&coldkey, old_hotkey, old_stake_amount);
+            Self::deposit_stake_added_event(&coldkey, new_hotkey, old_stake_amount);
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            weight.saturating_accrue(Self::stake_event_weight().saturating_mul(2));
+
+            // Swap StakeLocks.
+            Self::move_stake_lock(old_hotkey, &coldkey, new_hotkey, &coldkey);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 6));
 
             // Swap StakingHotkeys.
             // StakingHotkeys( coldkey ) --> Vec<hotkey> -- the hotkeys that the coldkey stakes.
@@ -315,6 +334,18 @@ impl<T: Config> Pallet<T> {
             staking_hotkeys.push(new_hotkey.clone());
             StakingHotkeys::<T>::insert(coldkey.clone(), staking_hotkeys);
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+            // Swap the hotkey in the coldkey's stake portfolio.
+            StakePortfolios::<T>::mutate_exists(&coldkey, |maybe_targets| {
+                if let Some(targets) = maybe_targets {
+                    for (hotkey, _) in targets.iter_mut() {
+                        if *hotkey == *old_hotkey {
+                            *hotkey = new_hotkey.clone();
+                        }
+                    }
+                }
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
 
         // 12. Swap ChildKeys.
@@ -365,6 +396,100 @@ impl<T: Config> Pallet<T> {
             weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
         }
 
+        // 14. Swap the per-subnet emission accumulators.
+        for (netuid, emission) in Self::get_hotkey_emission_by_netuid(old_hotkey) {
+            HotkeyEmissionByNetuid::<T>::remove(netuid, old_hotkey);
+            HotkeyEmissionByNetuid::<T>::mutate(netuid, new_hotkey, |new_emission| {
+                *new_emission = new_emission.saturating_add(emission)
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+
+        // 15. Swap the weights signer.
+        if let Some(signer) = WeightSigners::<T>::take(old_hotkey) {
+            WeightSignerHotkey::<T>::insert(&signer, new_hotkey);
+            WeightSigners::<T>::insert(new_hotkey, signer);
+            weight.saturating_accrue(T::DbWeight::get().writes(3));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+
+        // 16. Swap the stake checkpoints.
+        let checkpoints = StakeCheckpoints::<T>::take(old_hotkey);
+        if !checkpoints.is_empty() {
+            StakeCheckpoints::<T>::insert(new_hotkey, checkpoints);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+        // 17. Swap the delegate deregistration block.
+        if let Some(block) = DelegateDeregisteredAt::<T>::take(old_hotkey) {
+            DelegateDeregisteredAt::<T>::insert(new_hotkey, block);
+            DelegateInactivityExpirations::<T>::mutate(
+                block.saturating_add(T::DelegateInactivityGracePeriod::get()),
+                |hotkeys| {
+                    for hotkey in hotkeys.iter_mut() {
+                        if hotkey == old_hotkey {
+                            *hotkey = new_hotkey.clone();
+                        }
+                    }
+                },
+            );
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+        // 18. Swap the delegate manager.
+        if let Some(manager) = DelegateManagers::<T>::take(old_hotkey) {
+            DelegateManagers::<T>::insert(new_hotkey, manager);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+        // 19. Swap the reserved hotkeys.
+        for netuid in Self::get_all_subnet_netuids() {
+            ReservedHotkeys::<T>::mutate(netuid, |reserved| {
+                if reserved.contains(new_hotkey) {
+                    reserved.retain(|hotkey| hotkey != old_hotkey);
+                } else if let Some(hotkey) =
+                    reserved.iter_mut().find(|hotkey| *hotkey == old_hotkey)
+                {
+                    *hotkey = new_hotkey.clone();
+                }
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        // 20. Swap the delegate yields.
+        for netuid in Self::get_all_subnet_netuids() {
+            if let Some(delegate_yield) = DelegateYields::<T>::take(netuid, old_hotkey) {
+                DelegateYields::<T>::insert(netuid, new_hotkey, delegate_yield);
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        // 21. Swap the delegation opt-out.
+        if DelegationOptOut::<T>::take(old_hotkey) {
+            DelegationOptOut::<T>::insert(new_hotkey, true);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+        // 22. Swap the emission sanction, so a swap does not escape it.
+        if let Some(expiry_block) = SanctionedHotkeys::<T>::take(old_hotkey) {
+            SanctionedHotkeys::<T>::insert(new_hotkey, expiry_block);
+            SanctionExpirations::<T>::append(expiry_block, new_hotkey.clone());
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+        // 23. Swap the public good flag, so a swap does not escape the take lock.
+        if let Some(release_block) = PublicGoodDelegates::<T>::take(old_hotkey) {
+            PublicGoodDelegates::<T>::insert(new_hotkey, release_block);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
         // Return successful after swapping all the relevant terms.
         Ok(())
     }
@@ -0,0 +1,22 @@
+use super::*;
+use frame_support::traits::Get;
+
+impl<T: Config> Pallet<T> {
+    /// Updates the counters of the current block. A no-op unless the `metrics` feature is enabled.
+    pub fn record_block_metric(update: impl FnOnce(&mut BlockMetricsInfo)) {
+        if cfg!(feature = "metrics") {
+            BlockMetrics::<T>::mutate(Self::get_current_block_as_u64(), update);
+        }
+    }
+
+    /// Removes the counters of the block that just left the retention window.
+    pub fn prune_block_metrics() {
+        if cfg!(feature = "metrics") {
+            if let Some(expired_block) =
+                Self::get_current_block_as_u64().checked_sub(T::MetricsRetentionBlocks::get())
+            {
+                BlockMetrics::<T>::remove(expired_block);
+            }
+        }
+    }
+}
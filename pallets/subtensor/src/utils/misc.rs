@@ -4,7 +4,7 @@ use crate::{
     Error,
 };
 use sp_core::Get;
-use sp_core::U256;
+use sp_core::{H256, U256};
 use sp_runtime::Saturating;
 use substrate_fixed::types::I32F32;
 
@@ -15,7 +15,7 @@ impl<T: Config> Pallet<T> {
     ) -> Result<(), DispatchError> {
         let coldkey = ensure_signed_or_root(o);
         match coldkey {
-            Ok(Some(who)) if SubnetOwner::<T>::get(netuid) == who => Ok(()),
+            Ok(Some(who)) if Self::get_subnet_controller(netuid) == who => Ok(()),
             Ok(Some(_)) => Err(DispatchError::BadOrigin),
             Ok(None) => Ok(()),
             Err(x) => Err(x.into()),
@@ -273,6 +273,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NonAssociatedColdKey
         );
 
+        // Ensure the hotkey is still registered somewhere, or within its grace period.
+        Self::ensure_delegate_active(hotkey)?;
+
         Ok(())
     }
 
@@ -334,8 +337,11 @@ impl<T: Config> Pallet<T> {
         MaxDelegateTake::<T>::get()
     }
     pub fn get_default_delegate_take() -> u16 {
-        // Default to maximum
-        MaxDelegateTake::<T>::get()
+        DefaultTake::<T>::get().min(MaxDelegateTake::<T>::get())
+    }
+    pub fn set_default_delegate_take(take: u16) {
+        DefaultTake::<T>::put(take);
+        Self::deposit_event(Event::DefaultTakeSet(take));
     }
     // get_default_childkey_take
     pub fn get_default_childkey_take() -> u16 {
@@ -470,6 +476,13 @@ impl<T: Config> Pallet<T> {
         MinAllowedWeights::<T>::insert(netuid, min_allowed_weights);
         Self::deposit_event(Event::MinAllowedWeightSet(netuid, min_allowed_weights));
     }
+    pub fn get_max_allowed_weights(netuid: u16) -> u16 {
+        MaxAllowedWeights::<T>::get(netuid)
+    }
+    pub fn set_max_allowed_weights(netuid: u16, max_allowed_weights: u16) {
+        MaxAllowedWeights::<T>::insert(netuid, max_allowed_weights);
+        Self::deposit_event(Event::MaxAllowedWeightsSet(netuid, max_allowed_weights));
+    }
 
     pub fn get_max_allowed_uids(netuid: u16) -> u16 {
         MaxAllowedUids::<T>::get(netuid)
@@ -499,6 +512,24 @@ impl<T: Config> Pallet<T> {
     pub fn set_commit_reveal_weights_enabled(netuid: u16, enabled: bool) {
         CommitRevealWeightsEnabled::<T>::set(netuid, enabled);
     }
+    pub fn get_prune_emission_burn(netuid: u16) -> bool {
+        PruneEmissionBurn::<T>::get(netuid)
+    }
+    pub fn set_prune_emission_burn(netuid: u16, burn: bool) {
+        PruneEmissionBurn::<T>::set(netuid, burn);
+    }
+    pub fn get_min_axon_version(netuid: u16) -> u32 {
+        MinAxonVersion::<T>::get(netuid)
+    }
+    pub fn set_min_axon_version(netuid: u16, version: u32) {
+        MinAxonVersion::<T>::set(netuid, version);
+    }
+    pub fn get_weights_immunity_period(netuid: u16) -> u64 {
+        WeightsImmunityPeriod::<T>::get(netuid)
+    }
+    pub fn set_weights_immunity_period(netuid: u16, period: u64) {
+        WeightsImmunityPeriod::<T>::set(netuid, period);
+    }
 
     pub fn get_rho(netuid: u16) -> u16 {
         Rho::<T>::get(netuid)
@@ -514,6 +545,13 @@ impl<T: Config> Pallet<T> {
         ActivityCutoff::<T>::insert(netuid, activity_cutoff);
         Self::deposit_event(Event::ActivityCutoffSet(netuid, activity_cutoff));
     }
+    pub fn get_active_permits_only(netuid: u16) -> bool {
+        ActivePermitsOnly::<T>::get(netuid)
+    }
+    pub fn set_active_permits_only(netuid: u16, active_permits_only: bool) {
+        ActivePermitsOnly::<T>::insert(netuid, active_permits_only);
+        Self::deposit_event(Event::ActivePermitsOnlySet(netuid, active_permits_only));
+    }
 
     // Registration Toggle utils
     pub fn get_network_registration_allowed(netuid: u16) -> bool {
@@ -569,6 +607,37 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::MaxBurnSet(netuid, max_burn));
     }
 
+    pub fn get_min_stake_to_register(netuid: u16) -> u64 {
+        MinStakeToRegister::<T>::get(netuid)
+    }
+    pub fn set_min_stake_to_register(netuid: u16, min_stake: u64) {
+        MinStakeToRegister::<T>::insert(netuid, min_stake);
+    }
+    pub fn get_max_min_stake_to_register() -> u64 {
+        MaxMinStakeToRegister::<T>::get()
+    }
+    pub fn set_max_min_stake_to_register(max_min_stake: u64) {
+        MaxMinStakeToRegister::<T>::put(max_min_stake);
+    }
+    pub fn get_registration_challenge_commit(netuid: u16) -> Option<H256> {
+        RegistrationChallengeCommit::<T>::get(netuid)
+    }
+    pub fn set_registration_challenge_commit(netuid: u16, commit: Option<H256>) {
+        RegistrationChallengeCommit::<T>::set(netuid, commit);
+    }
+    pub fn get_reserved_hotkeys(netuid: u16) -> Vec<T::AccountId> {
+        ReservedHotkeys::<T>::get(netuid).into_inner()
+    }
+    pub fn set_reserved_hotkeys(netuid: u16, hotkeys: ReservedHotkeysOf<T>) {
+        ReservedHotkeys::<T>::insert(netuid, hotkeys);
+    }
+    pub fn get_max_reserved_uids() -> u16 {
+        MaxReservedUids::<T>::get()
+    }
+    pub fn set_max_reserved_uids(max_reserved: u16) {
+        MaxReservedUids::<T>::put(max_reserved);
+    }
+
     pub fn get_difficulty_as_u64(netuid: u16) -> u64 {
         Difficulty::<T>::get(netuid)
     }
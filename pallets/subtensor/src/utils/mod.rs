@@ -1,5 +1,11 @@
 use super::*;
 pub mod identity;
+pub mod metrics;
 pub mod misc;
+pub mod orphan_cleanup;
+pub mod pending_hyperparams;
 pub mod rate_limiting;
+pub mod safe_math_audit;
+pub mod scheduled_operations;
+pub mod storage_pages;
 pub mod try_state;
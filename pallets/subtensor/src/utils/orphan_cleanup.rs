@@ -0,0 +1,116 @@
+use super::*;
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::vec::Vec;
+
+/// Scan phases of the orphan cleanup, in order.
+const PHASE_STAKE: u8 = 0;
+const PHASE_AXONS: u8 = 1;
+const PHASE_DELEGATES: u8 = 2;
+
+impl<T: Config> Pallet<T> {
+    /// Incrementally removes orphaned storage records within `remaining_weight`.
+    ///
+    /// The scan walks Stake, then Axons, then Delegates, and stores its position so that the
+    /// next call picks up where this one stopped. At most one full pass is made per call.
+    ///
+    /// Returns the weight consumed.
+    pub fn clean_orphaned_storage(remaining_weight: Weight) -> Weight {
+        // Reading and writing the phase and cursor.
+        let mut used_weight: Weight = T::DbWeight::get().reads_writes(2, 2);
+        // Worst case for visiting and removing a single record.
+        let item_weight: Weight = T::DbWeight::get().reads_writes(4, 4);
+        if remaining_weight.any_lt(used_weight.saturating_add(item_weight)) {
+            return Weight::zero();
+        }
+
+        let mut phase: u8 = OrphanCleanupPhase::<T>::get();
+        let mut cursor: Option<Vec<u8>> = OrphanCleanupCursor::<T>::get();
+
+        while remaining_weight.all_gte(used_weight.saturating_add(item_weight)) {
+            used_weight = used_weight.saturating_add(item_weight);
+            let visited: Option<Vec<u8>> = match phase {
+                PHASE_STAKE => Self::clean_next_orphaned_stake(cursor.take()),
+                PHASE_AXONS => Self::clean_next_orphaned_axon(cursor.take()),
+                _ => Self::clean_next_orphaned_delegate(cursor.take()),
+            };
+            match visited {
+                Some(raw_key) => cursor = Some(raw_key),
+                None => {
+                    // The current map is exhausted, move on to the next one.
+                    let finished_pass: bool = phase >= PHASE_DELEGATES;
+                    phase = if finished_pass {
+                        PHASE_STAKE
+                    } else {
+                        phase.saturating_add(1)
+                    };
+                    if finished_pass {
+                        break;
+                    }
+                }
+            }
+        }
+
+        OrphanCleanupPhase::<T>::put(phase);
+        match cursor {
+            Some(raw_key) => OrphanCleanupCursor::<T>::put(raw_key),
+            None => OrphanCleanupCursor::<T>::kill(),
+        }
+        used_weight
+    }
+
+    /// Visits the Stake entry after `cursor` and removes it if it is zero and its hotkey is
+    /// no longer registered on any subnet. Returns the raw key visited, or None at the end of the map.
+    fn clean_next_orphaned_stake(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+        let mut iter = match cursor {
+            Some(raw_key) => Stake::<T>::iter_from(raw_key),
+            None => Stake::<T>::iter(),
+        };
+        let (hotkey, coldkey, stake) = iter.next()?;
+        let raw_key: Vec<u8> = iter.last_raw_key().to_vec();
+
+        if stake == 0 && !Self::is_hotkey_registered_on_any_network(&hotkey) {
+            Self::remove_stake_entry(&hotkey, &coldkey);
+            StakingHotkeys::<T>::mutate(&coldkey, |hotkeys| hotkeys.retain(|h| *h != hotkey));
+            StakeDeltaSinceLastEmissionDrain::<T>::remove(&hotkey, &coldkey);
+            OrphanedStakeRemoved::<T>::mutate(|count| *count = count.saturating_add(1));
+        }
+        Some(raw_key)
+    }
+
+    /// Visits the Axons entry after `cursor` and removes it if the hotkey no longer holds
+    /// a uid on that subnet. Returns the raw key visited, or None at the end of the map.
+    fn clean_next_orphaned_axon(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+        let mut iter = match cursor {
+            Some(raw_key) => Axons::<T>::iter_from(raw_key),
+            None => Axons::<T>::iter(),
+        };
+        let (netuid, hotkey, _) = iter.next()?;
+        let raw_key: Vec<u8> = iter.last_raw_key().to_vec();
+
+        if !Self::is_hotkey_registered_on_network(netuid, &hotkey) {
+            Axons::<T>::remove(netuid, &hotkey);
+            OrphanedAxonsRemoved::<T>::mutate(|count| *count = count.saturating_add(1));
+        }
+        Some(raw_key)
+    }
+
+    /// Visits the Delegates entry after `cursor` and removes it if the hotkey has no stake
+    /// and is not registered on any subnet. Returns the raw key visited, or None at the end of the map.
+    fn clean_next_orphaned_delegate(cursor: Option<Vec<u8>>) -> Option<Vec<u8>> {
+        let mut iter = match cursor {
+            Some(raw_key) => Delegates::<T>::iter_from(raw_key),
+            None => Delegates::<T>::iter(),
+        };
+        let (hotkey, _) = iter.next()?;
+        let raw_key: Vec<u8> = iter.last_raw_key().to_vec();
+
+        if Self::get_total_stake_for_hotkey(&hotkey) == 0
+            && !Self::is_hotkey_registered_on_any_network(&hotkey)
+        {
+            Delegates::<T>::remove(&hotkey);
+            DelegateStatsCache::<T>::remove(&hotkey);
+            OrphanedDelegatesRemoved::<T>::mutate(|count| *count = count.saturating_add(1));
+        }
+        Some(raw_key)
+    }
+}
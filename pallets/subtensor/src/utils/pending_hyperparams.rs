@@ -0,0 +1,103 @@
+use super::*;
+
+/// Epoch hyperparameters set mid-tempo are staged here and only applied once the subnet's
+/// epoch has run, so every validator sets weights and runs consensus against the same values
+/// for a whole tempo. Subnets with a tempo of 0 never run an epoch and apply them immediately.
+impl<T: Config> Pallet<T> {
+    /// Stages `kappa` for the subnet's next tempo boundary.
+    pub fn schedule_kappa(netuid: u16, kappa: u16) {
+        PendingKappa::<T>::insert(netuid, kappa);
+        Self::apply_pending_hyperparams_if_idle(netuid);
+    }
+
+    /// Stages `max_weight_limit` for the subnet's next tempo boundary.
+    pub fn schedule_max_weight_limit(netuid: u16, max_weight_limit: u16) {
+        PendingMaxWeightsLimit::<T>::insert(netuid, max_weight_limit);
+        Self::apply_pending_hyperparams_if_idle(netuid);
+    }
+
+    /// Stages the liquid alpha values for the subnet's next tempo boundary.
+    pub fn schedule_alpha_values(netuid: u16, alpha_low: u16, alpha_high: u16) {
+        PendingAlphaValues::<T>::insert(netuid, (alpha_low, alpha_high));
+        Self::apply_pending_hyperparams_if_idle(netuid);
+    }
+
+    /// Stages a bundle of hyperparameters for the subnet's next tempo boundary, replacing any
+    /// bundle staged earlier in the tempo.
+    pub fn schedule_hyperparam_bundle(netuid: u16, bundle: HyperparamBundle) {
+        PendingHyperparamBundle::<T>::insert(netuid, bundle);
+        Self::deposit_event(Event::HyperparamBundleScheduled(netuid));
+        Self::apply_pending_hyperparams_if_idle(netuid);
+    }
+
+    pub fn get_pending_hyperparam_bundle(netuid: u16) -> Option<HyperparamBundle> {
+        PendingHyperparamBundle::<T>::get(netuid)
+    }
+    pub fn get_pending_kappa(netuid: u16) -> Option<u16> {
+        PendingKappa::<T>::get(netuid)
+    }
+    pub fn get_pending_max_weight_limit(netuid: u16) -> Option<u16> {
+        PendingMaxWeightsLimit::<T>::get(netuid)
+    }
+    pub fn get_pending_alpha_values(netuid: u16) -> Option<(u16, u16)> {
+        PendingAlphaValues::<T>::get(netuid)
+    }
+
+    /// Applies every hyperparameter staged for the subnet.
+    pub fn apply_pending_hyperparams(netuid: u16) {
+        if let Some(kappa) = PendingKappa::<T>::take(netuid) {
+            Self::set_kappa(netuid, kappa);
+        }
+        if let Some(max_weight_limit) = PendingMaxWeightsLimit::<T>::take(netuid) {
+            Self::set_max_weight_limit(netuid, max_weight_limit);
+        }
+        if let Some(alpha_values) = PendingAlphaValues::<T>::take(netuid) {
+            AlphaValues::<T>::insert(netuid, alpha_values);
+        }
+        if let Some(bundle) = PendingHyperparamBundle::<T>::take(netuid) {
+            Self::apply_hyperparam_bundle(netuid, bundle);
+        }
+    }
+
+    /// Applies every field set in the bundle, after the individually staged hyperparameters so
+    /// the bundle wins over them.
+    fn apply_hyperparam_bundle(netuid: u16, bundle: HyperparamBundle) {
+        if let Some(kappa) = bundle.kappa {
+            Self::set_kappa(netuid, kappa);
+        }
+        if let Some(rho) = bundle.rho {
+            Self::set_rho(netuid, rho);
+        }
+        if let Some(max_weight_limit) = bundle.max_weight_limit {
+            Self::set_max_weight_limit(netuid, max_weight_limit);
+        }
+        if let Some(min_allowed_weights) = bundle.min_allowed_weights {
+            Self::set_min_allowed_weights(netuid, min_allowed_weights);
+        }
+        if let Some(max_allowed_weights) = bundle.max_allowed_weights {
+            Self::set_max_allowed_weights(netuid, max_allowed_weights);
+        }
+        if let Some(activity_cutoff) = bundle.activity_cutoff {
+            Self::set_activity_cutoff(netuid, activity_cutoff);
+        }
+        if let Some(immunity_period) = bundle.immunity_period {
+            Self::set_immunity_period(netuid, immunity_period);
+        }
+        if let Some(weights_set_rate_limit) = bundle.weights_set_rate_limit {
+            Self::set_weights_set_rate_limit(netuid, weights_set_rate_limit);
+        }
+        if let Some(weights_version_key) = bundle.weights_version_key {
+            Self::set_weights_version_key(netuid, weights_version_key);
+        }
+        if let Some(bonds_moving_average) = bundle.bonds_moving_average {
+            Self::set_bonds_moving_average(netuid, bonds_moving_average);
+        }
+        Self::deposit_event(Event::HyperparamBundleApplied(netuid));
+    }
+
+    fn apply_pending_hyperparams_if_idle(netuid: u16) {
+        if Self::get_tempo(netuid) == 0 {
+            Self::apply_pending_hyperparams(netuid);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use super::*;
+use substrate_fixed::types::{I64F64, I96F32};
+
+impl<T: Config> Pallet<T> {
+    /// Saturating u64 addition that is counted by the `safe-math-audit` feature.
+    pub fn audited_saturating_add(a: u64, b: u64, site: SaturationSite) -> u64 {
+        Self::record_saturation_audit(site, a.checked_add(b).is_none());
+        a.saturating_add(b)
+    }
+
+    /// Saturating u64 subtraction that is counted by the `safe-math-audit` feature.
+    pub fn audited_saturating_sub(a: u64, b: u64, site: SaturationSite) -> u64 {
+        Self::record_saturation_audit(site, a.checked_sub(b).is_none());
+        a.saturating_sub(b)
+    }
+
+    /// Saturating fixed-point multiplication that is counted by the `safe-math-audit` feature.
+    pub fn audited_saturating_mul(a: I96F32, b: I96F32, site: SaturationSite) -> I96F32 {
+        Self::record_saturation_audit(site, a.checked_mul(b).is_none());
+        a.saturating_mul(b)
+    }
+
+    /// Saturating I64F64 multiplication that is counted by the `safe-math-audit` feature.
+    pub fn audited_saturating_mul_i64f64(a: I64F64, b: I64F64, site: SaturationSite) -> I64F64 {
+        Self::record_saturation_audit(site, a.checked_mul(b).is_none());
+        a.saturating_mul(b)
+    }
+
+    /// Counts an audited operation at `site` and, if it saturated, the saturation. When
+    /// SafeMathAuditEvents is set, a saturation also emits ArithmeticSaturated. A no-op unless
+    /// the `safe-math-audit` feature is enabled.
+    pub fn record_saturation_audit(site: SaturationSite, saturated: bool) {
+        if cfg!(feature = "safe-math-audit") {
+            SafeMathAuditCounters::<T>::mutate(site, |(operations, saturations)| {
+                *operations = operations.saturating_add(1);
+                if saturated {
+                    *saturations = saturations.saturating_add(1);
+                }
+            });
+            if saturated && SafeMathAuditEvents::<T>::get() {
+                log::warn!("ArithmeticSaturated( site:{:?} )", site);
+                Self::deposit_event(Event::ArithmeticSaturated { site });
+            }
+        }
+    }
+
+    /// Sets whether saturations caught by the `safe-math-audit` feature emit an event.
+    pub fn set_safe_math_audit_events(enabled: bool) {
+        SafeMathAuditEvents::<T>::set(enabled);
+    }
+}
@@ -0,0 +1,223 @@
+use super::*;
+use frame_support::storage::with_storage_layer;
+use frame_support::traits::{
+    schedule::{v3::Anon as ScheduleAnon, DispatchTime},
+    Get,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{traits::Saturating, SaturatedConversion};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    /// Queues `operation` of `coldkey` in the scheduler `delay` blocks from now and records
+    /// it against the coldkey until it executes or is cancelled.
+    ///
+    /// Returns the block the operation executes at.
+    pub fn schedule_operation(
+        coldkey: &T::AccountId,
+        operation: ScheduledOperation<T::AccountId>,
+        delay: BlockNumberFor<T>,
+    ) -> Result<BlockNumberFor<T>, DispatchError> {
+        let current_block: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
+        let when: BlockNumberFor<T> = current_block.saturating_add(delay);
+        let id: u64 = NextScheduledOperationId::<T>::get();
+
+        let call = Call::<T>::execute_scheduled_operation {
+            coldkey: coldkey.clone(),
+            id,
+        };
+        let bound_call = T::Preimages::bound(LocalCallOf::<T>::from(call))
+            .map_err(|_| Error::<T>::FailedToSchedule)?;
+        let address: SchedulerAddressOf<T> = T::Scheduler::schedule(
+            DispatchTime::At(when),
+            None,
+            63,
+            frame_system::RawOrigin::Root.into(),
+            bound_call,
+        )
+        .map_err(|_| Error::<T>::FailedToSchedule)?;
+
+        ScheduledOperations::<T>::insert(
+            coldkey,
+            id,
+            (operation, when.saturated_into::<u64>(), address),
+        );
+        NextScheduledOperationId::<T>::put(id.saturating_add(1));
+        Ok(when)
+    }
+
+    /// ---- The implementation for the extrinsic execute_scheduled_operation, dispatched by
+    /// the scheduler at the execution block of the operation.
+    ///
+    /// The operation is removed whether or not it succeeds, since the scheduler does not run
+    /// its task again. A failing operation has its changes rolled back and is reported with
+    /// ScheduledOperationFailed.
+    ///
+    /// # Raises:
+    /// * 'ScheduledOperationNotFound':
+    ///     - The operation was cancelled or has already run.
+    ///
+    pub fn do_execute_scheduled_operation(
+        coldkey: T::AccountId,
+        id: u64,
+    ) -> DispatchResultWithPostInfo {
+        let (operation, _, _) = ScheduledOperations::<T>::take(&coldkey, id)
+            .ok_or(Error::<T>::ScheduledOperationNotFound)?;
+
+        log::debug!(
+            "do_execute_scheduled_operation( coldkey:{:?}, id:{:?}, operation:{:?} )",
+            coldkey,
+            id,
+            operation
+        );
+        let result: DispatchResultWithPostInfo = with_storage_layer(|| match &operation {
+            ScheduledOperation::ColdkeySwap { new_coldkey } => {
+                Self::do_swap_coldkey(&coldkey, new_coldkey)
+            }
+            ScheduledOperation::DissolveNetwork { netuid } => {
+                Self::user_remove_network(coldkey.clone(), *netuid)?;
+                Ok(().into())
+            }
+            ScheduledOperation::TakeIncrease { hotkey, take } => {
+                let origin: T::RuntimeOrigin =
+                    frame_system::RawOrigin::Signed(coldkey.clone()).into();
+                Self::do_increase_take(origin, hotkey.clone(), *take)?;
+                Ok(().into())
+            }
+        });
+        if let Err(error) = result {
+            // A failed swap no longer holds the coldkey, so it can schedule another.
+            if let ScheduledOperation::ColdkeySwap { .. } = operation {
+                ColdkeySwapScheduled::<T>::remove(&coldkey);
+            }
+            log::debug!(
+                "ScheduledOperationFailed( coldkey:{:?}, id:{:?}, error:{:?} )",
+                coldkey,
+                id,
+                error.error
+            );
+            Self::deposit_event(Event::ScheduledOperationFailed {
+                coldkey,
+                id,
+                error: error.error,
+            });
+            return Ok(().into());
+        }
+        result
+    }
+
+    /// ---- The implementation for the extrinsic cancel_scheduled_operation.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the coldkey that scheduled the operation.
+    ///
+    /// * 'id' (u64):
+    ///     - The id of the operation.
+    ///
+    /// # Event:
+    /// * ScheduledOperationCancelled;
+    ///     - On successfully removing the operation from the scheduler queue.
+    ///
+    /// # Raises:
+    /// * 'ScheduledOperationNotFound':
+    ///     - The coldkey has no pending operation with this id.
+    ///
+    pub fn do_cancel_scheduled_operation(origin: T::RuntimeOrigin, id: u64) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        let (operation, _, address) = ScheduledOperations::<T>::take(&coldkey, id)
+            .ok_or(Error::<T>::ScheduledOperationNotFound)?;
+        // The operation is dropped even if the scheduler no longer holds its task.
+        if T::Scheduler::cancel(address).is_err() {
+            log::debug!(
+                "cancel_scheduled_operation( coldkey:{:?}, id:{:?} ): no scheduler task",
+                coldkey,
+                id
+            );
+        }
+        if let ScheduledOperation::ColdkeySwap { .. } = operation {
+            ColdkeySwapScheduled::<T>::remove(&coldkey);
+        }
+
+        log::debug!(
+            "ScheduledOperationCancelled( coldkey:{:?}, id:{:?} )",
+            coldkey,
+            id
+        );
+        Self::deposit_event(Event::ScheduledOperationCancelled { coldkey, id });
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic increase_take.
+    ///
+    /// Runs the checks of do_increase_take now, and applies the take through it once
+    /// TakeIncreaseScheduleDuration blocks have passed.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the owning coldkey, or of the hotkey's delegate manager.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The delegate hotkey.
+    ///
+    /// * 'take' (u16):
+    ///     - The increased take.
+    ///
+    /// # Event:
+    /// * TakeIncreaseScheduled;
+    ///     - On successfully queueing the increase.
+    ///
+    /// # Raises:
+    /// * 'DelegateTakeTooLow':
+    ///     - The take is not greater than the current take.
+    ///
+    /// * 'PublicGoodTakeLocked':
+    ///     - The hotkey is a public good delegate not yet released from its take.
+    ///
+    pub fn do_schedule_increase_take(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        take: u16,
+    ) -> DispatchResult {
+        let coldkey = Self::ensure_take_origin(origin, &hotkey)?;
+        Self::do_take_checks(&coldkey, &hotkey)?;
+        if let Ok(current_take) = Delegates::<T>::try_get(&hotkey) {
+            ensure!(take > current_take, Error::<T>::DelegateTakeTooLow);
+        }
+        Self::ensure_delegate_take_in_range(take)?;
+        Self::ensure_take_increase_unlocked(&hotkey)?;
+
+        let execution_block: BlockNumberFor<T> = Self::schedule_operation(
+            &coldkey,
+            ScheduledOperation::TakeIncrease {
+                hotkey: hotkey.clone(),
+                take,
+            },
+            T::TakeIncreaseScheduleDuration::get(),
+        )?;
+
+        log::debug!(
+            "TakeIncreaseScheduled( coldkey:{:?}, hotkey:{:?}, take:{:?} )",
+            coldkey,
+            hotkey,
+            take
+        );
+        Self::deposit_event(Event::TakeIncreaseScheduled {
+            coldkey,
+            hotkey,
+            take,
+            execution_block,
+        });
+        Ok(())
+    }
+
+    /// Returns the (id, operation, execution_block) of every pending operation of a coldkey.
+    pub fn get_scheduled_operations_for_coldkey(
+        coldkey: &T::AccountId,
+    ) -> Vec<(u64, ScheduledOperation<T::AccountId>, u64)> {
+        ScheduledOperations::<T>::iter_prefix(coldkey)
+            .map(|(id, (operation, execution_block, _))| (id, operation, execution_block))
+            .collect()
+    }
+}
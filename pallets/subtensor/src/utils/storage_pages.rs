@@ -0,0 +1,180 @@
+use super::*;
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::vec::Vec;
+
+/// Number of entries read per page when a prefix is walked page by page.
+///
+/// Walks that can span blocks store the raw key a page returns and resume from it: the small
+/// nomination clearing, the unstake of every coldkey from a hotkey and the delegate statistics
+/// pass.
+/// Two Stake prefix walks stay within one block because they must see the prefix unchanged:
+/// the emission drain, metered by HotkeyNominatorCount and deferred when it does not fit, and
+/// the hotkey swap, whose weight counts every entry it moves. Weights prefixes are bounded by
+/// the uids of their subnet and are walked whole.
+pub const STORAGE_PAGE_SIZE: u32 = 256;
+
+impl<T: Config> Pallet<T> {
+    /// Returns up to `limit` Stake entries of `hotkey` following the raw key `cursor`, with the
+    /// raw key to continue from, or None once the prefix is exhausted.
+    pub fn stake_prefix_page(
+        hotkey: &T::AccountId,
+        cursor: Option<Vec<u8>>,
+        limit: u32,
+    ) -> (Vec<(T::AccountId, u64)>, Option<Vec<u8>>) {
+        let mut iter = match cursor {
+            Some(raw_key) => Stake::<T>::iter_prefix_from(hotkey, raw_key),
+            None => Stake::<T>::iter_prefix(hotkey),
+        };
+        let limit: usize = limit.max(1) as usize;
+        let page: Vec<(T::AccountId, u64)> = iter.by_ref().take(limit).collect();
+        let next: Option<Vec<u8>> = (page.len() >= limit).then(|| iter.last_raw_key().to_vec());
+        (page, next)
+    }
+
+    /// Returns up to `limit` (delegate, take) entries of Delegates following the raw key
+    /// `cursor`, with the raw key to continue from, or None once the map is exhausted.
+    pub fn delegates_page(
+        cursor: Option<Vec<u8>>,
+        limit: u32,
+    ) -> (Vec<(T::AccountId, u16)>, Option<Vec<u8>>) {
+        let mut iter = match cursor {
+            Some(raw_key) => Delegates::<T>::iter_from(raw_key),
+            None => Delegates::<T>::iter(),
+        };
+        let limit: usize = limit.max(1) as usize;
+        let page: Vec<(T::AccountId, u16)> = iter.by_ref().take(limit).collect();
+        let next: Option<Vec<u8>> = (page.len() >= limit).then(|| iter.last_raw_key().to_vec());
+        (page, next)
+    }
+
+    /// Returns up to `limit` entries of the whole Stake map following the raw key `cursor`,
+    /// with the raw key to continue from, or None once the map is exhausted.
+    pub fn stake_page(
+        cursor: Option<Vec<u8>>,
+        limit: u32,
+    ) -> (Vec<(T::AccountId, T::AccountId, u64)>, Option<Vec<u8>>) {
+        let mut iter = match cursor {
+            Some(raw_key) => Stake::<T>::iter_from(raw_key),
+            None => Stake::<T>::iter(),
+        };
+        let limit: usize = limit.max(1) as usize;
+        let page: Vec<(T::AccountId, T::AccountId, u64)> = iter.by_ref().take(limit).collect();
+        let next: Option<Vec<u8>> = (page.len() >= limit).then(|| iter.last_raw_key().to_vec());
+        (page, next)
+    }
+
+    /// Continues clearing the small nominations from the stored cursor, one page at a time
+    /// within `remaining_weight`. Returns the weight consumed.
+    pub fn continue_clearing_small_nominations(remaining_weight: Weight) -> Weight {
+        // Reading and writing the cursor.
+        let mut used_weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+        // Worst case for clearing a single nomination.
+        let page_weight: Weight = T::DbWeight::get()
+            .reads_writes(6, 6)
+            .saturating_mul(STORAGE_PAGE_SIZE as u64);
+        if !SmallNominationsCursor::<T>::exists() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        while remaining_weight.all_gte(used_weight.saturating_add(page_weight)) {
+            used_weight = used_weight.saturating_add(page_weight);
+            let Some(raw_key) = SmallNominationsCursor::<T>::get() else {
+                break;
+            };
+            Self::clear_small_nominations_page(Some(raw_key));
+        }
+        used_weight
+    }
+
+    /// Clears the small nominations of one page of the Stake map following `cursor`, and
+    /// stores where the next page starts.
+    pub fn clear_small_nominations_page(cursor: Option<Vec<u8>>) {
+        let (page, next) = Self::stake_page(cursor, STORAGE_PAGE_SIZE);
+        for (hotkey, coldkey, stake) in page {
+            Self::clear_small_nomination_if_required(&hotkey, &coldkey, stake);
+        }
+        match next {
+            Some(raw_key) => SmallNominationsCursor::<T>::put(raw_key),
+            None => SmallNominationsCursor::<T>::kill(),
+        }
+    }
+
+    /// Unstakes every coldkey from the hotkey, except for stake that is locked, which stays on
+    /// the hotkey until its lock expires. A hotkey with emission still to drain has it drained
+    /// at the next block step first. When no other hotkey is ahead of it in the queue, the
+    /// first page is unstaked straight away and the rest is left to on_idle.
+    pub fn unstake_all_coldkeys_from_hotkey_account(hotkey: &T::AccountId) {
+        if PendingdHotkeyEmission::<T>::get(hotkey) > 0 {
+            DeferredHotkeyDrains::<T>::mutate(|drains| {
+                if !drains.contains(hotkey) {
+                    drains.push(hotkey.clone());
+                }
+            });
+        }
+        let mut queue: Vec<T::AccountId> = HotkeyUnstakeQueue::<T>::get();
+        if !queue.contains(hotkey) {
+            queue.push(hotkey.clone());
+        }
+        let first: bool = queue.len() == 1;
+        HotkeyUnstakeQueue::<T>::put(queue);
+        if first {
+            Self::unstake_hotkey_page();
+        }
+    }
+
+    /// Continues unstaking the queued hotkeys from the stored cursor, one page at a time within
+    /// `remaining_weight`. Returns the weight consumed.
+    pub fn continue_unstaking_hotkeys(remaining_weight: Weight) -> Weight {
+        // Reading and writing the queue and the cursor.
+        let mut used_weight: Weight = T::DbWeight::get().reads_writes(2, 2);
+        // Worst case for unstaking a single coldkey.
+        let page_weight: Weight = T::DbWeight::get()
+            .reads_writes(12, 10)
+            .saturating_add(Self::stake_event_weight())
+            .saturating_mul(STORAGE_PAGE_SIZE as u64);
+        if !HotkeyUnstakeQueue::<T>::exists() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        while remaining_weight.all_gte(used_weight.saturating_add(page_weight)) {
+            used_weight = used_weight.saturating_add(page_weight);
+            if !Self::unstake_hotkey_page() {
+                break;
+            }
+        }
+        used_weight
+    }
+
+    /// Unstakes one page of the coldkeys of the first queued hotkey, and stores where the next
+    /// page starts. A hotkey with emission still to drain waits for the drain. Returns false if
+    /// there is nothing to do before the next block.
+    pub fn unstake_hotkey_page() -> bool {
+        let mut queue: Vec<T::AccountId> = HotkeyUnstakeQueue::<T>::get();
+        let Some(hotkey) = queue.first().cloned() else {
+            return false;
+        };
+        if PendingdHotkeyEmission::<T>::get(&hotkey) > 0 {
+            return false;
+        }
+
+        let cursor: Option<Vec<u8>> = HotkeyUnstakeCursor::<T>::get();
+        let (page, next) = Self::stake_prefix_page(&hotkey, cursor, STORAGE_PAGE_SIZE);
+        for (coldkey, stake) in page {
+            Self::return_unlocked_stake(&coldkey, &hotkey, stake);
+        }
+        if let Some(raw_key) = next {
+            HotkeyUnstakeCursor::<T>::put(raw_key);
+            return true;
+        }
+
+        // The hotkey is done with, move on to the next one.
+        HotkeyUnstakeCursor::<T>::kill();
+        queue.retain(|queued| *queued != hotkey);
+        if queue.is_empty() {
+            HotkeyUnstakeQueue::<T>::kill();
+        } else {
+            HotkeyUnstakeQueue::<T>::put(queue);
+        }
+        true
+    }
+}
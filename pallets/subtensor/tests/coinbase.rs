@@ -2,10 +2,14 @@
 use crate::mock::*;
 mod mock;
 use frame_support::assert_ok;
+use frame_support::weights::Weight;
 
-use pallet_subtensor::TargetStakesPerInterval;
+use codec::Encode;
+use pallet_subtensor::coinbase::run_coinbase::MAX_EPOCH_DEFERRALS;
+use pallet_subtensor::{Error, HotkeyEmissionByNetuid, TargetStakesPerInterval};
 use sp_core::U256;
-use substrate_fixed::types::I64F64;
+use sp_runtime::DispatchError;
+use substrate_fixed::types::{I64F64, I96F32};
 
 // Test the ability to hash all sorts of hotkeys.
 #[test]
@@ -1448,3 +1452,640 @@ fn test_coinbase_nominator_drainage_with_net_negative_delta() {
         log::debug!("Test completed");
     });
 }
+
+// Emission provenance is recorded per (hotkey, netuid).
+#[test]
+fn test_emission_by_netuid() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(1, 1, 0);
+        add_network(2, 1, 0);
+        register_ok_neuron(1, hotkey, coldkey, 0);
+        register_ok_neuron(2, hotkey, coldkey, 100_000);
+
+        SubtensorModule::accumulate_hotkey_emission(&hotkey, 1, 100, 10);
+        SubtensorModule::accumulate_hotkey_emission(&hotkey, 2, 0, 7);
+        SubtensorModule::accumulate_hotkey_emission(&hotkey, 1, 0, 5);
+
+        assert_eq!(HotkeyEmissionByNetuid::<Test>::get(1, hotkey), 115);
+        assert_eq!(HotkeyEmissionByNetuid::<Test>::get(2, hotkey), 7);
+
+        let mut by_netuid: Vec<(u16, u64)> =
+            SubtensorModule::get_emission_by_netuid(hotkey.encode())
+                .into_iter()
+                .map(|(netuid, emission)| (netuid.0, emission.0))
+                .collect();
+        by_netuid.sort();
+        assert_eq!(by_netuid, vec![(1, 115), (2, 7)]);
+
+        // Invalid account bytes return nothing.
+        assert!(SubtensorModule::get_emission_by_netuid(vec![0; 3]).is_empty());
+
+        // The accumulators go with the subnet.
+        SubtensorModule::remove_network(1);
+        assert_eq!(HotkeyEmissionByNetuid::<Test>::get(1, hotkey), 0);
+        assert_eq!(HotkeyEmissionByNetuid::<Test>::get(2, hotkey), 7);
+    });
+}
+
+// Pending subnet and hotkey emission are exposed for display between drains.
+#[test]
+fn test_get_pending_emission() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        add_network(netuid, 1, 0);
+
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 0);
+        assert_eq!(
+            SubtensorModule::get_hotkey_pending_emission(hotkey.encode()),
+            0
+        );
+
+        pallet_subtensor::PendingEmission::<Test>::insert(netuid, 1_000);
+        pallet_subtensor::PendingdHotkeyEmission::<Test>::insert(hotkey, 250);
+
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 1_000);
+        assert_eq!(
+            SubtensorModule::get_hotkey_pending_emission(hotkey.encode()),
+            250
+        );
+
+        // Invalid account bytes return nothing.
+        assert_eq!(SubtensorModule::get_hotkey_pending_emission(vec![0; 3]), 0);
+    });
+}
+
+// A new subnet's emission ramps up over the configured number of tempos.
+#[test]
+fn test_subnet_emission_ramp() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 9;
+        add_network(netuid, tempo, 0);
+        pallet_subtensor::NetworkRegisteredAt::<Test>::insert(netuid, 100);
+
+        // Disabled by default in the mock.
+        assert_eq!(
+            SubtensorModule::get_subnet_emission_ramp(netuid, 100),
+            I96F32::from_num(1)
+        );
+
+        SubtensorModule::set_subnet_emission_ramp_tempos(3);
+        // First tempo: 1/4, second tempo: 2/4, ..., full from the third tempo onwards.
+        assert_eq!(
+            SubtensorModule::get_subnet_emission_ramp(netuid, 105),
+            I96F32::from_num(1) / I96F32::from_num(4)
+        );
+        assert_eq!(
+            SubtensorModule::get_subnet_emission_ramp(netuid, 110),
+            I96F32::from_num(2) / I96F32::from_num(4)
+        );
+        assert_eq!(
+            SubtensorModule::get_subnet_emission_ramp(netuid, 130),
+            I96F32::from_num(1)
+        );
+
+        // The part of the emission held back by the ramp is recorded as recycled.
+        let current_block: u64 = SubtensorModule::get_current_block_as_u64();
+        pallet_subtensor::NetworkRegisteredAt::<Test>::insert(netuid, current_block);
+        SubtensorModule::set_emission_values(&[netuid], vec![100]).unwrap();
+        let recycled_before: u64 = SubtensorModule::get_rao_recycled(netuid);
+        step_block(1);
+        assert_eq!(pallet_subtensor::PendingEmission::<Test>::get(netuid), 25);
+        assert_eq!(
+            SubtensorModule::get_rao_recycled(netuid),
+            recycled_before + 75
+        );
+    });
+}
+
+// A subnet with emission disabled burns its emission until the re-enable block.
+#[test]
+fn test_subnet_emission_disabled() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 0, 0);
+        SubtensorModule::set_emission_values(&[netuid], vec![100]).unwrap();
+        pallet_subtensor::PendingEmission::<Test>::insert(netuid, 1_000);
+        SubtensorModule::set_emission_tithe(u16::MAX / 10);
+        let treasury = SubtensorModule::get_treasury_account();
+        SubtensorModule::add_balance_to_coldkey_account(&treasury, 1_000);
+
+        let reenable_block: u64 = SubtensorModule::get_current_block_as_u64() + 5;
+        SubtensorModule::set_subnet_emission_disabled(netuid, true, Some(reenable_block));
+        assert_eq!(pallet_subtensor::PendingEmission::<Test>::get(netuid), 0);
+
+        // The tithe is burned along with the rest of the emission.
+        step_block(4);
+        assert_eq!(pallet_subtensor::PendingEmission::<Test>::get(netuid), 0);
+        assert!(SubtensorModule::is_subnet_emission_disabled(netuid));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&treasury), 1_000);
+
+        step_block(1);
+        assert!(!SubtensorModule::is_subnet_emission_disabled(netuid));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&treasury), 1_009);
+        assert_eq!(pallet_subtensor::PendingEmission::<Test>::get(netuid), 91);
+    });
+}
+
+// A sanctioned hotkey's emission is burned until its sanction expires.
+#[test]
+fn test_sanctioned_hotkey_emission_burned() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(0);
+        let coldkey = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1000);
+        SubtensorModule::set_emission_values(&[netuid], vec![1]).unwrap();
+
+        let expiry_block: u64 = SubtensorModule::get_current_block_as_u64() + 3;
+        assert_eq!(
+            SubtensorModule::sanction_hotkey(
+                RuntimeOrigin::signed(coldkey),
+                U256::from(9),
+                expiry_block
+            ),
+            Err(Error::<Test>::HotKeyAccountNotExists.into())
+        );
+        assert_eq!(
+            SubtensorModule::sanction_hotkey(RuntimeOrigin::signed(coldkey), hotkey, 1),
+            Err(Error::<Test>::InvalidSanctionExpiry.into())
+        );
+        assert_ok!(SubtensorModule::sanction_hotkey(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            expiry_block
+        ));
+
+        // The emission of the first epoch is burned.
+        next_block();
+        next_block();
+        assert!(SubtensorModule::is_hotkey_sanctioned(&hotkey));
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 1000);
+        assert_eq!(SubtensorModule::get_pending_hotkey_emission(&hotkey), 0);
+
+        // The sanction expires and emission resumes.
+        next_block();
+        assert!(!SubtensorModule::is_hotkey_sanctioned(&hotkey));
+        next_block();
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey),
+            1000 + 2
+        );
+        assert_eq!(
+            SubtensorModule::lift_hotkey_sanction(RuntimeOrigin::signed(coldkey), hotkey),
+            Err(Error::<Test>::HotkeyNotSanctioned.into())
+        );
+    });
+}
+
+// A sanction burns the share a hotkey earns as a parent, and leaves the shares of its peers.
+#[test]
+fn test_sanctioned_hotkey_parent_share_burned() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let sanctioned_parent = U256::from(1);
+        let clean_parent = U256::from(2);
+        let child = U256::from(3);
+        let coldkey = U256::from(4);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, sanctioned_parent, coldkey, 0);
+        register_ok_neuron(netuid, clean_parent, coldkey, 1);
+        register_ok_neuron(netuid, child, coldkey, 2);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &coldkey,
+            &sanctioned_parent,
+            1000,
+        );
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &clean_parent, 1000);
+        for parent in [sanctioned_parent, clean_parent] {
+            assert_ok!(SubtensorModule::do_set_children(
+                RuntimeOrigin::signed(coldkey),
+                parent,
+                netuid,
+                vec![(u64::MAX, child)]
+            ));
+        }
+        let expiry_block: u64 = SubtensorModule::get_current_block_as_u64() + 10;
+        assert_ok!(SubtensorModule::sanction_hotkey(
+            RuntimeOrigin::signed(coldkey),
+            sanctioned_parent,
+            expiry_block
+        ));
+
+        // The sanctioned parent's share is burned while the child and the other parent accrue.
+        SubtensorModule::accumulate_hotkey_emission(&child, netuid, 1000, 100);
+        assert_eq!(
+            SubtensorModule::get_pending_hotkey_emission(&sanctioned_parent),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_pending_hotkey_emission(&clean_parent),
+            500
+        );
+        assert_eq!(SubtensorModule::get_pending_hotkey_emission(&child), 100);
+        System::assert_has_event(
+            pallet_subtensor::Event::<Test>::SanctionedHotkeyEmissionBurned {
+                hotkey: sanctioned_parent,
+                netuid,
+                amount: 500,
+            }
+            .into(),
+        );
+
+        // A sanctioned child still passes the parents their shares.
+        assert_ok!(SubtensorModule::lift_hotkey_sanction(
+            RuntimeOrigin::signed(coldkey),
+            sanctioned_parent
+        ));
+        assert_ok!(SubtensorModule::sanction_hotkey(
+            RuntimeOrigin::signed(coldkey),
+            child,
+            expiry_block
+        ));
+        SubtensorModule::accumulate_hotkey_emission(&child, netuid, 1000, 100);
+        assert_eq!(
+            SubtensorModule::get_pending_hotkey_emission(&sanctioned_parent),
+            500
+        );
+        assert_eq!(
+            SubtensorModule::get_pending_hotkey_emission(&clean_parent),
+            1000
+        );
+        assert_eq!(SubtensorModule::get_pending_hotkey_emission(&child), 100);
+    });
+}
+
+// The runtime emission hook redirects part of the block emission away from the subnet.
+#[test]
+fn test_on_emission_redirects_subnet_emission() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 0, 0);
+        SubtensorModule::set_emission_values(&[netuid], vec![100]).unwrap();
+
+        EmissionRedirect::set(30);
+        step_block(1);
+        assert_eq!(pallet_subtensor::PendingEmission::<Test>::get(netuid), 70);
+
+        // The hook cannot redirect more than the subnet earns.
+        EmissionRedirect::set(1_000);
+        step_block(1);
+        assert_eq!(pallet_subtensor::PendingEmission::<Test>::get(netuid), 70);
+        EmissionRedirect::set(0);
+    });
+}
+
+// The treasury tithe is minted into the treasury and paid out through approved proposals.
+#[test]
+fn test_emission_tithe_and_treasury_spend() {
+    new_test_ext(1).execute_with(|| {
+        let treasury = SubtensorModule::get_treasury_account();
+        let proposer = U256::from(1);
+        let beneficiary = U256::from(2);
+
+        // No tithe by default.
+        assert_eq!(SubtensorModule::pay_emission_tithe(1_000), 1_000);
+
+        // The tithe is capped.
+        SubtensorModule::set_emission_tithe(u16::MAX);
+        assert_eq!(
+            pallet_subtensor::EmissionTithe::<Test>::get(),
+            pallet_subtensor::coinbase::treasury::MAX_EMISSION_TITHE
+        );
+
+        SubtensorModule::set_emission_tithe(u16::MAX / 10);
+        let issuance = SubtensorModule::get_total_issuance();
+        let remaining = SubtensorModule::pay_emission_tithe(1_000);
+        let tithe = SubtensorModule::get_coldkey_balance(&treasury);
+        assert!(tithe > 0);
+        assert_eq!(remaining + tithe, 1_000);
+        assert_eq!(SubtensorModule::get_total_issuance(), issuance + tithe);
+
+        // Proposals hold a deposit and may not exceed what the treasury can spend.
+        let other_proposer = U256::from(3);
+        SubtensorModule::add_balance_to_coldkey_account(&proposer, 1_000);
+        SubtensorModule::add_balance_to_coldkey_account(&other_proposer, 1_000);
+        assert_eq!(
+            SubtensorModule::propose_treasury_spend(
+                RuntimeOrigin::signed(proposer),
+                beneficiary,
+                tithe
+            ),
+            Err(Error::<Test>::InsufficientTreasuryBalance.into())
+        );
+        assert_ok!(SubtensorModule::propose_treasury_spend(
+            RuntimeOrigin::signed(proposer),
+            beneficiary,
+            50
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&proposer), 1_000 - 100);
+        assert_eq!(pallet_subtensor::TreasuryDepositsHeld::<Test>::get(), 100);
+        assert_eq!(
+            SubtensorModule::approve_treasury_spend(RuntimeOrigin::signed(proposer), proposer),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(SubtensorModule::approve_treasury_spend(
+            RuntimeOrigin::root(),
+            proposer
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&beneficiary), 50);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&proposer), 1_000);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&treasury), tithe - 50);
+        assert_eq!(pallet_subtensor::TreasuryDepositsHeld::<Test>::get(), 0);
+
+        // The proposal is consumed, and overspending is refused.
+        assert_eq!(
+            SubtensorModule::approve_treasury_spend(RuntimeOrigin::root(), proposer),
+            Err(Error::<Test>::TreasuryProposalNotFound.into())
+        );
+        let spendable = SubtensorModule::get_treasury_spendable_balance();
+        assert_ok!(SubtensorModule::propose_treasury_spend(
+            RuntimeOrigin::signed(proposer),
+            beneficiary,
+            spendable
+        ));
+        assert_ok!(SubtensorModule::propose_treasury_spend(
+            RuntimeOrigin::signed(other_proposer),
+            beneficiary,
+            spendable
+        ));
+        assert_ok!(SubtensorModule::approve_treasury_spend(
+            RuntimeOrigin::root(),
+            other_proposer
+        ));
+        assert_eq!(
+            SubtensorModule::approve_treasury_spend(RuntimeOrigin::root(), proposer),
+            Err(Error::<Test>::InsufficientTreasuryBalance.into())
+        );
+
+        // A rejected proposal's deposit stays in the treasury.
+        assert_ok!(SubtensorModule::reject_treasury_spend(
+            RuntimeOrigin::root(),
+            proposer
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&proposer), 1_000 - 100);
+        assert_eq!(SubtensorModule::get_treasury_spendable_balance(), 100);
+        assert!(pallet_subtensor::TreasurySpendProposals::<Test>::get(proposer).is_none());
+    });
+}
+
+// Audited arithmetic is only counted with the safe-math-audit feature.
+#[test]
+fn test_safe_math_audit_counts_saturations() {
+    new_test_ext(1).execute_with(|| {
+        pallet_subtensor::SafeMathAuditEvents::<Test>::set(true);
+        let site = pallet_subtensor::SaturationSite::SubnetEmission;
+
+        assert_eq!(SubtensorModule::audited_saturating_add(1, 2, site), 3);
+        assert_eq!(
+            SubtensorModule::audited_saturating_add(u64::MAX, 1, site),
+            u64::MAX
+        );
+
+        let counters = pallet_subtensor::SafeMathAuditCounters::<Test>::get(site);
+        if cfg!(feature = "safe-math-audit") {
+            assert_eq!(counters, (2, 1));
+            System::assert_last_event(
+                pallet_subtensor::Event::<Test>::ArithmeticSaturated { site }.into(),
+            );
+        } else {
+            assert_eq!(counters, (0, 0));
+        }
+    });
+}
+
+// Epochs that do not fit in the block step budget move to the next block.
+#[test]
+fn test_block_step_defers_epochs_over_budget() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        add_network(netuid, tempo, 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 0);
+
+        let blocks_to_step: u16 = SubtensorModule::blocks_until_next_epoch(
+            netuid,
+            tempo,
+            SubtensorModule::get_current_block_as_u64(),
+        ) as u16;
+        step_block(blocks_to_step - 1);
+        let last_step: u64 = SubtensorModule::get_last_mechanism_step_block(netuid);
+
+        // Only the phases that cannot be deferred fit.
+        BlockStepWeightLimit::set(SubtensorModule::fixed_block_step_weight());
+        step_block(1);
+        let deferred_at: u64 = SubtensorModule::get_current_block_as_u64();
+        assert_eq!(
+            SubtensorModule::get_last_mechanism_step_block(netuid),
+            last_step
+        );
+        assert_eq!(
+            pallet_subtensor::NextEpochBlock::<Test>::get(netuid),
+            Some(deferred_at + 1)
+        );
+        System::assert_has_event(pallet_subtensor::Event::<Test>::EpochDeferred { netuid }.into());
+
+        BlockStepWeightLimit::set(Weight::MAX);
+        step_block(1);
+        assert_eq!(
+            SubtensorModule::get_last_mechanism_step_block(netuid),
+            deferred_at + 1
+        );
+    });
+}
+
+// An epoch that never fits in the block step budget runs once it has been deferred
+// MAX_EPOCH_DEFERRALS times.
+#[test]
+fn test_block_step_runs_epoch_after_max_deferrals() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        add_network(netuid, tempo, 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 0);
+
+        let blocks_to_step: u16 = SubtensorModule::blocks_until_next_epoch(
+            netuid,
+            tempo,
+            SubtensorModule::get_current_block_as_u64(),
+        ) as u16;
+        step_block(blocks_to_step - 1);
+        let last_step: u64 = SubtensorModule::get_last_mechanism_step_block(netuid);
+
+        BlockStepWeightLimit::set(SubtensorModule::fixed_block_step_weight());
+        step_block(MAX_EPOCH_DEFERRALS as u16);
+        assert_eq!(
+            SubtensorModule::get_last_mechanism_step_block(netuid),
+            last_step
+        );
+        assert_eq!(
+            pallet_subtensor::EpochDeferrals::<Test>::get(netuid),
+            MAX_EPOCH_DEFERRALS
+        );
+
+        step_block(1);
+        assert_eq!(
+            SubtensorModule::get_last_mechanism_step_block(netuid),
+            SubtensorModule::get_current_block_as_u64()
+        );
+        assert_eq!(pallet_subtensor::EpochDeferrals::<Test>::get(netuid), 0);
+    });
+}
+
+// A new subnet's grant is released if it stays active on every grant tempo, and burned the
+// first tempo it falls below the minimum.
+#[test]
+fn test_bootstrap_grant_released_or_clawed_back() {
+    new_test_ext(1).execute_with(|| {
+        let active_netuid: u16 = 1;
+        let idle_netuid: u16 = 2;
+        let owner = U256::from(10);
+        add_network(active_netuid, 10, 0);
+        add_network(idle_netuid, 10, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(active_netuid, owner);
+        pallet_subtensor::SubnetOwner::<Test>::insert(idle_netuid, owner);
+        register_ok_neuron(active_netuid, U256::from(1), U256::from(2), 0);
+        pallet_subtensor::ValidatorPermit::<Test>::insert(active_netuid, vec![true]);
+
+        // No grant is opened while grants are disabled.
+        SubtensorModule::open_bootstrap_grant(active_netuid);
+        assert!(pallet_subtensor::BootstrapGrants::<Test>::get(active_netuid).is_none());
+        assert_eq!(
+            SubtensorModule::escrow_bootstrap_grant(active_netuid, 1_000),
+            1_000
+        );
+
+        SubtensorModule::set_bootstrap_grant_params(u16::MAX / 2, 2, 1, 0);
+        SubtensorModule::open_bootstrap_grant(active_netuid);
+        SubtensorModule::open_bootstrap_grant(idle_netuid);
+
+        // Half of the cut is escrowed each tempo.
+        assert_eq!(
+            SubtensorModule::escrow_bootstrap_grant(active_netuid, 1_000),
+            501
+        );
+        assert_eq!(
+            pallet_subtensor::BootstrapGrants::<Test>::get(active_netuid),
+            Some((499, 1))
+        );
+
+        // The idle subnet has no validator, so its grant is burned on the first tempo and never
+        // minted, even though its share of this tempo is still withheld.
+        let issuance: u64 = SubtensorModule::get_total_issuance();
+        let balance: u64 = SubtensorModule::get_coldkey_balance(&owner);
+        assert_eq!(
+            SubtensorModule::escrow_bootstrap_grant(idle_netuid, 1_000),
+            501
+        );
+        System::assert_last_event(
+            pallet_subtensor::Event::<Test>::BootstrapGrantClawedBack {
+                netuid: idle_netuid,
+                amount: 499,
+            }
+            .into(),
+        );
+        assert!(pallet_subtensor::BootstrapGrants::<Test>::get(idle_netuid).is_none());
+
+        // Filling the idle subnet before the last grant tempo does not restore its grant.
+        register_ok_neuron(idle_netuid, U256::from(3), U256::from(4), 0);
+        pallet_subtensor::ValidatorPermit::<Test>::insert(idle_netuid, vec![true]);
+        assert_eq!(
+            SubtensorModule::escrow_bootstrap_grant(idle_netuid, 1_000),
+            1_000
+        );
+
+        // The active subnet kept its validator on every grant tempo, so its escrow is released.
+        assert_eq!(
+            SubtensorModule::escrow_bootstrap_grant(active_netuid, 1_000),
+            501
+        );
+        System::assert_last_event(
+            pallet_subtensor::Event::<Test>::BootstrapGrantReleased {
+                netuid: active_netuid,
+                coldkey: owner,
+                amount: 998,
+            }
+            .into(),
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&owner), balance + 998);
+        assert_eq!(SubtensorModule::get_total_issuance(), issuance + 998);
+
+        // Both grants are closed, so the full cut is paid from now on.
+        assert!(pallet_subtensor::BootstrapGrants::<Test>::get(active_netuid).is_none());
+        assert_eq!(
+            SubtensorModule::escrow_bootstrap_grant(active_netuid, 1_000),
+            1_000
+        );
+    });
+}
+
+// The grant's activity check reads the permits the epoch writes, so a neuron that only earns
+// its validator permit in the subnet's first epoch keeps the grant open.
+#[test]
+fn test_bootstrap_grant_checks_activity_after_first_epoch() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(10);
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 10, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_subnet_owner_cut(u16::MAX / 10);
+        SubtensorModule::set_emission_values(&[netuid], vec![1_000_000]).unwrap();
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+
+        SubtensorModule::set_bootstrap_grant_params(u16::MAX / 2, 2, 1, 0);
+        SubtensorModule::open_bootstrap_grant(netuid);
+        assert_eq!(
+            pallet_subtensor::ValidatorPermit::<Test>::get(netuid),
+            vec![false]
+        );
+
+        // Steps blocks until the grant has passed another tempo.
+        let run_grant_tempo = |tempos_left: u16| {
+            for _ in 0..100 {
+                step_block(1);
+                if pallet_subtensor::BootstrapGrants::<Test>::get(netuid)
+                    .map_or(true, |(_, left)| left < tempos_left)
+                {
+                    return;
+                }
+            }
+            panic!("no epoch ran");
+        };
+
+        // The first epoch grants the permit before the grant checks for a validator.
+        run_grant_tempo(2);
+        assert_eq!(
+            pallet_subtensor::ValidatorPermit::<Test>::get(netuid),
+            vec![true]
+        );
+        let (escrowed, tempos_left) =
+            pallet_subtensor::BootstrapGrants::<Test>::get(netuid).unwrap();
+        assert!(escrowed > 0);
+        assert_eq!(tempos_left, 1);
+
+        // The validator holds its permit, so the escrow is released on the last grant tempo.
+        let balance: u64 = SubtensorModule::get_coldkey_balance(&owner);
+        run_grant_tempo(1);
+        assert!(pallet_subtensor::BootstrapGrants::<Test>::get(netuid).is_none());
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::SubtensorModule(pallet_subtensor::Event::BootstrapGrantReleased {
+                netuid: 1,
+                ..
+            })
+        )));
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::SubtensorModule(pallet_subtensor::Event::BootstrapGrantClawedBack { .. })
+        )));
+        assert!(SubtensorModule::get_coldkey_balance(&owner) > balance + escrowed);
+    });
+}
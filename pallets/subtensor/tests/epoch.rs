@@ -5,6 +5,7 @@
 )]
 
 use crate::mock::*;
+use codec::Encode;
 use frame_support::{assert_err, assert_ok};
 use frame_system::Config;
 use pallet_subtensor::epoch::math::safe_exp;
@@ -1727,6 +1728,130 @@ fn test_active_stake() {
     });
 }
 
+// Test that stale validators lose their permit to active candidates when the subnet requires activity.
+#[test]
+fn test_active_permits_only() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_max_allowed_validators(netuid, 1);
+        SubtensorModule::set_activity_cutoff(netuid, 10);
+
+        // uid 0 has the most stake but stops setting weights, uid 1 stays active.
+        for i in 0..2 {
+            let hotkey = U256::from(i);
+            register_ok_neuron(netuid, hotkey, hotkey, i as u64 * 100_000);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &hotkey,
+                &hotkey,
+                2_000 - i as u64 * 1_000,
+            );
+        }
+        System::set_block_number(100);
+        SubtensorModule::set_last_update_for_uid(netuid, 1, 100);
+
+        SubtensorModule::epoch(netuid, 1_000_000_000);
+        assert!(SubtensorModule::get_validator_permit_for_uid(netuid, 0));
+        assert!(!SubtensorModule::get_validator_permit_for_uid(netuid, 1));
+
+        SubtensorModule::set_active_permits_only(netuid, true);
+        SubtensorModule::epoch(netuid, 1_000_000_000);
+        assert!(!SubtensorModule::get_validator_permit_for_uid(netuid, 0));
+        assert!(SubtensorModule::get_validator_permit_for_uid(netuid, 1));
+    });
+}
+
+// Test that epoch ignores the weights of validators that set fewer weights than the subnet's
+// min allowed weights instead of normalizing them.
+#[test]
+fn test_weights_below_min_allowed_weights() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, u16::MAX - 1, 0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+
+        // uid 0 is the only validator, uids 1 and 2 are servers.
+        for i in 0..3 {
+            let hotkey = U256::from(i);
+            register_ok_neuron(netuid, hotkey, hotkey, i as u64 * 100_000);
+        }
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &U256::from(0),
+            &U256::from(0),
+            1_000,
+        );
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        System::set_block_number(2);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(U256::from(0)),
+            netuid,
+            vec![1],
+            vec![u16::MAX],
+            0
+        ));
+
+        SubtensorModule::epoch(netuid, 1_000_000_000);
+        assert!(SubtensorModule::get_incentive_for_uid(netuid, 1) > 0);
+
+        // The row no longer meets the floor once it is raised.
+        SubtensorModule::set_min_allowed_weights(netuid, 2);
+        assert_eq!(
+            SubtensorModule::get_weights_below_floor(netuid, &[vec![1], vec![1], vec![1, 2]]),
+            vec![true, false, false]
+        );
+        SubtensorModule::epoch(netuid, 1_000_000_000);
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 1), 0);
+    });
+}
+
+// Test that an epoch simulation applies stake overrides and leaves the state untouched.
+#[test]
+fn test_simulate_epoch() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, u16::MAX - 1, 0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+
+        // uids 0 and 1 are validators with equal stake, each weighting its own server.
+        for i in 0..4 {
+            let hotkey = U256::from(i);
+            register_ok_neuron(netuid, hotkey, hotkey, i as u64 * 100_000);
+        }
+        System::set_block_number(2);
+        for (validator, server) in [(0, 2), (1, 3)] {
+            let hotkey = U256::from(validator);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&hotkey, &hotkey, 1_000);
+            SubtensorModule::set_validator_permit_for_uid(netuid, validator, true);
+            assert_ok!(SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![server],
+                vec![u16::MAX],
+                0
+            ));
+        }
+
+        // What if uid 1 had 2_000 more stake.
+        let overrides: Vec<(U256, u64)> = vec![(U256::from(1), 3_000)];
+        let simulation = SubtensorModule::simulate_epoch(netuid, 1_000_000_000, overrides.encode())
+            .expect("subnet exists");
+        assert_eq!(simulation.stake[0], 1_000.into());
+        assert_eq!(simulation.stake[1], 3_000.into());
+        assert!(simulation.incentive[3] > simulation.incentive[2]);
+
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&U256::from(1)),
+            1_000
+        );
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 3), 0);
+
+        assert!(SubtensorModule::simulate_epoch(netuid, 0, vec![0xff]).is_none());
+        assert!(SubtensorModule::simulate_epoch(netuid + 1, 0, overrides.encode()).is_none());
+    });
+}
+
 // Test that epoch masks out outdated weights and bonds of validators on deregistered servers.
 #[test]
 fn test_outdated_weights() {
@@ -2592,6 +2717,7 @@ fn test_get_set_alpha() {
             alpha_low,
             alpha_high
         ));
+        SubtensorModule::apply_pending_hyperparams(netuid);
         let (grabbed_alpha_low, grabbed_alpha_high): (u16, u16) =
             SubtensorModule::get_alpha_values(netuid);
 
@@ -2750,6 +2876,267 @@ fn test_blocks_since_last_step() {
         assert_eq!(SubtensorModule::get_blocks_since_last_step(netuid), 27);
     });
 }
+
+#[test]
+fn test_hyperparams_applied_at_tempo_boundary() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        add_network(netuid, tempo, 0);
+        let kappa: u16 = SubtensorModule::get_kappa(netuid);
+        let max_weight_limit: u16 = SubtensorModule::get_max_weight_limit(netuid);
+
+        SubtensorModule::schedule_kappa(netuid, kappa + 1);
+        SubtensorModule::schedule_max_weight_limit(netuid, max_weight_limit - 1);
+        SubtensorModule::schedule_alpha_values(netuid, 1000, u16::MAX);
+        step_block(1);
+
+        // Staged values are readable but the current values hold until the epoch runs.
+        assert_eq!(SubtensorModule::get_kappa(netuid), kappa);
+        assert_eq!(SubtensorModule::get_pending_kappa(netuid), Some(kappa + 1));
+        assert_eq!(
+            SubtensorModule::get_pending_max_weight_limit(netuid),
+            Some(max_weight_limit - 1)
+        );
+        assert_eq!(
+            SubtensorModule::get_pending_alpha_values(netuid),
+            Some((1000, u16::MAX))
+        );
+
+        let blocks_to_step: u16 = SubtensorModule::blocks_until_next_epoch(
+            netuid,
+            tempo,
+            SubtensorModule::get_current_block_as_u64(),
+        ) as u16;
+        step_block(blocks_to_step);
+
+        assert_eq!(SubtensorModule::get_kappa(netuid), kappa + 1);
+        assert_eq!(
+            SubtensorModule::get_max_weight_limit(netuid),
+            max_weight_limit - 1
+        );
+        assert_eq!(SubtensorModule::get_alpha_values(netuid), (1000, u16::MAX));
+        assert_eq!(SubtensorModule::get_pending_kappa(netuid), None);
+        assert_eq!(SubtensorModule::get_pending_max_weight_limit(netuid), None);
+        assert_eq!(SubtensorModule::get_pending_alpha_values(netuid), None);
+    });
+}
+
+#[test]
+fn test_subnet_randomness_drawn_each_epoch() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        add_network(netuid, tempo, 0);
+        assert_eq!(SubtensorModule::get_subnet_randomness(netuid), None);
+
+        let mut previous = None;
+        for _ in 0..2 {
+            let blocks_to_step: u16 = SubtensorModule::blocks_until_next_epoch(
+                netuid,
+                tempo,
+                SubtensorModule::get_current_block_as_u64(),
+            ) as u16;
+            step_block(blocks_to_step);
+
+            let (block, randomness) = SubtensorModule::get_subnet_randomness(netuid).unwrap();
+            assert_eq!(block, SubtensorModule::get_current_block_as_u64());
+            assert_ne!(Some(randomness), previous);
+            previous = Some(randomness);
+
+            // The value holds until the next epoch.
+            step_block(1);
+            assert_eq!(
+                SubtensorModule::get_subnet_randomness(netuid),
+                Some((block, randomness))
+            );
+        }
+    });
+}
+
+#[test]
+fn test_epoch_checksums_recorded_each_epoch() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        add_network(netuid, tempo, 0);
+        register_ok_neuron(netuid, U256::from(0), U256::from(10), 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(11), 100_000);
+        let row = |pairs: Vec<(u16, u16)>| WeightsRowOf::<Test>::truncate_from(pairs);
+        Weights::<Test>::insert(netuid, 0, row(vec![(0, u16::MAX), (1, u16::MAX)]));
+        assert_eq!(SubtensorModule::get_epoch_checksums(netuid), None);
+
+        let blocks_to_step: u16 = SubtensorModule::blocks_until_next_epoch(
+            netuid,
+            tempo,
+            SubtensorModule::get_current_block_as_u64(),
+        ) as u16;
+        step_block(blocks_to_step);
+
+        let (block, weights_hash, result_hash) =
+            SubtensorModule::get_epoch_checksums(netuid).unwrap();
+        assert_eq!(block, SubtensorModule::get_current_block_as_u64());
+        assert_eq!(weights_hash, SubtensorModule::get_weights_checksum(netuid));
+        assert_ne!(weights_hash, result_hash);
+
+        // Any change to the matrix changes its checksum.
+        Weights::<Test>::insert(netuid, 1, row(vec![(0, u16::MAX)]));
+        assert_ne!(weights_hash, SubtensorModule::get_weights_checksum(netuid));
+
+        // The entry goes with the subnet.
+        SubtensorModule::remove_network(netuid);
+        assert_eq!(SubtensorModule::get_epoch_checksums(netuid), None);
+    });
+}
+
+#[test]
+fn test_degenerate_weights_flagged_after_consecutive_tempos() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+        for uid in 0..6 {
+            register_ok_neuron(netuid, U256::from(uid), U256::from(uid + 10), uid * 100_000);
+        }
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        ValidatorPermit::<Test>::insert(netuid, vec![true; 6]);
+        let set = |uid: u16, dests: Vec<u16>, weights: Vec<u16>| {
+            assert_ok!(SubtensorModule::set_weights(
+                RuntimeOrigin::signed(U256::from(uid)),
+                netuid,
+                dests,
+                weights,
+                0
+            ));
+        };
+        // Zero weights are not stored, so uid 0 keeps an empty row and uid 1 only its self weight.
+        set(0, vec![1, 2], vec![0, 0]);
+        set(1, vec![0, 1], vec![0, u16::MAX]);
+        for uid in 2..5 {
+            set(uid, vec![0, 1], vec![100, 200]);
+        }
+        set(5, vec![0, 5], vec![100, 200]);
+        assert!(Weights::<Test>::get(netuid, 0).is_empty());
+        assert!(Weights::<Test>::contains_key(netuid, 0));
+
+        // Nothing is tracked until the subnet enables flagging.
+        SubtensorModule::update_weight_flags(netuid);
+        assert_eq!(
+            DegenerateWeightStreaks::<Test>::iter_prefix(netuid).count(),
+            0
+        );
+
+        SubtensorModule::set_weight_flagging(netuid, 2, 3);
+        SubtensorModule::update_weight_flags(netuid);
+        assert_eq!(DegenerateWeightStreaks::<Test>::get(netuid, 2), 1);
+        assert!(SubtensorModule::get_flagged_uids(netuid).is_empty());
+
+        SubtensorModule::update_weight_flags(netuid);
+        let block: u64 = SubtensorModule::get_current_block_as_u64();
+        let mut flagged = SubtensorModule::get_flagged_uids(netuid);
+        flagged.sort_by_key(|(uid, _, _)| *uid);
+        assert_eq!(
+            flagged,
+            vec![
+                (0, DegenerateWeights::AllZero, block),
+                (1, DegenerateWeights::SelfOnly, block),
+                (2, DegenerateWeights::Duplicate, block),
+                (3, DegenerateWeights::Duplicate, block),
+                (4, DegenerateWeights::Duplicate, block),
+            ]
+        );
+        System::assert_has_event(
+            Event::UidFlagged {
+                netuid,
+                uid: 1,
+                hotkey: U256::from(1),
+                reason: DegenerateWeights::SelfOnly,
+            }
+            .into(),
+        );
+
+        // Once one duplicate diverges, the other two fall below the threshold.
+        set(2, vec![0, 1], vec![200, 100]);
+        SubtensorModule::update_weight_flags(netuid);
+        let remaining: Vec<u16> = SubtensorModule::get_flagged_uids(netuid)
+            .into_iter()
+            .map(|(uid, _, _)| uid)
+            .collect();
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining.contains(&0) && remaining.contains(&1));
+        System::assert_has_event(Event::UidUnflagged { netuid, uid: 3 }.into());
+
+        // A replaced neuron starts with a clean record.
+        SubtensorModule::replace_neuron(netuid, 0, &U256::from(100), block);
+        assert!(FlaggedUids::<Test>::get(netuid, 0).is_none());
+
+        // Disabling flagging drops its state.
+        SubtensorModule::set_weight_flagging(netuid, 0, 3);
+        assert!(SubtensorModule::get_flagged_uids(netuid).is_empty());
+        assert_eq!(
+            DegenerateWeightStreaks::<Test>::iter_prefix(netuid).count(),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_change_tempo_keeps_epoch_cadence() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        add_network(netuid, tempo, 0);
+
+        let blocks_to_step: u16 = SubtensorModule::blocks_until_next_epoch(
+            netuid,
+            tempo,
+            SubtensorModule::get_current_block_as_u64(),
+        ) as u16;
+        step_block(blocks_to_step);
+        let first_epoch: u64 = SubtensorModule::get_current_block_as_u64();
+        assert_eq!(
+            SubtensorModule::get_last_mechanism_step_block(netuid),
+            first_epoch
+        );
+
+        // Shortening the tempo mid-way anchors the next epoch one new tempo after the last.
+        step_block(2);
+        SubtensorModule::change_tempo(netuid, 4);
+        step_block(2);
+        assert_eq!(
+            SubtensorModule::get_last_mechanism_step_block(netuid),
+            first_epoch
+        );
+        step_block(1);
+        assert_eq!(
+            SubtensorModule::get_last_mechanism_step_block(netuid),
+            first_epoch + 5
+        );
+        step_block(5);
+        assert_eq!(
+            SubtensorModule::get_last_mechanism_step_block(netuid),
+            first_epoch + 10
+        );
+
+        // An anchor already in the past moves to the next block rather than being skipped.
+        step_block(3);
+        SubtensorModule::change_tempo(netuid, 1);
+        step_block(1);
+        assert_eq!(
+            SubtensorModule::get_last_mechanism_step_block(netuid),
+            first_epoch + 14
+        );
+        step_block(1);
+        assert_eq!(
+            SubtensorModule::get_last_mechanism_step_block(netuid),
+            first_epoch + 14
+        );
+        step_block(1);
+        assert_eq!(
+            SubtensorModule::get_last_mechanism_step_block(netuid),
+            first_epoch + 16
+        );
+    });
+}
 // // Map the retention graph for consensus guarantees with an single epoch on a graph with 512 nodes, of which the first 64 are validators, the graph is split into a major and minor set, each setting specific weight on itself and the complement on the other.
 // //
 // // ```import torch
@@ -2347,3 +2347,49 @@ fn test_quantile() {
     let result = quantile(&data, 1.0);
     assert_eq!(result, I32F32::from_num(5.0));
 }
+
+// The host functions must return exactly what the runtime math returns.
+#[test]
+fn test_math_host_functions_match_runtime_math() {
+    use pallet_subtensor::epoch::host::subtensor_math;
+
+    let mut rng = thread_rng();
+    let (rows, columns): (usize, u16) = (64, 300);
+    let stake: Vec<I32F32> = (0..rows)
+        .map(|_| I32F32::from_num(rng.gen_range(0..4)))
+        .collect();
+    let weights: Vec<Vec<(u16, I32F32)>> = (0..rows)
+        .map(|_| {
+            (0..columns)
+                .filter(|_| rng.gen_bool(0.3))
+                .map(|c| (c, I32F32::from_num(rng.gen_range(0.0..1.0))))
+                .collect()
+        })
+        .collect();
+    let stake_bits: Vec<i64> = stake.iter().map(|s| s.to_bits()).collect();
+    let weights_bits: Vec<Vec<(u16, i64)>> = weights
+        .iter()
+        .map(|row| row.iter().map(|(c, w)| (*c, w.to_bits())).collect())
+        .collect();
+    let to_fixed =
+        |bits: Vec<i64>| -> Vec<I32F32> { bits.into_iter().map(I32F32::from_bits).collect() };
+
+    let majority: I32F32 = fixed(0.51);
+    assert_eq!(
+        to_fixed(subtensor_math::weighted_median_col_sparse(
+            stake_bits.clone(),
+            weights_bits.clone(),
+            columns,
+            majority.to_bits()
+        )),
+        weighted_median_col_sparse(&stake, &weights, columns, majority)
+    );
+    assert_eq!(
+        to_fixed(subtensor_math::matmul_sparse(
+            weights_bits,
+            stake_bits,
+            columns
+        )),
+        matmul_sparse(&weights, &stake, columns)
+    );
+}
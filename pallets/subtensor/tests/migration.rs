@@ -587,3 +587,184 @@ fn test_migrate_fix_pending_emissions() {
         assert!(StakingHotkeys::<Test>::get(migration_account).contains(taostats_old_hk_account));
     })
 }
+
+#[test]
+fn test_migrate_compress_weights() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let row = |pairs: Vec<(u16, u16)>| WeightsRowOf::<Test>::truncate_from(pairs);
+        Weights::<Test>::insert(netuid, 0, row(vec![(3, 10), (0, 0), (1, 20), (2, 0)]));
+        Weights::<Test>::insert(netuid, 1, row(vec![(0, 0), (1, 0)]));
+        Weights::<Test>::insert(netuid, 2, row(vec![(0, 5), (1, 6)]));
+
+        pallet_subtensor::migrations::migrate_compress_weights::migrate_compress_weights::<Test>();
+
+        assert_eq!(Weights::<Test>::get(netuid, 0), vec![(1, 20), (3, 10)]);
+        assert!(!Weights::<Test>::contains_key(netuid, 1));
+        assert_eq!(Weights::<Test>::get(netuid, 2), vec![(0, 5), (1, 6)]);
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_compress_weights".to_vec()
+        ));
+    })
+}
+
+#[test]
+fn test_migrate_axon_capabilities() {
+    use pallet_subtensor::migrations::migrate_axon_capabilities::deprecated_axon_info_format::OldAxonInfo;
+
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let old = OldAxonInfo {
+            block: 7,
+            version: 2,
+            ip: 1676056785,
+            port: 128,
+            ip_type: 4,
+            protocol: 0,
+            placeholder1: 1,
+            placeholder2: 2,
+        };
+        frame_support::storage::unhashed::put(&Axons::<Test>::hashed_key_for(netuid, hotkey), &old);
+
+        pallet_subtensor::migrations::migrate_axon_capabilities::migrate_axon_capabilities::<Test>(
+        );
+
+        assert_eq!(
+            Axons::<Test>::get(netuid, hotkey),
+            Some(AxonInfo {
+                block: 7,
+                version: 2,
+                ip: 1676056785,
+                port: 128,
+                ip_type: 4,
+                protocol: 0,
+                placeholder1: 1,
+                placeholder2: 2,
+                capabilities: 0,
+            })
+        );
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_axon_capabilities".to_vec()
+        ));
+    })
+}
+
+#[test]
+fn test_migrate_clamp_delegate_takes() {
+    new_test_ext(1).execute_with(|| {
+        let too_high = U256::from(1);
+        let too_low = U256::from(2);
+        let in_range = U256::from(3);
+        Delegates::<Test>::insert(too_high, u16::MAX);
+        Delegates::<Test>::insert(too_low, 0);
+        Delegates::<Test>::insert(in_range, 10_000);
+
+        pallet_subtensor::migrations::migrate_clamp_delegate_takes::migrate_clamp_delegate_takes::<
+            Test,
+        >();
+
+        assert_eq!(
+            Delegates::<Test>::get(too_high),
+            SubtensorModule::get_max_delegate_take()
+        );
+        // Takes below the min are never raised.
+        assert_eq!(Delegates::<Test>::get(too_low), 0);
+        assert_eq!(Delegates::<Test>::get(in_range), 10_000);
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_clamp_delegate_takes".to_vec()
+        ));
+    })
+}
+
+#[test]
+fn test_migrate_stake_provider_refs() {
+    new_test_ext(1).execute_with(|| {
+        let staker = U256::from(1);
+        let unstaked = U256::from(2);
+        TotalColdkeyStake::<Test>::insert(staker, 1_000);
+        TotalColdkeyStake::<Test>::insert(unstaked, 0);
+
+        pallet_subtensor::migrations::migrate_stake_provider_refs::migrate_stake_provider_refs::<
+            Test,
+        >();
+
+        assert_eq!(System::providers(&staker), 1);
+        assert!(StakeProviders::<Test>::get(staker));
+        assert_eq!(System::providers(&unstaked), 0);
+        assert!(!StakeProviders::<Test>::get(unstaked));
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_stake_provider_refs".to_vec()
+        ));
+    })
+}
+
+#[test]
+fn test_migrate_seed_delegate_inactivity() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let registered = U256::from(1);
+        let unregistered = U256::from(3);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, registered, U256::from(2), 0);
+        Delegates::<Test>::insert(registered, 10_000);
+        Delegates::<Test>::insert(unregistered, 10_000);
+        assert!(SubtensorModule::ensure_delegate_active(&unregistered).is_err());
+
+        migrations::migrate_seed_delegate_inactivity::migrate_seed_delegate_inactivity::<Test>();
+
+        assert!(!DelegateDeregisteredAt::<Test>::contains_key(registered));
+        assert_eq!(DelegateDeregisteredAt::<Test>::get(unregistered), Some(1));
+        assert_eq!(
+            DelegateInactivityExpirations::<Test>::get(1 + DelegateInactivityGracePeriod::get()),
+            vec![unregistered]
+        );
+        assert_ok!(SubtensorModule::ensure_delegate_active(&unregistered));
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_seed_delegate_inactivity".to_vec()
+        ));
+    })
+}
+
+#[test]
+fn test_migrate_count_hotkey_nominators() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let other_hotkey = U256::from(2);
+        Stake::<Test>::insert(hotkey, U256::from(10), 100);
+        Stake::<Test>::insert(hotkey, U256::from(11), 200);
+        Stake::<Test>::insert(other_hotkey, U256::from(10), 300);
+        HotkeyNominatorCount::<Test>::insert(other_hotkey, 5);
+
+        migrations::migrate_count_hotkey_nominators::migrate_count_hotkey_nominators::<Test>();
+
+        assert_eq!(HotkeyNominatorCount::<Test>::get(hotkey), 2);
+        assert_eq!(HotkeyNominatorCount::<Test>::get(other_hotkey), 1);
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_count_hotkey_nominators".to_vec()
+        ));
+    })
+}
+
+#[test]
+fn test_migrate_commit_tempo_index() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let commit_hash = sp_core::H256::from_low_u64_be(100);
+        frame_support::storage::unhashed::put(
+            &WeightCommits::<Test>::hashed_key_for(netuid, hotkey),
+            &(commit_hash, 7u64),
+        );
+
+        migrations::migrate_commit_tempo_index::migrate_commit_tempo_index::<Test>();
+
+        assert_eq!(
+            WeightCommits::<Test>::get(netuid, hotkey),
+            Some((commit_hash, 7, None))
+        );
+        assert!(HasMigrationRun::<Test>::get(
+            b"migrate_commit_tempo_index".to_vec()
+        ));
+    })
+}
@@ -1,19 +1,21 @@
 #![allow(clippy::arithmetic_side_effects, clippy::unwrap_used)]
+use codec::{Decode, Encode};
 use frame_support::derive_impl;
 use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::weights::constants::RocksDbWeight;
 use frame_support::weights::Weight;
 use frame_support::{
     assert_ok, parameter_types,
-    traits::{Everything, Hooks, PrivilegeCmp},
+    traits::{Everything, Hooks, PrivilegeCmp, Randomness},
 };
 use frame_system as system;
 use frame_system::{limits, EnsureNever, EnsureRoot, RawOrigin};
 use pallet_collective::MemberCount;
+use scale_info::TypeInfo;
 use sp_core::{Get, H256, U256};
 use sp_runtime::Perbill;
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BlakeTwo256, Hash, IdentifyAccount, IdentityLookup, Lazy, Verify},
     BuildStorage,
 };
 use sp_std::cmp::Ordering;
@@ -30,7 +32,7 @@ frame_support::construct_runtime!(
         TriumvirateMembers: pallet_membership::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>},
         Senate: pallet_collective::<Instance2>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>},
         SenateMembers: pallet_membership::<Instance2>::{Pallet, Call, Storage, Event<T>, Config<T>},
-        SubtensorModule: pallet_subtensor::{Pallet, Call, Storage, Event<T>},
+        SubtensorModule: pallet_subtensor::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
         Utility: pallet_utility::{Pallet, Call, Storage, Event},
         Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
         Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>},
@@ -69,6 +71,64 @@ pub type Balance = u64;
 #[allow(dead_code)]
 pub type BlockNumber = u64;
 
+/// Public key of a test weights signature: the hotkey account itself.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct TestWeightsSigner(pub U256);
+
+impl IdentifyAccount for TestWeightsSigner {
+    type AccountId = U256;
+    fn into_account(self) -> U256 {
+        self.0
+    }
+}
+
+/// Test weights signature: the signing hotkey and the hash of the signed payload.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct TestWeightsSignature(pub U256, pub H256);
+
+impl Verify for TestWeightsSignature {
+    type Signer = TestWeightsSigner;
+    fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &U256) -> bool {
+        self.0 == *signer && self.1 == BlakeTwo256::hash(msg.get())
+    }
+}
+
+/// Test randomness: the hash of the subject and the current block.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        let block: u64 = System::block_number();
+        (BlakeTwo256::hash_of(&(subject, block)), block)
+    }
+}
+
+parameter_types! {
+    pub static EmissionRedirect: u64 = 0;
+}
+
+/// Test emission hook: redirects up to EmissionRedirect of each subnet's block emission.
+pub struct TestOnEmission;
+
+impl pallet_subtensor::OnEmission for TestOnEmission {
+    fn on_subnet_emission(_: u16, emission: u64) -> u64 {
+        EmissionRedirect::get().min(emission)
+    }
+}
+
+parameter_types! {
+    pub static TakeChanges: Vec<(U256, u16, u16)> = vec![];
+}
+
+/// Test take hook: records every (hotkey, old_take, new_take) it is called with.
+pub struct TestOnTakeChanged;
+
+impl pallet_subtensor::OnTakeChanged<U256> for TestOnTakeChanged {
+    fn on_take_changed(hotkey: &U256, old_take: u16, new_take: u16) {
+        TakeChanges::mutate(|changes| changes.push((*hotkey, old_take, new_take)));
+    }
+}
+
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
 impl pallet_balances::Config for Test {
     type Balance = Balance;
@@ -177,6 +237,31 @@ parameter_types! {
     pub const InitialNetworkMaxStake: u64 = u64::MAX; // Maximum possible value for u64
     pub const InitialColdkeySwapScheduleDuration: u64 =  5 * 24 * 60 * 60 / 12; // Default as 5 days
     pub const InitialDissolveNetworkScheduleDuration: u64 =  5 * 24 * 60 * 60 / 12; // Default as 5 days
+    pub const MaxWeightsRowLength: u32 = 4096;
+    pub const TakeChangeProposalExpiry: u64 = 10;
+    pub const MetricsRetentionBlocks: u64 = 10;
+    pub const InitialSubnetEmissionRampTempos: u16 = 0; // tempos before a new subnet receives its full emission
+    pub const ConvictionLockPeriod: u64 = 10;
+    pub const StakeCheckpointInterval: u64 = 10;
+    pub const MaxStakeCheckpoints: u32 = 5;
+    pub const BlocksPerYear: u64 = 7200 * 365;
+    pub const DelegateInactivityGracePeriod: u64 = 10;
+    pub const MaxAuxScoreKinds: u16 = 4;
+    pub static BlockStepWeightLimit: Weight = Weight::MAX;
+    pub const DelegateStatsInterval: u64 = 10;
+    pub const InitialMaxMinStakeToRegister: u64 = 1_000_000_000; // 1000 TAO
+    pub const InitialMaxReservedUids: u16 = 4;
+    pub const MaxPortfolioHotkeys: u32 = 4;
+    pub const TakeIncreaseScheduleDuration: BlockNumber = 100;
+    pub const TreasuryProposalDeposit: u64 = 100;
+    pub const MaxRegistrationBids: u32 = 8;
+    pub const RandomnessBeaconEnabled: bool = true;
+    pub const RegistrationBidDeposit: u64 = 10;
+    pub const KeepStakingAccountsAlive: bool = true;
+    pub const PublicGoodReleaseDelay: u64 = 100;
+    pub const MinOwnerTempo: u16 = 2;
+    pub const OwnerTempoChangeRateLimit: u64 = 10;
+    pub const MaxReservedHotkeys: u32 = 16;
 }
 
 // Configure collective pallet for council
@@ -345,6 +430,7 @@ impl pallet_subtensor::Config for Test {
     type InitialIssuance = InitialIssuance;
     type SudoRuntimeCall = TestRuntimeCall;
     type CouncilOrigin = frame_system::EnsureSigned<AccountId>;
+    type SenateOrigin = frame_system::EnsureSigned<AccountId>;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
     type Scheduler = Scheduler;
@@ -399,8 +485,38 @@ impl pallet_subtensor::Config for Test {
     type InitialHotkeyEmissionTempo = InitialHotkeyEmissionTempo;
     type InitialNetworkMaxStake = InitialNetworkMaxStake;
     type Preimages = Preimage;
+    type WeightsSignature = TestWeightsSignature;
+    type WeightsSigner = TestWeightsSigner;
+    type Randomness = TestRandomness;
+    type RandomnessBeaconEnabled = RandomnessBeaconEnabled;
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    type MaxWeightsRowLength = MaxWeightsRowLength;
+    type TakeChangeProposalExpiry = TakeChangeProposalExpiry;
+    type MetricsRetentionBlocks = MetricsRetentionBlocks;
+    type InitialSubnetEmissionRampTempos = InitialSubnetEmissionRampTempos;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type StakeCheckpointInterval = StakeCheckpointInterval;
+    type MaxStakeCheckpoints = MaxStakeCheckpoints;
+    type BlocksPerYear = BlocksPerYear;
+    type DelegateInactivityGracePeriod = DelegateInactivityGracePeriod;
+    type MaxAuxScoreKinds = MaxAuxScoreKinds;
+    type BlockStepWeightLimit = BlockStepWeightLimit;
+    type DelegateStatsInterval = DelegateStatsInterval;
+    type InitialMaxMinStakeToRegister = InitialMaxMinStakeToRegister;
+    type InitialMaxReservedUids = InitialMaxReservedUids;
+    type OnEmission = TestOnEmission;
+    type OnTakeChanged = TestOnTakeChanged;
+    type MaxPortfolioHotkeys = MaxPortfolioHotkeys;
+    type TakeIncreaseScheduleDuration = TakeIncreaseScheduleDuration;
+    type TreasuryProposalDeposit = TreasuryProposalDeposit;
+    type MaxRegistrationBids = MaxRegistrationBids;
+    type RegistrationBidDeposit = RegistrationBidDeposit;
+    type KeepStakingAccountsAlive = KeepStakingAccountsAlive;
+    type PublicGoodReleaseDelay = PublicGoodReleaseDelay;
+    type MinOwnerTempo = MinOwnerTempo;
+    type OwnerTempoChangeRateLimit = OwnerTempoChangeRateLimit;
+    type MaxReservedHotkeys = MaxReservedHotkeys;
 }
 
 pub struct OriginPrivilegeCmp;
@@ -483,6 +599,28 @@ pub fn test_ext_with_balances(balances: Vec<(U256, u128)>) -> sp_io::TestExterna
     t.into()
 }
 
+#[allow(dead_code)]
+pub fn test_ext_with_subnets(
+    subnets: Vec<pallet_subtensor::GenesisSubnet<U256>>,
+) -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_subtensor::GenesisConfig::<Test> {
+        stakes: Vec::new(),
+        balances_issuance: 0,
+        subnets,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
 #[allow(dead_code)]
 pub(crate) fn step_block(n: u16) {
     for _ in 0..n {
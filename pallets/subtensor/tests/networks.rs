@@ -1,5 +1,5 @@
 use crate::mock::*;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use frame_system::Config;
 use pallet_subtensor::{ColdkeySwapScheduleDuration, DissolveNetworkScheduleDuration, Event};
 use sp_core::U256;
@@ -282,3 +282,138 @@ fn test_schedule_dissolve_network_execution_with_coldkey_swap() {
         assert!(!SubtensorModule::if_subnet_exist(netuid));
     })
 }
+
+#[test]
+fn test_subnet_lease_grant_and_expiry() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let lessee = U256::from(2);
+        add_network(netuid, 10, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+
+        // Only the owner may grant a lease, and it must end in the future.
+        assert_noop!(
+            SubtensorModule::grant_subnet_lease(
+                <<Test as Config>::RuntimeOrigin>::signed(lessee),
+                netuid,
+                lessee,
+                10
+            ),
+            pallet_subtensor::Error::<Test>::NotSubnetOwner
+        );
+        assert_noop!(
+            SubtensorModule::grant_subnet_lease(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                lessee,
+                1
+            ),
+            pallet_subtensor::Error::<Test>::InvalidSubnetLeaseEnd
+        );
+
+        assert_ok!(SubtensorModule::grant_subnet_lease(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            lessee,
+            5
+        ));
+        System::assert_last_event(
+            Event::SubnetLeaseGranted {
+                netuid,
+                lessee,
+                end_block: 5,
+            }
+            .into(),
+        );
+
+        // The lessee controls the subnet while the lease is active.
+        assert_eq!(SubtensorModule::get_subnet_controller(netuid), lessee);
+        assert_ok!(SubtensorModule::ensure_subnet_owner_or_root(
+            <<Test as Config>::RuntimeOrigin>::signed(lessee),
+            netuid
+        ));
+        assert!(SubtensorModule::ensure_subnet_owner_or_root(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid
+        )
+        .is_err());
+        assert_noop!(
+            SubtensorModule::grant_subnet_lease(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                owner,
+                8
+            ),
+            pallet_subtensor::Error::<Test>::SubnetLeaseActive
+        );
+
+        // Control reverts once the end block is reached.
+        step_block(4);
+        assert!(pallet_subtensor::SubnetLease::<Test>::get(netuid).is_none());
+        System::assert_has_event(Event::SubnetLeaseExpired { netuid, lessee }.into());
+        assert_eq!(SubtensorModule::get_subnet_controller(netuid), owner);
+        assert_ok!(SubtensorModule::ensure_subnet_owner_or_root(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid
+        ));
+    });
+}
+
+#[test]
+fn test_genesis_subnets() {
+    let owner = U256::from(1);
+    let subnet = |netuid: u16| pallet_subtensor::GenesisSubnet {
+        netuid,
+        owner,
+        tempo: 13,
+        max_allowed_uids: Some(3),
+        max_allowed_validators: None,
+        immunity_period: None,
+        min_allowed_weights: None,
+        max_weights_limit: None,
+        kappa: Some(100),
+        weights_set_rate_limit: None,
+        burn: Some(1_000),
+        difficulty: None,
+        neurons: (0..3_u64)
+            .map(|i| (U256::from(100 + i), U256::from(200 + i), 1_000 * (i + 1)))
+            .collect(),
+    };
+
+    test_ext_with_subnets(vec![subnet(1), subnet(2)]).execute_with(|| {
+        for netuid in [1, 2] {
+            assert!(SubtensorModule::if_subnet_exist(netuid));
+            assert_eq!(pallet_subtensor::SubnetOwner::<Test>::get(netuid), owner);
+
+            // Set hyperparameters are applied, the rest keep the network defaults.
+            assert_eq!(SubtensorModule::get_tempo(netuid), 13);
+            assert_eq!(SubtensorModule::get_max_allowed_uids(netuid), 3);
+            assert_eq!(SubtensorModule::get_kappa(netuid), 100);
+            assert_eq!(SubtensorModule::get_burn_as_u64(netuid), 1_000);
+            assert_eq!(SubtensorModule::get_max_allowed_validators(netuid), 64);
+
+            // Neurons are registered in order with their stake.
+            assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 3);
+            for i in 0..3_u64 {
+                let hotkey = U256::from(200 + i);
+                assert_eq!(
+                    SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey),
+                    Ok(i as u16)
+                );
+                assert_eq!(
+                    SubtensorModule::get_owning_coldkey_for_hotkey(&hotkey),
+                    U256::from(100 + i)
+                );
+            }
+        }
+
+        // Each subnet's stake is added to its hotkeys and issued at genesis.
+        assert_eq!(SubtensorModule::get_total_stake(), 2 * 6_000);
+        assert_eq!(SubtensorModule::get_total_issuance(), 2 * 6_000);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&U256::from(202)),
+            2 * 3_000
+        );
+    });
+}
@@ -1,6 +1,11 @@
+#![allow(clippy::unwrap_used)]
+
 mod mock;
 use mock::*;
 
+use codec::{Decode, Encode};
+use pallet_subtensor::rpc_info::metagraph::metagraph_fields;
+use pallet_subtensor::rpc_info::subnet_state::{SubnetState, SUBNET_STATE_VERSION};
 use sp_core::U256;
 
 #[test]
@@ -70,3 +75,127 @@ fn test_get_neurons_empty() {
         assert_eq!(neurons.len(), neuron_count as usize);
     });
 }
+
+#[test]
+fn test_get_metagraph_fields_none() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+
+        let metagraph = SubtensorModule::get_metagraph_fields(netuid, metagraph_fields::ALL);
+        assert_eq!(metagraph, None);
+    });
+}
+
+#[test]
+fn test_get_metagraph_fields_selective() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+
+        let tempo: u16 = 2;
+        let modality: u16 = 2;
+
+        add_network(netuid, tempo, modality);
+
+        let neuron_count = 3;
+        for index in 0..neuron_count {
+            let hotkey = U256::from(index);
+            let coldkey = U256::from(index + 100);
+            let nonce: u64 = 39420842 + index;
+            register_ok_neuron(netuid, hotkey, coldkey, nonce);
+        }
+
+        let metagraph = SubtensorModule::get_metagraph_fields(
+            netuid,
+            metagraph_fields::HOTKEYS | metagraph_fields::STAKE,
+        )
+        .unwrap();
+        assert_eq!(metagraph.n.0, neuron_count as u16);
+        assert_eq!(
+            metagraph.hotkeys,
+            Some((0..neuron_count).map(U256::from).collect::<Vec<_>>())
+        );
+        assert_eq!(
+            metagraph.stake.map(|s| s.len()),
+            Some(neuron_count as usize)
+        );
+        assert_eq!(metagraph.coldkeys, None);
+        assert_eq!(metagraph.rank, None);
+        assert_eq!(metagraph.axons, None);
+
+        let full = SubtensorModule::get_metagraph_fields(netuid, metagraph_fields::ALL).unwrap();
+        assert_eq!(
+            full.coldkeys,
+            Some(
+                (0..neuron_count)
+                    .map(|i| U256::from(i + 100))
+                    .collect::<Vec<_>>()
+            )
+        );
+        assert_eq!(full.rank.map(|r| r.len()), Some(neuron_count as usize));
+        assert_eq!(full.axons.map(|a| a.len()), Some(neuron_count as usize));
+
+        let empty = SubtensorModule::get_metagraph_fields(netuid, 0).unwrap();
+        assert_eq!(empty.hotkeys, None);
+        assert_eq!(empty.validator_permit, None);
+    });
+}
+
+#[test]
+fn test_get_netuids_for_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let other_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+
+        add_network(1, 2, 0);
+        add_network(2, 2, 0);
+        add_network(3, 2, 0);
+        register_ok_neuron(1, hotkey, coldkey, 39420842);
+        register_ok_neuron(2, other_hotkey, coldkey, 39420843);
+        register_ok_neuron(2, hotkey, coldkey, 12345);
+
+        let mut netuids: Vec<(u16, u16)> = SubtensorModule::get_netuids_for_hotkey(hotkey.encode())
+            .into_iter()
+            .map(|(netuid, uid)| (netuid.0, uid.0))
+            .collect();
+        netuids.sort();
+        assert_eq!(netuids, vec![(1, 0), (2, 1)]);
+
+        // Invalid account bytes return nothing.
+        assert!(SubtensorModule::get_netuids_for_hotkey(vec![0; 3]).is_empty());
+    });
+}
+
+#[test]
+fn test_get_subnet_state() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        assert_eq!(SubtensorModule::get_subnet_state(netuid), None);
+
+        add_network(netuid, 2, 0);
+        let neuron_count = 3;
+        for index in 0..neuron_count {
+            register_ok_neuron(netuid, U256::from(index), U256::from(index + 100), index);
+        }
+
+        let state = SubtensorModule::get_subnet_state(netuid).unwrap();
+        assert_eq!(state.version, SUBNET_STATE_VERSION);
+        assert_eq!(state.tempo, 2);
+        assert_eq!(
+            state.hotkeys,
+            (0..neuron_count).map(U256::from).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            state.coldkeys,
+            (0..neuron_count)
+                .map(|i| U256::from(i + 100))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(state.axons.len(), neuron_count as usize);
+        assert_eq!(state.weights.len(), neuron_count as usize);
+
+        // The snapshot round-trips through SCALE.
+        let decoded = SubnetState::<Test>::decode(&mut &state.encode()[..]).unwrap();
+        assert_eq!(decoded, state);
+    });
+}
@@ -0,0 +1,84 @@
+mod mock;
+use frame_support::{traits::Get, weights::Weight};
+use mock::*;
+use pallet_subtensor::*;
+use sp_core::U256;
+
+// Plenty of weight to finish a full pass over the test state.
+fn full_pass_weight() -> Weight {
+    Weight::from_parts(u64::MAX, u64::MAX)
+}
+
+#[test]
+fn test_clean_orphaned_storage_removes_orphans() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let registered_hotkey = U256::from(1);
+        let orphan_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, registered_hotkey, coldkey, 0);
+
+        // Zero stake on a hotkey that is registered nowhere.
+        Stake::<Test>::insert(orphan_hotkey, coldkey, 0);
+        StakingHotkeys::<Test>::mutate(coldkey, |hotkeys| hotkeys.push(orphan_hotkey));
+        // Zero stake on a registered hotkey is kept.
+        Stake::<Test>::insert(registered_hotkey, coldkey, 0);
+        // Axons for pruned and live uids.
+        Axons::<Test>::insert(netuid, orphan_hotkey, AxonInfoOf::default());
+        Axons::<Test>::insert(netuid, registered_hotkey, AxonInfoOf::default());
+        // Delegates with and without a registration.
+        Delegates::<Test>::insert(orphan_hotkey, 100);
+        Delegates::<Test>::insert(registered_hotkey, 100);
+
+        let used = SubtensorModule::clean_orphaned_storage(full_pass_weight());
+        assert!(used.ref_time() > 0);
+
+        assert!(!Stake::<Test>::contains_key(orphan_hotkey, coldkey));
+        assert!(!StakingHotkeys::<Test>::get(coldkey).contains(&orphan_hotkey));
+        assert!(Stake::<Test>::contains_key(registered_hotkey, coldkey));
+        assert!(!Axons::<Test>::contains_key(netuid, orphan_hotkey));
+        assert!(Axons::<Test>::contains_key(netuid, registered_hotkey));
+        assert!(!Delegates::<Test>::contains_key(orphan_hotkey));
+        assert!(Delegates::<Test>::contains_key(registered_hotkey));
+
+        assert_eq!(OrphanedStakeRemoved::<Test>::get(), 1);
+        assert_eq!(OrphanedAxonsRemoved::<Test>::get(), 1);
+        assert_eq!(OrphanedDelegatesRemoved::<Test>::get(), 1);
+
+        // A full pass resets the scan to the start.
+        assert_eq!(OrphanCleanupPhase::<Test>::get(), 0);
+        assert_eq!(OrphanCleanupCursor::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn test_clean_orphaned_storage_respects_weight() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(3);
+        for i in 0..10 {
+            Stake::<Test>::insert(U256::from(100 + i), coldkey, 0);
+        }
+
+        // Not enough weight for a single record: nothing is done.
+        assert_eq!(
+            SubtensorModule::clean_orphaned_storage(Weight::zero()),
+            Weight::zero()
+        );
+        assert_eq!(OrphanedStakeRemoved::<Test>::get(), 0);
+
+        // Enough weight for bookkeeping plus three records.
+        let db = <Test as frame_system::Config>::DbWeight::get();
+        let budget = db
+            .reads_writes(2, 2)
+            .saturating_add(db.reads_writes(4, 4).saturating_mul(3));
+        let used = SubtensorModule::clean_orphaned_storage(budget);
+        assert!(used.all_lte(budget));
+        assert_eq!(OrphanedStakeRemoved::<Test>::get(), 3);
+        assert!(OrphanCleanupCursor::<Test>::get().is_some());
+
+        // The next call resumes from the cursor and finishes the map.
+        SubtensorModule::clean_orphaned_storage(full_pass_weight());
+        assert_eq!(OrphanedStakeRemoved::<Test>::get(), 10);
+    });
+}
@@ -7,8 +7,8 @@ use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays
 use frame_support::sp_runtime::{transaction_validity::InvalidTransaction, DispatchError};
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::Config;
-use pallet_subtensor::{AxonInfoOf, Error, SubtensorSignedExtension};
-use sp_core::U256;
+use pallet_subtensor::{AxonInfoOf, Error, RegistrationMechanism, SubtensorSignedExtension};
+use sp_core::{H256, U256};
 use sp_runtime::traits::{DispatchInfoOf, SignedExtension};
 
 mod mock;
@@ -416,6 +416,89 @@ fn test_burned_registration_rate_allows_burn_adjustment() {
     });
 }
 
+#[test]
+fn test_burned_registration_requires_min_stake() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let staked_hotkey = U256::from(2);
+        let coldkey = U256::from(667);
+        let min_stake: u64 = 5000;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::set_min_stake_to_register(netuid, min_stake);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+
+        // Balance alone does not count towards the minimum stake.
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey
+            ),
+            Error::<Test>::NotEnoughStakeToRegister
+        );
+
+        SubtensorModule::create_account_if_non_existent(&coldkey, &staked_hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &coldkey,
+            &staked_hotkey,
+            min_stake,
+        );
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+
+        // The stake is held, not spent.
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&coldkey),
+            min_stake
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 9000);
+    });
+}
+
+#[test]
+fn test_burned_registration_requires_challenge() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(667);
+        let commit = H256::repeat_byte(7);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000);
+        SubtensorModule::set_registration_challenge_commit(netuid, Some(commit));
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey
+            ),
+            Error::<Test>::RegistrationChallengeMismatch
+        );
+        assert_noop!(
+            SubtensorModule::burned_register_with_challenge(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey,
+                H256::repeat_byte(8)
+            ),
+            Error::<Test>::RegistrationChallengeMismatch
+        );
+        assert_ok!(SubtensorModule::burned_register_with_challenge(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey,
+            commit
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 1);
+    });
+}
+
 #[test]
 fn test_burned_registration_ok() {
     new_test_ext(1).execute_with(|| {
@@ -1261,6 +1344,85 @@ fn test_registration_get_uid_to_prune_none_in_immunity_period() {
     });
 }
 
+#[test]
+fn test_registration_get_uid_to_prune_skips_reserved_hotkeys() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, U256::from(0), U256::from(0), 39420842);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 12412392);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 100);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 110);
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 0);
+
+        // The lowest scoring neuron is reserved, so the next one is pruned instead.
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 100);
+        SubtensorModule::set_reserved_hotkeys(netuid, vec![U256::from(0)].try_into().unwrap());
+        assert_eq!(SubtensorModule::get_neuron_to_prune(netuid), 1);
+        assert_eq!(SubtensorModule::get_pruning_score_for_uid(netuid, 0), 100);
+    });
+}
+
+#[test]
+fn test_registration_holds_slots_for_reserved_hotkeys() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let reserved = U256::from(10);
+        add_network(netuid, 0, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        SubtensorModule::set_reserved_hotkeys(netuid, vec![reserved].try_into().unwrap());
+
+        register_ok_neuron(netuid, U256::from(1), U256::from(101), 100_000);
+        register_ok_neuron(netuid, U256::from(2), U256::from(102), 200_000);
+
+        // The last slot is held for the reserved hotkey, so another hotkey replaces a neuron.
+        register_ok_neuron(netuid, U256::from(3), U256::from(103), 300_000);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+
+        // The reserved hotkey takes the held slot.
+        register_ok_neuron(netuid, reserved, U256::from(110), 400_000);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 3);
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &reserved),
+            Ok(2)
+        );
+    });
+}
+
+#[test]
+fn test_registration_fails_when_every_neuron_is_reserved() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let reserved = U256::from(1);
+        let hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        add_network(netuid, 0, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 1);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        register_ok_neuron(netuid, reserved, U256::from(101), 100_000);
+        SubtensorModule::set_reserved_hotkeys(netuid, vec![reserved].try_into().unwrap());
+
+        SubtensorModule::set_burn(netuid, 1);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey,
+            ),
+            Error::<Test>::NoNeuronIdAvailable
+        );
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &reserved),
+            Ok(0)
+        );
+    });
+}
+
 #[test]
 fn test_registration_pruning() {
     new_test_ext(1).execute_with(|| {
@@ -2130,3 +2292,231 @@ fn test_registration_disabled() {
 //         );
 //     });
 // }
+
+#[test]
+fn test_registration_auction_top_bids_win() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_adjustment_interval(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 2);
+        SubtensorModule::set_min_burn(netuid, 100);
+        SubtensorModule::set_registration_mechanism(netuid, RegistrationMechanism::Auction);
+
+        // Key 4 never reveals its bid.
+        let bids: [(u64, u64); 4] = [(1, 500), (2, 300), (3, 400), (4, 450)];
+        let salt = H256::repeat_byte(7);
+        let commitment = |key: u64, amount: u64| {
+            SubtensorModule::registration_bid_hash(
+                &U256::from(1000 + key),
+                &U256::from(key),
+                netuid,
+                amount,
+                salt,
+            )
+        };
+        for key in 1..=9 {
+            SubtensorModule::add_balance_to_coldkey_account(&U256::from(1000 + key), 1000);
+        }
+
+        // Fixed burn registration is closed while the auction runs.
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1001)),
+                netuid,
+                U256::from(1)
+            ),
+            Error::<Test>::RegistrationByAuctionOnly
+        );
+        assert_noop!(
+            SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1001)),
+                netuid,
+                U256::from(1),
+                commitment(1, 99),
+                99
+            ),
+            Error::<Test>::RegistrationBidTooLow
+        );
+
+        // Every bid escrows 600 and the deposit of 10, hiding its amount.
+        for (key, amount) in bids {
+            assert_ok!(SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1000 + key)),
+                netuid,
+                U256::from(key),
+                commitment(key, amount),
+                600
+            ));
+            assert_eq!(
+                SubtensorModule::get_coldkey_balance(&U256::from(1000 + key)),
+                390
+            );
+        }
+        assert_noop!(
+            SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1001)),
+                netuid,
+                U256::from(1),
+                commitment(1, 600),
+                600
+            ),
+            Error::<Test>::RegistrationBidAlreadyPlaced
+        );
+        // The auction holds at most MaxRegistrationBids bids.
+        for key in 5..=8 {
+            assert_ok!(SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1000 + key)),
+                netuid,
+                U256::from(key),
+                commitment(key, 100),
+                100
+            ));
+        }
+        assert_noop!(
+            SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1009)),
+                netuid,
+                U256::from(9),
+                commitment(9, 100),
+                100
+            ),
+            Error::<Test>::TooManyRegistrationBids
+        );
+        assert_noop!(
+            SubtensorModule::reveal_registration_bid(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1001)),
+                netuid,
+                U256::from(1),
+                500,
+                salt
+            ),
+            Error::<Test>::RegistrationBidRevealNotOpen
+        );
+
+        // The reveal window is the last quarter of the adjustment interval.
+        step_block(7);
+        assert_noop!(
+            SubtensorModule::bid_for_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1009)),
+                netuid,
+                U256::from(9),
+                commitment(9, 100),
+                100
+            ),
+            Error::<Test>::RegistrationBidCommitClosed
+        );
+        assert_noop!(
+            SubtensorModule::reveal_registration_bid(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1001)),
+                netuid,
+                U256::from(1),
+                550,
+                salt
+            ),
+            Error::<Test>::InvalidRegistrationBidReveal
+        );
+        for (key, amount) in bids.into_iter().take(3) {
+            assert_ok!(SubtensorModule::reveal_registration_bid(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1000 + key)),
+                netuid,
+                U256::from(key),
+                amount,
+                salt
+            ));
+        }
+
+        step_block(2);
+
+        // The two highest revealed bids are registered and burned, the lowest one refunded.
+        assert!(SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &U256::from(1)
+        ));
+        assert!(SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &U256::from(3)
+        ));
+        assert!(!SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &U256::from(2)
+        ));
+        assert!(!SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &U256::from(4)
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&U256::from(1001)), 500);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&U256::from(1002)),
+            1000
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&U256::from(1003)), 600);
+        // Unrevealed bids get their escrow back and forfeit the deposit.
+        for key in 4..=8 {
+            assert_eq!(
+                SubtensorModule::get_coldkey_balance(&U256::from(1000 + key)),
+                990
+            );
+        }
+        assert_eq!(SubtensorModule::get_rao_recycled(netuid), 950);
+        assert!(pallet_subtensor::RegistrationBids::<Test>::get(netuid).is_empty());
+    });
+}
+
+#[test]
+fn test_registration_rejects_hotkeys_as_coldkeys() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 100);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        for key in [1, 3, 5, 667] {
+            SubtensorModule::add_balance_to_coldkey_account(&U256::from(key), 10000);
+        }
+
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(667)),
+            netuid,
+            U256::from(1)
+        ));
+        // A hotkey owned by another coldkey cannot sign as a coldkey.
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                U256::from(2)
+            ),
+            Error::<Test>::ColdkeyIsRegisteredHotkey
+        );
+        // Self-owned combined keys are allowed until strict separation is enabled.
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(3)),
+            netuid,
+            U256::from(3)
+        ));
+
+        SubtensorModule::set_strict_key_usage_separation(true);
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(3)),
+                netuid,
+                U256::from(4)
+            ),
+            Error::<Test>::ColdkeyIsRegisteredHotkey
+        );
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(5)),
+                netuid,
+                U256::from(667)
+            ),
+            Error::<Test>::HotkeyIsInUseAsColdkey
+        );
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(5)),
+            netuid,
+            U256::from(6)
+        ));
+    });
+}
@@ -33,6 +33,29 @@ fn test_root_register_network_exist() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test root -- test_root_register_uses_default_take --exact --nocapture
+#[test]
+fn test_root_register_uses_default_take() {
+    new_test_ext(1).execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+        let hotkey_account_id: U256 = U256::from(1);
+        let coldkey_account_id = U256::from(667);
+        let take: u16 = SubtensorModule::get_min_delegate_take();
+        SubtensorModule::set_default_delegate_take(take);
+
+        assert_ok!(SubtensorModule::root_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey_account_id), take);
+        assert!(
+            System::events().contains(&record(RuntimeEvent::SubtensorModule(
+                pallet_subtensor::Event::DelegateAdded(coldkey_account_id, hotkey_account_id, take)
+            )))
+        );
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test root -- test_set_weights_not_root_error --exact --nocapture
 #[test]
 fn test_set_weights_not_root_error() {
@@ -99,6 +99,69 @@ fn test_serving_ok() {
     });
 }
 
+#[test]
+fn test_serving_axon_capabilities_and_min_version() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        let version: u32 = 2;
+        let capabilities: u64 = 0b101;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        assert_ok!(SubtensorModule::serve_axon_with_capabilities(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            version,
+            1676056785,
+            128,
+            4,
+            0,
+            0,
+            0,
+            capabilities
+        ));
+        assert_eq!(
+            SubtensorModule::get_axon_info(netuid, &hotkey_account_id).capabilities,
+            capabilities
+        );
+
+        // Serving without capabilities keeps the advertised ones.
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            version,
+            1676056785,
+            129,
+            4,
+            0,
+            0,
+            0
+        ));
+        let axon = SubtensorModule::get_axon_info(netuid, &hotkey_account_id);
+        assert_eq!(axon.port, 129);
+        assert_eq!(axon.capabilities, capabilities);
+        assert_eq!(
+            SubtensorModule::get_visible_axon_info(netuid, &hotkey_account_id),
+            axon
+        );
+
+        // Outdated axons are hidden from queries but stay stored.
+        SubtensorModule::set_min_axon_version(netuid, version + 1);
+        assert_eq!(
+            SubtensorModule::get_visible_axon_info(netuid, &hotkey_account_id),
+            AxonInfo::default()
+        );
+        assert_eq!(
+            SubtensorModule::get_axon_info(netuid, &hotkey_account_id),
+            axon
+        );
+        assert!(SubtensorModule::is_hotkey_registered_on_network(
+            netuid,
+            &hotkey_account_id
+        ));
+    });
+}
+
 #[test]
 fn test_serving_set_metadata_update() {
     new_test_ext(1).execute_with(|| {
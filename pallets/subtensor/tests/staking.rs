@@ -1,12 +1,16 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::arithmetic_side_effects)]
 
+use codec::{Compact, Encode};
 use frame_support::{assert_err, assert_noop, assert_ok, traits::Currency};
 use frame_system::Config;
 mod mock;
 use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays};
+use frame_support::sp_runtime::transaction_validity::InvalidTransaction;
 use frame_support::sp_runtime::DispatchError;
+use frame_support::weights::Weight;
 use mock::*;
+use pallet_subtensor::rpc_info::delegate_info::MAX_NOMINATORS_PAGE;
 use pallet_subtensor::*;
 use sp_core::{H256, U256};
 
@@ -72,6 +76,9 @@ fn test_add_stake_ok_no_emission() {
             SubtensorModule::get_total_stake_for_hotkey(&hotkey_account_id),
             9999
         );
+        System::assert_last_event(
+            Event::StakeAdded(coldkey_account_id, hotkey_account_id, 9999, 9999, 9999).into(),
+        );
 
         // Check if balance has decreased
         assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey_account_id), 1);
@@ -181,6 +188,13 @@ fn test_add_stake_err_neuron_does_not_belong_to_coldkey() {
         // Give it some $$$ in his coldkey balance
         SubtensorModule::add_balance_to_coldkey_account(&other_cold_key, 100000);
 
+        // The hotkey opts out of delegated stake
+        assert_ok!(SubtensorModule::set_delegate_availability(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_id),
+            hotkey_id,
+            false
+        ));
+
         // Perform the request which is signed by a different cold key
         let result = SubtensorModule::add_stake(
             <<Test as Config>::RuntimeOrigin>::signed(other_cold_key),
@@ -194,6 +208,85 @@ fn test_add_stake_err_neuron_does_not_belong_to_coldkey() {
     });
 }
 
+#[test]
+fn test_add_stake_to_implicit_delegate() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_id = U256::from(544);
+        let hotkey_id = U256::from(54544);
+        let other_cold_key = U256::from(99498);
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_id, coldkey_id, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&other_cold_key, 100000);
+
+        // A registered hotkey accepts nominations without become_delegate.
+        assert!(!SubtensorModule::hotkey_is_delegate(&hotkey_id));
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(other_cold_key),
+            hotkey_id,
+            1000
+        ));
+        assert!(SubtensorModule::hotkey_is_delegate(&hotkey_id));
+        assert_eq!(
+            SubtensorModule::get_hotkey_take(&hotkey_id),
+            SubtensorModule::get_default_delegate_take()
+        );
+        System::assert_has_event(
+            Event::DelegateAdded(
+                coldkey_id,
+                hotkey_id,
+                SubtensorModule::get_default_delegate_take(),
+            )
+            .into(),
+        );
+
+        // The recorded take no longer follows the default take.
+        let take: u16 = SubtensorModule::get_default_delegate_take();
+        SubtensorModule::set_default_delegate_take(take - 1);
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey_id), take);
+
+        // Only the owner can opt out.
+        assert_noop!(
+            SubtensorModule::set_delegate_availability(
+                <<Test as Config>::RuntimeOrigin>::signed(other_cold_key),
+                hotkey_id,
+                false
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_ok!(SubtensorModule::set_delegate_availability(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_id),
+            hotkey_id,
+            false
+        ));
+        System::assert_last_event(
+            Event::DelegateAvailabilitySet {
+                coldkey: coldkey_id,
+                hotkey: hotkey_id,
+                available: false,
+            }
+            .into(),
+        );
+
+        // After opting out, nominators can still withdraw but not add.
+        step_block(1);
+        assert_noop!(
+            SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(other_cold_key),
+                hotkey_id,
+                1000
+            ),
+            Error::<Test>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(other_cold_key),
+            hotkey_id,
+            1000
+        ));
+    });
+}
+
 #[test]
 fn test_add_stake_err_not_enough_belance() {
     new_test_ext(1).execute_with(|| {
@@ -914,6 +1007,34 @@ fn test_remove_stake_from_hotkey_account() {
     });
 }
 
+// The nominator count of a hotkey follows its Stake entries.
+#[test]
+fn test_hotkey_nominator_count_tracks_stake_entries() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        let nominator = U256::from(3);
+        SubtensorModule::create_account_if_non_existent(&owner, &hotkey);
+        assert_eq!(
+            pallet_subtensor::HotkeyNominatorCount::<Test>::get(hotkey),
+            1
+        );
+
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 100);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 100);
+        assert_eq!(
+            pallet_subtensor::HotkeyNominatorCount::<Test>::get(hotkey),
+            2
+        );
+
+        SubtensorModule::empty_stake_on_coldkey_hotkey_account(&nominator, &hotkey);
+        assert_eq!(
+            pallet_subtensor::HotkeyNominatorCount::<Test>::get(hotkey),
+            1
+        );
+    });
+}
+
 #[test]
 fn test_remove_stake_from_hotkey_account_registered_in_various_networks() {
     new_test_ext(1).execute_with(|| {
@@ -1318,6 +1439,7 @@ fn test_unstake_all_coldkeys_from_hotkey_account_single_staker() {
 
         // Run unstake_all_coldkeys_from_hotkey_account
         SubtensorModule::unstake_all_coldkeys_from_hotkey_account(&hotkey_id);
+        System::assert_last_event(Event::StakeRemoved(coldkey0_id, hotkey_id, amount, 0, 0).into());
 
         // Verify total stake is 0
         assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey_id), 0);
@@ -1834,6 +1956,148 @@ fn test_delegate_take_can_be_increased() {
     });
 }
 
+// Verify both take extrinsics notify the take hook with the old and new take
+#[test]
+fn test_take_change_notifies_hook() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let min_take = SubtensorModule::get_min_delegate_take();
+
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey0, 100000);
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            min_take
+        ));
+        step_block(1 + InitialTxDelegateTakeRateLimit::get() as u16);
+
+        assert_ok!(SubtensorModule::do_increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            u16::MAX / 8
+        ));
+        assert_ok!(SubtensorModule::do_decrease_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            min_take
+        ));
+        // A rejected change is not reported.
+        assert_err!(
+            SubtensorModule::do_decrease_take(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+                hotkey0,
+                min_take
+            ),
+            Error::<Test>::DelegateTakeTooLow
+        );
+
+        assert_eq!(
+            TakeChanges::get(),
+            vec![
+                (hotkey0, min_take, u16::MAX / 8),
+                (hotkey0, u16::MAX / 8, min_take)
+            ]
+        );
+    });
+}
+
+// Verify a scheduled take increase is applied once its delay has passed
+#[test]
+fn test_schedule_increase_take() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey0, 100000);
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            SubtensorModule::get_min_delegate_take()
+        ));
+
+        // The checks of do_increase_take apply when scheduling.
+        assert_err!(
+            SubtensorModule::increase_take(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+                hotkey0,
+                SubtensorModule::get_min_delegate_take()
+            ),
+            Error::<Test>::DelegateTakeTooLow
+        );
+        assert_ok!(SubtensorModule::increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            u16::MAX / 8
+        ));
+        let execution_block = System::block_number() + TakeIncreaseScheduleDuration::get();
+        assert_eq!(
+            SubtensorModule::get_scheduled_operations_for_coldkey(&coldkey0),
+            vec![(
+                0,
+                ScheduledOperation::TakeIncrease {
+                    hotkey: hotkey0,
+                    take: u16::MAX / 8
+                },
+                execution_block
+            )]
+        );
+
+        step_block((TakeIncreaseScheduleDuration::get() - 1) as u16);
+        assert_eq!(
+            SubtensorModule::get_hotkey_take(&hotkey0),
+            SubtensorModule::get_min_delegate_take()
+        );
+        step_block(1);
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), u16::MAX / 8);
+        assert!(SubtensorModule::get_scheduled_operations_for_coldkey(&coldkey0).is_empty());
+    });
+
+    // An increase that fails at its execution block is dropped and reported.
+    assert_ok!(SubtensorModule::increase_take(
+        <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+        hotkey0,
+        u16::MAX / 7
+    ));
+    step_block(1 + InitialTxDelegateTakeRateLimit::get() as u16);
+    assert_ok!(SubtensorModule::do_increase_take(
+        <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+        hotkey0,
+        u16::MAX / 6
+    ));
+    step_block(TakeIncreaseScheduleDuration::get() as u16);
+    System::assert_has_event(
+        Event::ScheduledOperationFailed {
+            coldkey: coldkey0,
+            id: 1,
+            error: Error::<Test>::DelegateTakeTooLow.into(),
+        }
+        .into(),
+    );
+    assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), u16::MAX / 6);
+    assert!(SubtensorModule::get_scheduled_operations_for_coldkey(&coldkey0).is_empty());
+
+    // An operation the scheduler no longer holds can still be cancelled.
+    assert_ok!(SubtensorModule::increase_take(
+        <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+        hotkey0,
+        u16::MAX / 5
+    ));
+    let execution_block = System::block_number() + TakeIncreaseScheduleDuration::get();
+    pallet_scheduler::Agenda::<Test>::remove(execution_block);
+    assert_ok!(SubtensorModule::cancel_scheduled_operation(
+        <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+        2
+    ));
+    assert!(SubtensorModule::get_scheduled_operations_for_coldkey(&coldkey0).is_empty());
+}
+
 // Verify delegate take can not be decreased with increase_take
 #[test]
 fn test_delegate_take_can_not_be_decreased_with_increase_take() {
@@ -2407,5 +2671,1270 @@ fn test_stake_delta_tracks_adds_and_removes() {
             i128::from(owner_added_stake)
                 .saturating_add_unsigned((owner_adds_more_stake - owner_removed_stake).into())
         );
+
+        // Removing the whole stake drops the delta with it
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(delegate_coldkey),
+            delegate_hotkey,
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&delegate_coldkey, &delegate_hotkey)
+        ));
+        assert!(!StakeDeltaSinceLastEmissionDrain::<Test>::contains_key(
+            delegate_hotkey,
+            delegate_coldkey
+        ));
+    });
+}
+
+// Verify a proposed take change only applies once confirmed before expiry
+#[test]
+fn test_propose_and_confirm_take_change() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let other_coldkey = U256::from(4);
+
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            SubtensorModule::get_min_delegate_take()
+        ));
+        step_block(1 + InitialTxDelegateTakeRateLimit::get() as u16);
+
+        // Nothing to confirm yet.
+        assert_noop!(
+            SubtensorModule::confirm_take_change(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+                hotkey0
+            ),
+            Error::<Test>::NoPendingTakeChange
+        );
+
+        // Only the owning coldkey can propose.
+        assert_noop!(
+            SubtensorModule::propose_take_change(
+                <<Test as Config>::RuntimeOrigin>::signed(other_coldkey),
+                hotkey0,
+                u16::MAX / 8
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        assert_ok!(SubtensorModule::propose_take_change(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            u16::MAX / 8
+        ));
+        // The take is unchanged until confirmed.
+        assert_eq!(
+            SubtensorModule::get_hotkey_take(&hotkey0),
+            SubtensorModule::get_min_delegate_take()
+        );
+
+        // A confirmed increase is queued like increase_take.
+        assert_ok!(SubtensorModule::confirm_take_change(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0
+        ));
+        assert!(PendingTakeChanges::<Test>::get(hotkey0).is_none());
+        assert_eq!(
+            SubtensorModule::get_hotkey_take(&hotkey0),
+            SubtensorModule::get_min_delegate_take()
+        );
+        step_block(TakeIncreaseScheduleDuration::get() as u16);
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), u16::MAX / 8);
+
+        // Confirming the current take only clears the proposal.
+        assert_ok!(SubtensorModule::propose_take_change(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            u16::MAX / 8
+        ));
+        assert_ok!(SubtensorModule::confirm_take_change(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), u16::MAX / 8);
+        assert!(PendingTakeChanges::<Test>::get(hotkey0).is_none());
+
+        // An expired proposal can no longer be confirmed.
+        assert_ok!(SubtensorModule::propose_take_change(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            u16::MAX / 10
+        ));
+        step_block(TakeChangeProposalExpiry::get() as u16 + 1);
+        assert_noop!(
+            SubtensorModule::confirm_take_change(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+                hotkey0
+            ),
+            Error::<Test>::TakeChangeProposalExpired
+        );
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), u16::MAX / 8);
+    });
+}
+
+// Verify a delegate manager can change the take of the hotkey until the coldkey revokes it
+#[test]
+fn test_delegate_manager_changes_take() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let manager = U256::from(5);
+        let other_hotkey = U256::from(6);
+
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        register_ok_neuron(netuid, other_hotkey, coldkey0, 124124);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            SubtensorModule::get_min_delegate_take()
+        ));
+
+        // Only the owning coldkey can appoint a manager.
+        assert_noop!(
+            SubtensorModule::set_delegate_manager(
+                <<Test as Config>::RuntimeOrigin>::signed(manager),
+                hotkey0,
+                manager
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_ok!(SubtensorModule::set_delegate_manager(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            manager
+        ));
+        System::assert_last_event(
+            Event::DelegateManagerSet {
+                coldkey: coldkey0,
+                hotkey: hotkey0,
+                manager,
+            }
+            .into(),
+        );
+
+        // The manager acts for the owning coldkey on this hotkey only.
+        step_block(1 + InitialTxDelegateTakeRateLimit::get() as u16);
+        assert_ok!(SubtensorModule::increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(manager),
+            hotkey0,
+            u16::MAX / 8
+        ));
+        step_block(TakeIncreaseScheduleDuration::get() as u16);
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), u16::MAX / 8);
+        assert_noop!(
+            SubtensorModule::increase_take(
+                <<Test as Config>::RuntimeOrigin>::signed(manager),
+                other_hotkey,
+                u16::MAX / 8
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_ok!(SubtensorModule::decrease_take(
+            <<Test as Config>::RuntimeOrigin>::signed(manager),
+            hotkey0,
+            u16::MAX / 10
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), u16::MAX / 10);
+
+        // Once revoked the manager can no longer change the take.
+        assert_ok!(SubtensorModule::remove_delegate_manager(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0
+        ));
+        assert_noop!(
+            SubtensorModule::decrease_take(
+                <<Test as Config>::RuntimeOrigin>::signed(manager),
+                hotkey0,
+                u16::MAX / 12
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_noop!(
+            SubtensorModule::remove_delegate_manager(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+                hotkey0
+            ),
+            Error::<Test>::NoDelegateManager
+        );
+    });
+}
+
+// Verify take change events carry both the previous and the new take
+#[test]
+fn test_take_change_events_include_old_take() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let min_take = SubtensorModule::get_min_delegate_take();
+
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            min_take
+        ));
+        System::assert_last_event(
+            Event::DelegateUpdated {
+                coldkey: coldkey0,
+                hotkey: hotkey0,
+                old_take: None,
+                new_take: min_take,
+            }
+            .into(),
+        );
+
+        step_block(1 + InitialTxDelegateTakeRateLimit::get() as u16);
+
+        assert_ok!(SubtensorModule::do_increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            u16::MAX / 8
+        ));
+        System::assert_has_event(
+            Event::TakeIncreased(coldkey0, hotkey0, min_take, u16::MAX / 8).into(),
+        );
+        System::assert_last_event(
+            Event::DelegateUpdated {
+                coldkey: coldkey0,
+                hotkey: hotkey0,
+                old_take: Some(min_take),
+                new_take: u16::MAX / 8,
+            }
+            .into(),
+        );
+    });
+}
+
+// Verify per-block metrics are only recorded with the metrics feature and pruned after the retention window
+#[test]
+fn test_block_metrics_stake_ops() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(533453);
+        let coldkey_account_id = U256::from(55453);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey_account_id, 10000);
+
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            10000
+        ));
+
+        let metrics = BlockMetrics::<Test>::get(1);
+        if cfg!(feature = "metrics") {
+            assert_eq!(metrics.registrations, 1);
+            assert_eq!(metrics.stake_ops, 1);
+        } else {
+            assert_eq!(metrics, BlockMetricsInfo::default());
+        }
+
+        step_block(MetricsRetentionBlocks::get() as u16);
+        assert_eq!(BlockMetrics::<Test>::get(1), BlockMetricsInfo::default());
+    });
+}
+
+// Verify locked stake weighs more in governance and cannot be removed until it unlocks
+#[test]
+fn test_lock_stake_conviction() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+
+        assert_noop!(
+            SubtensorModule::lock_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                600,
+                0
+            ),
+            Error::<Test>::InvalidConviction
+        );
+        assert_noop!(
+            SubtensorModule::lock_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                1_001,
+                1
+            ),
+            Error::<Test>::NotEnoughStakeToWithdraw
+        );
+
+        assert_ok!(SubtensorModule::lock_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            600,
+            2
+        ));
+        let unlock_block = 1 + 2 * ConvictionLockPeriod::get();
+        assert_eq!(
+            StakeLocks::<Test>::get(hotkey, coldkey),
+            Some((600, 2, unlock_block))
+        );
+        assert_eq!(
+            SubtensorModule::get_governance_stake_for_hotkey(&hotkey),
+            1_000 + 600 * 2
+        );
+
+        // A lock can only be extended.
+        assert_noop!(
+            SubtensorModule::lock_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                500,
+                2
+            ),
+            Error::<Test>::StakeLockCannotBeReduced
+        );
+
+        // Only the unlocked part of the stake can be removed.
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                401
+            ),
+            Error::<Test>::StakeIsLocked
+        );
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            400
+        ));
+
+        // block_step releases the lock at its unlock block.
+        run_to_block(unlock_block);
+        assert!(StakeLocks::<Test>::get(hotkey, coldkey).is_none());
+        assert_eq!(
+            SubtensorModule::get_governance_stake_for_hotkey(&hotkey),
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey)
+        );
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            600
+        ));
+    });
+}
+
+// Verify forced unstakes keep locked stake, and that locks follow the stake they cover
+#[test]
+fn test_stake_lock_forced_unstake_and_merge() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        let coldkey = U256::from(3);
+        let new_coldkey = U256::from(4);
+        SubtensorModule::create_account_if_non_existent(&owner, &hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        assert_ok!(SubtensorModule::lock_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            600,
+            2
+        ));
+
+        // Small nomination clearing skips locked nominations.
+        SubtensorModule::set_nominator_min_required_stake(5_000);
+        SubtensorModule::clear_small_nomination_if_required(&hotkey, &coldkey, 1_000);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            1_000
+        );
+
+        // Unstaking everyone from the hotkey leaves the locked part in place.
+        SubtensorModule::unstake_all_coldkeys_from_hotkey_account(&hotkey);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            600
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 400);
+
+        // A decrease below the lock shrinks it and the conviction bonus with it.
+        SubtensorModule::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 200);
+        assert_eq!(SubtensorModule::get_locked_stake(&hotkey, &coldkey), 400);
+        assert_eq!(
+            SubtensorModule::get_governance_stake_for_hotkey(&hotkey),
+            400 + 400 * 2
+        );
+
+        // Moving a lock onto an existing one merges them.
+        let unlock_block = 1 + 2 * ConvictionLockPeriod::get();
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&new_coldkey, &hotkey, 300);
+        assert_ok!(SubtensorModule::lock_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(new_coldkey),
+            hotkey,
+            300,
+            1
+        ));
+        SubtensorModule::move_stake_lock(&hotkey, &coldkey, &hotkey, &new_coldkey);
+        assert_eq!(
+            StakeLocks::<Test>::get(hotkey, new_coldkey),
+            Some((700, 1, unlock_block))
+        );
+        assert!(StakeLocks::<Test>::get(hotkey, coldkey).is_none());
+        assert_eq!(
+            SubtensorModule::get_governance_stake_for_hotkey(&hotkey),
+            700 + 700
+        );
+    });
+}
+
+// unstake_all_and_reap empties the staking account and credits the coldkey.
+#[test]
+fn test_unstake_all_and_reap() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+
+        assert_noop!(
+            SubtensorModule::unstake_all_and_reap(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey
+            ),
+            Error::<Test>::StakeToWithdrawIsZero
+        );
+
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        assert_ok!(SubtensorModule::lock_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            100,
+            1
+        ));
+        assert_noop!(
+            SubtensorModule::unstake_all_and_reap(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey
+            ),
+            Error::<Test>::StakeIsLocked
+        );
+
+        // Release the lock once its period has passed.
+        run_to_block(SubtensorModule::get_current_block_as_u64() + 11);
+        assert_eq!(SubtensorModule::get_locked_stake(&hotkey, &coldkey), 0);
+
+        assert_ok!(SubtensorModule::unstake_all_and_reap(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey
+        ));
+        System::assert_last_event(Event::StakeRemoved(coldkey, hotkey, 1_000, 0, 0).into());
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            0
+        );
+        assert!(!Stake::<Test>::contains_key(hotkey, coldkey));
+        // No stray stake delta is left for the emptied pair.
+        assert!(!StakeDeltaSinceLastEmissionDrain::<Test>::contains_key(
+            hotkey, coldkey
+        ));
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 1_000);
+    });
+}
+
+// get_nominators pages through a delegate's nominators sorted by stake.
+#[test]
+fn test_get_nominators_paginated() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        SubtensorModule::create_account_if_non_existent(&owner, &hotkey);
+        for (coldkey, stake) in [(10, 300), (11, 100), (12, 500), (13, 200)] {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &U256::from(coldkey),
+                &hotkey,
+                stake,
+            );
+        }
+
+        let page = |start: u32, limit: u32| -> Vec<(U256, u64)> {
+            SubtensorModule::get_nominators(hotkey.encode(), start, limit)
+                .into_iter()
+                .map(|(coldkey, stake)| (coldkey, stake.0))
+                .collect()
+        };
+        assert_eq!(
+            page(0, 2),
+            vec![(U256::from(12), 500), (U256::from(10), 300)]
+        );
+        assert_eq!(
+            page(2, 10),
+            vec![(U256::from(13), 200), (U256::from(11), 100)]
+        );
+        assert!(page(4, 10).is_empty());
+        assert!(page(1, 0).is_empty());
+        assert_eq!(
+            page(1, 2),
+            vec![(U256::from(10), 300), (U256::from(13), 200)]
+        );
+
+        // Equal stakes are ordered by coldkey, and pages are capped.
+        for coldkey in 20..(20 + MAX_NOMINATORS_PAGE as u64) {
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &U256::from(coldkey),
+                &hotkey,
+                50,
+            );
+        }
+        assert_eq!(page(4, 2), vec![(U256::from(20), 50), (U256::from(21), 50)]);
+        assert_eq!(page(0, u32::MAX).len(), MAX_NOMINATORS_PAGE as usize);
+
+        // Invalid account bytes return nothing.
+        assert!(SubtensorModule::get_nominators(vec![0; 3], 0, 10).is_empty());
+    });
+}
+
+// Stake checkpoints form a bounded ring buffer from which delegate returns are computed.
+#[test]
+fn test_stake_checkpoints_and_delegate_apr() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let interval: u64 = StakeCheckpointInterval::get();
+        add_network(1, 1, 0);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000_000);
+        Delegates::<Test>::insert(hotkey, 0);
+
+        // Nothing is recorded off the interval, and no return without two checkpoints.
+        SubtensorModule::record_stake_checkpoints(interval + 1, &mut Weight::zero());
+        assert!(StakeCheckpoints::<Test>::get(hotkey).is_empty());
+        assert_eq!(SubtensorModule::get_delegate_apr(hotkey.encode(), 1), 0);
+
+        // A pass over budget waits for a later block, recording the block it started at.
+        BlockStepWeightLimit::set(Weight::zero());
+        SubtensorModule::record_stake_checkpoints(interval, &mut Weight::zero());
+        assert!(StakeCheckpoints::<Test>::get(hotkey).is_empty());
+        BlockStepWeightLimit::set(Weight::MAX);
+        SubtensorModule::record_stake_checkpoints(interval + 1, &mut Weight::zero());
+        assert_eq!(
+            StakeCheckpoints::<Test>::get(hotkey).last(),
+            Some(&(interval, 1_000_000, 0, 0))
+        );
+        assert!(StakeCheckpointPass::<Test>::get().is_none());
+
+        for i in 1..=7 {
+            HotkeyEmissionByNetuid::<Test>::insert(1, hotkey, i * 100);
+            SubtensorModule::record_stake_checkpoints(i * interval, &mut Weight::zero());
+        }
+
+        // Only the newest MaxStakeCheckpoints are kept, oldest first, with the take.
+        let checkpoints = StakeCheckpoints::<Test>::get(hotkey);
+        assert_eq!(checkpoints.len() as u32, MaxStakeCheckpoints::get());
+        assert_eq!(
+            checkpoints.first(),
+            Some(&(3 * interval, 1_000_000, 300, 0))
+        );
+        assert_eq!(checkpoints.last(), Some(&(7 * interval, 1_000_000, 700, 0)));
+
+        // 100 emission per interval on 1_000_000 stake, over a year of blocks, per 1000.
+        let expected: u64 = 100 * BlocksPerYear::get() / interval / 1_000;
+        assert_eq!(
+            SubtensorModule::get_delegate_apr(hotkey.encode(), 1),
+            expected
+        );
+        assert_eq!(
+            SubtensorModule::get_delegate_apr(hotkey.encode(), 100),
+            expected
+        );
+
+        // The take recorded at the start of an interval applies to its emission.
+        Delegates::<Test>::insert(hotkey, u16::MAX);
+        HotkeyEmissionByNetuid::<Test>::insert(1, hotkey, 800);
+        SubtensorModule::record_stake_checkpoints(8 * interval, &mut Weight::zero());
+        assert_eq!(
+            SubtensorModule::get_delegate_apr(hotkey.encode(), 1),
+            expected
+        );
+        HotkeyEmissionByNetuid::<Test>::insert(1, hotkey, 900);
+        SubtensorModule::record_stake_checkpoints(9 * interval, &mut Weight::zero());
+        assert_eq!(SubtensorModule::get_delegate_apr(hotkey.encode(), 1), 0);
+    });
+}
+
+// Delegate statistics are served from a cache refreshed every DelegateStatsInterval blocks.
+#[test]
+fn test_delegate_stats_cache_refreshes_on_interval() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let nominator = U256::from(3);
+        let interval: u64 = DelegateStatsInterval::get();
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 500);
+        Delegates::<Test>::insert(hotkey, 0);
+
+        // Before the first refresh the statistics are computed on the fly.
+        assert!(DelegateStatsCache::<Test>::get(hotkey).is_none());
+        let stats = SubtensorModule::get_delegate_stats(hotkey.encode()).unwrap();
+        assert_eq!(stats.nominators, 2);
+        assert_eq!(stats.nominated_stake, 500);
+
+        // Nothing is cached off the interval.
+        SubtensorModule::refresh_delegate_stats(interval + 1, &mut Weight::zero());
+        assert!(DelegateStatsCache::<Test>::get(hotkey).is_none());
+
+        SubtensorModule::refresh_delegate_stats(interval, &mut Weight::zero());
+        assert_eq!(
+            DelegateStatsCache::<Test>::get(hotkey).map(|stats| stats.block),
+            Some(interval)
+        );
+
+        // Later stake changes show only after the next refresh.
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 500);
+        let stats = SubtensorModule::get_delegate_stats(hotkey.encode()).unwrap();
+        assert_eq!(stats.nominated_stake, 500);
+        SubtensorModule::refresh_delegate_stats(2 * interval, &mut Weight::zero());
+        let stats = SubtensorModule::get_delegate_stats(hotkey.encode()).unwrap();
+        assert_eq!(stats.nominated_stake, 1_000);
+
+        // Accounts that are no longer delegates are not served.
+        Delegates::<Test>::remove(hotkey);
+        assert!(SubtensorModule::get_delegate_stats(hotkey.encode()).is_none());
+    });
+}
+
+// A delegate statistics pass that does not fit in the block step budget continues in the
+// following blocks.
+#[test]
+fn test_delegate_stats_pass_spans_blocks_over_budget() {
+    new_test_ext(1).execute_with(|| {
+        let interval: u64 = DelegateStatsInterval::get();
+        let hotkeys: Vec<U256> = (1..=3).map(U256::from).collect();
+        for hotkey in &hotkeys {
+            SubtensorModule::create_account_if_non_existent(&U256::from(10), hotkey);
+            Delegates::<Test>::insert(hotkey, 0);
+        }
+
+        // Room for a single delegate per block.
+        BlockStepWeightLimit::set(SubtensorModule::delegate_stats_page_weight());
+        SubtensorModule::refresh_delegate_stats(interval, &mut Weight::zero());
+        let cached = || {
+            hotkeys
+                .iter()
+                .filter(|hotkey| DelegateStatsCache::<Test>::contains_key(hotkey))
+                .count()
+        };
+        assert_eq!(cached(), 1);
+        assert!(DelegateStatsPass::<Test>::get().is_some());
+
+        SubtensorModule::refresh_delegate_stats(interval + 1, &mut Weight::zero());
+        SubtensorModule::refresh_delegate_stats(interval + 2, &mut Weight::zero());
+        assert_eq!(cached(), 3);
+        assert!(DelegateStatsPass::<Test>::get().is_none());
+        assert!(hotkeys.iter().all(|hotkey| {
+            DelegateStatsCache::<Test>::get(hotkey).map(|stats| stats.block) == Some(interval)
+        }));
+    });
+}
+
+// The nominations of a delegate too large for one block are read over several blocks.
+#[test]
+fn test_delegate_stats_resume_nominations_over_budget() {
+    new_test_ext(1).execute_with(|| {
+        let interval: u64 = DelegateStatsInterval::get();
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        let nominations: u32 = pallet_subtensor::utils::storage_pages::STORAGE_PAGE_SIZE + 10;
+        SubtensorModule::create_account_if_non_existent(&owner, &hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1);
+        for i in 0..nominations {
+            let coldkey = U256::from(1_000 + i);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1);
+        }
+        Delegates::<Test>::insert(hotkey, 0);
+
+        // Room for a single page of nominations per block.
+        BlockStepWeightLimit::set(SubtensorModule::delegate_stats_page_weight());
+        SubtensorModule::refresh_delegate_stats(interval, &mut Weight::zero());
+        assert!(DelegateStatsCache::<Test>::get(hotkey).is_none());
+        assert!(DelegateStatsNominations::<Test>::get().is_some());
+
+        SubtensorModule::refresh_delegate_stats(interval + 1, &mut Weight::zero());
+        assert!(DelegateStatsNominations::<Test>::get().is_none());
+        assert!(DelegateStatsPass::<Test>::get().is_none());
+        let stats = DelegateStatsCache::<Test>::get(hotkey).unwrap();
+        assert_eq!(stats.block, interval);
+        assert_eq!(stats.nominators, nominations + 1);
+        assert_eq!(stats.nominated_stake, nominations as u64);
+    });
+}
+
+#[test]
+fn test_delegate_becomes_inactive_after_grace_period() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let delegate = U256::from(1);
+        let owner = U256::from(2);
+        let nominator = U256::from(3);
+        add_network(netuid, 0, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 1);
+        register_ok_neuron(netuid, delegate, owner, 0);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            delegate,
+            SubtensorModule::get_min_delegate_take()
+        ));
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, 10_000);
+
+        // Pruning the delegate from its only subnet starts the grace period.
+        register_ok_neuron(netuid, U256::from(4), U256::from(5), 0);
+        assert!(!SubtensorModule::is_hotkey_registered_on_any_network(
+            &delegate
+        ));
+        assert!(DelegateDeregisteredAt::<Test>::contains_key(delegate));
+        assert_ok!(SubtensorModule::ensure_delegate_active(&delegate));
+        assert!(!SubtensorModule::is_delegate_inactive(&delegate));
+
+        let expiry: u64 = System::block_number() + DelegateInactivityGracePeriod::get();
+        assert_eq!(
+            DelegateInactivityExpirations::<Test>::get(expiry),
+            vec![delegate]
+        );
+
+        run_to_block(expiry);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(Event::DelegateInactive {
+            hotkey: delegate,
+        }));
+        assert!(SubtensorModule::is_delegate_inactive(&delegate));
+        assert!(DelegateInactivityExpirations::<Test>::get(expiry).is_empty());
+
+        // The inactive delegate can no longer change take, take nominations or be listed.
+        assert_noop!(
+            SubtensorModule::decrease_take(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                delegate,
+                0
+            ),
+            Error::<Test>::DelegateInactive
+        );
+        assert_noop!(
+            SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                delegate,
+                1_000
+            ),
+            Error::<Test>::DelegateInactive
+        );
+        assert!(SubtensorModule::get_delegates().is_empty());
+    });
+}
+
+#[test]
+fn test_removing_network_starts_delegate_grace_period() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let delegate = U256::from(1);
+        let owner = U256::from(2);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, delegate, owner, 0);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            delegate,
+            SubtensorModule::get_min_delegate_take()
+        ));
+
+        SubtensorModule::remove_network(netuid);
+
+        assert!(!SubtensorModule::is_hotkey_registered_on_any_network(
+            &delegate
+        ));
+        assert_eq!(
+            DelegateDeregisteredAt::<Test>::get(delegate),
+            Some(System::block_number())
+        );
+        assert_ok!(SubtensorModule::ensure_delegate_active(&delegate));
+    });
+}
+
+#[test]
+fn test_stake_distribution() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        assert!(SubtensorModule::get_stake_distribution(netuid).is_none());
+        add_network(netuid, 13, 0);
+        for i in 0..4 {
+            register_ok_neuron(netuid, U256::from(i), U256::from(i + 100), i * 10_000);
+        }
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &U256::from(103),
+            &U256::from(3),
+            100,
+        );
+
+        // One of four neurons holds all the stake.
+        let distribution = SubtensorModule::get_stake_distribution(netuid).unwrap();
+        assert_eq!(distribution.holders.0, 4);
+        assert_eq!(distribution.total_stake.0, 100);
+        assert_eq!(distribution.gini.0, 49_151);
+        assert_eq!(distribution.top_shares.first().unwrap().1 .0, u16::MAX);
+        assert_eq!(distribution.median_stake.0, 0);
+
+        // An even spread has no inequality.
+        let distribution = SubtensorModule::compute_stake_distribution(vec![5, 5, 5, 5]);
+        assert_eq!(distribution.gini.0, 0);
+        assert_eq!(distribution.top_shares.get(2).unwrap().1 .0, u16::MAX / 4);
+        assert_eq!(distribution.median_stake.0, 5);
+    });
+}
+
+#[test]
+fn test_record_delegate_yield() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let other_hotkey = U256::from(3);
+        add_network(netuid, 13, 0);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 2_000_000_000);
+        Delegates::<Test>::insert(hotkey, 6_553);
+
+        // Half a TAO per staked TAO, less a take of about 10% for nominators.
+        SubtensorModule::record_delegate_yield(netuid, &hotkey, 1_000_000_000);
+        let delegate_yield = DelegateYields::<Test>::get(netuid, hotkey).unwrap();
+        assert_eq!(delegate_yield.stake, 2_000_000_000);
+        assert_eq!(delegate_yield.emission_per_tao, 500_000_000);
+        assert_eq!(delegate_yield.nominator_emission_per_tao, 450_003_814);
+
+        // Hotkeys that are not delegates have no yield recorded.
+        SubtensorModule::record_delegate_yield(netuid, &other_hotkey, 1_000_000_000);
+        assert!(SubtensorModule::get_delegate_yield(other_hotkey.encode(), netuid).is_none());
+    });
+}
+
+#[test]
+fn test_preflight_call() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+
+        // A failing call reports its error variant.
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::remove_stake {
+            hotkey,
+            amount_unstaked: 1_000,
+        });
+        let result =
+            SubtensorModule::preflight_call(call.encode(), coldkey.encode()).expect("decodes");
+        assert_eq!(
+            result.error,
+            Some(Error::<Test>::NotEnoughStakeToWithdraw.into())
+        );
+        assert_eq!(result.error_name, b"NotEnoughStakeToWithdraw".to_vec());
+
+        // A succeeding call leaves the state untouched.
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::add_stake {
+            hotkey,
+            amount_staked: 1_000,
+        });
+        let result =
+            SubtensorModule::preflight_call(call.encode(), coldkey.encode()).expect("decodes");
+        assert_eq!(result.error, None);
+        assert!(result.error_name.is_empty());
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 10_000);
+        assert_eq!(result.invalid, None);
+
+        // A call the signed extension rejects reports why and is not dispatched.
+        SubtensorModule::set_target_registrations_per_interval(netuid, 0);
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::burned_register {
+            netuid,
+            hotkey: U256::from(3),
+        });
+        let result =
+            SubtensorModule::preflight_call(call.encode(), coldkey.encode()).expect("decodes");
+        assert_eq!(result.invalid, Some(InvalidTransaction::Custom(5).into()));
+        assert_eq!(result.error, None);
+
+        // Inputs that do not decode are rejected.
+        assert_eq!(
+            SubtensorModule::preflight_call(vec![0xff], coldkey.encode()),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_rebalance_stake_portfolio() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey1 = U256::from(2);
+        let hotkey2 = U256::from(3);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey1, coldkey, 0);
+        register_ok_neuron(netuid, hotkey2, coldkey, 100_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey1, 3_000);
+
+        assert_noop!(
+            SubtensorModule::rebalance_portfolio(<<Test as Config>::RuntimeOrigin>::signed(
+                coldkey
+            )),
+            Error::<Test>::NoStakePortfolio
+        );
+        assert_noop!(
+            SubtensorModule::set_stake_portfolio(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                vec![(hotkey1, 1), (hotkey1, 2)]
+            ),
+            Error::<Test>::InvalidStakePortfolio
+        );
+        assert_noop!(
+            SubtensorModule::set_stake_portfolio(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                (0..=MaxPortfolioHotkeys::get() as u64)
+                    .map(|i| (U256::from(i), 1))
+                    .collect()
+            ),
+            Error::<Test>::TooManyPortfolioHotkeys
+        );
+
+        assert_ok!(SubtensorModule::set_stake_portfolio(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            vec![(hotkey1, 1), (hotkey2, 2)]
+        ));
+        assert_ok!(SubtensorModule::rebalance_portfolio(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey)
+        ));
+        System::assert_last_event(
+            Event::StakePortfolioRebalanced {
+                coldkey,
+                moved: 2_000,
+            }
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey1),
+            1_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey2),
+            2_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&coldkey),
+            3_000
+        );
+        System::assert_has_event(Event::StakeRemoved(coldkey, hotkey1, 2_000, 1_000, 1_000).into());
+        System::assert_has_event(Event::StakeAdded(coldkey, hotkey2, 2_000, 2_000, 3_000).into());
+    });
+}
+
+#[test]
+fn test_rebalance_stake_portfolio_checks_every_move() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey1 = U256::from(2);
+        let hotkey2 = U256::from(3);
+        let other_coldkey = U256::from(4);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey1, coldkey, 0);
+        register_ok_neuron(netuid, hotkey2, other_coldkey, 100_000);
+        Delegates::<Test>::insert(hotkey2, SubtensorModule::get_default_delegate_take());
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey1, 3_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey2, 100);
+        SubtensorModule::set_nominator_min_required_stake(500);
+
+        // A nomination left below the nominator minimum is moved in full.
+        assert_ok!(SubtensorModule::set_stake_portfolio(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            vec![(hotkey1, 100), (hotkey2, 1)]
+        ));
+        assert_ok!(SubtensorModule::rebalance_portfolio(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey)
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey2),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey1),
+            3_100
+        );
+
+        // Every move counts toward the rate limit of its pair.
+        SubtensorModule::set_target_stakes_per_interval(1);
+        assert_ok!(SubtensorModule::set_stake_portfolio(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            vec![(hotkey1, 1), (hotkey2, 1)]
+        ));
+        assert_noop!(
+            SubtensorModule::rebalance_portfolio(<<Test as Config>::RuntimeOrigin>::signed(
+                coldkey
+            )),
+            Error::<Test>::UnstakeRateLimitExceeded
+        );
+    });
+}
+
+#[test]
+fn test_get_coldkey_report() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let other_hotkey = U256::from(3);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        register_ok_neuron(netuid, other_hotkey, U256::from(4), 100_000);
+        SubnetOwner::<Test>::insert(netuid, coldkey);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &other_hotkey, 500);
+        StakeLocks::<Test>::insert(other_hotkey, coldkey, (200, 1, 5_000));
+        PendingTakeChanges::<Test>::insert(hotkey, (1_000, 100));
+
+        let report = SubtensorModule::get_coldkey_report(coldkey.encode()).expect("decodes");
+        assert_eq!(report.coldkey, coldkey);
+        assert_eq!(report.balance, Compact(10_000));
+        assert_eq!(report.total_stake, Compact(1_500));
+        assert_eq!(
+            report.stakes,
+            vec![(hotkey, Compact(1_000)), (other_hotkey, Compact(500))]
+        );
+        assert_eq!(report.owned_hotkeys, vec![hotkey]);
+        assert_eq!(report.owned_subnets, vec![Compact(netuid)]);
+        assert_eq!(
+            report.stake_locks,
+            vec![(other_hotkey, Compact(200), Compact(5_000))]
+        );
+        assert_eq!(
+            report.pending_take_changes,
+            vec![(hotkey, Compact(1_000), Compact(100))]
+        );
+        assert!(!report.coldkey_swap_scheduled);
+        assert_eq!(report.identity, None);
+
+        assert!(SubtensorModule::get_coldkey_report(vec![0xff]).is_none());
+    });
+}
+
+#[test]
+fn test_stake_prefix_pages() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        for i in 0..5u64 {
+            Stake::<Test>::insert(hotkey, U256::from(10 + i), 100 + i);
+        }
+        Stake::<Test>::insert(U256::from(2), U256::from(10), 1);
+
+        let (first, cursor) = SubtensorModule::stake_prefix_page(&hotkey, None, 2);
+        assert_eq!(first.len(), 2);
+        let (second, cursor) = SubtensorModule::stake_prefix_page(&hotkey, cursor, 2);
+        assert_eq!(second.len(), 2);
+        let (third, cursor) = SubtensorModule::stake_prefix_page(&hotkey, cursor, 2);
+        assert_eq!(third.len(), 1);
+        assert!(cursor.is_none());
+
+        // The pages cover the prefix exactly once, and nothing of the other hotkey.
+        let paged: Vec<(U256, u64)> = first.into_iter().chain(second).chain(third).collect();
+        let all: Vec<(U256, u64)> = Stake::<Test>::iter_prefix(hotkey).collect();
+        assert_eq!(paged, all);
+    });
+}
+
+#[test]
+fn test_clear_small_nominations_continues_from_cursor() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        let nominations: u32 = pallet_subtensor::utils::storage_pages::STORAGE_PAGE_SIZE + 44;
+        SubtensorModule::create_account_if_non_existent(&owner, &hotkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &hotkey, 1);
+        for i in 0..nominations {
+            let coldkey = U256::from(1_000 + i);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1);
+        }
+        SubtensorModule::set_nominator_min_required_stake(10);
+
+        // A single page is cleared straight away and the rest is left to on_idle.
+        SubtensorModule::clear_small_nominations();
+        assert!(SmallNominationsCursor::<Test>::get().is_some());
+        assert!(Stake::<Test>::iter_prefix(hotkey).count() > 1);
+
+        SubtensorModule::continue_clearing_small_nominations(frame_support::weights::Weight::MAX);
+        assert!(SmallNominationsCursor::<Test>::get().is_none());
+        let remaining: Vec<(U256, u64)> = Stake::<Test>::iter_prefix(hotkey).collect();
+        assert_eq!(remaining, vec![(owner, 1)]);
+    });
+}
+
+#[test]
+fn test_unstake_all_coldkeys_continues_from_cursor() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        let nominations: u32 = pallet_subtensor::utils::storage_pages::STORAGE_PAGE_SIZE + 44;
+        SubtensorModule::create_account_if_non_existent(&owner, &hotkey);
+        for i in 0..nominations {
+            let coldkey = U256::from(1_000 + i);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 10);
+        }
+
+        // A single page is unstaked straight away and the rest is left to on_idle.
+        SubtensorModule::unstake_all_coldkeys_from_hotkey_account(&hotkey);
+        assert!(HotkeyUnstakeCursor::<Test>::get().is_some());
+        assert_eq!(HotkeyUnstakeQueue::<Test>::get(), vec![hotkey]);
+        let staked = || {
+            Stake::<Test>::iter_prefix(hotkey)
+                .filter(|(_, stake)| *stake > 0)
+                .count()
+        };
+        assert_eq!(staked(), 44);
+
+        // The hotkey cannot be swapped away from the coldkeys still to be unstaked.
+        assert_err!(
+            SubtensorModule::do_swap_hotkey(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                &hotkey,
+                &U256::from(3)
+            ),
+            Error::<Test>::HotkeyUnstakeInProgress
+        );
+
+        SubtensorModule::continue_unstaking_hotkeys(Weight::MAX);
+        assert!(HotkeyUnstakeCursor::<Test>::get().is_none());
+        assert!(!HotkeyUnstakeQueue::<Test>::exists());
+        assert_eq!(staked(), 0);
+        assert_eq!(SubtensorModule::get_total_stake_for_hotkey(&hotkey), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&U256::from(1_000)), 10);
+    });
+}
+
+#[test]
+fn test_stake_keeps_account_alive() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(3), 124124);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        let providers = System::providers(&coldkey);
+
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            10_000
+        ));
+        // The stake provides for the account, which survives without any free balance.
+        assert_eq!(System::providers(&coldkey), providers);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 0);
+        assert!(System::account_exists(&coldkey));
+        assert!(StakeProviders::<Test>::get(coldkey));
+
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            hotkey,
+            10_000
+        ));
+        assert!(!StakeProviders::<Test>::get(coldkey));
+        assert_eq!(System::providers(&coldkey), providers);
+    });
+}
+
+#[test]
+fn test_public_good_delegate_zero_take() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let min_take = SubtensorModule::get_min_delegate_take();
+
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey0, 100000);
+        let netuid = 1;
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+        assert_ok!(SubtensorModule::do_become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            min_take
+        ));
+
+        // The min take floor holds until the hotkey opts in.
+        assert_err!(
+            SubtensorModule::do_decrease_take(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+                hotkey0,
+                0
+            ),
+            Error::<Test>::DelegateTakeTooLow
+        );
+        assert_err!(
+            SubtensorModule::set_public_good_delegate(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+                hotkey0,
+                false
+            ),
+            Error::<Test>::NotPublicGoodDelegate
+        );
+        assert_ok!(SubtensorModule::set_public_good_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            true
+        ));
+        assert_ok!(SubtensorModule::do_decrease_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            0
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), 0);
+
+        // The take stays locked while opted in, and for the release delay after opting out.
+        step_block(1 + InitialTxDelegateTakeRateLimit::get() as u16);
+        assert_err!(
+            SubtensorModule::do_increase_take(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+                hotkey0,
+                min_take
+            ),
+            Error::<Test>::PublicGoodTakeLocked
+        );
+        assert_ok!(SubtensorModule::set_public_good_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            false
+        ));
+        let release_block =
+            SubtensorModule::get_current_block_as_u64() + PublicGoodReleaseDelay::get();
+        assert_eq!(
+            PublicGoodDelegates::<Test>::get(hotkey0),
+            Some(release_block)
+        );
+        assert_err!(
+            SubtensorModule::do_increase_take(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+                hotkey0,
+                min_take
+            ),
+            Error::<Test>::PublicGoodTakeLocked
+        );
+
+        run_to_block(release_block);
+        assert_ok!(SubtensorModule::do_increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey0),
+            hotkey0,
+            min_take
+        ));
+        assert_eq!(PublicGoodDelegates::<Test>::get(hotkey0), None);
     });
 }
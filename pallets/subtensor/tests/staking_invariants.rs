@@ -0,0 +1,332 @@
+#![allow(
+    clippy::indexing_slicing,
+    clippy::unwrap_used,
+    clippy::arithmetic_side_effects
+)]
+mod mock;
+use frame_support::storage::with_storage_layer;
+use frame_support::traits::{fungible::Inspect, Get};
+use frame_support::weights::Weight;
+use frame_system::Config;
+use mock::*;
+use pallet_subtensor::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use sp_core::U256;
+use std::collections::BTreeMap;
+
+/// Random sequences run against the mock runtime, each from its own seed so a failure can be
+/// replayed.
+const SEEDS: u64 = 8;
+/// Operations per sequence.
+const STEPS: u32 = 300;
+
+const NETUID: u16 = 1;
+
+/// The keys a sequence operates on. Swapped keys are replaced by their new key.
+struct Actors {
+    coldkeys: Vec<U256>,
+    /// (hotkey, owning coldkey)
+    hotkeys: Vec<(U256, U256)>,
+    next_key: u64,
+    /// Take increases waiting in the scheduler.
+    scheduled_takes: Vec<ScheduledTake>,
+    /// Scheduled take increases that were applied.
+    applied_takes: u32,
+}
+
+/// A take increase accepted by increase_take.
+struct ScheduledTake {
+    coldkey: U256,
+    hotkey: U256,
+    take: u16,
+    id: u64,
+    execution_block: u64,
+}
+
+impl Actors {
+    fn fresh_key(&mut self) -> U256 {
+        self.next_key += 1;
+        U256::from(self.next_key)
+    }
+}
+
+fn setup() -> Actors {
+    add_network(NETUID, 1, 0);
+    SubtensorModule::set_max_registrations_per_block(NETUID, 100);
+    SubtensorModule::set_target_registrations_per_interval(NETUID, 100);
+    SubtensorModule::set_emission_values(&[NETUID], vec![1_000_000]).unwrap();
+    SubtensorModule::set_hotkey_emission_tempo(1);
+    SubtensorModule::set_target_stakes_per_interval(u64::MAX);
+    SubtensorModule::set_tx_rate_limit(0);
+    SubtensorModule::set_tx_delegate_take_rate_limit(0);
+    SubtensorModule::set_nominator_min_required_stake(1_000);
+
+    let coldkeys: Vec<U256> = (10..16).map(U256::from).collect();
+    for coldkey in coldkeys.iter() {
+        SubtensorModule::add_balance_to_coldkey_account(coldkey, 1_000_000);
+    }
+    let hotkeys: Vec<(U256, U256)> = (0..3)
+        .map(|i| (U256::from(i), coldkeys[i as usize]))
+        .collect();
+    for (i, (hotkey, coldkey)) in hotkeys.iter().enumerate() {
+        register_ok_neuron(NETUID, *hotkey, *coldkey, 100_000 * i as u64);
+    }
+
+    Actors {
+        coldkeys,
+        hotkeys,
+        next_key: 1_000,
+        scheduled_takes: Vec::new(),
+        applied_takes: 0,
+    }
+}
+
+/// Free balance plus stake, which every operation but emission must conserve.
+fn free_plus_stake() -> u128 {
+    <Balances as Inspect<U256>>::total_issuance() as u128 + TotalStake::<Test>::get() as u128
+}
+
+/// Runs one random operation. Returns whether it may create tokens.
+fn random_step(rng: &mut StdRng, actors: &mut Actors) -> bool {
+    let coldkey: U256 = actors.coldkeys[rng.gen_range(0..actors.coldkeys.len())];
+    let (hotkey, owner) = actors.hotkeys[rng.gen_range(0..actors.hotkeys.len())];
+    let origin = <<Test as Config>::RuntimeOrigin>::signed;
+
+    // The outcome of every call is ignored: rejected calls must leave the state intact too.
+    match rng.gen_range(0..8) {
+        0 | 1 => {
+            let balance: u64 = SubtensorModule::get_coldkey_balance(&coldkey);
+            let amount: u64 = rng.gen_range(1..=balance + 10);
+            let _ =
+                with_storage_layer(|| SubtensorModule::add_stake(origin(coldkey), hotkey, amount));
+        }
+        2 | 3 => {
+            let stake: u64 = SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey);
+            let amount: u64 = rng.gen_range(1..=stake + 10);
+            let _ = with_storage_layer(|| {
+                SubtensorModule::remove_stake(origin(coldkey), hotkey, amount)
+            });
+        }
+        4 => {
+            let take: u16 = rng.gen_range(0..=u16::MAX / 4);
+            if rng.gen_bool(0.5) {
+                let id: u64 = NextScheduledOperationId::<Test>::get();
+                if with_storage_layer(|| {
+                    SubtensorModule::increase_take(origin(owner), hotkey, take)
+                })
+                .is_ok()
+                {
+                    actors.scheduled_takes.push(ScheduledTake {
+                        coldkey: owner,
+                        hotkey,
+                        take,
+                        id,
+                        execution_block: System::block_number()
+                            + <Test as pallet_subtensor::Config>::TakeIncreaseScheduleDuration::get(
+                            ),
+                    });
+                }
+            } else {
+                let _ = with_storage_layer(|| {
+                    SubtensorModule::decrease_take(origin(owner), hotkey, take)
+                });
+            }
+        }
+        5 => {
+            let new_coldkey: U256 = actors.fresh_key();
+            let mut weight = Weight::zero();
+            if with_storage_layer(|| {
+                SubtensorModule::perform_swap_coldkey(&coldkey, &new_coldkey, &mut weight)
+            })
+            .is_ok()
+            {
+                for key in actors.coldkeys.iter_mut().filter(|key| **key == coldkey) {
+                    *key = new_coldkey;
+                }
+                for (_, owner) in actors.hotkeys.iter_mut().filter(|(_, o)| *o == coldkey) {
+                    *owner = new_coldkey;
+                }
+            }
+        }
+        6 => {
+            let new_hotkey: U256 = actors.fresh_key();
+            let mut weight = Weight::zero();
+            if with_storage_layer(|| {
+                SubtensorModule::perform_hotkey_swap(&hotkey, &new_hotkey, &owner, &mut weight)
+            })
+            .is_ok()
+            {
+                for (key, _) in actors.hotkeys.iter_mut().filter(|(h, _)| *h == hotkey) {
+                    *key = new_hotkey;
+                }
+            }
+        }
+        _ => {
+            // Enough blocks over a sequence for scheduled take increases to come due.
+            step_block(rng.gen_range(1..=8));
+            return true;
+        }
+    }
+    false
+}
+
+/// Checks the stake maps agree with each other and with the ownership and take bookkeeping.
+fn assert_staking_invariants(context: &str) {
+    let mut total: u128 = 0;
+    let mut hotkey_totals: BTreeMap<U256, u128> = BTreeMap::new();
+    let mut coldkey_totals: BTreeMap<U256, u128> = BTreeMap::new();
+    for (hotkey, coldkey, stake) in Stake::<Test>::iter() {
+        total += stake as u128;
+        *hotkey_totals.entry(hotkey).or_default() += stake as u128;
+        *coldkey_totals.entry(coldkey).or_default() += stake as u128;
+        if stake > 0 {
+            assert!(
+                StakingHotkeys::<Test>::get(coldkey).contains(&hotkey),
+                "{context}: {hotkey:?} missing from the staking hotkeys of {coldkey:?}"
+            );
+        }
+    }
+    assert_eq!(
+        total,
+        TotalStake::<Test>::get() as u128,
+        "{context}: TotalStake"
+    );
+
+    for (hotkey, stake) in TotalHotkeyStake::<Test>::iter() {
+        let expected: u128 = hotkey_totals.remove(&hotkey).unwrap_or(0);
+        assert_eq!(
+            stake as u128, expected,
+            "{context}: TotalHotkeyStake of {hotkey:?}"
+        );
+    }
+    assert!(
+        hotkey_totals.values().all(|stake| *stake == 0),
+        "{context}: stake on hotkeys without a total: {hotkey_totals:?}"
+    );
+    for (coldkey, stake) in TotalColdkeyStake::<Test>::iter() {
+        let expected: u128 = coldkey_totals.remove(&coldkey).unwrap_or(0);
+        assert_eq!(
+            stake as u128, expected,
+            "{context}: TotalColdkeyStake of {coldkey:?}"
+        );
+        assert_eq!(
+            StakeProviders::<Test>::get(coldkey),
+            stake > 0,
+            "{context}: stake provider of {coldkey:?}"
+        );
+    }
+    assert!(
+        coldkey_totals.values().all(|stake| *stake == 0),
+        "{context}: stake of coldkeys without a total: {coldkey_totals:?}"
+    );
+
+    for (coldkey, hotkeys) in OwnedHotkeys::<Test>::iter() {
+        for hotkey in hotkeys {
+            assert_eq!(
+                Owner::<Test>::get(hotkey),
+                coldkey,
+                "{context}: owner of {hotkey:?}"
+            );
+        }
+    }
+
+    let max_take: u16 = SubtensorModule::get_max_delegate_take();
+    for (hotkey, take) in Delegates::<Test>::iter() {
+        assert!(
+            take <= max_take,
+            "{context}: take of {hotkey:?} above the max"
+        );
+    }
+
+    for (hotkey, coldkey, _) in StakeDeltaSinceLastEmissionDrain::<Test>::iter() {
+        assert!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey) > 0,
+            "{context}: stake delta of {coldkey:?} on {hotkey:?} without a stake"
+        );
+    }
+}
+
+/// Checks every scheduled take increase that came due either ran or reported its failure, and
+/// counts the ones that were applied.
+fn assert_scheduled_takes(actors: &mut Actors, context: &str) {
+    let now: u64 = System::block_number();
+    let events: Vec<Event<Test>> = System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::SubtensorModule(event) => Some(event),
+            _ => None,
+        })
+        .collect();
+    let mut applied: u32 = 0;
+    actors.scheduled_takes.retain(|scheduled| {
+        if scheduled.execution_block > now {
+            return true;
+        }
+        assert!(
+            !ScheduledOperations::<Test>::contains_key(scheduled.coldkey, scheduled.id),
+            "{context}: take increase {} still scheduled",
+            scheduled.id
+        );
+        let failed: bool = events.iter().any(|event| {
+            matches!(event, Event::ScheduledOperationFailed { coldkey, id, .. }
+                if *coldkey == scheduled.coldkey && *id == scheduled.id)
+        });
+        if !failed {
+            let increased: bool = events.iter().any(|event| {
+                matches!(event, Event::TakeIncreased(coldkey, hotkey, _, take)
+                    if *coldkey == scheduled.coldkey
+                        && *hotkey == scheduled.hotkey
+                        && *take == scheduled.take)
+            });
+            assert!(
+                increased,
+                "{context}: take increase {} neither applied nor failed",
+                scheduled.id
+            );
+            applied += 1;
+        }
+        false
+    });
+    actors.applied_takes += applied;
+}
+
+#[test]
+fn test_staking_invariants_hold_over_random_sequences() {
+    let mut applied_takes: u32 = 0;
+    for seed in 0..SEEDS {
+        new_test_ext(1).execute_with(|| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut actors = setup();
+            assert_staking_invariants(&format!("seed {seed}, setup"));
+
+            for step in 0..STEPS {
+                let before: u128 = free_plus_stake();
+                let mints: bool = random_step(&mut rng, &mut actors);
+                let after: u128 = free_plus_stake();
+
+                let context = format!("seed {seed}, step {step}");
+                if mints {
+                    assert!(after >= before, "{context}: emission burned tokens");
+                } else {
+                    assert_eq!(after, before, "{context}: tokens not conserved");
+                }
+                assert_staking_invariants(&context);
+                assert_scheduled_takes(&mut actors, &context);
+            }
+
+            // Let the increases still waiting in the scheduler come due.
+            let delay: u64 =
+                <Test as pallet_subtensor::Config>::TakeIncreaseScheduleDuration::get();
+            step_block(delay as u16);
+            let context = format!("seed {seed}, end");
+            assert_staking_invariants(&context);
+            assert_scheduled_takes(&mut actors, &context);
+            assert!(
+                actors.scheduled_takes.is_empty(),
+                "{context}: take increases pending"
+            );
+            applied_takes += actors.applied_takes;
+        });
+    }
+    assert!(applied_takes > 0, "no scheduled take increase was applied");
+}
@@ -88,6 +88,8 @@ fn test_swap_stake() {
 
         assert!(!Stake::<Test>::contains_key(hotkey, old_coldkey));
         assert_eq!(Stake::<Test>::get(hotkey, new_coldkey), stake);
+        System::assert_has_event(Event::StakeRemoved(old_coldkey, hotkey, stake, 0, 0).into());
+        System::assert_has_event(Event::StakeAdded(new_coldkey, hotkey, stake, 0, 0).into());
     });
 }
 
@@ -1461,6 +1463,71 @@ fn test_schedule_swap_coldkey_execution() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_cancel_scheduled_coldkey_swap --exact --nocapture
+#[test]
+fn test_cancel_scheduled_coldkey_swap() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let netuid = 1u16;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, old_coldkey, 0);
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey
+        ));
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        let operations = SubtensorModule::get_scheduled_operations(old_coldkey.encode())
+            .expect("coldkey decodes");
+        assert_eq!(operations.len(), 1);
+        assert_eq!(
+            operations[0].operation,
+            ScheduledOperation::ColdkeySwap { new_coldkey }
+        );
+        assert_eq!(operations[0].execution_block, execution_block.into());
+        let id: u64 = operations[0].id.into();
+
+        // Only the coldkey that scheduled the operation can cancel it.
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_operation(
+                <<Test as Config>::RuntimeOrigin>::signed(new_coldkey),
+                id
+            ),
+            Error::<Test>::ScheduledOperationNotFound
+        );
+        assert_ok!(SubtensorModule::cancel_scheduled_operation(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            id
+        ));
+        System::assert_last_event(
+            Event::ScheduledOperationCancelled {
+                coldkey: old_coldkey,
+                id,
+            }
+            .into(),
+        );
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert!(SubtensorModule::get_scheduled_operations_for_coldkey(&old_coldkey).is_empty());
+
+        // The cancelled swap never runs.
+        run_to_block(execution_block + 1);
+        assert_eq!(Owner::<Test>::get(hotkey), old_coldkey);
+
+        // A running operation cannot be dispatched other than by the scheduler.
+        assert_noop!(
+            SubtensorModule::execute_scheduled_operation(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                old_coldkey,
+                id
+            ),
+            BadOrigin
+        );
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --test swap_coldkey -- test_direct_swap_coldkey_call_fails --exact --nocapture
 #[test]
 fn test_direct_swap_coldkey_call_fails() {
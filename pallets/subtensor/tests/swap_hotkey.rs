@@ -320,7 +320,7 @@ fn test_swap_weight_commits() {
         let new_hotkey = U256::from(2);
         let coldkey = U256::from(3);
         let netuid = 0u16;
-        let weight_commits = (H256::from_low_u64_be(100), 200);
+        let weight_commits = (H256::from_low_u64_be(100), 200, Some(40));
         let mut weight = Weight::zero();
 
         add_network(netuid, 0, 1);
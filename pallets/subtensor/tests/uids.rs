@@ -1,8 +1,12 @@
 #![allow(clippy::unwrap_used)]
 
 use crate::mock::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, weights::Weight};
 use frame_system::Config;
+use pallet_subtensor::{
+    DeferredHotkeyDrains, Emission, Event, HotkeyUnstakeQueue, MetagraphPrunedUids,
+    MetagraphRegisteredUids, PendingEmission, PendingdHotkeyEmission, ValidatorPermit,
+};
 use sp_core::U256;
 
 mod mock;
@@ -371,3 +375,100 @@ fn test_replace_neuron_multiple_subnets_unstake_all() {
         );
     });
 }
+
+#[test]
+fn test_metagraph_digest() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        add_network(netuid, tempo, 0);
+
+        register_ok_neuron(netuid, U256::from(1), U256::from(101), 0);
+        register_ok_neuron(netuid, U256::from(2), U256::from(102), 100_000);
+        assert_eq!(MetagraphRegisteredUids::<Test>::get(netuid), vec![0, 1]);
+        assert!(MetagraphPrunedUids::<Test>::get(netuid).is_empty());
+
+        // Replacing uid 0 marks it as pruned without duplicating the registration.
+        SubtensorModule::replace_neuron(netuid, 0, &U256::from(3), 0);
+        assert_eq!(MetagraphRegisteredUids::<Test>::get(netuid), vec![0, 1]);
+        assert_eq!(MetagraphPrunedUids::<Test>::get(netuid), vec![0]);
+
+        ValidatorPermit::<Test>::insert(netuid, vec![true, false]);
+        SubtensorModule::emit_metagraph_digest(netuid, &[false, true]);
+        System::assert_last_event(
+            Event::MetagraphDigest {
+                netuid,
+                registered: vec![0, 1],
+                pruned: vec![0],
+                permits_granted: vec![0],
+                permits_revoked: vec![1],
+            }
+            .into(),
+        );
+
+        // The change tracking is reset for the next tempo.
+        assert!(MetagraphRegisteredUids::<Test>::get(netuid).is_empty());
+        assert!(MetagraphPrunedUids::<Test>::get(netuid).is_empty());
+    });
+}
+
+#[test]
+fn test_replace_neuron_settles_pending_emission() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        let pruned_hotkey = U256::from(1);
+        let pruned_coldkey = U256::from(101);
+        register_ok_neuron(netuid, pruned_hotkey, pruned_coldkey, 0);
+        register_ok_neuron(netuid, U256::from(2), U256::from(102), 100_000);
+        let nominator = U256::from(103);
+        Delegates::<Test>::insert(pruned_hotkey, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+            &pruned_coldkey,
+            &pruned_hotkey,
+            300,
+        );
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &pruned_hotkey, 100);
+
+        // uid 0 earned a quarter of the last epoch's emission.
+        Emission::<Test>::insert(netuid, vec![250, 750]);
+        PendingEmission::<Test>::insert(netuid, 1_000);
+        let issuance = SubtensorModule::get_total_issuance();
+
+        // The share is left to the hotkey's next drain, and its stakers are unstaked after it.
+        SubtensorModule::replace_neuron(netuid, 0, &U256::from(3), 0);
+        assert_eq!(PendingEmission::<Test>::get(netuid), 750);
+        assert_eq!(PendingdHotkeyEmission::<Test>::get(pruned_hotkey), 250);
+        assert_eq!(DeferredHotkeyDrains::<Test>::get(), vec![pruned_hotkey]);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&nominator), 0);
+        assert_eq!(SubtensorModule::get_total_issuance(), issuance);
+
+        // The drain pays the share pro rata to the stakers, then the stake is returned.
+        step_block(1);
+        SubtensorModule::continue_unstaking_hotkeys(Weight::MAX);
+        assert!(HotkeyUnstakeQueue::<Test>::get().is_empty());
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&pruned_coldkey),
+            300 + 188
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&nominator), 100 + 62);
+        assert_eq!(SubtensorModule::get_total_issuance(), issuance + 250);
+        System::assert_has_event(
+            Event::PrunedNeuronEmissionSettled {
+                netuid,
+                uid: 0,
+                hotkey: pruned_hotkey,
+                amount: 250,
+                burned: false,
+            }
+            .into(),
+        );
+
+        // With burning enabled the share is dropped from the pending emission without payout.
+        SubtensorModule::set_prune_emission_burn(netuid, true);
+        SubtensorModule::replace_neuron(netuid, 1, &U256::from(4), 0);
+        assert_eq!(PendingEmission::<Test>::get(netuid), 750 - 562);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&U256::from(102)), 0);
+        assert_eq!(SubtensorModule::get_total_issuance(), issuance + 250);
+    });
+}
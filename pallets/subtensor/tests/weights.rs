@@ -4,10 +4,15 @@ mod mock;
 use frame_support::{
     assert_err, assert_ok,
     dispatch::{DispatchClass, DispatchInfo, DispatchResult, GetDispatchInfo, Pays},
-    pallet_prelude::{InvalidTransaction, TransactionValidityError},
+    pallet_prelude::{
+        InvalidTransaction, TransactionSource, TransactionValidityError, ValidateUnsigned,
+    },
+    traits::Get,
 };
 use mock::*;
-use pallet_subtensor::{Error, Owner};
+use pallet_subtensor::{
+    Error, Owner, WeightCommits, WeightSigners, Weights, WeightsFilter, WeightsNonce,
+};
 use sp_core::{H256, U256};
 use sp_runtime::{
     traits::{BlakeTwo256, DispatchInfoOf, Hash, SignedExtension},
@@ -36,7 +41,8 @@ fn test_set_weights_dispatch_info_ok() {
         let dispatch_info = call.get_dispatch_info();
 
         assert_eq!(dispatch_info.class, DispatchClass::Normal);
-        assert_eq!(dispatch_info.pays_fee, Pays::No);
+        // The fee is refunded after dispatch for validators within their free quota.
+        assert_eq!(dispatch_info.pays_fee, Pays::Yes);
     });
 }
 #[test]
@@ -156,7 +162,8 @@ fn test_commit_weights_dispatch_info_ok() {
         let dispatch_info = call.get_dispatch_info();
 
         assert_eq!(dispatch_info.class, DispatchClass::Normal);
-        assert_eq!(dispatch_info.pays_fee, Pays::No);
+        // The fee is refunded after dispatch for permitted validators.
+        assert_eq!(dispatch_info.pays_fee, Pays::Yes);
     });
 }
 
@@ -255,7 +262,8 @@ fn test_reveal_weights_dispatch_info_ok() {
         let dispatch_info = call.get_dispatch_info();
 
         assert_eq!(dispatch_info.class, DispatchClass::Normal);
-        assert_eq!(dispatch_info.pays_fee, Pays::No);
+        // The fee is refunded after dispatch for validators within their free quota.
+        assert_eq!(dispatch_info.pays_fee, Pays::Yes);
     });
 }
 
@@ -819,7 +827,7 @@ fn test_no_signature() {
         let uids: Vec<u16> = vec![];
         let values: Vec<u16> = vec![];
         let result = SubtensorModule::set_weights(RuntimeOrigin::none(), 1, uids, values, 0);
-        assert_eq!(result, Err(DispatchError::BadOrigin));
+        assert_eq!(result, Err(DispatchError::BadOrigin.into()));
     });
 }
 
@@ -972,6 +980,50 @@ fn test_set_weight_too_many_uids() {
     });
 }
 
+// Tests that the weights set fails if you pass more uids than the subnet's max allowed weights
+#[test]
+fn test_set_weight_above_max_allowed_weights() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        add_network(netuid, tempo, 0);
+
+        for i in 0..3 {
+            register_ok_neuron(
+                netuid,
+                U256::from(i),
+                U256::from(i + 10),
+                i as u64 * 100_000,
+            );
+        }
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        SubtensorModule::set_max_allowed_weights(netuid, 2);
+
+        // Should fail because we are setting more weights than the cap, though all uids exist.
+        let result = commit_reveal_set_weights(
+            U256::from(0),
+            netuid,
+            vec![0, 1, 2],
+            vec![10, 10, 10],
+            salt.clone(),
+            0,
+        );
+        assert_eq!(result, Err(Error::<Test>::WeightVecLengthIsHigh.into()));
+
+        // Shouldnt fail because we are setting as many weights as the cap.
+        assert_ok!(commit_reveal_set_weights(
+            U256::from(0),
+            netuid,
+            vec![1, 2],
+            vec![10, 10],
+            salt,
+            0
+        ));
+    });
+}
+
 // Tests that the weights set doesn't panic if you pass weights that sum to larger than u16 max.
 #[test]
 fn test_set_weights_sum_larger_than_u16_max() {
@@ -1008,6 +1060,160 @@ fn test_set_weights_sum_larger_than_u16_max() {
     });
 }
 
+// Tests that stored weights rows drop zero values and are sorted by uid.
+#[test]
+fn test_set_weights_stores_compressed_row() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        add_network(netuid, tempo, 0);
+
+        register_ok_neuron(1, U256::from(1), U256::from(2), 100_000);
+        let neuron_uid: u16 = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(1))
+            .expect("Not registered.");
+        SubtensorModule::set_validator_permit_for_uid(netuid, neuron_uid, true);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+
+        register_ok_neuron(1, U256::from(3), U256::from(4), 300_000);
+        register_ok_neuron(1, U256::from(5), U256::from(6), 500_000);
+        SubtensorModule::set_min_allowed_weights(1, 3);
+
+        let weight_keys: Vec<u16> = vec![2, 0, 1];
+        let weight_values: Vec<u16> = vec![u16::MAX, 0, u16::MAX / 2];
+        assert_ok!(commit_reveal_set_weights(
+            U256::from(1),
+            1,
+            weight_keys,
+            weight_values,
+            salt,
+            0
+        ));
+
+        assert_eq!(
+            Weights::<Test>::get(netuid, neuron_uid),
+            vec![(1, u16::MAX / 2), (2, u16::MAX)]
+        );
+    });
+}
+
+// Tests that uids filtered from a weights set are reported in an event with their reason.
+#[test]
+fn test_set_weights_reports_filtered_uids() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, false);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 100_000);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300_000);
+        register_ok_neuron(netuid, U256::from(5), U256::from(6), 500_000);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(U256::from(1)),
+            netuid,
+            vec![2, 0, 1],
+            vec![u16::MAX, 0, 0],
+            0
+        ));
+        assert_eq!(Weights::<Test>::get(netuid, 0), vec![(2, u16::MAX)]);
+        System::assert_has_event(
+            pallet_subtensor::Event::WeightsFiltered {
+                netuid,
+                uid: 0,
+                filtered_uids: vec![
+                    (0, WeightsFilter::ZeroWeight),
+                    (1, WeightsFilter::ZeroWeight),
+                ],
+            }
+            .into(),
+        );
+
+        // The epoch masks self-weights.
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(U256::from(1)),
+            netuid,
+            vec![0, 2],
+            vec![1, u16::MAX],
+            0
+        ));
+        System::assert_has_event(
+            pallet_subtensor::Event::WeightsFiltered {
+                netuid,
+                uid: 0,
+                filtered_uids: vec![(0, WeightsFilter::SelfWeight)],
+            }
+            .into(),
+        );
+
+        // Zero weights count toward min allowed weights, but the epoch masks a row whose
+        // non-zero weights fall short of it.
+        SubtensorModule::set_min_allowed_weights(netuid, 2);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(U256::from(1)),
+            netuid,
+            vec![1, 2],
+            vec![0, u16::MAX],
+            0
+        ));
+        System::assert_has_event(
+            pallet_subtensor::Event::WeightsFiltered {
+                netuid,
+                uid: 0,
+                filtered_uids: vec![
+                    (1, WeightsFilter::ZeroWeight),
+                    (2, WeightsFilter::BelowWeightFloor),
+                ],
+            }
+            .into(),
+        );
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+
+        // Nothing is reported when every weight is stored.
+        System::reset_events();
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(U256::from(1)),
+            netuid,
+            vec![1, 2],
+            vec![1, u16::MAX],
+            0
+        ));
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::SubtensorModule(pallet_subtensor::Event::WeightsFiltered { .. })
+        )));
+    });
+}
+
+#[test]
+fn test_compress_weights_row() {
+    new_test_ext(0).execute_with(|| {
+        let row: Vec<(u16, u16)> = vec![(5, 1), (2, 0), (0, 7), (3, 0)];
+        assert_eq!(
+            SubtensorModule::compress_weights_row(row),
+            vec![(0, 7), (5, 1)]
+        );
+        assert!(SubtensorModule::compress_weights_row(vec![(1, 0)]).is_empty());
+    });
+}
+
+#[test]
+fn test_check_weights_row_length() {
+    new_test_ext(0).execute_with(|| {
+        let max_len: usize =
+            <Test as pallet_subtensor::Config>::MaxWeightsRowLength::get() as usize;
+        let uids: Vec<u16> = (0..max_len as u16).collect();
+        assert!(SubtensorModule::check_weights_row_length(&uids));
+
+        let too_many: Vec<u16> = (0..=max_len as u16).collect();
+        assert!(!SubtensorModule::check_weights_row_length(&too_many));
+    });
+}
+
 /// Check _truthy_ path for self weight
 #[test]
 fn test_check_length_allows_singleton() {
@@ -1381,14 +1587,8 @@ fn test_commit_reveal_weights_ok() {
         let version_key: u64 = 0;
         let hotkey: U256 = U256::from(1);
 
-        let commit_hash: H256 = BlakeTwo256::hash_of(&(
-            hotkey,
-            netuid,
-            uids.clone(),
-            weight_values.clone(),
-            salt.clone(),
-            version_key,
-        ));
+        let commit_hash: H256 =
+            get_commit_hash(hotkey, netuid, &uids, &weight_values, &salt, version_key);
 
         add_network(netuid, 0, 0);
         register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
@@ -1429,14 +1629,10 @@ fn test_commit_reveal_interval() {
         let version_key: u64 = 0;
         let hotkey: U256 = U256::from(1);
 
-        let commit_hash: H256 = BlakeTwo256::hash_of(&(
-            hotkey,
-            netuid,
-            uids.clone(),
-            weight_values.clone(),
-            salt.clone(),
-            version_key,
-        ));
+        // Each commit is bound to the tempo index of the block it is made at.
+        let commit_hash = || -> H256 {
+            get_commit_hash(hotkey, netuid, &uids, &weight_values, &salt, version_key)
+        };
 
         add_network(netuid, 0, 0);
         register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
@@ -1452,10 +1648,10 @@ fn test_commit_reveal_interval() {
         assert_ok!(SubtensorModule::commit_weights(
             RuntimeOrigin::signed(hotkey),
             netuid,
-            commit_hash
+            commit_hash()
         ));
         assert_err!(
-            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash),
+            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash()),
             Error::<Test>::WeightsCommitNotAllowed
         );
         assert_err!(
@@ -1471,7 +1667,7 @@ fn test_commit_reveal_interval() {
         );
         step_block(99);
         assert_err!(
-            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash),
+            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash()),
             Error::<Test>::WeightsCommitNotAllowed
         );
         assert_err!(
@@ -1497,7 +1693,7 @@ fn test_commit_reveal_interval() {
         assert_ok!(SubtensorModule::commit_weights(
             RuntimeOrigin::signed(hotkey),
             netuid,
-            commit_hash
+            commit_hash()
         ));
         assert_err!(
             SubtensorModule::reveal_weights(
@@ -1524,7 +1720,7 @@ fn test_commit_reveal_interval() {
         assert_ok!(SubtensorModule::commit_weights(
             RuntimeOrigin::signed(hotkey),
             netuid,
-            commit_hash
+            commit_hash()
         ));
         step_block(205);
         assert_err!(
@@ -1543,17 +1739,17 @@ fn test_commit_reveal_interval() {
         assert_ok!(SubtensorModule::commit_weights(
             RuntimeOrigin::signed(hotkey),
             netuid,
-            commit_hash
+            commit_hash()
         ));
         step_block(425);
-        let commit_hash_2: H256 = BlakeTwo256::hash_of(&(
+        let commit_hash_2: H256 = get_commit_hash(
             hotkey,
             netuid,
-            uids.clone(),
-            weight_values.clone(),
-            salt.clone(),
+            &uids,
+            &weight_values,
+            &salt,
             version_key + 1,
-        ));
+        );
         assert_ok!(SubtensorModule::commit_weights(
             RuntimeOrigin::signed(hotkey),
             netuid,
@@ -1602,14 +1798,8 @@ fn test_commit_reveal_hash() {
         SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
         SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
 
-        let commit_hash: H256 = BlakeTwo256::hash_of(&(
-            hotkey,
-            netuid,
-            uids.clone(),
-            weight_values.clone(),
-            salt.clone(),
-            version_key,
-        ));
+        let commit_hash: H256 =
+            get_commit_hash(hotkey, netuid, &uids, &weight_values, &salt, version_key);
 
         assert_ok!(SubtensorModule::commit_weights(
             RuntimeOrigin::signed(hotkey),
@@ -1685,14 +1875,8 @@ fn test_commit_reveal_disabled_or_enabled() {
         let version_key: u64 = 0;
         let hotkey: U256 = U256::from(1);
 
-        let commit_hash: H256 = BlakeTwo256::hash_of(&(
-            hotkey,
-            netuid,
-            uids.clone(),
-            weight_values.clone(),
-            salt.clone(),
-            version_key,
-        ));
+        let commit_hash: H256 =
+            get_commit_hash(hotkey, netuid, &uids, &weight_values, &salt, version_key);
 
         add_network(netuid, 0, 0);
         register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
@@ -1777,15 +1961,6 @@ fn test_toggle_commit_reveal_weights_and_set_weights() {
         let version_key: u64 = 0;
         let hotkey: U256 = U256::from(1);
 
-        let commit_hash: H256 = BlakeTwo256::hash_of(&(
-            hotkey,
-            netuid,
-            uids.clone(),
-            weight_values.clone(),
-            salt.clone(),
-            version_key,
-        ));
-
         add_network(netuid, 0, 0);
         register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
         register_ok_neuron(netuid, U256::from(1), U256::from(2), 100000);
@@ -1810,6 +1985,9 @@ fn test_toggle_commit_reveal_weights_and_set_weights() {
         // Enable Commit/Reveal
         SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
 
+        let commit_hash: H256 =
+            get_commit_hash(hotkey, netuid, &uids, &weight_values, &salt, version_key);
+
         // Commit is enabled the same block
         assert_ok!(SubtensorModule::commit_weights(
             RuntimeOrigin::signed(hotkey),
@@ -1857,14 +2035,8 @@ fn test_commit_reveal_bad_salt_fail() {
         let version_key: u64 = 0;
         let hotkey: U256 = U256::from(1);
 
-        let commit_hash: H256 = BlakeTwo256::hash_of(&(
-            hotkey,
-            netuid,
-            uids.clone(),
-            weight_values.clone(),
-            salt.clone(),
-            version_key,
-        ));
+        let commit_hash: H256 =
+            get_commit_hash(hotkey, netuid, &uids, &weight_values, &salt, version_key);
 
         add_network(netuid, 0, 0);
         register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
@@ -1898,6 +2070,221 @@ fn test_commit_reveal_bad_salt_fail() {
     });
 }
 
+#[test]
+fn test_commit_reveal_hash_bound_to_netuid_and_tempo() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let other_netuid: u16 = 2;
+        let uids: Vec<u16> = vec![0, 1];
+        let weight_values: Vec<u16> = vec![10, 10];
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let version_key: u64 = 0;
+        let hotkey: U256 = U256::from(1);
+
+        add_network(netuid, 0, 0);
+        add_network(other_netuid, 0, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        register_ok_neuron(other_netuid, hotkey, U256::from(2), 200000);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        for id in [netuid, other_netuid] {
+            SubtensorModule::set_weights_set_rate_limit(id, 5);
+            SubtensorModule::set_commit_reveal_weights_interval(id, 5);
+            SubtensorModule::set_commit_reveal_weights_enabled(id, true);
+        }
+
+        // A commit made for the next tempo cannot be revealed in this one.
+        let block: u64 = SubtensorModule::get_current_block_as_u64();
+        let tempo_index: u64 = SubtensorModule::get_commit_tempo_index(netuid, block);
+        let next_tempo_hash: H256 = SubtensorModule::get_commit_hash(
+            &hotkey,
+            netuid,
+            tempo_index + 1,
+            &uids,
+            &weight_values,
+            &salt,
+            version_key,
+        );
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            next_tempo_hash
+        ));
+
+        // A commit made for netuid cannot be replayed on other_netuid.
+        let netuid_hash: H256 =
+            get_commit_hash(hotkey, netuid, &uids, &weight_values, &salt, version_key);
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            other_netuid,
+            netuid_hash
+        ));
+
+        step_block(5);
+
+        assert_err!(
+            SubtensorModule::reveal_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                weight_values.clone(),
+                salt.clone(),
+                version_key,
+            ),
+            Error::<Test>::CommitHashTempoMismatch
+        );
+        assert_err!(
+            SubtensorModule::reveal_weights(
+                RuntimeOrigin::signed(hotkey),
+                other_netuid,
+                uids.clone(),
+                weight_values.clone(),
+                salt.clone(),
+                version_key,
+            ),
+            Error::<Test>::InvalidRevealCommitHashNotMatch
+        );
+
+        // A commit bound to its own subnet and tempo is revealed.
+        let commit_hash: H256 =
+            get_commit_hash(hotkey, netuid, &uids, &weight_values, &salt, version_key);
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+        step_block(5);
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids,
+            weight_values,
+            salt,
+            version_key,
+        ));
+    });
+}
+
+#[test]
+fn test_commit_reveal_survives_interval_change() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let uids: Vec<u16> = vec![0, 1];
+        let weight_values: Vec<u16> = vec![10, 10];
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let version_key: u64 = 0;
+        let hotkey: U256 = U256::from(1);
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        // Commit at block 6, in tempo index 1 of an interval of 5.
+        step_block(5);
+        let commit_hash: H256 =
+            get_commit_hash(hotkey, netuid, &uids, &weight_values, &salt, version_key);
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+        assert_eq!(
+            WeightCommits::<Test>::get(netuid, hotkey),
+            Some((commit_hash, 6, Some(1)))
+        );
+
+        // With an interval of 3 block 6 falls in tempo index 2, the reveal still uses 1.
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 3);
+        step_block(3);
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids,
+            weight_values,
+            salt,
+            version_key,
+        ));
+    });
+}
+
+#[test]
+fn test_reveal_commit_made_before_tempo_index() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let uids: Vec<u16> = vec![0, 1];
+        let weight_values: Vec<u16> = vec![10, 10];
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let version_key: u64 = 0;
+        let hotkey: U256 = U256::from(1);
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        // A commit left in flight by the upgrade carries no tempo index.
+        let legacy_hash: H256 = SubtensorModule::get_legacy_commit_hash(
+            &hotkey,
+            netuid,
+            &uids,
+            &weight_values,
+            &salt,
+            version_key,
+        );
+        WeightCommits::<Test>::insert(
+            netuid,
+            hotkey,
+            (
+                legacy_hash,
+                SubtensorModule::get_current_block_as_u64(),
+                None,
+            ),
+        );
+
+        step_block(5);
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids,
+            weight_values,
+            salt,
+            version_key,
+        ));
+    });
+}
+
+/// Returns the hash a hotkey commits at the current block to reveal the given weights.
+fn get_commit_hash(
+    hotkey: U256,
+    netuid: u16,
+    uids: &[u16],
+    values: &[u16],
+    salt: &[u16],
+    version_key: u64,
+) -> H256 {
+    let block: u64 = SubtensorModule::get_current_block_as_u64();
+    let tempo_index: u64 = SubtensorModule::get_commit_tempo_index(netuid, block);
+    SubtensorModule::get_commit_hash(
+        &hotkey,
+        netuid,
+        tempo_index,
+        uids,
+        values,
+        salt,
+        version_key,
+    )
+}
+
 fn commit_reveal_set_weights(
     hotkey: U256,
     netuid: u16,
@@ -1910,14 +2297,7 @@ fn commit_reveal_set_weights(
     SubtensorModule::set_weights_set_rate_limit(netuid, 5);
     SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
 
-    let commit_hash: H256 = BlakeTwo256::hash_of(&(
-        hotkey,
-        netuid,
-        uids.clone(),
-        weights.clone(),
-        salt.clone(),
-        version_key,
-    ));
+    let commit_hash: H256 = get_commit_hash(hotkey, netuid, &uids, &weights, &salt, version_key);
 
     SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash)?;
 
@@ -1934,3 +2314,345 @@ fn commit_reveal_set_weights(
 
     Ok(())
 }
+
+// Tests that an appointed weights signer sets weights on behalf of its hotkey.
+#[test]
+fn test_weights_signer_sets_weights_for_hotkey() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let signer = U256::from(3);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 10);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, false);
+
+        // A registered hotkey cannot be appointed as a signer.
+        assert_err!(
+            SubtensorModule::set_weights_signer(RuntimeOrigin::signed(hotkey), hotkey),
+            Error::<Test>::WeightsSignerAlreadyInUse
+        );
+
+        // Without a signer the call is made for the signer's own (unregistered) account.
+        assert_err!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(signer),
+                netuid,
+                vec![0],
+                vec![1],
+                0
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+
+        assert_ok!(SubtensorModule::set_weights_signer(
+            RuntimeOrigin::signed(hotkey),
+            signer
+        ));
+        assert_eq!(WeightSigners::<Test>::get(hotkey), Some(signer));
+
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(signer),
+            netuid,
+            vec![0],
+            vec![1],
+            0
+        ));
+        // The weights are stored under the hotkey's uid.
+        assert_eq!(
+            Weights::<Test>::get(netuid, 0)
+                .iter()
+                .map(|(uid, _)| *uid)
+                .collect::<Vec<u16>>(),
+            vec![0]
+        );
+
+        // Once removed the signer has no authority left.
+        assert_ok!(SubtensorModule::remove_weights_signer(
+            RuntimeOrigin::signed(hotkey)
+        ));
+        assert_err!(
+            SubtensorModule::remove_weights_signer(RuntimeOrigin::signed(hotkey)),
+            Error::<Test>::NoWeightsSigner
+        );
+        assert_eq!(SubtensorModule::get_weights_hotkey(&signer, netuid), signer);
+
+        // A signer that registers itself sets weights for its own uid on that subnet.
+        assert_ok!(SubtensorModule::set_weights_signer(
+            RuntimeOrigin::signed(hotkey),
+            signer
+        ));
+        register_ok_neuron(netuid, signer, coldkey, 20);
+        assert_eq!(SubtensorModule::get_weights_hotkey(&signer, netuid), signer);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(signer),
+            netuid,
+            vec![1],
+            vec![1],
+            0
+        ));
+        assert_eq!(
+            Weights::<Test>::get(netuid, 1)
+                .iter()
+                .map(|(uid, _)| *uid)
+                .collect::<Vec<u16>>(),
+            vec![1]
+        );
+        // It still signs for the hotkey on subnets it is not registered on.
+        assert_eq!(
+            SubtensorModule::get_weights_hotkey(&signer, netuid + 1),
+            hotkey
+        );
+    });
+}
+
+#[test]
+fn test_set_weights_unsigned() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 10);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, false);
+
+        let sign = |nonce: u64| {
+            let payload =
+                SubtensorModule::weights_signature_payload(&hotkey, netuid, &[0], &[1], 0, nonce);
+            TestWeightsSignature(hotkey, BlakeTwo256::hash(&payload))
+        };
+        let call = |nonce: u64, signature: TestWeightsSignature| {
+            pallet_subtensor::Call::<Test>::set_weights_unsigned {
+                hotkey,
+                netuid,
+                dests: vec![0],
+                weights: vec![1],
+                version_key: 0,
+                nonce,
+                signature,
+            }
+        };
+
+        // Payloads signed by another key or for a future nonce are not accepted.
+        assert_eq!(
+            SubtensorModule::validate_unsigned(
+                TransactionSource::External,
+                &call(0, TestWeightsSignature(coldkey, sign(0).1))
+            ),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::BadProof
+            ))
+        );
+        assert_eq!(
+            SubtensorModule::validate_unsigned(TransactionSource::External, &call(1, sign(1))),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Future
+            ))
+        );
+        assert_ok!(SubtensorModule::validate_unsigned(
+            TransactionSource::External,
+            &call(0, sign(0))
+        ));
+        // Payloads signed for another chain are not accepted either.
+        let foreign = sign(0);
+        frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+        assert_eq!(
+            SubtensorModule::validate_unsigned(TransactionSource::External, &call(0, foreign)),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::BadProof
+            ))
+        );
+
+        assert_ok!(SubtensorModule::set_weights_unsigned(
+            RuntimeOrigin::none(),
+            hotkey,
+            netuid,
+            vec![0],
+            vec![1],
+            0,
+            0,
+            sign(0)
+        ));
+        assert_eq!(
+            Weights::<Test>::get(netuid, 0)
+                .iter()
+                .map(|(uid, _)| *uid)
+                .collect::<Vec<u16>>(),
+            vec![0]
+        );
+        assert_eq!(WeightsNonce::<Test>::get(hotkey), 1);
+
+        // The consumed nonce cannot be replayed.
+        assert_eq!(
+            SubtensorModule::validate_unsigned(TransactionSource::External, &call(0, sign(0))),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Stale))
+        );
+        assert_err!(
+            SubtensorModule::set_weights_unsigned(
+                RuntimeOrigin::none(),
+                hotkey,
+                netuid,
+                vec![0],
+                vec![1],
+                0,
+                0,
+                sign(0)
+            ),
+            Error::<Test>::InvalidWeightsNonce
+        );
+        assert_err!(
+            SubtensorModule::set_weights_unsigned(
+                RuntimeOrigin::signed(hotkey),
+                hotkey,
+                netuid,
+                vec![0],
+                vec![1],
+                0,
+                1,
+                sign(1)
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_set_weights_within_weights_immunity_period() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 10);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, false);
+        SubtensorModule::set_weights_immunity_period(netuid, 5);
+
+        assert_err!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![0],
+                vec![1],
+                0
+            ),
+            Error::<Test>::WeightsImmunityPeriodActive
+        );
+
+        step_block(5);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![0],
+            vec![1],
+            0
+        ));
+    });
+}
+
+#[test]
+fn test_set_aux_scores() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        let validator = U256::from(1);
+        let miner = U256::from(3);
+        add_network(netuid, tempo, 0);
+        register_ok_neuron(netuid, validator, U256::from(2), 10);
+        register_ok_neuron(netuid, miner, U256::from(4), 20);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+
+        let set = |hotkey: U256, kind: u16, uids: Vec<u16>, values: Vec<u16>| {
+            SubtensorModule::set_aux_scores(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                kind,
+                uids,
+                values,
+            )
+        };
+
+        assert_err!(
+            set(validator, 4, vec![1], vec![10]),
+            Error::<Test>::InvalidAuxScoreKind
+        );
+        assert_err!(
+            set(validator, 0, vec![1], vec![10, 20]),
+            Error::<Test>::WeightVecNotEqualSize
+        );
+        assert_err!(
+            set(miner, 0, vec![1], vec![10]),
+            Error::<Test>::NeuronNoValidatorPermit
+        );
+        assert_err!(
+            set(validator, 0, vec![1, 1], vec![10, 20]),
+            Error::<Test>::DuplicateUids
+        );
+        assert_err!(
+            set(validator, 0, vec![2], vec![10]),
+            Error::<Test>::UidVecContainInvalidOne
+        );
+
+        assert_ok!(set(validator, 0, vec![0, 1], vec![10, 20]));
+        assert_ok!(set(validator, 3, vec![1], vec![30]));
+        assert_eq!(
+            SubtensorModule::get_aux_scores(netuid, 0, 0),
+            vec![(0, 10), (1, 20)]
+        );
+        assert_eq!(SubtensorModule::get_aux_scores(netuid, 0, 3), vec![(1, 30)]);
+        // Weights are untouched.
+        assert!(Weights::<Test>::get(netuid, 0).is_empty());
+
+        // The scores are dropped at the next epoch.
+        let blocks_to_step: u16 = SubtensorModule::blocks_until_next_epoch(
+            netuid,
+            tempo,
+            SubtensorModule::get_current_block_as_u64(),
+        ) as u16;
+        step_block(blocks_to_step);
+        assert!(SubtensorModule::get_aux_scores(netuid, 0, 0).is_empty());
+        assert!(SubtensorModule::get_aux_scores(netuid, 0, 3).is_empty());
+    });
+}
+
+#[test]
+fn test_set_weights_free_quota_for_validators() {
+    new_test_ext(0).execute_with(|| {
+        let netuid: u16 = 1;
+        let validator = U256::from(1);
+        let miner = U256::from(3);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, validator, U256::from(2), 10);
+        register_ok_neuron(netuid, miner, U256::from(4), 20);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, false);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+
+        let set = |hotkey: U256, dest: u16| {
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![dest],
+                vec![1],
+                0,
+            )
+            .map(|info| info.pays_fee)
+        };
+
+        // One free call per window for the permitted validator, paid beyond it.
+        assert_eq!(set(validator, 1), Ok(Pays::No));
+        assert_eq!(set(validator, 1), Ok(Pays::Yes));
+        // Hotkeys without a validator permit always pay.
+        assert_eq!(set(miner, 1), Ok(Pays::Yes));
+
+        step_block(1);
+        assert_eq!(set(validator, 1), Ok(Pays::No));
+
+        // Commits are already limited to one per interval, so permitted validators commit free.
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        let commit = |hotkey: U256| {
+            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, H256::zero())
+                .map(|info| info.pays_fee)
+        };
+        assert_eq!(commit(validator), Ok(Pays::No));
+        assert_eq!(commit(miner), Ok(Pays::Yes));
+    });
+}
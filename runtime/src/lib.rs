@@ -155,12 +155,73 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 /// up by `pallet_aura` to implement `fn slot_duration()`.
 ///
 /// Change this to adjust the block time.
+pub const MILLISECS_PER_BLOCK: u64 = block_timing::MILLISECS_PER_BLOCK;
+
+/// Block time and every parameter counted in blocks that must shrink along with it. The
+/// `fast-blocks` feature swaps in the development set, keeping tempo, rate limits, immunity,
+/// schedules and difficulty floors consistent with each other for local testing.
 #[cfg(not(feature = "fast-blocks"))]
-pub const MILLISECS_PER_BLOCK: u64 = 12000;
+mod block_timing {
+    use super::BlockNumber;
+
+    pub const MILLISECS_PER_BLOCK: u64 = 12000;
+    pub const SUBNET_TEMPO: u16 = 99;
+    pub const ADJUSTMENT_INTERVAL: u16 = 100;
+    pub const IMMUNITY_PERIOD: u16 = 4096;
+    pub const ACTIVITY_CUTOFF: u16 = 5000;
+    pub const DIFFICULTY: u64 = 10_000_000;
+    pub const MIN_DIFFICULTY: u64 = 10_000_000;
+    pub const SERVING_RATE_LIMIT: u64 = 50;
+    pub const TX_RATE_LIMIT: u64 = 1000;
+    pub const DELEGATE_TAKE_RATE_LIMIT: u64 = 216000; // 30 days at 12 seconds per block
+    pub const CHILDKEY_TAKE_RATE_LIMIT: u64 = 216000; // 30 days at 12 seconds per block
+    pub const NETWORK_IMMUNITY: u64 = 7 * 7200; // 7 days
+    pub const NETWORK_LOCK_REDUCTION_INTERVAL: u64 = 14 * 7200; // 14 days
+    pub const NETWORK_RATE_LIMIT: u64 = 7200; // 1 day
+    pub const HOTKEY_EMISSION_TEMPO: u64 = 7200; // Drain every day.
+    pub const SCHEDULE_DURATION: BlockNumber = 5 * 24 * 60 * 60 / 12; // 5 days
+    pub const COMMITMENT_RATE_LIMIT: BlockNumber = 100;
+    pub const TAKE_CHANGE_PROPOSAL_EXPIRY: u64 = 7200; // 1 day
+    pub const METRICS_RETENTION: u64 = 7200; // 1 day
+    pub const CONVICTION_LOCK_PERIOD: u64 = 50400; // 1 week per conviction level
+    pub const STAKE_CHECKPOINT_INTERVAL: u64 = 7200; // 1 day
+    pub const DELEGATE_INACTIVITY_GRACE_PERIOD: u64 = 50400; // 1 week
+    pub const DELEGATE_STATS_INTERVAL: u64 = 360; // 72 minutes
+    pub const PUBLIC_GOOD_RELEASE_DELAY: u64 = 30 * 7200; // 30 days
+    pub const OWNER_TEMPO_CHANGE_RATE_LIMIT: u64 = 7200; // 1 day
+}
 
 /// Fast blocks for development
 #[cfg(feature = "fast-blocks")]
-pub const MILLISECS_PER_BLOCK: u64 = 250;
+mod block_timing {
+    use super::BlockNumber;
+
+    pub const MILLISECS_PER_BLOCK: u64 = 250;
+    pub const SUBNET_TEMPO: u16 = 10;
+    pub const ADJUSTMENT_INTERVAL: u16 = 10;
+    pub const IMMUNITY_PERIOD: u16 = 20;
+    pub const ACTIVITY_CUTOFF: u16 = 500;
+    pub const DIFFICULTY: u64 = 10_000;
+    pub const MIN_DIFFICULTY: u64 = 10_000;
+    pub const SERVING_RATE_LIMIT: u64 = 0;
+    pub const TX_RATE_LIMIT: u64 = 0;
+    pub const DELEGATE_TAKE_RATE_LIMIT: u64 = 5;
+    pub const CHILDKEY_TAKE_RATE_LIMIT: u64 = 5;
+    pub const NETWORK_IMMUNITY: u64 = 100;
+    pub const NETWORK_LOCK_REDUCTION_INTERVAL: u64 = 200;
+    pub const NETWORK_RATE_LIMIT: u64 = 10;
+    pub const HOTKEY_EMISSION_TEMPO: u64 = 10;
+    pub const SCHEDULE_DURATION: BlockNumber = 50;
+    pub const COMMITMENT_RATE_LIMIT: BlockNumber = 10;
+    pub const TAKE_CHANGE_PROPOSAL_EXPIRY: u64 = 100;
+    pub const METRICS_RETENTION: u64 = 100;
+    pub const CONVICTION_LOCK_PERIOD: u64 = 50;
+    pub const STAKE_CHECKPOINT_INTERVAL: u64 = 10;
+    pub const DELEGATE_INACTIVITY_GRACE_PERIOD: u64 = 100;
+    pub const DELEGATE_STATS_INTERVAL: u64 = 10;
+    pub const PUBLIC_GOOD_RELEASE_DELAY: u64 = 100;
+    pub const OWNER_TEMPO_CHANGE_RATE_LIMIT: u64 = 10;
+}
 
 // NOTE: Currently it is not possible to change the slot duration after the chain has started.
 //       Attempting to do so will brick block production.
@@ -318,6 +379,7 @@ impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
                     pallet_subtensor::Call::set_weights { .. }
                         | pallet_subtensor::Call::set_root_weights { .. }
                         | pallet_subtensor::Call::serve_axon { .. }
+                        | pallet_subtensor::Call::serve_axon_with_capabilities { .. }
                 )
                 | RuntimeCall::Commitments(pallet_commitments::Call::set_commitment { .. })
         )
@@ -648,6 +710,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_register_with_challenge { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::bid_for_registration { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::reveal_registration_bid { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
             ),
             ProxyType::Transfer => matches!(
@@ -672,6 +743,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::dissolve_network { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_register_with_challenge { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::bid_for_registration { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::reveal_registration_bid { .. }
+                    )
                     | RuntimeCall::Triumvirate(..)
             ),
             ProxyType::Triumvirate => matches!(
@@ -694,6 +774,18 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::burned_register_with_challenge { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::register_with_challenge { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::bid_for_registration { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::reveal_registration_bid { .. }
+                    )
             ),
         }
     }
@@ -844,7 +936,7 @@ parameter_types! {
     pub const MaxCommitFields: u32 = 1;
     pub const CommitmentInitialDeposit: Balance = 0; // Free
     pub const CommitmentFieldDeposit: Balance = 0; // Free
-    pub const CommitmentRateLimit: BlockNumber = 100; // Allow commitment every 100 blocks
+    pub const CommitmentRateLimit: BlockNumber = block_timing::COMMITMENT_RATE_LIMIT;
 }
 
 pub struct AllowCommitments;
@@ -858,6 +950,11 @@ impl CanCommit<AccountId> for AllowCommitments {
     fn can_commit(_: u16, _: &AccountId) -> bool {
         true
     }
+
+    // Only registered hotkeys may commit, so a subnet never needs more.
+    fn max_commitments(netuid: u16) -> u32 {
+        SubtensorModule::get_max_allowed_uids(netuid) as u32
+    }
 }
 
 impl pallet_commitments::Config for Runtime {
@@ -873,18 +970,6 @@ impl pallet_commitments::Config for Runtime {
     type RateLimit = CommitmentRateLimit;
 }
 
-#[cfg(not(feature = "fast-blocks"))]
-pub const INITIAL_SUBNET_TEMPO: u16 = 99;
-
-#[cfg(feature = "fast-blocks")]
-pub const INITIAL_SUBNET_TEMPO: u16 = 10;
-
-#[cfg(not(feature = "fast-blocks"))]
-pub const INITIAL_CHILDKEY_TAKE_RATELIMIT: u64 = 216000; // 30 days at 12 seconds per block
-
-#[cfg(feature = "fast-blocks")]
-pub const INITIAL_CHILDKEY_TAKE_RATELIMIT: u64 = 5;
-
 // Configure the pallet subtensor.
 parameter_types! {
     pub const SubtensorInitialRho: u16 = 10;
@@ -897,13 +982,13 @@ parameter_types! {
     pub const SubtensorInitialValidatorPruneLen: u64 = 1;
     pub const SubtensorInitialScalingLawPower: u16 = 50; // 0.5
     pub const SubtensorInitialMaxAllowedValidators: u16 = 128;
-    pub const SubtensorInitialTempo: u16 = INITIAL_SUBNET_TEMPO;
-    pub const SubtensorInitialDifficulty: u64 = 10_000_000;
-    pub const SubtensorInitialAdjustmentInterval: u16 = 100;
+    pub const SubtensorInitialTempo: u16 = block_timing::SUBNET_TEMPO;
+    pub const SubtensorInitialDifficulty: u64 = block_timing::DIFFICULTY;
+    pub const SubtensorInitialAdjustmentInterval: u16 = block_timing::ADJUSTMENT_INTERVAL;
     pub const SubtensorInitialAdjustmentAlpha: u64 = 0; // no weight to previous value.
     pub const SubtensorInitialTargetRegistrationsPerInterval: u16 = 2;
-    pub const SubtensorInitialImmunityPeriod: u16 = 4096;
-    pub const SubtensorInitialActivityCutoff: u16 = 5000;
+    pub const SubtensorInitialImmunityPeriod: u16 = block_timing::IMMUNITY_PERIOD;
+    pub const SubtensorInitialActivityCutoff: u16 = block_timing::ACTIVITY_CUTOFF;
     pub const SubtensorInitialMaxRegistrationsPerBlock: u16 = 1;
     pub const SubtensorInitialPruningScore : u16 = u16::MAX;
     pub const SubtensorInitialBondsMovingAverage: u64 = 900_000;
@@ -913,34 +998,60 @@ parameter_types! {
     pub const SubtensorInitialMinChildKeyTake: u16 = 0; // 0 %
     pub const SubtensorInitialMaxChildKeyTake: u16 = 11_796; // 18 %
     pub const SubtensorInitialWeightsVersionKey: u64 = 0;
-    pub const SubtensorInitialMinDifficulty: u64 = 10_000_000;
+    pub const SubtensorInitialMinDifficulty: u64 = block_timing::MIN_DIFFICULTY;
     pub const SubtensorInitialMaxDifficulty: u64 = u64::MAX / 4;
-    pub const SubtensorInitialServingRateLimit: u64 = 50;
+    pub const SubtensorInitialServingRateLimit: u64 = block_timing::SERVING_RATE_LIMIT;
     pub const SubtensorInitialBurn: u64 = 1_000_000_000; // 1 tao
     pub const SubtensorInitialMinBurn: u64 = 1_000_000_000; // 1 tao
     pub const SubtensorInitialMaxBurn: u64 = 100_000_000_000; // 100 tao
-    pub const SubtensorInitialTxRateLimit: u64 = 1000;
-    pub const SubtensorInitialTxDelegateTakeRateLimit: u64 = 216000; // 30 days at 12 seconds per block
-    pub const SubtensorInitialTxChildKeyTakeRateLimit: u64 = INITIAL_CHILDKEY_TAKE_RATELIMIT;
+    pub const SubtensorInitialTxRateLimit: u64 = block_timing::TX_RATE_LIMIT;
+    pub const SubtensorInitialTxDelegateTakeRateLimit: u64 = block_timing::DELEGATE_TAKE_RATE_LIMIT;
+    pub const SubtensorInitialTxChildKeyTakeRateLimit: u64 = block_timing::CHILDKEY_TAKE_RATE_LIMIT;
     pub const SubtensorInitialRAORecycledForRegistration: u64 = 0; // 0 rao
     pub const SubtensorInitialSenateRequiredStakePercentage: u64 = 1; // 1 percent of total stake
-    pub const SubtensorInitialNetworkImmunity: u64 = 7 * 7200;
+    pub const SubtensorInitialNetworkImmunity: u64 = block_timing::NETWORK_IMMUNITY;
     pub const SubtensorInitialMinAllowedUids: u16 = 128;
     pub const SubtensorInitialMinLockCost: u64 = 1_000_000_000_000; // 1000 TAO
     pub const SubtensorInitialSubnetOwnerCut: u16 = 11_796; // 18 percent
     pub const SubtensorInitialSubnetLimit: u16 = 12;
-    pub const SubtensorInitialNetworkLockReductionInterval: u64 = 14 * 7200;
-    pub const SubtensorInitialNetworkRateLimit: u64 = 7200;
+    pub const SubtensorInitialNetworkLockReductionInterval: u64 = block_timing::NETWORK_LOCK_REDUCTION_INTERVAL;
+    pub const SubtensorInitialNetworkRateLimit: u64 = block_timing::NETWORK_RATE_LIMIT;
     pub const SubtensorInitialTargetStakesPerInterval: u16 = 1;
     pub const SubtensorInitialKeySwapCost: u64 = 1_000_000_000;
     pub const InitialAlphaHigh: u16 = 58982; // Represents 0.9 as per the production default
     pub const InitialAlphaLow: u16 = 45875; // Represents 0.7 as per the production default
     pub const InitialLiquidAlphaOn: bool = false; // Default value for LiquidAlphaOn
-    pub const SubtensorInitialHotkeyEmissionTempo: u64 = 7200; // Drain every day.
+    pub const SubtensorInitialHotkeyEmissionTempo: u64 = block_timing::HOTKEY_EMISSION_TEMPO;
     pub const SubtensorInitialNetworkMaxStake: u64 = u64::MAX; // Maximum possible value for u64, this make the make stake infinity
-    pub const  InitialColdkeySwapScheduleDuration: BlockNumber = 5 * 24 * 60 * 60 / 12; // 5 days
-    pub const  InitialDissolveNetworkScheduleDuration: BlockNumber = 5 * 24 * 60 * 60 / 12; // 5 days
-
+    pub const  InitialColdkeySwapScheduleDuration: BlockNumber = block_timing::SCHEDULE_DURATION;
+    pub const  InitialDissolveNetworkScheduleDuration: BlockNumber = block_timing::SCHEDULE_DURATION;
+    pub const SubtensorMaxWeightsRowLength: u32 = 4096; // Matches SubtensorInitialMaxAllowedUids.
+    pub const SubtensorTakeChangeProposalExpiry: u64 = block_timing::TAKE_CHANGE_PROPOSAL_EXPIRY;
+    pub const SubtensorMetricsRetentionBlocks: u64 = block_timing::METRICS_RETENTION;
+    pub const SubtensorInitialSubnetEmissionRampTempos: u16 = 10; // tempos before a new subnet receives its full emission
+    pub const SubtensorConvictionLockPeriod: u64 = block_timing::CONVICTION_LOCK_PERIOD;
+    pub const SubtensorStakeCheckpointInterval: u64 = block_timing::STAKE_CHECKPOINT_INTERVAL;
+    pub const SubtensorMaxStakeCheckpoints: u32 = 30;
+    pub const SubtensorBlocksPerYear: u64 = DAYS as u64 * 365;
+    pub const SubtensorDelegateInactivityGracePeriod: u64 = block_timing::DELEGATE_INACTIVITY_GRACE_PERIOD;
+    pub const SubtensorMaxAuxScoreKinds: u16 = 8;
+    pub SubtensorBlockStepWeightLimit: Weight = Perbill::from_percent(25) *
+        BlockWeights::get().max_block;
+    pub const SubtensorDelegateStatsInterval: u64 = block_timing::DELEGATE_STATS_INTERVAL;
+    pub const SubtensorInitialMaxMinStakeToRegister: u64 = 1_000_000_000_000; // 1000 TAO
+    pub const SubtensorInitialMaxReservedUids: u16 = 8;
+    pub const SubtensorMaxPortfolioHotkeys: u32 = 32;
+    pub const SubtensorTakeIncreaseScheduleDuration: BlockNumber = block_timing::SCHEDULE_DURATION;
+    pub const SubtensorTreasuryProposalDeposit: u64 = 1_000_000_000; // 1 TAO
+    pub const SubtensorMaxRegistrationBids: u32 = 64;
+    // The collective flip can be biased by block authors, so the beacon is off on mainnet.
+    pub const SubtensorRandomnessBeaconEnabled: bool = cfg!(feature = "insecure-randomness-beacon");
+    pub const SubtensorRegistrationBidDeposit: u64 = 100_000_000; // 0.1 TAO
+    pub const SubtensorKeepStakingAccountsAlive: bool = true;
+    pub const SubtensorPublicGoodReleaseDelay: u64 = block_timing::PUBLIC_GOOD_RELEASE_DELAY;
+    pub const SubtensorMinOwnerTempo: u16 = 10;
+    pub const SubtensorOwnerTempoChangeRateLimit: u64 = block_timing::OWNER_TEMPO_CHANGE_RATE_LIMIT;
+    pub const SubtensorMaxReservedHotkeys: u32 = 64;
 }
 
 impl pallet_subtensor::Config for Runtime {
@@ -949,6 +1060,7 @@ impl pallet_subtensor::Config for Runtime {
     type SudoRuntimeCall = RuntimeCall;
     type Currency = Balances;
     type CouncilOrigin = EnsureMajoritySenate;
+    type SenateOrigin = EnsureMajoritySenate;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
     type Scheduler = Scheduler;
@@ -1004,8 +1116,38 @@ impl pallet_subtensor::Config for Runtime {
     type InitialHotkeyEmissionTempo = SubtensorInitialHotkeyEmissionTempo;
     type InitialNetworkMaxStake = SubtensorInitialNetworkMaxStake;
     type Preimages = Preimage;
+    type WeightsSignature = Signature;
+    type WeightsSigner = <Signature as Verify>::Signer;
+    type Randomness = RandomnessCollectiveFlip;
+    type RandomnessBeaconEnabled = SubtensorRandomnessBeaconEnabled;
     type InitialColdkeySwapScheduleDuration = InitialColdkeySwapScheduleDuration;
     type InitialDissolveNetworkScheduleDuration = InitialDissolveNetworkScheduleDuration;
+    type MaxWeightsRowLength = SubtensorMaxWeightsRowLength;
+    type TakeChangeProposalExpiry = SubtensorTakeChangeProposalExpiry;
+    type MetricsRetentionBlocks = SubtensorMetricsRetentionBlocks;
+    type InitialSubnetEmissionRampTempos = SubtensorInitialSubnetEmissionRampTempos;
+    type ConvictionLockPeriod = SubtensorConvictionLockPeriod;
+    type StakeCheckpointInterval = SubtensorStakeCheckpointInterval;
+    type MaxStakeCheckpoints = SubtensorMaxStakeCheckpoints;
+    type BlocksPerYear = SubtensorBlocksPerYear;
+    type DelegateInactivityGracePeriod = SubtensorDelegateInactivityGracePeriod;
+    type MaxAuxScoreKinds = SubtensorMaxAuxScoreKinds;
+    type BlockStepWeightLimit = SubtensorBlockStepWeightLimit;
+    type DelegateStatsInterval = SubtensorDelegateStatsInterval;
+    type InitialMaxMinStakeToRegister = SubtensorInitialMaxMinStakeToRegister;
+    type InitialMaxReservedUids = SubtensorInitialMaxReservedUids;
+    type OnEmission = ();
+    type OnTakeChanged = ();
+    type MaxPortfolioHotkeys = SubtensorMaxPortfolioHotkeys;
+    type TakeIncreaseScheduleDuration = SubtensorTakeIncreaseScheduleDuration;
+    type TreasuryProposalDeposit = SubtensorTreasuryProposalDeposit;
+    type MaxRegistrationBids = SubtensorMaxRegistrationBids;
+    type RegistrationBidDeposit = SubtensorRegistrationBidDeposit;
+    type KeepStakingAccountsAlive = SubtensorKeepStakingAccountsAlive;
+    type PublicGoodReleaseDelay = SubtensorPublicGoodReleaseDelay;
+    type MinOwnerTempo = SubtensorMinOwnerTempo;
+    type OwnerTempoChangeRateLimit = SubtensorOwnerTempoChangeRateLimit;
+    type MaxReservedHotkeys = SubtensorMaxReservedHotkeys;
 }
 
 use sp_runtime::BoundedVec;
@@ -1378,6 +1520,35 @@ impl_runtime_apis! {
             let result = SubtensorModule::get_delegated(delegatee_account_vec);
             result.encode()
         }
+
+        fn get_nominators(delegate_account_vec: Vec<u8>, start: u32, limit: u32) -> Vec<u8> {
+            let result = SubtensorModule::get_nominators(delegate_account_vec, start, limit);
+            result.encode()
+        }
+
+        fn get_delegate_apr(delegate_account_vec: Vec<u8>, window: u32) -> u64 {
+            SubtensorModule::get_delegate_apr(delegate_account_vec, window)
+        }
+
+        fn get_delegate_stats(delegate_account_vec: Vec<u8>) -> Vec<u8> {
+            let _result = SubtensorModule::get_delegate_stats(delegate_account_vec);
+            if _result.is_some() {
+                let result = _result.expect("Could not get DelegateStats");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_delegate_yield(delegate_account_vec: Vec<u8>, netuid: u16) -> Vec<u8> {
+            let _result = SubtensorModule::get_delegate_yield(delegate_account_vec, netuid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get DelegateYield");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::NeuronInfoRuntimeApi<Block> for Runtime {
@@ -1410,6 +1581,21 @@ impl_runtime_apis! {
                 vec![]
             }
         }
+
+        fn get_metagraph_fields(netuid: u16, fields: u32) -> Vec<u8> {
+            let _result = SubtensorModule::get_metagraph_fields(netuid, fields);
+            if _result.is_some() {
+                let result = _result.expect("Could not get MetagraphFields");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_netuids_for_hotkey( hotkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let result = SubtensorModule::get_netuids_for_hotkey( hotkey_account_vec );
+            result.encode()
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetInfoRuntimeApi<Block> for Runtime {
@@ -1452,6 +1638,28 @@ impl_runtime_apis! {
                 vec![]
             }
         }
+
+        fn get_pending_emission(netuid: u16) -> u64 {
+            SubtensorModule::get_pending_emission(netuid)
+        }
+
+        fn get_subnet_state(netuid: u16) -> Vec<u8> {
+            let _result = SubtensorModule::get_subnet_state(netuid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get SubnetState");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_subnet_randomness(netuid: u16) -> Vec<u8> {
+            SubtensorModule::get_subnet_randomness(netuid).encode()
+        }
+
+        fn get_epoch_checksums(netuid: u16) -> Vec<u8> {
+            SubtensorModule::get_epoch_checksums(netuid).encode()
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::StakeInfoRuntimeApi<Block> for Runtime {
@@ -1464,6 +1672,50 @@ impl_runtime_apis! {
             let result = SubtensorModule::get_stake_info_for_coldkeys( coldkey_account_vecs );
             result.encode()
         }
+
+        fn get_emission_by_netuid( hotkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let result = SubtensorModule::get_emission_by_netuid( hotkey_account_vec );
+            result.encode()
+        }
+
+        fn get_hotkey_pending_emission( hotkey_account_vec: Vec<u8> ) -> u64 {
+            SubtensorModule::get_hotkey_pending_emission( hotkey_account_vec )
+        }
+
+        fn get_stake_distribution( netuid: u16 ) -> Vec<u8> {
+            let _result = SubtensorModule::get_stake_distribution( netuid );
+            if _result.is_some() {
+                let result = _result.expect("Could not get StakeDistribution");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_global_stake_distribution() -> Vec<u8> {
+            let result = SubtensorModule::get_global_stake_distribution();
+            result.encode()
+        }
+
+        fn get_coldkey_report( coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::get_coldkey_report( coldkey_account_vec );
+            if _result.is_some() {
+                let result = _result.expect("Could not get ColdkeyReport");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_scheduled_operations( coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::get_scheduled_operations( coldkey_account_vec );
+            if _result.is_some() {
+                let result = _result.expect("Could not get ScheduledOperations");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block> for Runtime {
@@ -1471,6 +1723,30 @@ impl_runtime_apis! {
             SubtensorModule::get_network_lock_cost()
         }
     }
+
+    impl subtensor_custom_rpc_runtime_api::PreflightRuntimeApi<Block> for Runtime {
+        fn preflight_call( call: Vec<u8>, signer_account_vec: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::preflight_call(call, signer_account_vec);
+            if _result.is_some() {
+                let result = _result.expect("Could not get PreflightResult");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::EpochSimulationRuntimeApi<Block> for Runtime {
+        fn simulate_epoch( netuid: u16, rao_emission: u64, stake_overrides: Vec<u8> ) -> Vec<u8> {
+            let _result = SubtensorModule::simulate_epoch(netuid, rao_emission, stake_overrides);
+            if _result.is_some() {
+                let result = _result.expect("Could not get EpochSimulation");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
 }
 
 // #[cfg(test)]
@@ -0,0 +1,120 @@
+#![allow(clippy::unwrap_used)]
+
+use codec::{DecodeAll, Encode};
+use node_subtensor_runtime::{
+    AccountId, BuildStorage, RuntimeGenesisConfig, SubtensorModule, System,
+};
+use subtensor_custom_rpc::json::{
+    CompactNum, DelegateInfoJson, NeuronInfoLiteJson, SubnetInfoJson,
+};
+
+const HOTKEY: [u8; 32] = [1_u8; 32];
+const COLDKEY: [u8; 32] = [2_u8; 32];
+const NOMINATOR: [u8; 32] = [3_u8; 32];
+
+const NETUID: u16 = 1;
+const TEMPO: u16 = 13;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig::default()
+        .build_storage()
+        .unwrap()
+        .into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// Registers HOTKEY on NETUID, owned by COLDKEY, as a delegate with stake from NOMINATOR.
+fn setup_delegate() {
+    let hotkey = AccountId::from(HOTKEY);
+    let coldkey = AccountId::from(COLDKEY);
+    let nominator = AccountId::from(NOMINATOR);
+
+    SubtensorModule::init_new_network(NETUID, TEMPO);
+    SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+    SubtensorModule::append_neuron(NETUID, &hotkey, 0);
+    SubtensorModule::delegate_hotkey(&hotkey, 11_796);
+    SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 1_000);
+}
+
+#[test]
+fn test_delegate_info_decodes_as_json() {
+    new_test_ext().execute_with(|| {
+        setup_delegate();
+        let hotkey = AccountId::from(HOTKEY);
+
+        let bytes = SubtensorModule::get_delegate(hotkey.encode())
+            .unwrap()
+            .encode();
+        let delegate = DelegateInfoJson::decode_all(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(delegate.delegate_ss58, hotkey);
+        assert_eq!(delegate.take, CompactNum(11_796));
+        assert_eq!(
+            delegate.nominators,
+            vec![(AccountId::from(NOMINATOR), CompactNum(1_000))]
+        );
+        assert_eq!(delegate.owner_ss58, AccountId::from(COLDKEY));
+        assert_eq!(delegate.registrations, vec![CompactNum(NETUID)]);
+
+        // The listing decodes the same way, and accounts are served as SS58 strings.
+        let bytes = SubtensorModule::get_delegates().encode();
+        let delegates = Vec::<DelegateInfoJson>::decode_all(&mut bytes.as_slice()).unwrap();
+        assert_eq!(delegates, vec![delegate]);
+
+        let json = serde_json::to_value(&delegates).unwrap();
+        assert_eq!(json[0]["delegate_ss58"], hotkey.to_string());
+        assert_eq!(json[0]["take"], 11_796);
+    });
+}
+
+#[test]
+fn test_neuron_info_lite_decodes_as_json() {
+    new_test_ext().execute_with(|| {
+        setup_delegate();
+
+        let bytes = SubtensorModule::get_neurons_lite(NETUID).encode();
+        let neurons = Vec::<NeuronInfoLiteJson>::decode_all(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(neurons.len(), 1);
+        let neuron = neurons.first().unwrap();
+        assert_eq!(neuron.hotkey, AccountId::from(HOTKEY));
+        assert_eq!(neuron.coldkey, AccountId::from(COLDKEY));
+        assert_eq!(neuron.uid, CompactNum(0));
+        assert_eq!(neuron.netuid, CompactNum(NETUID));
+        assert_eq!(
+            neuron.stake,
+            vec![(AccountId::from(NOMINATOR), CompactNum(1_000))]
+        );
+
+        let bytes = SubtensorModule::get_neuron_lite(NETUID, 0)
+            .unwrap()
+            .encode();
+        let single = NeuronInfoLiteJson::decode_all(&mut bytes.as_slice()).unwrap();
+        assert_eq!(&single, neuron);
+    });
+}
+
+#[test]
+fn test_subnet_info_decodes_as_json() {
+    new_test_ext().execute_with(|| {
+        setup_delegate();
+
+        let bytes = SubtensorModule::get_subnet_info(NETUID).unwrap().encode();
+        let subnet = SubnetInfoJson::decode_all(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(subnet.netuid, CompactNum(NETUID));
+        assert_eq!(subnet.tempo, CompactNum(TEMPO));
+        assert_eq!(subnet.subnetwork_n, CompactNum(1));
+        assert_eq!(
+            subnet.max_allowed_uids,
+            CompactNum(SubtensorModule::get_max_allowed_uids(NETUID))
+        );
+        assert_eq!(subnet.owner, SubtensorModule::get_subnet_owner(NETUID));
+
+        let bytes = SubtensorModule::get_subnets_info().encode();
+        let subnets = Vec::<Option<SubnetInfoJson>>::decode_all(&mut bytes.as_slice()).unwrap();
+        assert!(subnets.contains(&Some(subnet)));
+    });
+}